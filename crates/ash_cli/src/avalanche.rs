@@ -2,9 +2,12 @@
 // Copyright (c) 2023, E36 Knots
 
 mod blockchain;
+mod evm;
 mod network;
 mod node;
+mod p;
 mod subnet;
+mod tx;
 mod validator;
 mod vm;
 mod wallet;
@@ -14,7 +17,7 @@ mod x;
 // Module that contains the avalanche subcommand parser
 
 use crate::utils::{error::CliError, parsing::*};
-use ash_sdk::avalanche::AvalancheNetwork;
+use ash_sdk::avalanche::{blockchains::AvalancheBlockchain, AvalancheNetwork};
 use clap::{Parser, Subcommand};
 
 #[derive(Parser)]
@@ -28,9 +31,12 @@ pub(crate) struct AvalancheCommand {
 #[derive(Subcommand)]
 enum AvalancheSubcommands {
     Blockchain(blockchain::BlockchainCommand),
+    Evm(evm::EvmCommand),
     Network(network::NetworkCommand),
     Node(node::NodeCommand),
+    P(p::PCommand),
     Subnet(subnet::SubnetCommand),
+    Tx(tx::TxCommand),
     Validator(validator::ValidatorCommand),
     Vm(vm::VmCommand),
     Wallet(wallet::WalletCommand),
@@ -44,6 +50,24 @@ fn load_network(network_name: &str, config: Option<&str>) -> Result<AvalancheNet
         .map_err(|e| CliError::dataerr(format!("Error loading network: {e}")))
 }
 
+// Load a blockchain by its ID or by its name depending on whether chain is an ID
+fn load_blockchain(
+    network: &AvalancheNetwork,
+    chain: &str,
+) -> Result<AvalancheBlockchain, CliError> {
+    let blockchain = match parse_id(chain) {
+        Ok(id) => network
+            .get_blockchain(id)
+            .map_err(|e| CliError::dataerr(format!("Error loading blockchain info: {e}")))?,
+        Err(_) => network
+            .get_blockchain_by_name(chain)
+            .map_err(|e| CliError::dataerr(format!("Error loading blockchain info: {e}")))?,
+    }
+    .clone();
+
+    Ok(blockchain)
+}
+
 // Recursively update the Subnets (and their blockchains)
 fn update_network_subnets(network: &mut AvalancheNetwork) -> Result<(), CliError> {
     network
@@ -66,6 +90,28 @@ fn update_subnet_validators(
     Ok(())
 }
 
+// Update a Subnet's validators along with their BLS public key (signer)
+fn update_subnet_validators_with_signers(
+    network: &mut AvalancheNetwork,
+    subnet_id: &str,
+) -> Result<(), CliError> {
+    network
+        .update_subnet_validators_with_signers(parse_id(subnet_id)?)
+        .map_err(|e| CliError::dataerr(format!("Error updating validators: {e}")))?;
+    Ok(())
+}
+
+// Update a Subnet's pending validators
+fn update_subnet_pending_validators(
+    network: &mut AvalancheNetwork,
+    subnet_id: &str,
+) -> Result<(), CliError> {
+    network
+        .update_subnet_pending_validators(parse_id(subnet_id)?)
+        .map_err(|e| CliError::dataerr(format!("Error updating pending validators: {e}")))?;
+    Ok(())
+}
+
 // Parse avalanche subcommand
 pub(crate) fn parse(
     avalanche: AvalancheCommand,
@@ -74,9 +120,12 @@ pub(crate) fn parse(
 ) -> Result<(), CliError> {
     match avalanche.command {
         AvalancheSubcommands::Blockchain(blockchain) => blockchain::parse(blockchain, config, json),
+        AvalancheSubcommands::Evm(evm) => evm::parse(evm, config, json),
         AvalancheSubcommands::Network(network) => network::parse(network, config, json),
         AvalancheSubcommands::Node(node) => node::parse(node, json),
+        AvalancheSubcommands::P(p) => p::parse(p, config, json),
         AvalancheSubcommands::Subnet(subnet) => subnet::parse(subnet, config, json),
+        AvalancheSubcommands::Tx(tx) => tx::parse(tx, config, json),
         AvalancheSubcommands::Validator(validator) => validator::parse(validator, config, json),
         AvalancheSubcommands::Vm(vm) => vm::parse(vm, json),
         AvalancheSubcommands::Wallet(wallet) => wallet::parse(wallet, config, json),
@@ -4,11 +4,12 @@
 // Module that contains the blockchain subcommand parser
 
 use crate::{
-    avalanche::{wallet::*, *},
+    avalanche::{tx::*, wallet::*, *},
     utils::{error::CliError, parsing::*, templating::*, version_tx_cmd},
 };
 use ash_sdk::avalanche::{
     blockchains::AvalancheBlockchain,
+    txs::p,
     vms::{subnet_evm::AVAX_SUBNET_EVM_ID, AvalancheVmType},
 };
 use async_std::task;
@@ -32,6 +33,7 @@ pub(crate) struct BlockchainCommand {
 }
 
 #[derive(Subcommand)]
+#[allow(clippy::large_enum_variant)]
 enum BlockchainSubcommands {
     /// Create a new blockchain
     #[command(version = version_tx_cmd(true))]
@@ -57,20 +59,19 @@ enum BlockchainSubcommands {
         /// Subnet ID to create the blockchain on
         #[arg(long, short = 's')]
         subnet_id: String,
-        /// Private key to sign the transaction with (must be a control key)
-        #[arg(long, short = 'p', env = "AVALANCHE_PRIVATE_KEY")]
-        private_key: String,
-        /// Private key encoding (cb58 or hex)
-        #[arg(
-            long,
-            short = 'e',
-            default_value = "cb58",
-            env = "AVALANCHE_KEY_ENCODING"
-        )]
-        key_encoding: PrivateKeyEncoding,
+        #[command(flatten)]
+        signer_args: SignerArgs,
         /// Whether to wait for transaction acceptance
         #[arg(long, short = 'w')]
         wait: bool,
+        /// Write the unsigned transaction to a file instead of issuing it, to be signed by the wallet and the Subnet control keys
+        /// with `ash avalanche tx sign`
+        #[arg(long, conflicts_with = "wait")]
+        unsigned_out: Option<String>,
+        /// Control keys that authorize the transaction written to the unsigned file (comma-separated P-Chain addresses),
+        /// defaults to the wallet key and the first control keys of the Subnet
+        #[arg(long, value_delimiter = ',', requires = "unsigned_out")]
+        subnet_auth_keys: Vec<String>,
     },
     /// Get the hex encoded ID of a blockchain from its CB58 ID, or vice-versa
     #[command(version = version_tx_cmd(false))]
@@ -88,9 +89,10 @@ fn create(
     vm_id: &str,
     genesis_data: Option<String>,
     genesis_file: Option<String>,
-    private_key: &str,
-    key_encoding: PrivateKeyEncoding,
+    signer_args: &SignerArgs,
     wait: bool,
+    unsigned_out: Option<&str>,
+    subnet_auth_keys: &[String],
     config: Option<&str>,
     json: bool,
 ) -> Result<(), CliError> {
@@ -129,13 +131,37 @@ fn create(
         },
     };
 
-    let network = load_network(network_name, config)?;
-    let wallet = create_wallet(&network, private_key, key_encoding)?;
+    let mut network = load_network(network_name, config)?;
+    let wallet = create_wallet(&network, signer_args)?;
     let subnet_id_parsed = parse_id(subnet_id)?;
     let vm_id_parsed = parse_id(vm_id)?;
     let genesis_bytes = hex::decode(genesis_hex.trim_start_matches("0x"))
         .map_err(|e| CliError::dataerr(format!("Error decoding genesis data: {e}")))?;
 
+    // Write the transaction to be signed by the Subnet control keys
+    if let Some(unsigned_out) = unsigned_out {
+        update_network_subnets(&mut network)?;
+        let subnet = network
+            .get_subnet(subnet_id_parsed)
+            .map_err(|e| CliError::dataerr(format!("Error loading Subnet info: {e}")))?;
+
+        let partial_tx = task::block_on(async {
+            p::build_create_blockchain_partial_tx(
+                &wallet,
+                subnet,
+                genesis_bytes,
+                vm_id_parsed,
+                name,
+                subnet_auth_keys,
+            )
+            .await
+        })
+        .map_err(|e| CliError::dataerr(format!("Error building blockchain creation: {e}")))?;
+
+        write_partial_tx(unsigned_out, &partial_tx)?;
+        return print_partial_tx(unsigned_out, &partial_tx, TxEncoding::Hex, json);
+    }
+
     if wait {
         eprintln!("Waiting for transaction to be accepted...");
     }
@@ -201,9 +227,10 @@ pub(crate) fn parse(
             genesis_str,
             genesis_file,
             subnet_id,
-            private_key,
-            key_encoding,
+            signer_args,
             wait,
+            unsigned_out,
+            subnet_auth_keys,
         } => create(
             &subnet.network,
             &subnet_id,
@@ -212,9 +239,10 @@ pub(crate) fn parse(
             &vm_id,
             genesis_str,
             genesis_file,
-            &private_key,
-            key_encoding,
+            &signer_args,
             wait,
+            unsigned_out.as_deref(),
+            &subnet_auth_keys,
             config,
             json,
         ),
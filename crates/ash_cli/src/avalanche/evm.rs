@@ -0,0 +1,169 @@
+// SPDX-License-Identifier: BSD-3-Clause
+// Copyright (c) 2023, E36 Knots
+
+// Module that contains the evm subcommand parser
+
+use crate::{
+    avalanche::{wallet::*, *},
+    utils::{error::CliError, templating::*, version_tx_cmd},
+};
+use ash_sdk::ethers::{
+    types::Address,
+    utils::{format_ether, parse_ether},
+};
+use async_std::task;
+use clap::{Parser, Subcommand};
+use std::{str::FromStr, sync::Arc};
+
+/// Interact with EVM blockchains (C-Chain and Subnet-EVM chains)
+#[derive(Parser)]
+#[command()]
+pub(crate) struct EvmCommand {
+    #[command(subcommand)]
+    command: EvmSubcommands,
+    /// Avalanche network
+    #[arg(
+        long,
+        short = 'n',
+        default_value = "fuji",
+        global = true,
+        env = "AVALANCHE_NETWORK"
+    )]
+    network: String,
+    /// Blockchain ID or name
+    #[arg(long, short = 'b', default_value = "C-Chain", global = true)]
+    chain: String,
+}
+
+#[derive(Subcommand)]
+enum EvmSubcommands {
+    /// Get the native token balance of an address
+    #[command(version = version_tx_cmd(false))]
+    Balance {
+        /// Address to get the balance of
+        address: String,
+    },
+    /// Transfer any amount of native token to an address
+    #[command(version = version_tx_cmd(true))]
+    Transfer {
+        /// Amount of native token to send (in ether equivalent, 1 ether = 10^18 wei)
+        amount: f64,
+        /// Address to send the native token to
+        to: String,
+        #[command(flatten)]
+        signer_args: SignerArgs,
+    },
+}
+
+// Parse an EVM address from a string
+fn parse_evm_address(address: &str) -> Result<Address, CliError> {
+    Address::from_str(address)
+        .map_err(|e| CliError::dataerr(format!("Error parsing EVM address '{address}': {e}")))
+}
+
+fn balance(
+    network_name: &str,
+    chain: &str,
+    address: &str,
+    config: Option<&str>,
+    json: bool,
+) -> Result<(), CliError> {
+    let evm_address = parse_evm_address(address)?;
+
+    let network = load_network(network_name, config)?;
+    let blockchain = load_blockchain(&network, chain)?;
+
+    let balance =
+        task::block_on(async { blockchain.get_evm_balance(evm_address).await }).map_err(|e| {
+            CliError::dataerr(format!("Error getting balance for address {address}: {e}"))
+        })?;
+
+    if json {
+        println!(
+            "{}",
+            serde_json::json!({
+                "address": address,
+                "blockchainID": blockchain.id.to_string(),
+                "balance": balance.to_string(),
+            })
+        );
+        return Ok(());
+    }
+
+    println!(
+        "{}",
+        template_evm_balance(address, &blockchain, &format_ether(balance), 0)
+    );
+
+    Ok(())
+}
+
+fn transfer(
+    network_name: &str,
+    chain: &str,
+    to: &str,
+    amount: f64,
+    signer_args: &SignerArgs,
+    config: Option<&str>,
+    json: bool,
+) -> Result<(), CliError> {
+    let to_address = parse_evm_address(to)?;
+    let wei_amount = parse_ether(amount)
+        .map_err(|e| CliError::dataerr(format!("Error parsing amount '{amount}': {e}")))?;
+
+    let network = load_network(network_name, config)?;
+    let blockchain = load_blockchain(&network, chain)?;
+
+    let wallet = create_wallet(&network, signer_args)?;
+
+    eprintln!("Waiting for transaction to be mined...");
+
+    let tx_hash = task::block_on(async {
+        let client = Arc::new(blockchain.get_evm_signer(&wallet).await?);
+        blockchain
+            .transfer_evm_native(client, to_address, wei_amount)
+            .await
+    })
+    .map_err(|e| {
+        CliError::dataerr(format!(
+            "Error transferring {amount} of native token to '{to}': {e}"
+        ))
+    })?;
+
+    if json {
+        println!(
+            "{}",
+            serde_json::json!({ "txHash": format!("{tx_hash:?}") })
+        );
+        return Ok(());
+    }
+
+    println!(
+        "{}",
+        template_evm_transfer(&format!("{tx_hash:?}"), to, &blockchain, amount, 0)
+    );
+
+    Ok(())
+}
+
+// Parse evm subcommand
+pub(crate) fn parse(evm: EvmCommand, config: Option<&str>, json: bool) -> Result<(), CliError> {
+    match evm.command {
+        EvmSubcommands::Balance { address } => {
+            balance(&evm.network, &evm.chain, &address, config, json)
+        }
+        EvmSubcommands::Transfer {
+            amount,
+            to,
+            signer_args,
+        } => transfer(
+            &evm.network,
+            &evm.chain,
+            &to,
+            amount,
+            &signer_args,
+            config,
+            json,
+        ),
+    }
+}
@@ -220,11 +220,10 @@ fn generate_id(output_dir: Option<String>, json: bool) -> Result<(), CliError> {
 
     println!("Node ID: {}", type_colorize(&node_id.to_string()));
 
-    if output_dir.is_some() {
+    if let Some(output_dir) = &output_dir {
         println!(
             "Certificate and key files written to '{}/node.crt' and '{}/node.key'",
-            output_dir.as_ref().unwrap(),
-            output_dir.as_ref().unwrap()
+            output_dir, output_dir
         );
     } else {
         println!(
@@ -316,11 +315,8 @@ fn generate_bls_key(output_dir: Option<String>, json: bool) -> Result<(), CliErr
         type_colorize(&format!("0x{}", hex::encode(&bls_pop.proof_of_possession)))
     );
 
-    if output_dir.is_some() {
-        println!(
-            "BLS private key file written to '{}/bls.key'",
-            output_dir.as_ref().unwrap()
-        );
+    if let Some(output_dir) = &output_dir {
+        println!("BLS private key file written to '{}/bls.key'", output_dir);
     } else {
         println!(
             "BLS private key: {}",
@@ -0,0 +1,129 @@
+// SPDX-License-Identifier: BSD-3-Clause
+// Copyright (c) 2023, E36 Knots
+
+// Module that contains the p subcommand parser
+
+use crate::{
+    avalanche::*,
+    utils::{error::CliError, templating::*, version_tx_cmd},
+};
+use clap::{Parser, Subcommand};
+
+/// Interact with Avalanche P-Chain
+#[derive(Parser)]
+#[command()]
+pub(crate) struct PCommand {
+    #[command(subcommand)]
+    command: PSubcommands,
+    /// Avalanche network
+    #[arg(
+        long,
+        short = 'n',
+        default_value = "fuji",
+        global = true,
+        env = "AVALANCHE_NETWORK"
+    )]
+    network: String,
+}
+
+#[derive(Subcommand)]
+enum PSubcommands {
+    /// Get the AVAX balance of an address (unlocked and locked)
+    #[command(version = version_tx_cmd(false))]
+    Balance {
+        /// Address to get the balance of
+        address: String,
+    },
+    /// List the UTXOs of an address
+    #[command(version = version_tx_cmd(false))]
+    Utxos {
+        /// Address to list the UTXOs of
+        address: String,
+    },
+    /// Get the amount staked by an address
+    #[command(version = version_tx_cmd(false))]
+    Stake {
+        /// Address to get the staked amount of
+        address: String,
+    },
+}
+
+fn balance(
+    network_name: &str,
+    address: &str,
+    config: Option<&str>,
+    json: bool,
+) -> Result<(), CliError> {
+    let network = load_network(network_name, config)?;
+
+    let balance = network.get_pchain_balance(address).map_err(|e| {
+        CliError::dataerr(format!("Error getting balance for address {address}: {e}"))
+    })?;
+
+    if json {
+        println!("{}", serde_json::to_string(&balance).unwrap());
+        return Ok(());
+    }
+
+    println!("{}", template_pchain_balance(address, &balance, 0));
+
+    Ok(())
+}
+
+fn utxos(
+    network_name: &str,
+    address: &str,
+    config: Option<&str>,
+    json: bool,
+) -> Result<(), CliError> {
+    let network = load_network(network_name, config)?;
+
+    let utxos = network.get_pchain_utxos(address).map_err(|e| {
+        CliError::dataerr(format!("Error getting UTXOs for address {address}: {e}"))
+    })?;
+
+    if json {
+        println!("{}", serde_json::to_string(&utxos).unwrap());
+        return Ok(());
+    }
+
+    println!(
+        "Found {} UTXOs for '{}' on P-Chain:",
+        type_colorize(&utxos.len()),
+        type_colorize(&address)
+    );
+    println!("{}", template_utxos_table(&utxos, 0));
+
+    Ok(())
+}
+
+fn stake(
+    network_name: &str,
+    address: &str,
+    config: Option<&str>,
+    json: bool,
+) -> Result<(), CliError> {
+    let network = load_network(network_name, config)?;
+
+    let stake = network.get_pchain_stake(address).map_err(|e| {
+        CliError::dataerr(format!("Error getting stake for address {address}: {e}"))
+    })?;
+
+    if json {
+        println!("{}", serde_json::to_string(&stake).unwrap());
+        return Ok(());
+    }
+
+    println!("{}", template_pchain_stake(address, &stake, 0));
+
+    Ok(())
+}
+
+// Parse p subcommand
+pub(crate) fn parse(p: PCommand, config: Option<&str>, json: bool) -> Result<(), CliError> {
+    match p.command {
+        PSubcommands::Balance { address } => balance(&p.network, &address, config, json),
+        PSubcommands::Utxos { address } => utxos(&p.network, &address, config, json),
+        PSubcommands::Stake { address } => stake(&p.network, &address, config, json),
+    }
+}
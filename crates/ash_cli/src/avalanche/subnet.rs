@@ -4,12 +4,13 @@
 // Module that contains the subnet subcommand parser
 
 use crate::{
-    avalanche::{wallet::*, *},
+    avalanche::{tx::*, wallet::*, *},
     utils::{error::CliError, parsing::*, templating::*, version_tx_cmd},
 };
-use ash_sdk::avalanche::subnets::AvalancheSubnet;
+use ash_sdk::avalanche::{subnets::AvalancheSubnet, txs::p};
 use async_std::task;
 use clap::{Parser, Subcommand};
+use colored::Colorize;
 
 /// Interact with Avalanche Subnets
 #[derive(Parser)]
@@ -41,24 +42,24 @@ enum SubnetSubcommands {
         /// Whether to show extended information (here about validators)
         #[arg(long, short = 'e')]
         extended: bool,
+        /// Also show the validators of the Subnet at this past P-Chain height
+        #[arg(long)]
+        height: Option<u64>,
     },
     /// Create a new Subnet
     #[command(version = version_tx_cmd(true))]
     Create {
-        /// Private key to sign the transaction with
-        #[arg(long, short = 'p', env = "AVALANCHE_PRIVATE_KEY")]
-        private_key: String,
-        /// Private key encoding (cb58 or hex)
-        #[arg(
-            long,
-            short = 'e',
-            default_value = "cb58",
-            env = "AVALANCHE_KEY_ENCODING"
-        )]
-        key_encoding: PrivateKeyEncoding,
+        #[command(flatten)]
+        signer_args: SignerArgs,
         /// Whether to wait for transaction acceptance
         #[arg(long, short = 'w')]
         wait: bool,
+        /// Write the unsigned transaction to a file instead of issuing it, to be signed with `ash avalanche tx sign`
+        #[arg(long, conflicts_with = "wait")]
+        unsigned_out: Option<String>,
+        /// Show the UTXOs that would be consumed, the fee, the change and the resulting balance without issuing the transaction
+        #[arg(long, conflicts_with_all = ["wait", "unsigned_out"])]
+        dry_run: bool,
     },
 }
 
@@ -88,6 +89,7 @@ fn info(
     network_name: &str,
     id: &str,
     extended: bool,
+    height: Option<u64>,
     config: Option<&str>,
     json: bool,
 ) -> Result<(), CliError> {
@@ -95,30 +97,80 @@ fn info(
     update_network_subnets(&mut network)?;
     update_subnet_validators(&mut network, id)?;
 
+    // The pending validators are best-effort as not every node serves them
+    if let Err(e) = update_subnet_pending_validators(&mut network, id) {
+        eprintln!(
+            "{}",
+            format!("Warning: not showing the pending validators: {}", e.message).yellow()
+        );
+    }
+
     let subnet = network
         .get_subnet(parse_id(id)?)
         .map_err(|e| CliError::dataerr(format!("Error loading Subnet info: {e}")))?;
+    let validators_at = match height {
+        Some(height) => Some((
+            height,
+            network
+                .get_subnet_validators_at(subnet.id, height)
+                .map_err(|e| {
+                    CliError::dataerr(format!("Error getting validators at height {height}: {e}"))
+                })?,
+        )),
+        None => None,
+    };
 
     if json {
-        println!("{}", serde_json::to_string(&subnet).unwrap());
+        let mut subnet_json = serde_json::to_value(subnet).unwrap();
+        if let Some((height, validators)) = validators_at {
+            subnet_json["validatorsAt"] = serde_json::json!({
+                "height": height,
+                "validators": validators
+                    .iter()
+                    .map(|validator| serde_json::json!({
+                        "nodeID": validator.node_id.to_string(),
+                        "weight": validator.weight,
+                    }))
+                    .collect::<Vec<_>>(),
+            });
+        }
+        println!("{}", serde_json::to_string(&subnet_json).unwrap());
         return Ok(());
     }
 
     println!("{}", template_subnet_info(subnet, false, extended, 0));
 
+    if let Some((height, validators)) = validators_at {
+        println!("{}", template_validators_at(height, &validators, 0));
+    }
+
     Ok(())
 }
 
 fn create(
     network_name: &str,
-    private_key: &str,
-    key_encoding: PrivateKeyEncoding,
+    signer_args: &SignerArgs,
     wait: bool,
+    unsigned_out: Option<&str>,
+    dry_run: bool,
     config: Option<&str>,
     json: bool,
 ) -> Result<(), CliError> {
     let network = load_network(network_name, config)?;
-    let wallet = create_wallet(&network, private_key, key_encoding)?;
+    let wallet = create_wallet(&network, signer_args)?;
+
+    // Write the transaction to be signed later (e.g. on an air-gapped machine)
+    // or only preview the funds it would spend
+    if unsigned_out.is_some() || dry_run {
+        let partial_tx = task::block_on(async { p::build_create_subnet_partial_tx(&wallet).await })
+            .map_err(|e| CliError::dataerr(format!("Error building Subnet creation: {e}")))?;
+
+        if let Some(unsigned_out) = unsigned_out {
+            write_partial_tx(unsigned_out, &partial_tx)?;
+            return print_partial_tx(unsigned_out, &partial_tx, TxEncoding::Hex, json);
+        }
+        return print_tx_simulation(&wallet, &partial_tx, json);
+    }
 
     if wait {
         eprintln!("Waiting for transaction to be accepted...");
@@ -144,19 +196,23 @@ pub(crate) fn parse(
     json: bool,
 ) -> Result<(), CliError> {
     match subnet.command {
-        SubnetSubcommands::Info { id, extended } => {
-            info(&subnet.network, &id, extended, config, json)
-        }
+        SubnetSubcommands::Info {
+            id,
+            extended,
+            height,
+        } => info(&subnet.network, &id, extended, height, config, json),
         SubnetSubcommands::List => list(&subnet.network, config, json),
         SubnetSubcommands::Create {
-            private_key,
-            key_encoding,
+            signer_args,
             wait,
+            unsigned_out,
+            dry_run,
         } => create(
             &subnet.network,
-            &private_key,
-            key_encoding,
+            &signer_args,
             wait,
+            unsigned_out.as_deref(),
+            dry_run,
             config,
             json,
         ),
@@ -0,0 +1,476 @@
+// SPDX-License-Identifier: BSD-3-Clause
+// Copyright (c) 2023, E36 Knots
+
+// Module that contains the tx subcommand parser
+
+use crate::{
+    avalanche::{wallet::*, *},
+    utils::{error::CliError, parsing::*, prompt::confirm_action, templating::*, version_tx_cmd},
+};
+use ash_sdk::avalanche::{
+    txs::{
+        get_tx, get_tx_status, issue_signed_tx, p, partial::AvalanchePartialTx,
+        wait_txs_acceptance, AvalancheTxEncoding,
+    },
+    wallets::AvalancheWallet,
+};
+use async_std::task;
+use clap::{ArgGroup, Parser, Subcommand, ValueEnum};
+use std::{fmt::Display, fs};
+
+/// Sign, issue and follow up on transactions (e.g. multisig Subnet operations or transactions signed on an air-gapped machine)
+#[derive(Parser)]
+#[command()]
+pub(crate) struct TxCommand {
+    #[command(subcommand)]
+    command: TxSubcommands,
+    /// Avalanche network
+    #[arg(
+        long,
+        short = 'n',
+        default_value = "mainnet",
+        global = true,
+        env = "AVALANCHE_NETWORK"
+    )]
+    network: String,
+}
+
+#[derive(Subcommand)]
+enum TxSubcommands {
+    /// Add the wallet signature(s) to a partially signed transaction file
+    /// The transaction is decoded and shown before being signed
+    /// The network is not used, so transactions can be signed on an air-gapped machine
+    #[command(version = version_tx_cmd(false))]
+    Sign {
+        /// Partially signed transaction file
+        file: String,
+        #[command(flatten)]
+        signer_args: SignerArgs,
+        /// File to write the signed transaction to, defaults to the input file
+        #[arg(long, short = 'o')]
+        output: Option<String>,
+        /// Encoding of the signed transaction bytes displayed once fully signed (hex or cb58)
+        #[arg(long, default_value = "hex")]
+        encoding: TxEncoding,
+        /// Assume yes to all prompts
+        #[arg(long, short = 'y')]
+        yes: bool,
+    },
+    /// Merge the signatures of several copies of a partially signed transaction
+    #[command(version = version_tx_cmd(false))]
+    Merge {
+        /// Partially signed transaction files
+        #[arg(required = true, num_args = 2..)]
+        files: Vec<String>,
+        /// File to write the merged transaction to
+        #[arg(long, short = 'o')]
+        output: String,
+    },
+    /// Issue a partially signed transaction once all the signatures are collected, or signed transaction bytes
+    #[command(version = version_tx_cmd(true))]
+    #[command(group(ArgGroup::new("tx_source").args(["file", "tx"]).required(true).multiple(false)))]
+    Broadcast {
+        /// Partially signed transaction file, or file containing signed transaction bytes (hex or CB58)
+        #[arg(long)]
+        file: Option<String>,
+        /// Signed transaction bytes (hex or CB58)
+        #[arg(long)]
+        tx: Option<String>,
+        /// Chain to issue the signed transaction bytes on (ignored for partially signed transaction files)
+        #[arg(long, default_value = "P")]
+        chain: PrimaryNetworkChain,
+        /// Whether to wait for transaction acceptance
+        #[arg(long, short = 'w')]
+        wait: bool,
+    },
+    /// Show the status of transactions
+    #[command(version = version_tx_cmd(false))]
+    Status {
+        /// Transaction IDs
+        #[arg(required = true)]
+        tx_ids: Vec<String>,
+        /// Chain the transactions were issued on
+        #[arg(long, default_value = "P")]
+        chain: PrimaryNetworkChain,
+    },
+    /// Show the content and status of a transaction
+    #[command(version = version_tx_cmd(false))]
+    Show {
+        /// Transaction ID
+        tx_id: String,
+        /// Chain the transaction was issued on
+        #[arg(long, default_value = "P")]
+        chain: PrimaryNetworkChain,
+    },
+    /// Wait for transactions to be accepted
+    /// Fail as soon as one of them is rejected or is not accepted before the timeout
+    #[command(version = version_tx_cmd(false))]
+    Wait {
+        /// Transaction IDs
+        #[arg(required = true)]
+        tx_ids: Vec<String>,
+        /// Chain the transactions were issued on
+        #[arg(long, default_value = "P")]
+        chain: PrimaryNetworkChain,
+    },
+}
+
+/// Transaction bytes encoding
+#[derive(Display, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
+pub(crate) enum TxEncoding {
+    Hex,
+    Cb58,
+}
+
+impl From<TxEncoding> for AvalancheTxEncoding {
+    fn from(encoding: TxEncoding) -> Self {
+        match encoding {
+            TxEncoding::Hex => AvalancheTxEncoding::Hex,
+            TxEncoding::Cb58 => AvalancheTxEncoding::Cb58,
+        }
+    }
+}
+
+// Parse a partially signed transaction
+// The unsigned transaction bytes are checked against the transaction content
+fn parse_partial_tx(partial_tx_json: &str) -> Result<AvalanchePartialTx, CliError> {
+    let partial_tx: AvalanchePartialTx = serde_json::from_str(partial_tx_json)
+        .map_err(|e| CliError::dataerr(format!("Error parsing transaction file: {e}")))?;
+
+    task::block_on(async { partial_tx.verify().await })
+        .map_err(|e| CliError::dataerr(format!("Error verifying transaction file: {e}")))?;
+
+    Ok(partial_tx)
+}
+
+// Pack the unsigned transaction from the content of a partially signed transaction
+// Return the unsigned transaction bytes encoded in hex and CB58 (with checksum)
+fn encode_unsigned_tx(partial_tx: &AvalanchePartialTx) -> Result<(String, String), CliError> {
+    let unsigned_tx_bytes = task::block_on(async { partial_tx.verify().await })
+        .map_err(|e| CliError::dataerr(format!("Error verifying transaction file: {e}")))?;
+
+    Ok((
+        AvalancheTxEncoding::Hex.encode(&unsigned_tx_bytes),
+        AvalancheTxEncoding::Cb58.encode(&unsigned_tx_bytes),
+    ))
+}
+
+// Read a partially signed transaction from a file
+pub(crate) fn read_partial_tx(path: &str) -> Result<AvalanchePartialTx, CliError> {
+    let partial_tx_json = fs::read_to_string(path)
+        .map_err(|e| CliError::dataerr(format!("Error reading transaction file: {e}")))?;
+
+    parse_partial_tx(&partial_tx_json)
+}
+
+// Write a partially signed transaction to a file
+pub(crate) fn write_partial_tx(
+    path: &str,
+    partial_tx: &AvalanchePartialTx,
+) -> Result<(), CliError> {
+    fs::write(path, serde_json::to_string_pretty(partial_tx).unwrap())
+        .map_err(|e| CliError::dataerr(format!("Error writing transaction file: {e}")))
+}
+
+// Print the signing status and the unsigned bytes of a partially signed transaction written to a file
+// The ID and the encoded bytes of the signed transaction are printed once fully signed
+pub(crate) fn print_partial_tx(
+    path: &str,
+    partial_tx: &AvalanchePartialTx,
+    encoding: TxEncoding,
+    json: bool,
+) -> Result<(), CliError> {
+    let unsigned_tx = encode_unsigned_tx(partial_tx)?;
+    let signed_tx = match partial_tx.is_fully_signed() {
+        true => {
+            let (tx_id, signed_tx_bytes) =
+                task::block_on(async { partial_tx.signed_tx().await })
+                    .map_err(|e| CliError::dataerr(format!("Error assembling transaction: {e}")))?;
+            Some((
+                tx_id.to_string(),
+                AvalancheTxEncoding::from(encoding).encode(&signed_tx_bytes),
+            ))
+        }
+        false => None,
+    };
+
+    if json {
+        let mut partial_tx_json = serde_json::json!({
+            "file": path,
+            "txType": partial_tx.content.tx_type(),
+            "chain": partial_tx.content.chain().to_string(),
+            "fullySigned": partial_tx.is_fully_signed(),
+            "missingSigners": partial_tx.missing_signers(),
+            "unsignedTx": unsigned_tx.0,
+            "unsignedTxCB58": unsigned_tx.1,
+        });
+        if let Some((tx_id, encoded_tx)) = &signed_tx {
+            partial_tx_json["txID"] = serde_json::json!(tx_id);
+            partial_tx_json["signedTx"] = serde_json::json!(encoded_tx);
+        }
+        println!("{partial_tx_json}");
+        return Ok(());
+    }
+
+    println!(
+        "{}",
+        template_partial_tx(partial_tx, path, &unsigned_tx, signed_tx.as_ref(), 0)
+    );
+
+    Ok(())
+}
+
+// Print the funds that would be spent by a P-Chain transaction built for the wallet, without issuing it
+pub(crate) fn print_tx_simulation(
+    wallet: &AvalancheWallet,
+    partial_tx: &AvalanchePartialTx,
+    json: bool,
+) -> Result<(), CliError> {
+    let simulation = p::simulate_partial_tx(wallet, partial_tx)
+        .map_err(|e| CliError::dataerr(format!("Error simulating transaction: {e}")))?;
+
+    if json {
+        println!("{}", serde_json::to_string(&simulation).unwrap());
+        return Ok(());
+    }
+
+    println!("{}", template_tx_simulation(&simulation, 0));
+
+    Ok(())
+}
+
+fn sign(
+    file: &str,
+    signer_args: &SignerArgs,
+    output: Option<&str>,
+    encoding: TxEncoding,
+    yes: bool,
+    json: bool,
+) -> Result<(), CliError> {
+    let signer = create_signer(signer_args)?;
+
+    let mut partial_tx = read_partial_tx(file)?;
+
+    // Show the transaction and the unsigned bytes packed from its content
+    if !json {
+        let unsigned_tx = encode_unsigned_tx(&partial_tx)?;
+        println!(
+            "{}",
+            template_partial_tx_content(&partial_tx, &unsigned_tx, 0)
+        );
+    }
+    if !yes && !confirm_action("transaction", Some("sign")) {
+        return Ok(());
+    }
+
+    let signatures_count = task::block_on(async { partial_tx.sign(&signer).await })
+        .map_err(|e| CliError::dataerr(format!("Error signing transaction: {e}")))?;
+
+    let output = output.unwrap_or(file);
+    write_partial_tx(output, &partial_tx)?;
+
+    if !json {
+        let chain = partial_tx.content.chain();
+        println!(
+            "Added {} signature(s) from '{}'",
+            type_colorize(&signatures_count),
+            type_colorize(
+                &signer
+                    .public_key()
+                    .to_hrp_address(partial_tx.content.network_id(), &chain.to_string())
+                    .unwrap_or_default()
+            )
+        );
+    }
+    print_partial_tx(output, &partial_tx, encoding, json)
+}
+
+fn merge(files: &[String], output: &str, json: bool) -> Result<(), CliError> {
+    let mut partial_tx = read_partial_tx(&files[0])?;
+
+    for file in files.iter().skip(1) {
+        let other_partial_tx = read_partial_tx(file)?;
+        task::block_on(async { partial_tx.merge(&other_partial_tx).await })
+            .map_err(|e| CliError::dataerr(format!("Error merging '{file}': {e}")))?;
+    }
+
+    write_partial_tx(output, &partial_tx)?;
+    print_partial_tx(output, &partial_tx, TxEncoding::Hex, json)
+}
+
+fn broadcast(
+    network_name: &str,
+    file: Option<&str>,
+    tx: Option<&str>,
+    chain: PrimaryNetworkChain,
+    wait: bool,
+    config: Option<&str>,
+    json: bool,
+) -> Result<(), CliError> {
+    let network = load_network(network_name, config)?;
+
+    // The file contains either a partially signed transaction or signed transaction bytes
+    let tx_content = match (file, tx) {
+        (Some(file), _) => fs::read_to_string(file)
+            .map_err(|e| CliError::dataerr(format!("Error reading transaction file: {e}")))?,
+        (None, tx) => tx.unwrap_or_default().to_string(),
+    };
+    let partial_tx = match file.is_some() && tx_content.trim_start().starts_with('{') {
+        true => Some(parse_partial_tx(&tx_content)?),
+        false => None,
+    };
+
+    if wait {
+        eprintln!("Waiting for transaction to be accepted...");
+    }
+
+    let (tx_type, tx_id) = match &partial_tx {
+        Some(partial_tx) => (
+            partial_tx.content.tx_type(),
+            task::block_on(async { partial_tx.issue(&network, wait).await }),
+        ),
+        None => {
+            let signed_tx_bytes = AvalancheTxEncoding::decode(&tx_content)
+                .map_err(|e| CliError::dataerr(format!("Error broadcasting transaction: {e}")))?;
+            (
+                "signed".to_string(),
+                task::block_on(async {
+                    issue_signed_tx(&network, chain.into(), "signed", &signed_tx_bytes, wait).await
+                }),
+            )
+        }
+    };
+    let tx_id =
+        tx_id.map_err(|e| CliError::dataerr(format!("Error broadcasting transaction: {e}")))?;
+
+    if json {
+        println!("{}", serde_json::json!({ "txID": tx_id.to_string() }));
+        return Ok(());
+    }
+
+    println!(
+        "{}",
+        template_tx_broadcast(&tx_type, &tx_id.to_string(), wait, 0)
+    );
+
+    Ok(())
+}
+
+fn status(
+    network_name: &str,
+    tx_ids: &[String],
+    chain: PrimaryNetworkChain,
+    config: Option<&str>,
+    json: bool,
+) -> Result<(), CliError> {
+    let network = load_network(network_name, config)?;
+
+    let mut statuses = Vec::new();
+    for tx_id in tx_ids.iter() {
+        let status = get_tx_status(&network, chain.into(), parse_id(tx_id)?)
+            .map_err(|e| CliError::dataerr(format!("Error getting transaction status: {e}")))?;
+        statuses.push((tx_id, status));
+    }
+
+    if json {
+        let statuses_json = statuses
+            .iter()
+            .map(|(tx_id, status)| serde_json::json!({ "txID": tx_id, "status": status }))
+            .collect::<Vec<_>>();
+        println!("{}", serde_json::json!(statuses_json));
+        return Ok(());
+    }
+
+    for (tx_id, status) in statuses.iter() {
+        println!("{}", template_tx_status(tx_id, chain.into(), status, 0));
+    }
+
+    Ok(())
+}
+
+fn show(
+    network_name: &str,
+    tx_id: &str,
+    chain: PrimaryNetworkChain,
+    config: Option<&str>,
+    json: bool,
+) -> Result<(), CliError> {
+    let network = load_network(network_name, config)?;
+    let tx_id_parsed = parse_id(tx_id)?;
+
+    let status = get_tx_status(&network, chain.into(), tx_id_parsed)
+        .map_err(|e| CliError::dataerr(format!("Error getting transaction status: {e}")))?;
+    let tx = get_tx(&network, chain.into(), tx_id_parsed)
+        .map_err(|e| CliError::dataerr(format!("Error getting transaction: {e}")))?;
+
+    if json {
+        let mut tx_json = serde_json::to_value(&tx).unwrap();
+        tx_json["status"] = serde_json::json!(status);
+        println!("{tx_json}");
+        return Ok(());
+    }
+
+    println!("{}", template_tx_info(&tx, &status, 0));
+
+    Ok(())
+}
+
+fn wait(
+    network_name: &str,
+    tx_ids: &[String],
+    chain: PrimaryNetworkChain,
+    config: Option<&str>,
+    json: bool,
+) -> Result<(), CliError> {
+    let network = load_network(network_name, config)?;
+    let tx_ids_parsed = tx_ids
+        .iter()
+        .map(|tx_id| parse_id(tx_id))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if !json {
+        eprintln!(
+            "Waiting for {} transaction(s) to be accepted...",
+            tx_ids_parsed.len()
+        );
+    }
+
+    task::block_on(async { wait_txs_acceptance(&network, chain.into(), &tx_ids_parsed).await })
+        .map_err(|e| CliError::dataerr(format!("Error waiting for transactions: {e}")))?;
+
+    // Show the final status of the transactions
+    status(network_name, tx_ids, chain, config, json)
+}
+
+// Parse tx subcommand
+pub(crate) fn parse(tx: TxCommand, config: Option<&str>, json: bool) -> Result<(), CliError> {
+    match tx.command {
+        TxSubcommands::Sign {
+            file,
+            signer_args,
+            output,
+            encoding,
+            yes,
+        } => sign(&file, &signer_args, output.as_deref(), encoding, yes, json),
+        TxSubcommands::Merge { files, output } => merge(&files, &output, json),
+        TxSubcommands::Broadcast {
+            file,
+            tx: tx_arg,
+            chain,
+            wait,
+        } => broadcast(
+            &tx.network,
+            file.as_deref(),
+            tx_arg.as_deref(),
+            chain,
+            wait,
+            config,
+            json,
+        ),
+        TxSubcommands::Status { tx_ids, chain } => {
+            status(&tx.network, &tx_ids, chain, config, json)
+        }
+        TxSubcommands::Show { tx_id, chain } => show(&tx.network, &tx_id, chain, config, json),
+        TxSubcommands::Wait { tx_ids, chain } => wait(&tx.network, &tx_ids, chain, config, json),
+    }
+}
@@ -4,16 +4,23 @@
 // Module that contains the validator subcommand parser
 
 use crate::{
-    avalanche::{wallet::*, *},
-    utils::{error::CliError, parsing::*, templating::*, version_tx_cmd},
+    avalanche::{tx::*, wallet::*, *},
+    utils::{
+        error::CliError, parsing::*, prompt::confirm_action, state::ValidatorRenewalsPlan,
+        templating::*, version_tx_cmd,
+    },
 };
 use ash_sdk::avalanche::{
-    nodes::ProofOfPossession, subnets::AvalancheSubnetType, AVAX_PRIMARY_NETWORK_ID,
+    nodes::ProofOfPossession,
+    subnets::{AvalancheSubnetType, AvalancheValidatorRenewal},
+    txs::p,
+    AVAX_PRIMARY_NETWORK_ID,
 };
 use async_std::task;
 use chrono::Utc;
-use clap::{Parser, Subcommand, ValueEnum};
-use std::fmt::Display;
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use colored::Colorize;
+use std::{fmt::Display, thread};
 
 /// Node signer format
 #[derive(Display, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
@@ -22,6 +29,17 @@ pub(crate) enum SignerFormat {
     Json,
 }
 
+/// Owners of the staking rewards on the Primary Network
+#[derive(Args)]
+pub(crate) struct RewardsOwnerArgs {
+    /// Owners of the rewards (comma-separated P-Chain addresses), defaults to the wallet address (Primary Network only)
+    #[arg(long, value_delimiter = ',')]
+    reward_owners: Vec<String>,
+    /// Number of reward owners that have to sign to spend the rewards
+    #[arg(long, default_value = "1")]
+    reward_threshold: u32,
+}
+
 /// Interact with Avalanche validators
 #[derive(Parser)]
 #[command()]
@@ -48,6 +66,7 @@ pub(crate) struct ValidatorCommand {
 }
 
 #[derive(Subcommand)]
+#[allow(clippy::large_enum_variant)]
 enum ValidatorSubcommands {
     /// Add a validator to a Subnet
     #[command(version = version_tx_cmd(true))]
@@ -65,17 +84,8 @@ enum ValidatorSubcommands {
         /// Delegation fee (percentage), defaults to 2%
         #[arg(long, short = 'f', default_value = "2")]
         delegation_fee: u32,
-        /// Private key to sign the transaction with
-        #[arg(long, short = 'p', env = "AVALANCHE_PRIVATE_KEY")]
-        private_key: String,
-        /// Private key encoding (cb58 or hex)
-        #[arg(
-            long,
-            short = 'e',
-            default_value = "cb58",
-            env = "AVALANCHE_KEY_ENCODING"
-        )]
-        key_encoding: PrivateKeyEncoding,
+        #[command(flatten)]
+        signer_args: SignerArgs,
         /// Signer (BLS public key and PoP) in "public_key:PoP" or JSON format
         /// (e.g. '{"publicKey":"public_key","proofOfPossession":"pop"}')
         #[arg(long, short = 'B')]
@@ -83,13 +93,111 @@ enum ValidatorSubcommands {
         /// Signer format (str or json)
         #[arg(long, short = 'F', default_value = "str")]
         signer_format: SignerFormat,
+        #[command(flatten)]
+        rewards_owner_args: RewardsOwnerArgs,
+        /// Whether to wait for transaction acceptance
+        #[arg(long, short = 'w')]
+        wait: bool,
+        /// Write the unsigned transaction to a file instead of issuing it, to be signed by the wallet
+        /// (and the Subnet control keys on permissioned Subnets) with `ash avalanche tx sign`
+        #[arg(long, conflicts_with = "wait")]
+        unsigned_out: Option<String>,
+        /// Control keys that authorize the transaction written to the unsigned file (comma-separated P-Chain addresses),
+        /// defaults to the wallet key and the first control keys of the Subnet (permissioned Subnets only)
+        #[arg(long, value_delimiter = ',', requires = "unsigned_out")]
+        subnet_auth_keys: Vec<String>,
+        /// Show the UTXOs that would be consumed, the fee, the stake, the change and the resulting balance
+        /// without issuing the transaction
+        #[arg(long, conflicts_with_all = ["wait", "unsigned_out"])]
+        dry_run: bool,
+    },
+    /// Remove a validator from a permissioned Subnet before its end time
+    #[command(version = version_tx_cmd(true))]
+    Remove {
+        /// Validator NodeID
+        id: String,
+        #[command(flatten)]
+        signer_args: SignerArgs,
+        /// Whether to wait for transaction acceptance
+        #[arg(long, short = 'w')]
+        wait: bool,
+        /// Assume yes to all prompts
+        #[arg(long, short = 'y')]
+        yes: bool,
+    },
+    /// Renew in batch the permissioned Subnet validators whose validation ends soon
+    ///
+    /// A validator can only be renewed once its current validation has ended.
+    /// The planned renewals are saved to a plan file so that the validators that have left the Subnet
+    /// are still renewed when this command runs again (e.g. from a scheduler). Use --follow to wait for them instead.
+    #[command(version = version_tx_cmd(true))]
+    Renew {
+        /// Plan the renewal of the validators whose validation ends within this number of days
+        #[arg(long, short = 'W', default_value = "7")]
+        within: u64,
+        /// Duration of the new validations in days (capped by the end of the Primary Network validation)
+        #[arg(long, short = 'D', default_value = "30", value_parser = clap::value_parser!(u64).range(1..))]
+        duration: u64,
+        #[command(flatten)]
+        signer_args: SignerArgs,
+        /// Whether to wait for transactions acceptance
+        #[arg(long, short = 'w')]
+        wait: bool,
+        /// Wait for the planned validations to end and renew them as they end
+        #[arg(long, short = 'f')]
+        follow: bool,
+        /// Show the planned renewals without issuing them
+        #[arg(long, conflicts_with_all = ["wait", "follow"])]
+        dry_run: bool,
+        /// Path to the renewals plan file
+        /// Defaults to ~/.local/state/ash/renewals/<network>-<subnet_id>.json
+        #[arg(long)]
+        plan_file: Option<String>,
+        /// Assume yes to all prompts
+        #[arg(long, short = 'y')]
+        yes: bool,
+    },
+    /// Delegate stake to a validator of the Primary Network
+    #[command(version = version_tx_cmd(true))]
+    Delegate {
+        /// Validator NodeID
+        id: String,
+        /// Stake in AVAX
+        stake: u64,
+        /// Start time of the delegation (YYYY-MM-DDTHH:MM:SSZ), defaults to now
+        #[arg(long, short = 'S')]
+        start_time: Option<String>,
+        /// End time of the delegation (YYYY-MM-DDTHH:MM:SSZ)
+        #[arg(long, short = 'E')]
+        end_time: String,
+        #[command(flatten)]
+        rewards_owner_args: RewardsOwnerArgs,
+        #[command(flatten)]
+        signer_args: SignerArgs,
         /// Whether to wait for transaction acceptance
         #[arg(long, short = 'w')]
         wait: bool,
     },
     /// List the Subnet's validators
     #[command(version = version_tx_cmd(false))]
-    List,
+    List {
+        /// List the pending validators (validations starting in the future) instead of the current ones
+        #[arg(long)]
+        pending: bool,
+        /// Only list these validators (comma-separated NodeIDs)
+        #[arg(long, value_delimiter = ',')]
+        node_ids: Vec<String>,
+    },
+    /// Report the reward eligibility and expiry of the Subnet's validators
+    #[command(version = version_tx_cmd(false))]
+    Report {
+        /// Flag the validators whose validation ends within this number of days
+        #[arg(long, short = 'd', default_value = "7")]
+        expiry_days: u64,
+        /// Only report the flagged validators (disconnected, below the uptime threshold or expiring)
+        #[arg(long)]
+        flagged_only: bool,
+    },
     /// Show validator information
     #[command(version = version_tx_cmd(false))]
     Info {
@@ -98,32 +206,47 @@ enum ValidatorSubcommands {
     },
 }
 
-// List the Subnet's validators
+// List the Subnet's current or pending validators
 fn list(
     network_name: &str,
     subnet_id: &str,
+    pending: bool,
+    node_ids: &[String],
     config: Option<&str>,
     json: bool,
 ) -> Result<(), CliError> {
+    let node_ids_parsed = node_ids
+        .iter()
+        .map(|id| parse_node_id(id))
+        .collect::<Result<Vec<_>, _>>()?;
+
     let mut network = load_network(network_name, config)?;
     update_network_subnets(&mut network)?;
 
-    update_subnet_validators(&mut network, subnet_id)?;
+    match pending {
+        true => update_subnet_pending_validators(&mut network, subnet_id)?,
+        false => update_subnet_validators(&mut network, subnet_id)?,
+    }
     let subnet = network
         .get_subnet(parse_id(subnet_id)?)
         .map_err(|e| CliError::dataerr(format!("Error listing validators: {e}")))?;
-    let validators = subnet.validators.clone();
-    format!(
-        "Found {} validators on Subnet '{}':",
-        type_colorize(&subnet.validators.len()),
-        type_colorize(&subnet_id)
-    );
+    let validators = subnet.filter_validators(pending, &node_ids_parsed);
 
     if json {
         println!("{}", serde_json::to_string(&validators).unwrap());
         return Ok(());
     }
 
+    println!(
+        "Found {} {}validators on Subnet '{}':",
+        type_colorize(&validators.len()),
+        match pending {
+            true => "pending ",
+            false => "",
+        },
+        type_colorize(&subnet_id)
+    );
+
     for validator in validators.iter() {
         println!(
             "{}",
@@ -134,6 +257,44 @@ fn list(
     Ok(())
 }
 
+// Report the reward eligibility and expiry of the Subnet's validators
+fn report(
+    network_name: &str,
+    subnet_id: &str,
+    expiry_days: u64,
+    flagged_only: bool,
+    config: Option<&str>,
+    json: bool,
+) -> Result<(), CliError> {
+    let mut network = load_network(network_name, config)?;
+    update_network_subnets(&mut network)?;
+    update_subnet_validators(&mut network, subnet_id)?;
+
+    let subnet = network
+        .get_subnet(parse_id(subnet_id)?)
+        .map_err(|e| CliError::dataerr(format!("Error reporting validators: {e}")))?;
+    let reports: Vec<_> = subnet
+        .validators_report(expiry_days)
+        .into_iter()
+        .filter(|report| !flagged_only || report.is_flagged())
+        .collect();
+
+    if json {
+        println!("{}", serde_json::to_string(&reports).unwrap());
+        return Ok(());
+    }
+
+    println!(
+        "Report of {} validators on Subnet '{}' ({} flagged):",
+        type_colorize(&reports.len()),
+        type_colorize(&subnet_id),
+        type_colorize(&reports.iter().filter(|report| report.is_flagged()).count())
+    );
+    println!("{}", template_validators_report_table(&reports, 2));
+
+    Ok(())
+}
+
 fn info(
     network_name: &str,
     subnet_id: &str,
@@ -165,6 +326,36 @@ fn info(
     Ok(())
 }
 
+// Parse the signer (BLS public key and PoP) of a validator
+fn parse_signer(
+    signer: Option<&str>,
+    signer_format: SignerFormat,
+) -> Result<Option<ProofOfPossession>, CliError> {
+    let Some(signer_str) = signer else {
+        return Ok(None);
+    };
+
+    let signer_parsed = match signer_format {
+        SignerFormat::Str => {
+            let parts: Vec<&str> = signer_str.split(':').collect();
+            if parts.len() != 2 {
+                return Err(CliError::dataerr(
+                    "Signer must be in the format 'public_key:PoP'".to_string(),
+                ));
+            }
+            serde_json::from_value::<ProofOfPossession>(serde_json::json!({
+                "publicKey": parts[0],
+                "proofOfPossession": parts[1]
+            }))
+            .map_err(|e| CliError::dataerr(format!("Error parsing signer: {e}")))?
+        }
+        SignerFormat::Json => serde_json::from_str(signer_str)
+            .map_err(|e| CliError::dataerr(format!("Error parsing signer: {e}")))?,
+    };
+
+    Ok(Some(signer_parsed))
+}
+
 fn add(
     network_name: &str,
     subnet_id: &str,
@@ -173,11 +364,13 @@ fn add(
     start_time: Option<String>,
     end_time: String,
     delegation_fee: u32,
-    private_key: &str,
-    key_encoding: PrivateKeyEncoding,
-    signer: Option<String>,
-    signer_format: SignerFormat,
+    signer_args: &SignerArgs,
+    signer: Option<ProofOfPossession>,
+    rewards_owner_args: &RewardsOwnerArgs,
     wait: bool,
+    unsigned_out: Option<&str>,
+    subnet_auth_keys: &[String],
+    dry_run: bool,
     config: Option<&str>,
     json: bool,
 ) -> Result<(), CliError> {
@@ -187,56 +380,102 @@ fn add(
         None => Utc::now(),
     };
     let end_time_parsed = parse_datetime(&end_time)?;
-    let signer_parsed = match signer.clone() {
-        Some(signer_str) => match signer_format {
-            SignerFormat::Str => {
-                let parts: Vec<&str> = signer_str.split(':').collect();
-                if parts.len() != 2 {
-                    return Err(CliError::dataerr(
-                        "Signer must be in the format 'public_key:PoP'".to_string(),
-                    ));
-                }
-                serde_json::from_value::<ProofOfPossession>(serde_json::json!({
-                    "publicKey": parts[0],
-                    "proofOfPossession": parts[1]
-                }))
-                .map_err(|e| CliError::dataerr(format!("Error parsing signer: {e}")))?
-            }
-            SignerFormat::Json => serde_json::from_str(&signer_str)
-                .map_err(|e| CliError::dataerr(format!("Error parsing signer: {e}")))?,
-        },
-        None => ProofOfPossession::default(),
-    };
-
     let mut network = load_network(network_name, config)?;
     update_network_subnets(&mut network)?;
 
     let subnet = network
         .get_subnet(parse_id(subnet_id)?)
         .map_err(|e| CliError::dataerr(format!("Error loading Subnet info: {e}")))?;
-    let wallet = create_wallet(&network, private_key, key_encoding)?;
+    let wallet = create_wallet(&network, signer_args)?;
 
-    if wait {
-        eprintln!("Waiting for transaction to be accepted...");
-    }
+    // Write the transaction to be signed later (e.g. by the Subnet control keys or on an air-gapped machine)
+    // or only preview the funds it would spend
+    if unsigned_out.is_some() || dry_run {
+        if !subnet_auth_keys.is_empty() && subnet.subnet_type != AvalancheSubnetType::Permissioned {
+            return Err(CliError::dataerr(format!(
+                "Error adding validator: Subnet authorization keys are only supported on permissioned Subnets, not on {} Subnets",
+                subnet.subnet_type
+            )));
+        }
 
-    let validator = match subnet.subnet_type {
-        AvalancheSubnetType::PrimaryNetwork => task::block_on(async {
-            subnet
-                .add_validator_permissionless(
+        let partial_tx = match subnet.subnet_type {
+            AvalancheSubnetType::PrimaryNetwork => {
+                let stake_amount = parse_avax_amount(stake_or_weight)?;
+                let rewards_owner = p::rewards_owner(
                     &wallet,
+                    &rewards_owner_args.reward_owners,
+                    rewards_owner_args.reward_threshold,
+                )
+                .map_err(|e| {
+                    CliError::dataerr(format!("Error building validator addition: {e}"))
+                })?;
+                task::block_on(async {
+                    p::build_add_permissionless_validator_partial_tx(
+                        &wallet,
+                        node_id_parsed,
+                        subnet.id,
+                        stake_amount,
+                        start_time_parsed,
+                        end_time_parsed,
+                        delegation_fee,
+                        signer,
+                        &rewards_owner,
+                    )
+                    .await
+                })
+            }
+            AvalancheSubnetType::Permissioned => task::block_on(async {
+                p::build_add_permissioned_subnet_validator_partial_tx(
+                    &wallet,
+                    subnet,
                     node_id_parsed,
-                    subnet.id,
-                    // Multiply by 1 billion to convert from AVAX to nAVAX
-                    stake_or_weight * 1_000_000_000,
+                    stake_or_weight,
                     start_time_parsed,
                     end_time_parsed,
-                    delegation_fee,
-                    signer.map(|_| signer_parsed),
-                    wait,
+                    subnet_auth_keys,
                 )
                 .await
-        }),
+            }),
+            AvalancheSubnetType::Elastic => {
+                return Err(CliError::dataerr(
+                    "Adding a validator to an elastic Subnet is not yet supported".to_string(),
+                ));
+            }
+        }
+        .map_err(|e| CliError::dataerr(format!("Error building validator addition: {e}")))?;
+
+        if let Some(unsigned_out) = unsigned_out {
+            write_partial_tx(unsigned_out, &partial_tx)?;
+            return print_partial_tx(unsigned_out, &partial_tx, TxEncoding::Hex, json);
+        }
+        return print_tx_simulation(&wallet, &partial_tx, json);
+    }
+
+    if wait {
+        eprintln!("Waiting for transaction to be accepted...");
+    }
+
+    let validator = match subnet.subnet_type {
+        AvalancheSubnetType::PrimaryNetwork => {
+            let stake_amount = parse_avax_amount(stake_or_weight)?;
+            task::block_on(async {
+                subnet
+                    .add_validator_permissionless(
+                        &wallet,
+                        node_id_parsed,
+                        subnet.id,
+                        stake_amount,
+                        start_time_parsed,
+                        end_time_parsed,
+                        delegation_fee,
+                        signer,
+                        &rewards_owner_args.reward_owners,
+                        rewards_owner_args.reward_threshold,
+                        wait,
+                    )
+                    .await
+            })
+        }
         AvalancheSubnetType::Permissioned => task::block_on(async {
             subnet
                 .add_validator_permissioned(
@@ -267,6 +506,328 @@ fn add(
     Ok(())
 }
 
+fn remove(
+    network_name: &str,
+    subnet_id: &str,
+    id: &str,
+    signer_args: &SignerArgs,
+    wait: bool,
+    yes: bool,
+    config: Option<&str>,
+    json: bool,
+) -> Result<(), CliError> {
+    let node_id_parsed = parse_node_id(id)?;
+
+    let mut network = load_network(network_name, config)?;
+    update_network_subnets(&mut network)?;
+    update_subnet_validators(&mut network, subnet_id)?;
+
+    // Pending validators are only fetched if needed as not every node serves them
+    let is_current_validator = network
+        .get_subnet(parse_id(subnet_id)?)
+        .map_err(|e| CliError::dataerr(format!("Error loading Subnet info: {e}")))?
+        .get_validator(node_id_parsed)
+        .is_ok();
+    if !is_current_validator {
+        update_subnet_pending_validators(&mut network, subnet_id)?;
+    }
+
+    let subnet = network
+        .get_subnet(parse_id(subnet_id)?)
+        .map_err(|e| CliError::dataerr(format!("Error loading Subnet info: {e}")))?;
+    let wallet = create_wallet(&network, signer_args)?;
+
+    // Make sure the validator exists, whether current or pending, before prompting for confirmation
+    subnet
+        .get_validator(node_id_parsed)
+        .or_else(|_| subnet.get_pending_validator(node_id_parsed))
+        .map_err(|e| CliError::dataerr(format!("Error removing validator: {e}")))?;
+
+    if !yes && !confirm_action("validator", Some("remove")) {
+        return Ok(());
+    }
+
+    if wait {
+        eprintln!("Waiting for transaction to be accepted...");
+    }
+
+    let tx_id = task::block_on(async {
+        subnet
+            .remove_validator_permissioned(&wallet, node_id_parsed, wait)
+            .await
+    })
+    .map_err(|e| CliError::dataerr(format!("Error removing validator: {e}")))?;
+
+    if json {
+        println!(
+            "{}",
+            serde_json::json!({ "txID": tx_id.to_string(), "nodeID": id })
+        );
+        return Ok(());
+    }
+
+    println!(
+        "{}",
+        template_validator_remove(&tx_id.to_string(), id, subnet_id, wait)
+    );
+
+    Ok(())
+}
+
+fn renew(
+    network_name: &str,
+    subnet_id: &str,
+    within: u64,
+    duration: u64,
+    signer_args: &SignerArgs,
+    wait: bool,
+    follow: bool,
+    dry_run: bool,
+    plan_file: Option<&str>,
+    yes: bool,
+    config: Option<&str>,
+    json: bool,
+) -> Result<(), CliError> {
+    let window = parse_days(within)?;
+    let validation_duration = parse_days(duration)?;
+
+    let mut network = load_network(network_name, config)?;
+    update_network_subnets(&mut network)?;
+    update_subnet_validators(&mut network, subnet_id)?;
+    update_subnet_pending_validators(&mut network, subnet_id)?;
+    update_subnet_validators(&mut network, AVAX_PRIMARY_NETWORK_ID)?;
+
+    let primary_network = network
+        .get_subnet(parse_id(AVAX_PRIMARY_NETWORK_ID)?)
+        .map_err(|e| CliError::dataerr(format!("Error loading Primary Network info: {e}")))?;
+    let subnet = network
+        .get_subnet(parse_id(subnet_id)?)
+        .map_err(|e| CliError::dataerr(format!("Error loading Subnet info: {e}")))?;
+
+    let wallet = create_wallet(&network, signer_args)?;
+    let staking_rules = p::AvalancheStakingRules::primary_network(&wallet)
+        .map_err(|e| CliError::dataerr(format!("Error getting staking rules: {e}")))?;
+
+    // The validators that have left the Subnet since the last run can only be renewed from the saved plan
+    let plan_file = match plan_file {
+        Some(plan_file) => plan_file.to_string(),
+        None => ValidatorRenewalsPlan::default_file(network_name, subnet_id),
+    };
+    let planned_renewals = match ValidatorRenewalsPlan::load(&plan_file)? {
+        Some(plan) if plan.subnet_id == subnet.id.to_string() => plan.renewals,
+        Some(_) => {
+            return Err(CliError::dataerr(format!(
+                "Error: renewals plan file '{plan_file}' belongs to another Subnet"
+            )))
+        }
+        None => vec![],
+    };
+
+    let renewals = subnet
+        .plan_validator_renewals(
+            &primary_network.validators,
+            &planned_renewals,
+            window,
+            validation_duration,
+            staking_rules.min_stake_duration,
+        )
+        .map_err(|e| CliError::dataerr(format!("Error planning validator renewals: {e}")))?;
+    let save_plan = |renewals: &[AvalancheValidatorRenewal]| {
+        ValidatorRenewalsPlan {
+            subnet_id: subnet.id.to_string(),
+            renewals: renewals
+                .iter()
+                .filter(|renewal| renewal.tx_id.is_none())
+                .cloned()
+                .collect(),
+        }
+        .save(&plan_file)
+    };
+
+    if !dry_run && renewals.is_empty() {
+        save_plan(&renewals)?;
+    }
+    if dry_run || renewals.is_empty() {
+        if json {
+            println!("{}", serde_json::to_string(&renewals).unwrap());
+            return Ok(());
+        }
+
+        println!(
+            "Found {} validators to renew on Subnet '{}':",
+            type_colorize(&renewals.len()),
+            type_colorize(&subnet_id)
+        );
+        if !renewals.is_empty() {
+            println!("{}", template_validator_renewals_table(&renewals, 2));
+        }
+        return Ok(());
+    }
+
+    if !json {
+        println!(
+            "Planned renewals of {} validators on Subnet '{}':",
+            type_colorize(&renewals.len()),
+            type_colorize(&subnet_id)
+        );
+        println!("{}", template_validator_renewals_table(&renewals, 2));
+    }
+    if !yes && !confirm_action("validators batch", Some("renew")) {
+        return Ok(());
+    }
+
+    if wait {
+        eprintln!("Waiting for transactions to be accepted...");
+    }
+
+    // Without --follow, only the validations that have already ended are renewed
+    // With --follow, the validations are renewed as they end
+    let mut renewed = renewals;
+    let mut next_end_time = Utc::now().timestamp() as u64;
+    loop {
+        let renewing = renewed
+            .iter()
+            .filter(|renewal| {
+                renewal.error.is_none()
+                    && renewal.tx_id.is_none()
+                    && renewal.current_end_time <= next_end_time
+            })
+            .count();
+        if renewing > 0 {
+            renewed =
+                task::block_on(async { subnet.renew_validators(&wallet, &renewed, wait).await })
+                    .map_err(|e| CliError::dataerr(format!("Error renewing validators: {e}")))?;
+            eprintln!(
+                "Issued the renewals of {} of {} validators whose validation has ended",
+                type_colorize(
+                    &renewed
+                        .iter()
+                        .filter(|renewal| renewal.current_end_time <= next_end_time
+                            && renewal.tx_id.is_some())
+                        .count()
+                ),
+                type_colorize(&renewing)
+            );
+        }
+
+        save_plan(&renewed)?;
+
+        let Some(end_time) = renewed
+            .iter()
+            .filter(|renewal| renewal.error.is_none() && renewal.tx_id.is_none())
+            .map(|renewal| renewal.current_end_time)
+            .min()
+        else {
+            break;
+        };
+        if !follow {
+            eprintln!(
+                "{}",
+                format!(
+                    "The next validation ends at {} (UTC): run this command again after it or use --follow",
+                    human_readable_timestamp(end_time)
+                )
+                .yellow()
+            );
+            break;
+        }
+
+        eprintln!(
+            "Waiting for the next validation to end at {} (UTC)...",
+            human_readable_timestamp(end_time)
+        );
+        let now = Utc::now().timestamp() as u64;
+        if end_time > now {
+            thread::sleep(std::time::Duration::from_secs(end_time - now));
+        }
+        next_end_time = end_time;
+    }
+
+    if json {
+        println!("{}", serde_json::to_string(&renewed).unwrap());
+        return Ok(());
+    }
+
+    println!(
+        "{} {} of {} validators on Subnet '{}':",
+        match wait {
+            true => "Renewed",
+            false => "Initiated renewal of",
+        },
+        type_colorize(
+            &renewed
+                .iter()
+                .filter(|renewal| renewal.tx_id.is_some())
+                .count()
+        ),
+        type_colorize(&renewed.len()),
+        type_colorize(&subnet_id)
+    );
+    println!("{}", template_validator_renewals_table(&renewed, 2));
+
+    Ok(())
+}
+
+fn delegate(
+    network_name: &str,
+    subnet_id: &str,
+    id: &str,
+    stake: u64,
+    start_time: Option<String>,
+    end_time: String,
+    rewards_owner_args: &RewardsOwnerArgs,
+    signer_args: &SignerArgs,
+    wait: bool,
+    config: Option<&str>,
+    json: bool,
+) -> Result<(), CliError> {
+    let node_id_parsed = parse_node_id(id)?;
+    let start_time_parsed = match start_time {
+        Some(start_time) => parse_datetime(&start_time)?,
+        None => Utc::now(),
+    };
+    let end_time_parsed = parse_datetime(&end_time)?;
+
+    let mut network = load_network(network_name, config)?;
+    update_network_subnets(&mut network)?;
+    update_subnet_validators(&mut network, subnet_id)?;
+
+    let subnet = network
+        .get_subnet(parse_id(subnet_id)?)
+        .map_err(|e| CliError::dataerr(format!("Error loading Subnet info: {e}")))?;
+    let wallet = create_wallet(&network, signer_args)?;
+
+    if wait {
+        eprintln!("Waiting for transaction to be accepted...");
+    }
+
+    let stake_amount = parse_avax_amount(stake)?;
+    let delegator = task::block_on(async {
+        subnet
+            .add_delegator(
+                &wallet,
+                node_id_parsed,
+                stake_amount,
+                start_time_parsed,
+                end_time_parsed,
+                &rewards_owner_args.reward_owners,
+                rewards_owner_args.reward_threshold,
+                wait,
+            )
+            .await
+    })
+    .map_err(|e| CliError::dataerr(format!("Error adding delegator: {e}")))?;
+
+    if json {
+        println!("{}", serde_json::to_string(&delegator).unwrap());
+        return Ok(());
+    }
+
+    println!("{}", template_delegator_add(&delegator, wait));
+
+    Ok(())
+}
+
 // Parse validator subcommand
 pub(crate) fn parse(
     validator: ValidatorCommand,
@@ -280,11 +841,14 @@ pub(crate) fn parse(
             start_time,
             end_time,
             delegation_fee,
-            private_key,
-            key_encoding,
+            signer_args,
             signer,
             signer_format,
+            rewards_owner_args,
             wait,
+            unsigned_out,
+            subnet_auth_keys,
+            dry_run,
         } => add(
             &validator.network,
             &validator.subnet_id,
@@ -293,17 +857,96 @@ pub(crate) fn parse(
             start_time,
             end_time,
             delegation_fee,
-            &private_key,
-            key_encoding,
-            signer,
-            signer_format,
+            &signer_args,
+            parse_signer(signer.as_deref(), signer_format)?,
+            &rewards_owner_args,
+            wait,
+            unsigned_out.as_deref(),
+            &subnet_auth_keys,
+            dry_run,
+            config,
+            json,
+        ),
+        ValidatorSubcommands::Remove {
+            id,
+            signer_args,
+            wait,
+            yes,
+        } => remove(
+            &validator.network,
+            &validator.subnet_id,
+            &id,
+            &signer_args,
+            wait,
+            yes,
+            config,
+            json,
+        ),
+        ValidatorSubcommands::Renew {
+            within,
+            duration,
+            signer_args,
             wait,
+            follow,
+            dry_run,
+            plan_file,
+            yes,
+        } => renew(
+            &validator.network,
+            &validator.subnet_id,
+            within,
+            duration,
+            &signer_args,
+            wait,
+            follow,
+            dry_run,
+            plan_file.as_deref(),
+            yes,
+            config,
+            json,
+        ),
+        ValidatorSubcommands::Delegate {
+            id,
+            stake,
+            start_time,
+            end_time,
+            rewards_owner_args,
+            signer_args,
+            wait,
+        } => delegate(
+            &validator.network,
+            &validator.subnet_id,
+            &id,
+            stake,
+            start_time,
+            end_time,
+            &rewards_owner_args,
+            &signer_args,
+            wait,
+            config,
+            json,
+        ),
+        ValidatorSubcommands::Report {
+            expiry_days,
+            flagged_only,
+        } => report(
+            &validator.network,
+            &validator.subnet_id,
+            expiry_days,
+            flagged_only,
             config,
             json,
         ),
         ValidatorSubcommands::Info { id } => {
             info(&validator.network, &validator.subnet_id, &id, config, json)
         }
-        ValidatorSubcommands::List => list(&validator.network, &validator.subnet_id, config, json),
+        ValidatorSubcommands::List { pending, node_ids } => list(
+            &validator.network,
+            &validator.subnet_id,
+            pending,
+            &node_ids,
+            config,
+            json,
+        ),
     }
 }
@@ -5,11 +5,38 @@
 
 use crate::{
     avalanche::*,
-    utils::{error::CliError, templating::*, version_tx_cmd},
+    utils::{
+        error::CliError, keyring::get_keyring_value, prompt::confirm_action, templating::*,
+        version_tx_cmd,
+    },
+};
+#[cfg(unix)]
+use ash_sdk::avalanche::wallets::remote::RemoteSigner;
+use ash_sdk::avalanche::{
+    txs::{move_avax, AvalanchePrimaryNetworkChain},
+    wallets::{
+        derive_private_key, generate_private_key,
+        keystore::{decrypt_keystore, get_keystore_path, list_keystores, remove_keystore},
+        AvalancheSignerKey, AvalancheWallet, AvalancheWalletInfo, LocalSigner,
+        AVAX_ACCOUNT_DERIV_PATH,
+    },
 };
-use ash_sdk::avalanche::wallets::{generate_private_key, AvalancheWallet, AvalancheWalletInfo};
-use clap::{Parser, Subcommand, ValueEnum};
-use std::fmt::Display;
+use async_std::task;
+use clap::{ArgGroup, Args, Parser, Subcommand, ValueEnum};
+use colored::Colorize;
+use inquire::Password;
+use std::{
+    env,
+    fmt::Display,
+    io,
+    path::{Path, PathBuf},
+};
+
+// Keyring target of the wallet private keys
+const KEYRING_TARGET: &str = "ash-avalanche-wallet";
+const KEYRING_FALLBACK_FILES_DIR: &str = "~/.ash-avalanche/keys";
+// Directory of the named wallets keystores (can be overridden with AVALANCHE_WALLETS_DIR)
+const WALLETS_DIR: &str = "~/.ash-avalanche/wallets";
 
 /// Interact with Avalanche wallets
 #[derive(Parser)]
@@ -30,13 +57,13 @@ pub(crate) struct WalletCommand {
 
 #[derive(Subcommand)]
 enum WalletSubcommands {
-    /// Get information about a wallet (linked to a private key)
+    /// Get information about a wallet (linked to a private key or a mnemonic phrase)
     #[command(version = version_tx_cmd(false))]
     Info {
-        /// Private key of the wallet
+        /// Private key (or mnemonic phrase) of the wallet
         #[arg(env = "AVALANCHE_PRIVATE_KEY")]
         private_key: String,
-        /// Private key encoding (cb58 or hex)
+        /// Private key encoding (cb58, hex or mnemonic)
         #[arg(
             long,
             short = 'e',
@@ -44,27 +71,313 @@ enum WalletSubcommands {
             env = "AVALANCHE_KEY_ENCODING"
         )]
         key_encoding: PrivateKeyEncoding,
+        /// Index of the account to derive from the mnemonic phrase
+        #[arg(long, short = 'i', default_value = "0")]
+        account_index: u32,
+    },
+    /// Discover the accounts derived from a mnemonic phrase
+    #[command(version = version_tx_cmd(false))]
+    Discover {
+        /// Mnemonic phrase to derive the accounts from
+        #[arg(env = "AVALANCHE_MNEMONIC_PHRASE")]
+        phrase: String,
+        /// Index of the first account to derive
+        #[arg(long, short = 's', default_value = "0")]
+        start_index: u32,
+        /// Number of accounts to derive
+        #[arg(long, default_value = "10")]
+        count: u32,
     },
     /// Randomly generate a private key (giving access to a wallet)
     #[command(version = version_tx_cmd(false))]
     Generate,
+    /// Import a private key as a named wallet, stored in a password-encrypted keystore
+    #[command(version = version_tx_cmd(false))]
+    Import {
+        /// Name of the wallet
+        name: String,
+        /// Read the private key to import from stdin instead of prompting it
+        #[arg(long, conflicts_with = "generate")]
+        stdin: bool,
+        /// Private key encoding (cb58 or hex)
+        #[arg(
+            long,
+            short = 'e',
+            default_value = "cb58",
+            env = "AVALANCHE_KEY_ENCODING"
+        )]
+        key_encoding: PrivateKeyEncoding,
+        /// Randomly generate the private key of the wallet
+        #[arg(long, short = 'g')]
+        generate: bool,
+    },
+    /// List the named wallets
+    #[command(version = version_tx_cmd(false))]
+    List,
+    /// Remove a named wallet
+    #[command(version = version_tx_cmd(false))]
+    Remove {
+        /// Name of the wallet
+        name: String,
+        /// Assume yes to all prompts
+        #[arg(long, short = 'y')]
+        yes: bool,
+    },
+    /// Export the private key of a named wallet
+    #[command(version = version_tx_cmd(false))]
+    Export {
+        /// Name of the wallet
+        name: String,
+    },
+    /// Move AVAX between the X-Chain, P-Chain and C-Chain (export then import)
+    #[command(version = version_tx_cmd(true))]
+    Move {
+        /// Amount of AVAX to move (1 AVAX = 10^9 nAVAX), the fees are paid on top of it
+        amount: String,
+        /// Chain to move the AVAX from
+        #[arg(long, short = 'f', ignore_case = true)]
+        from: PrimaryNetworkChain,
+        /// Chain to move the AVAX to
+        #[arg(long, short = 't', ignore_case = true)]
+        to: PrimaryNetworkChain,
+        #[command(flatten)]
+        signer_args: SignerArgs,
+        /// Whether to wait for the import transaction acceptance (the export one is always awaited)
+        #[arg(long, short = 'w')]
+        wait: bool,
+    },
+}
+
+/// Chains of the Primary Network
+#[derive(Display, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
+pub(crate) enum PrimaryNetworkChain {
+    #[value(name = "X")]
+    X,
+    #[value(name = "P")]
+    P,
+    #[value(name = "C")]
+    C,
+}
+
+impl From<PrimaryNetworkChain> for AvalanchePrimaryNetworkChain {
+    fn from(chain: PrimaryNetworkChain) -> Self {
+        match chain {
+            PrimaryNetworkChain::X => AvalanchePrimaryNetworkChain::X,
+            PrimaryNetworkChain::P => AvalanchePrimaryNetworkChain::P,
+            PrimaryNetworkChain::C => AvalanchePrimaryNetworkChain::C,
+        }
+    }
+}
+
+/// Key used to sign the transactions
+/// Exactly one key source has to be provided
+#[derive(Args)]
+#[command(group(
+    ArgGroup::new("signer_key")
+        .args(["private_key", "wallet", "keystore", "keyring", "remote_signer", "public_key"])
+        .required(true)
+        .multiple(false)
+))]
+pub(crate) struct SignerArgs {
+    /// Private key to sign the transaction(s) with
+    #[arg(long, short = 'p', env = "AVALANCHE_PRIVATE_KEY")]
+    private_key: Option<String>,
+    /// Private key encoding (cb58, hex or mnemonic)
+    #[arg(
+        long,
+        short = 'e',
+        default_value = "cb58",
+        env = "AVALANCHE_KEY_ENCODING"
+    )]
+    key_encoding: PrivateKeyEncoding,
+    /// Named wallet to sign the transaction(s) with (see `ash avalanche wallet import`)
+    #[arg(long, env = "AVALANCHE_WALLET")]
+    wallet: Option<String>,
+    /// Encrypted keystore file to sign the transaction(s) with (password read from AVALANCHE_KEYSTORE_PASSWORD or prompted)
+    #[arg(long, env = "AVALANCHE_KEYSTORE")]
+    keystore: Option<String>,
+    /// Device keyring entry holding the private key (CB58 or hex) to sign the transaction(s) with
+    #[arg(long, env = "AVALANCHE_KEYRING_ENTRY")]
+    keyring: Option<String>,
+    /// Unix socket of a remote signing process to sign the transaction(s) with
+    #[arg(long, env = "AVALANCHE_REMOTE_SIGNER")]
+    remote_signer: Option<String>,
+    /// Public key (hex) of a watch-only wallet, which can only build unsigned transactions (see --unsigned-out)
+    #[arg(long, env = "AVALANCHE_PUBLIC_KEY")]
+    public_key: Option<String>,
 }
 
 #[derive(Display, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
 pub(crate) enum PrivateKeyEncoding {
     Cb58,
     Hex,
+    /// BIP-39 mnemonic phrase (the first account is used)
+    Mnemonic,
+}
+
+// Create a local signer from a CB58 or hex-encoded private key
+fn parse_local_signer(private_key: &str) -> Result<LocalSigner, CliError> {
+    match private_key.starts_with("0x") {
+        true => LocalSigner::from_hex(private_key),
+        false => LocalSigner::from_cb58(private_key),
+    }
+    .map_err(|e| CliError::dataerr(format!("Error parsing private key: {e}")))
 }
 
-// Create a wallet from a private key
+// Get the password of a keystore from the environment or prompt it
+// New passwords have to be confirmed when prompted
+fn get_keystore_password(keystore: &str, new_password: bool) -> Result<String, CliError> {
+    if let Ok(password) = env::var("AVALANCHE_KEYSTORE_PASSWORD") {
+        return Ok(password);
+    }
+
+    let prompt_message = format!("Password of keystore '{keystore}':");
+    let password_prompt = Password::new(&prompt_message);
+    match new_password {
+        true => password_prompt,
+        false => password_prompt.without_confirmation(),
+    }
+    .prompt()
+    .map_err(|e| CliError::dataerr(format!("Error reading keystore password: {e}")))
+}
+
+// Get the directory of the named wallets
+fn get_wallets_dir() -> PathBuf {
+    let wallets_dir = env::var("AVALANCHE_WALLETS_DIR").unwrap_or(WALLETS_DIR.to_string());
+
+    PathBuf::from(shellexpand::tilde(&wallets_dir).to_string())
+}
+
+// Get the keystore path of a named wallet
+fn get_wallet_keystore_path(name: &str) -> Result<PathBuf, CliError> {
+    let keystore_path = get_keystore_path(&get_wallets_dir(), name)
+        .map_err(|e| CliError::dataerr(format!("Error loading wallet '{name}': {e}")))?;
+
+    if !keystore_path.exists() {
+        return Err(CliError::dataerr(format!(
+            "Error loading wallet '{name}': wallet not found in '{}'",
+            get_wallets_dir().display()
+        )));
+    }
+
+    Ok(keystore_path)
+}
+
+// Create the signer backend selected by the signer arguments, if any
+fn create_signer_backend(signer_args: &SignerArgs) -> Result<Option<AvalancheSignerKey>, CliError> {
+    let signer_creation_error = |e| CliError::dataerr(format!("Error creating signer: {e}"));
+
+    if let Some(remote_signer) = &signer_args.remote_signer {
+        #[cfg(unix)]
+        return Ok(Some(
+            RemoteSigner::connect(Path::new(remote_signer))
+                .map_err(signer_creation_error)?
+                .into(),
+        ));
+        #[cfg(not(unix))]
+        return Err(CliError::dataerr(format!(
+            "Error creating signer: remote signer '{remote_signer}' requires Unix sockets"
+        )));
+    }
+
+    // Named wallets are keystores stored in the wallets directory
+    let keystore_path = match (&signer_args.wallet, &signer_args.keystore) {
+        (Some(wallet_name), _) => Some(get_wallet_keystore_path(wallet_name)?),
+        (None, Some(keystore)) => Some(PathBuf::from(keystore)),
+        (None, None) => None,
+    };
+
+    if let Some(keystore_path) = keystore_path {
+        let password = get_keystore_password(&keystore_path.display().to_string(), false)?;
+        return Ok(Some(
+            LocalSigner::from_keystore(&keystore_path, &password)
+                .map_err(signer_creation_error)?
+                .into(),
+        ));
+    }
+
+    if let Some(keyring_entry) = &signer_args.keyring {
+        let local_signer = parse_local_signer(&get_keyring_value(
+            KEYRING_TARGET,
+            keyring_entry,
+            KEYRING_FALLBACK_FILES_DIR,
+            &format!("private key from keyring entry '{keyring_entry}'"),
+        )?)?;
+        return Ok(Some(local_signer.into()));
+    }
+
+    Ok(None)
+}
+
+// Create a signer from the signer arguments without connecting to the network
+// It is used to sign transactions on an air-gapped machine
+pub(crate) fn create_signer(signer_args: &SignerArgs) -> Result<AvalancheSignerKey, CliError> {
+    if signer_args.public_key.is_some() {
+        return Err(CliError::dataerr(
+            "Error creating signer: a watch-only wallet cannot sign transactions".to_string(),
+        ));
+    }
+
+    if let Some(signer) = create_signer_backend(signer_args)? {
+        return Ok(signer);
+    }
+
+    // The private key is required by clap if no signer backend is provided
+    let private_key = signer_args.private_key.as_deref().unwrap_or_default();
+    let local_signer = match signer_args.key_encoding {
+        PrivateKeyEncoding::Cb58 => LocalSigner::from_cb58(private_key),
+        PrivateKeyEncoding::Hex => LocalSigner::from_hex(private_key),
+        PrivateKeyEncoding::Mnemonic => {
+            derive_private_key(private_key, AVAX_ACCOUNT_DERIV_PATH, 0).map(LocalSigner::new)
+        }
+    }
+    .map_err(|e| CliError::dataerr(format!("Error creating signer from private key: {e}")))?;
+
+    Ok(local_signer.into())
+}
+
+// Create a wallet from the signer arguments
+// Mnemonic phrases are derived at the first account index
 pub(crate) fn create_wallet(
+    network: &AvalancheNetwork,
+    signer_args: &SignerArgs,
+) -> Result<AvalancheWallet, CliError> {
+    let wallet_creation_error =
+        |e| CliError::dataerr(format!("Error creating wallet from signer: {e}"));
+
+    if let Some(public_key) = &signer_args.public_key {
+        return network
+            .create_wallet_from_public_key(public_key)
+            .map_err(wallet_creation_error);
+    }
+
+    match create_signer_backend(signer_args)? {
+        Some(signer) => network
+            .create_wallet_from_signer(signer)
+            .map_err(wallet_creation_error),
+        // The private key is required by clap if no signer backend is provided
+        None => create_wallet_with_account_index(
+            network,
+            signer_args.private_key.as_deref().unwrap_or_default(),
+            signer_args.key_encoding,
+            0,
+        ),
+    }
+}
+
+// Create a wallet from a private key or from an account of a mnemonic phrase
+pub(crate) fn create_wallet_with_account_index(
     network: &AvalancheNetwork,
     private_key: &str,
     key_encoding: PrivateKeyEncoding,
+    account_index: u32,
 ) -> Result<AvalancheWallet, CliError> {
     let wallet = match key_encoding {
         PrivateKeyEncoding::Cb58 => network.create_wallet_from_cb58(private_key),
         PrivateKeyEncoding::Hex => network.create_wallet_from_hex(private_key),
+        PrivateKeyEncoding::Mnemonic => {
+            network.create_wallet_from_mnemonic(private_key, account_index)
+        }
     }
     .map_err(|e| CliError::dataerr(format!("Error creating wallet from private key: {e}")))?;
 
@@ -75,12 +388,14 @@ fn info(
     network_name: &str,
     private_key: &str,
     key_encoding: PrivateKeyEncoding,
+    account_index: u32,
     config: Option<&str>,
     json: bool,
 ) -> Result<(), CliError> {
     let network = load_network(network_name, config)?;
 
-    let wallet = create_wallet(&network, private_key, key_encoding)?;
+    let wallet =
+        create_wallet_with_account_index(&network, private_key, key_encoding, account_index)?;
 
     let wallet_info: AvalancheWalletInfo = wallet.into();
 
@@ -94,6 +409,34 @@ fn info(
     Ok(())
 }
 
+fn discover(
+    network_name: &str,
+    phrase: &str,
+    start_index: u32,
+    count: u32,
+    config: Option<&str>,
+    json: bool,
+) -> Result<(), CliError> {
+    let network = load_network(network_name, config)?;
+
+    let accounts = network
+        .discover_mnemonic_accounts(phrase, start_index, count)
+        .map_err(|e| {
+            CliError::dataerr(format!(
+                "Error discovering accounts from mnemonic phrase: {e}"
+            ))
+        })?;
+
+    if json {
+        println!("{}", serde_json::to_string(&accounts).unwrap());
+        return Ok(());
+    }
+
+    println!("{}", template_mnemonic_accounts_table(&accounts, 0));
+
+    Ok(())
+}
+
 fn generate(json: bool) -> Result<(), CliError> {
     let private_key = generate_private_key()
         .map_err(|e| CliError::dataerr(format!("Error generating private key: {e}")))?;
@@ -114,6 +457,203 @@ fn generate(json: bool) -> Result<(), CliError> {
     Ok(())
 }
 
+fn import(
+    name: &str,
+    stdin: bool,
+    key_encoding: PrivateKeyEncoding,
+    generate: bool,
+    json: bool,
+) -> Result<(), CliError> {
+    let local_signer = match generate {
+        true => LocalSigner::new(
+            generate_private_key()
+                .map_err(|e| CliError::dataerr(format!("Error generating private key: {e}")))?,
+        ),
+        false => {
+            // The private key is never read from the arguments so that it does not end up in the shell history
+            let private_key = match stdin {
+                true => {
+                    let mut private_key = String::new();
+                    io::stdin().read_line(&mut private_key).map_err(|e| {
+                        CliError::dataerr(format!("Error reading private key: {e}"))
+                    })?;
+                    private_key.trim().to_string()
+                }
+                false => Password::new("Private key:")
+                    .without_confirmation()
+                    .prompt()
+                    .map_err(|e| CliError::dataerr(format!("Error reading private key: {e}")))?,
+            };
+
+            match key_encoding {
+                PrivateKeyEncoding::Cb58 => LocalSigner::from_cb58(&private_key),
+                PrivateKeyEncoding::Hex => LocalSigner::from_hex(&private_key),
+                PrivateKeyEncoding::Mnemonic => {
+                    return Err(CliError::dataerr(
+                        "Error importing wallet: mnemonic phrases cannot be imported, import the private key of an account instead"
+                            .to_string(),
+                    ))
+                }
+            }
+            .map_err(|e| CliError::dataerr(format!("Error parsing private key: {e}")))?
+        }
+    };
+
+    let password = get_keystore_password(name, true)?;
+    let keystore_path = local_signer
+        .to_keystore(&get_wallets_dir(), name, &password)
+        .map_err(|e| CliError::dataerr(format!("Error importing wallet '{name}': {e}")))?;
+
+    if json {
+        println!(
+            "{}",
+            serde_json::json!({ "name": name, "keystore": keystore_path.display().to_string() })
+        );
+        return Ok(());
+    }
+
+    println!(
+        "{}",
+        format!(
+            "Wallet '{name}' imported to keystore '{}'!",
+            keystore_path.display()
+        )
+        .green()
+    );
+
+    Ok(())
+}
+
+fn list(json: bool) -> Result<(), CliError> {
+    let wallets_dir = get_wallets_dir();
+    let names = list_keystores(&wallets_dir)
+        .map_err(|e| CliError::dataerr(format!("Error listing wallets: {e}")))?;
+
+    if json {
+        println!(
+            "{}",
+            serde_json::json!(names
+                .iter()
+                .map(|name| serde_json::json!({
+                    "name": name,
+                    "keystore": wallets_dir.join(name).display().to_string(),
+                }))
+                .collect::<Vec<_>>())
+        );
+        return Ok(());
+    }
+
+    println!("{}", template_wallets_table(&names, &wallets_dir, 0));
+
+    Ok(())
+}
+
+fn remove(name: &str, yes: bool, json: bool) -> Result<(), CliError> {
+    // Make sure the wallet exists before prompting for confirmation
+    get_wallet_keystore_path(name)?;
+
+    if !yes && !confirm_action("wallet", Some("remove")) {
+        return Ok(());
+    }
+
+    remove_keystore(&get_wallets_dir(), name)
+        .map_err(|e| CliError::dataerr(format!("Error removing wallet '{name}': {e}")))?;
+
+    if json {
+        println!("{}", serde_json::json!({ "name": name }));
+        return Ok(());
+    }
+
+    println!("{}", format!("Wallet '{name}' removed!").green());
+
+    Ok(())
+}
+
+fn export(name: &str, json: bool) -> Result<(), CliError> {
+    let keystore_path = get_wallet_keystore_path(name)?;
+    let password = get_keystore_password(&keystore_path.display().to_string(), false)?;
+
+    let private_key = decrypt_keystore(&keystore_path, &password)
+        .map_err(|e| CliError::dataerr(format!("Error exporting wallet '{name}': {e}")))?;
+
+    if json {
+        println!(
+            "{}",
+            serde_json::json!({ "cb58": private_key.to_cb58(), "hex": private_key.to_hex() })
+        );
+        return Ok(());
+    }
+
+    println!(
+        "{}",
+        template_generate_private_key(&private_key.to_cb58(), &private_key.to_hex(), 0)
+    );
+
+    Ok(())
+}
+
+fn move_funds(
+    network_name: &str,
+    amount: &str,
+    from: PrimaryNetworkChain,
+    to: PrimaryNetworkChain,
+    signer_args: &SignerArgs,
+    wait: bool,
+    config: Option<&str>,
+    json: bool,
+) -> Result<(), CliError> {
+    let amount_navax = parse_avax_decimal_amount(amount)?;
+
+    let network = load_network(network_name, config)?;
+
+    let wallet = create_wallet(&network, signer_args)?;
+
+    eprintln!("Waiting for export transaction to be accepted...");
+
+    let (export_tx_id, import_tx_id) = task::block_on(async {
+        move_avax(
+            &network,
+            &wallet,
+            from.into(),
+            to.into(),
+            amount_navax,
+            wait,
+        )
+        .await
+    })
+    .map_err(|e| {
+        CliError::dataerr(format!(
+            "Error moving {amount} AVAX from {from}-Chain to {to}-Chain: {e}"
+        ))
+    })?;
+
+    if json {
+        println!(
+            "{}",
+            serde_json::json!({
+                "exportTxID": export_tx_id.to_string(),
+                "importTxID": import_tx_id.to_string(),
+            })
+        );
+        return Ok(());
+    }
+
+    println!(
+        "{}",
+        template_wallet_move(
+            amount,
+            &from.to_string(),
+            &to.to_string(),
+            &export_tx_id.to_string(),
+            &import_tx_id.to_string(),
+            wait,
+            0
+        )
+    );
+
+    Ok(())
+}
+
 // Parse wallet subcommand
 pub(crate) fn parse(
     wallet: WalletCommand,
@@ -124,7 +664,45 @@ pub(crate) fn parse(
         WalletSubcommands::Info {
             private_key,
             key_encoding,
-        } => info(&wallet.network, &private_key, key_encoding, config, json),
+            account_index,
+        } => info(
+            &wallet.network,
+            &private_key,
+            key_encoding,
+            account_index,
+            config,
+            json,
+        ),
+        WalletSubcommands::Discover {
+            phrase,
+            start_index,
+            count,
+        } => discover(&wallet.network, &phrase, start_index, count, config, json),
         WalletSubcommands::Generate => generate(json),
+        WalletSubcommands::Import {
+            name,
+            stdin,
+            key_encoding,
+            generate,
+        } => import(&name, stdin, key_encoding, generate, json),
+        WalletSubcommands::List => list(json),
+        WalletSubcommands::Remove { name, yes } => remove(&name, yes, json),
+        WalletSubcommands::Export { name } => export(&name, json),
+        WalletSubcommands::Move {
+            amount,
+            from,
+            to,
+            signer_args,
+            wait,
+        } => move_funds(
+            &wallet.network,
+            &amount,
+            from,
+            to,
+            &signer_args,
+            wait,
+            config,
+            json,
+        ),
     }
 }
@@ -4,12 +4,29 @@
 // Module that contains the vm subcommand parser
 
 use crate::{
-    avalanche::*,
-    utils::{error::CliError, parsing::*, templating::*},
+    avalanche::{wallet::*, *},
+    utils::{error::CliError, parsing::*, state::WarpWatchCursor, templating::*, version_tx_cmd},
+};
+use ash_sdk::{
+    avalanche::{
+        blockchains::AvalancheBlockchain,
+        warp::{
+            endpoints::StaticEndpointResolver, WarpMessage, WarpMessagePayload,
+            WarpSignatureRequestPolicy,
+        },
+        AvalancheNetwork,
+    },
+    errors::AshError,
+    ethers::types::{Address, H256},
+    ids::Id,
 };
 use async_std::task;
 use clap::{Parser, Subcommand};
 use colored::Colorize;
+use std::{str::FromStr, sync::Arc, thread, time::Duration};
+
+// Maximum delay between two retries of the watcher after an RPC error (in seconds)
+const WATCH_MAX_RETRY_DELAY: u64 = 300;
 
 /// Interact with Avalanche Warp Messaging
 #[derive(Parser)]
@@ -26,6 +43,11 @@ pub(crate) struct WarpCommand {
         env = "AVALANCHE_NETWORK"
     )]
     network: String,
+    /// HTTP endpoint of a validator node used to request Warp message signatures,
+    /// in the format <node_id>=<http_endpoint> (can be repeated)
+    /// Overrides the node endpoints of the configuration
+    #[arg(long = "node-endpoint", global = true)]
+    node_endpoints: Vec<String>,
 }
 
 #[derive(Subcommand)]
@@ -46,10 +68,140 @@ enum WarpSubcommands {
         #[arg(long, short = 'e')]
         extended: bool,
     },
+    /// Watch the Warp: follow new blocks and emit Avalanche Warp Messages as they are sent
+    /// The last processed block is persisted to a cursor file to resume after a restart
+    #[command()]
+    Watch {
+        /// Source chain ID or name
+        source_chain: String,
+        /// Block from which to start watching if there is no cursor
+        #[arg(long, short = 'f', default_value = "latest")]
+        from_block: String,
+        /// Path to the cursor file
+        /// Defaults to ~/.local/state/ash/warp/<network>-<blockchain_id>.json
+        #[arg(long)]
+        cursor_file: Option<String>,
+        /// Interval between block polls (in seconds)
+        #[arg(
+            long,
+            short = 'i',
+            default_value = "5",
+            value_parser = clap::value_parser!(u64).range(1..)
+        )]
+        interval: u64,
+        /// Maximum number of blocks to query at once
+        #[arg(
+            long,
+            short = 'm',
+            default_value = "2048",
+            value_parser = clap::value_parser!(u64).range(1..)
+        )]
+        max_blocks: u64,
+        /// Show extended information (notably signatures)
+        /// This option is only available in non-JSON mode
+        #[arg(long, short = 'e')]
+        extended: bool,
+    },
+    /// Send a Warp message from a chain to an address on a destination chain
+    #[command(version = version_tx_cmd(true))]
+    Send {
+        /// Source chain ID or name
+        source_chain: String,
+        /// Destination chain ID or name
+        #[arg(long, short = 'd')]
+        destination_chain: String,
+        /// Destination address of the message
+        #[arg(long, short = 'a')]
+        destination_address: String,
+        /// Hex-encoded payload of the message
+        #[arg(long, short = 'm', default_value = "0x")]
+        payload: String,
+        #[command(flatten)]
+        signer_args: SignerArgs,
+    },
+    /// Relay a Warp message: aggregate its signatures and deliver it to the destination chain
+    #[command(version = version_tx_cmd(true))]
+    Relay {
+        /// Warp message ID
+        message_id: String,
+        /// Source chain ID or name
+        #[arg(long, short = 's')]
+        source_chain: String,
+        /// Destination chain ID or name
+        /// Defaults to the destination chain of the message
+        #[arg(long, short = 'd')]
+        destination_chain: Option<String>,
+        /// Block from which to search for the message
+        #[arg(long, short = 'f', default_value = "earliest")]
+        from_block: String,
+        /// Block at which to stop searching for the message
+        #[arg(long, short = 't', default_value = "latest")]
+        to_block: String,
+        /// Hex-encoded calldata of the transaction sent to the message destination address
+        #[arg(long, default_value = "0x")]
+        calldata: String,
+        /// Minimum percentage of the Subnet weight that must have signed the message
+        #[arg(
+            long,
+            short = 'q',
+            default_value = "67",
+            value_parser = clap::value_parser!(u8).range(1..=100)
+        )]
+        quorum: u8,
+        /// Timeout of each signature request to a validator node (in seconds)
+        #[arg(long, default_value = "10")]
+        signature_timeout: u64,
+        /// Number of retries after a failed signature request to a validator node
+        #[arg(long, default_value = "2")]
+        signature_retries: u32,
+        #[command(flatten)]
+        signer_args: SignerArgs,
+    },
+}
+
+// Add the node endpoints provided on the command line to the network
+fn add_node_endpoints(
+    network: &mut AvalancheNetwork,
+    node_endpoints: &[String],
+) -> Result<(), CliError> {
+    for node_endpoint in node_endpoints {
+        let node_endpoint = parse_node_endpoint(node_endpoint)?;
+        network
+            .node_endpoints
+            .retain(|existing| existing.node_id != node_endpoint.node_id);
+        network.node_endpoints.push(node_endpoint);
+    }
+
+    Ok(())
+}
+
+// Get the resolver of the validator nodes RPC endpoints of a blockchain
+// If the peers of the blockchain RPC node cannot be queried, only use the configured node endpoints
+fn get_validator_endpoint_resolver(
+    network: &AvalancheNetwork,
+    blockchain: &AvalancheBlockchain,
+) -> StaticEndpointResolver {
+    network
+        .get_validator_endpoint_resolver(blockchain)
+        .unwrap_or_else(|e| {
+            eprintln!(
+                "{}",
+                format!("Warning: only using the configured node endpoints: {e}").yellow()
+            );
+            StaticEndpointResolver::new(&network.node_endpoints, None)
+        })
+}
+
+// Warn about the logs that could not be parsed as Warp messages and are skipped
+fn warn_warp_log_failures(failures: &[(H256, AshError)]) {
+    for (_, e) in failures {
+        eprintln!("{}", format!("Warning: skipping Warp log: {e}").yellow());
+    }
 }
 
 fn navigate(
     network_name: &str,
+    node_endpoints: &[String],
     source_chain: &str,
     from_block: &str,
     to_block: &str,
@@ -66,40 +218,43 @@ fn navigate(
     );
 
     let mut network = load_network(network_name, config)?;
+    add_node_endpoints(&mut network, node_endpoints)?;
     update_network_subnets(&mut network)?;
 
-    // Try loading the blockchain by its ID or by its name depending on whether source_chain is an ID
-    let blockchain_id = parse_id(source_chain);
-    let blockchain = match blockchain_id {
-        Ok(id) => network
-            .get_blockchain(id)
-            .map_err(|e| CliError::dataerr(format!("Error loading blockchain info: {e}")))?,
-        Err(_) => network
-            .get_blockchain_by_name(source_chain)
-            .map_err(|e| CliError::dataerr(format!("Error loading blockchain info: {e}")))?,
-    }
-    .clone();
-    update_subnet_validators(&mut network, &blockchain.subnet_id.to_string())?;
+    let blockchain = load_blockchain(&network, source_chain)?;
+    update_subnet_validators_with_signers(&mut network, &blockchain.subnet_id.to_string())?;
 
     let subnet = network
         .get_subnet(blockchain.subnet_id)
         .map_err(|e| CliError::dataerr(format!("Error loading subnet info: {e}")))?;
+    let resolver = get_validator_endpoint_resolver(&network, &blockchain);
 
-    let warp_messages =
+    let (warp_messages, failures) =
         task::block_on(async { blockchain.get_warp_messages(from_block, to_block).await })
-            .map_err(|e| CliError::dataerr(format!("Error reading warp messages: {e}")))?
-            .iter()
-            .map(|warp_message| {
-                let mut signed_warp_message = warp_message.clone();
-                let signatures = subnet
-                    .get_warp_message_node_signatures(warp_message, None)
-                    .unwrap_or(vec![]);
-                for sig in signatures {
-                    signed_warp_message.add_node_signature(sig);
-                }
-                signed_warp_message
-            })
-            .collect::<Vec<_>>();
+            .map_err(|e| CliError::dataerr(format!("Error reading warp messages: {e}")))?;
+    warn_warp_log_failures(&failures);
+
+    let warp_messages = warp_messages
+        .iter()
+        .map(|warp_message| {
+            let mut signed_warp_message = warp_message.clone();
+            let signatures = subnet
+                .get_warp_message_node_signatures(
+                    warp_message,
+                    None,
+                    &WarpSignatureRequestPolicy::default(),
+                    &resolver,
+                )
+                .map_err(|e| {
+                    CliError::dataerr(format!("Error getting Warp message signatures: {e}"))
+                })?
+                .signatures;
+            for sig in signatures {
+                signed_warp_message.add_node_signature(sig);
+            }
+            Ok(signed_warp_message)
+        })
+        .collect::<Result<Vec<_>, CliError>>()?;
 
     if json {
         println!("{}", serde_json::to_string(&warp_messages).unwrap());
@@ -117,6 +272,421 @@ fn navigate(
     Ok(())
 }
 
+fn watch(
+    network_name: &str,
+    node_endpoints: &[String],
+    source_chain: &str,
+    from_block: &str,
+    cursor_file: Option<&str>,
+    interval: u64,
+    max_blocks: u64,
+    extended: bool,
+    config: Option<&str>,
+    json: bool,
+) -> Result<(), CliError> {
+    // Display warning about the experimental nature of this feature
+    eprintln!(
+        "{}",
+        "Warning: this feature is experimental and may break at any time."
+            .yellow()
+            .bold()
+    );
+
+    let mut network = load_network(network_name, config)?;
+    add_node_endpoints(&mut network, node_endpoints)?;
+    update_network_subnets(&mut network)?;
+
+    let blockchain = load_blockchain(&network, source_chain)?;
+    update_subnet_validators_with_signers(&mut network, &blockchain.subnet_id.to_string())?;
+
+    let subnet = network
+        .get_subnet(blockchain.subnet_id)
+        .map_err(|e| CliError::dataerr(format!("Error loading subnet info: {e}")))?;
+    let resolver = get_validator_endpoint_resolver(&network, &blockchain);
+
+    let cursor_file = match cursor_file {
+        Some(cursor_file) => cursor_file.to_string(),
+        None => WarpWatchCursor::default_file(network_name, &blockchain.id.to_string()),
+    };
+
+    // Resume from the cursor if it exists, otherwise start from the provided block
+    let mut next_block = match WarpWatchCursor::load(&cursor_file)? {
+        Some(cursor) if cursor.blockchain_id == blockchain.id.to_string() => {
+            cursor.last_processed_block.saturating_add(1)
+        }
+        Some(_) => {
+            return Err(CliError::dataerr(format!(
+                "Error: cursor file '{cursor_file}' belongs to another blockchain"
+            )))
+        }
+        None => match from_block {
+            "latest" => get_latest_block_number(&blockchain)?,
+            "earliest" => 0,
+            block => block.parse::<u64>().map_err(|e| {
+                CliError::dataerr(format!("Error parsing block number '{block}': {e}"))
+            })?,
+        },
+    };
+
+    eprintln!(
+        "Watching Warp messages sent from '{}' starting at block {}...",
+        blockchain.name, next_block
+    );
+
+    // Transient RPC errors must not end the watcher, they are retried with an exponential backoff
+    // Ranges that cannot be read for another reason are reported and skipped
+    let mut retry_delay = interval;
+    loop {
+        let (to_block, warp_messages) =
+            match get_next_warp_messages(&blockchain, next_block, max_blocks) {
+                Ok(Some(next_warp_messages)) => {
+                    retry_delay = interval;
+                    next_warp_messages
+                }
+                Ok(None) => {
+                    retry_delay = interval;
+                    thread::sleep(Duration::from_secs(interval));
+                    continue;
+                }
+                Err(e @ AshError::RpcError(_)) => {
+                    eprintln!(
+                        "{}",
+                        format!("Error reading Warp messages: {e}, retrying in {retry_delay}s...")
+                            .yellow()
+                    );
+                    thread::sleep(Duration::from_secs(retry_delay));
+                    retry_delay = (retry_delay * 2).clamp(1, WATCH_MAX_RETRY_DELAY);
+                    continue;
+                }
+                Err(e) => {
+                    return Err(CliError::dataerr(format!(
+                        "Error reading Warp messages: {e}"
+                    )))
+                }
+            };
+
+        for warp_message in warp_messages {
+            let mut signed_warp_message = warp_message.clone();
+            let signatures = subnet
+                .get_warp_message_node_signatures(
+                    &warp_message,
+                    None,
+                    &WarpSignatureRequestPolicy::default(),
+                    &resolver,
+                )
+                .map_err(|e| {
+                    CliError::dataerr(format!("Error getting Warp message signatures: {e}"))
+                })?
+                .signatures;
+            for sig in signatures {
+                signed_warp_message.add_node_signature(sig);
+            }
+
+            if json {
+                println!("{}", serde_json::to_string(&signed_warp_message).unwrap());
+            } else {
+                println!(
+                    "{}",
+                    template_warp_message(&signed_warp_message, &blockchain, extended, true, 0)
+                );
+            }
+        }
+
+        WarpWatchCursor {
+            blockchain_id: blockchain.id.to_string(),
+            last_processed_block: to_block,
+        }
+        .save(&cursor_file)?;
+
+        next_block = to_block.saturating_add(1);
+    }
+}
+
+// Get the Warp messages sent in the next range of blocks (at most `max_blocks`)
+// Return the last block of the range along with the messages, or None if there is no new block
+// Only RPC errors are returned: a range that cannot be read for another reason is reported and skipped
+fn get_next_warp_messages(
+    blockchain: &AvalancheBlockchain,
+    next_block: u64,
+    max_blocks: u64,
+) -> Result<Option<(u64, Vec<WarpMessage>)>, AshError> {
+    let latest_block = task::block_on(async { blockchain.get_latest_block_number().await })?;
+    if next_block > latest_block {
+        return Ok(None);
+    }
+    let to_block = latest_block.min(next_block.saturating_add(max_blocks.saturating_sub(1)));
+
+    let warp_messages = match task::block_on(async {
+        blockchain
+            .get_warp_messages(&next_block.to_string(), &to_block.to_string())
+            .await
+    }) {
+        Ok((warp_messages, failures)) => {
+            warn_warp_log_failures(&failures);
+            warp_messages
+        }
+        Err(e @ AshError::RpcError(_)) => return Err(e),
+        Err(e) => {
+            eprintln!(
+                "{}",
+                format!("Warning: skipping blocks {next_block} to {to_block}: {e}").yellow()
+            );
+            vec![]
+        }
+    };
+
+    Ok(Some((to_block, warp_messages)))
+}
+
+// Get the latest block number of a blockchain
+fn get_latest_block_number(blockchain: &AvalancheBlockchain) -> Result<u64, CliError> {
+    task::block_on(async { blockchain.get_latest_block_number().await })
+        .map_err(|e| CliError::dataerr(format!("Error getting latest block number: {e}")))
+}
+
+fn send(
+    network_name: &str,
+    source_chain: &str,
+    destination_chain: &str,
+    destination_address: &str,
+    payload: &str,
+    signer_args: &SignerArgs,
+    config: Option<&str>,
+    json: bool,
+) -> Result<(), CliError> {
+    // Display warning about the experimental nature of this feature
+    eprintln!(
+        "{}",
+        "Warning: this feature is experimental and may break at any time."
+            .yellow()
+            .bold()
+    );
+
+    let destination_address = Address::from_str(destination_address).map_err(|e| {
+        CliError::dataerr(format!(
+            "Error parsing destination address '{destination_address}': {e}"
+        ))
+    })?;
+    let payload = hex::decode(payload.trim_start_matches("0x"))
+        .map_err(|e| CliError::dataerr(format!("Error decoding payload: {e}")))?;
+
+    let mut network = load_network(network_name, config)?;
+    update_network_subnets(&mut network)?;
+
+    let source_blockchain = load_blockchain(&network, source_chain)?;
+
+    // The destination chain can be a chain that is not known by the network (e.g. the anycast ID)
+    let destination_chain_id = match parse_id(destination_chain) {
+        Ok(id) => id,
+        Err(_) => load_blockchain(&network, destination_chain)?.id,
+    };
+
+    let wallet = create_wallet(&network, signer_args)?;
+
+    eprintln!("Waiting for transaction to be mined...");
+
+    let (tx_hash, unsigned_message) = task::block_on(async {
+        let client = Arc::new(source_blockchain.get_evm_signer(&wallet).await?);
+        source_blockchain
+            .send_warp_message(client, destination_chain_id, destination_address, payload)
+            .await
+    })
+    .map_err(|e| CliError::dataerr(format!("Error sending Warp message: {e}")))?;
+
+    if json {
+        println!(
+            "{}",
+            serde_json::json!({
+                "unsignedMessage": unsigned_message,
+                "txHash": format!("{tx_hash:?}"),
+            })
+        );
+        return Ok(());
+    }
+
+    println!(
+        "{}",
+        template_warp_send(
+            &unsigned_message,
+            &source_blockchain,
+            &format!("{tx_hash:?}"),
+            0
+        )
+    );
+
+    Ok(())
+}
+
+fn relay(
+    network_name: &str,
+    node_endpoints: &[String],
+    message_id: &str,
+    source_chain: &str,
+    destination_chain: Option<&str>,
+    from_block: &str,
+    to_block: &str,
+    calldata: &str,
+    quorum: u8,
+    signature_timeout: u64,
+    signature_retries: u32,
+    signer_args: &SignerArgs,
+    config: Option<&str>,
+    json: bool,
+) -> Result<(), CliError> {
+    // Display warning about the experimental nature of this feature
+    eprintln!(
+        "{}",
+        "Warning: this feature is experimental and may break at any time."
+            .yellow()
+            .bold()
+    );
+
+    let message_id = parse_id(message_id)?;
+    let calldata = hex::decode(calldata.trim_start_matches("0x"))
+        .map_err(|e| CliError::dataerr(format!("Error decoding calldata: {e}")))?;
+
+    let mut network = load_network(network_name, config)?;
+    add_node_endpoints(&mut network, node_endpoints)?;
+    update_network_subnets(&mut network)?;
+
+    let source_blockchain = load_blockchain(&network, source_chain)?;
+    update_subnet_validators_with_signers(&mut network, &source_blockchain.subnet_id.to_string())?;
+
+    let subnet = network
+        .get_subnet(source_blockchain.subnet_id)
+        .map_err(|e| CliError::dataerr(format!("Error loading subnet info: {e}")))?;
+    let resolver = get_validator_endpoint_resolver(&network, &source_blockchain);
+
+    // Find the message among the ones sent from the source chain
+    let (warp_messages, failures) = task::block_on(async {
+        source_blockchain
+            .get_warp_messages(from_block, to_block)
+            .await
+    })
+    .map_err(|e| CliError::dataerr(format!("Error reading warp messages: {e}")))?;
+    warn_warp_log_failures(&failures);
+    let mut warp_message = warp_messages
+        .into_iter()
+        .find(|warp_message| warp_message.unsigned_message.id == message_id)
+        .ok_or(CliError::dataerr(format!(
+            "Error: Warp message '{message_id}' not found on '{}' between blocks '{from_block}' and '{to_block}'",
+            source_blockchain.name
+        )))?;
+
+    // Collect the validator nodes signatures until the quorum is reached
+    let collection = subnet
+        .get_warp_message_node_signatures(
+            &warp_message,
+            Some(quorum),
+            &WarpSignatureRequestPolicy {
+                timeout: Duration::from_secs(signature_timeout),
+                retries: signature_retries,
+                ..Default::default()
+            },
+            &resolver,
+        )
+        .map_err(|e| CliError::dataerr(format!("Error getting Warp message signatures: {e}")))?;
+    for failure in collection.failures.iter() {
+        eprintln!(
+            "{}",
+            format!(
+                "Warning: failed to get signature from node '{}' after {} attempt(s): {}",
+                failure.node_id, failure.attempts, failure.error
+            )
+            .yellow()
+        );
+    }
+    let signatures = collection.signatures;
+
+    // Verify the signatures and only keep the valid ones
+    let verification = WarpMessage {
+        node_signatures: signatures.clone(),
+        ..warp_message.clone()
+    }
+    .verify_node_signatures(&subnet.validators);
+    for node_id in verification
+        .invalid_signers
+        .iter()
+        .chain(verification.unknown_signers.iter())
+    {
+        eprintln!(
+            "{}",
+            format!("Warning: ignoring invalid signature from node '{node_id}'").yellow()
+        );
+    }
+    for signature in signatures {
+        if verification.valid_signers.contains(&signature.node_id) {
+            warp_message.add_node_signature(signature);
+        }
+    }
+
+    if !verification.is_quorum_reached(quorum) {
+        return Err(CliError::dataerr(format!(
+            "Error: Warp message is signed by {:.2}% of the Subnet weight, below the {quorum}% quorum",
+            verification.signed_weight_percentage()
+        )));
+    }
+
+    // Aggregate the valid signatures
+    let signed_message = warp_message
+        .get_signed_message(&subnet.validators)
+        .map_err(|e| {
+            CliError::dataerr(format!("Error aggregating Warp message signatures: {e}"))
+        })?;
+
+    let destination_blockchain = match destination_chain {
+        Some(chain) => load_blockchain(&network, chain)?,
+        None => match &warp_message.unsigned_message.payload {
+            WarpMessagePayload::SubnetEVMAddressedPayload(addressed_payload) => {
+                let destination_chain_id =
+                    Id::from_slice(addressed_payload.destination_chain_id.as_bytes());
+                load_blockchain(&network, &destination_chain_id.to_string())?
+            }
+            _ => {
+                return Err(CliError::dataerr(
+                    "Error: cannot get the destination chain of the message, please provide it with --destination-chain".to_string(),
+                ))
+            }
+        },
+    };
+
+    let wallet = create_wallet(&network, signer_args)?;
+
+    eprintln!("Waiting for delivery transaction to be mined...");
+
+    let tx_hash = task::block_on(async {
+        let client = Arc::new(destination_blockchain.get_evm_signer(&wallet).await?);
+        destination_blockchain
+            .deliver_warp_message(client, &signed_message, calldata)
+            .await
+    })
+    .map_err(|e| CliError::dataerr(format!("Error delivering Warp message: {e}")))?;
+
+    if json {
+        println!(
+            "{}",
+            serde_json::json!({
+                "signedMessage": signed_message,
+                "txHash": format!("{tx_hash:?}"),
+            })
+        );
+        return Ok(());
+    }
+
+    println!(
+        "{}",
+        template_warp_relay(
+            &warp_message,
+            &signed_message,
+            &destination_blockchain,
+            &format!("{tx_hash:?}"),
+            0
+        )
+    );
+
+    Ok(())
+}
+
 // Parse warp subcommand
 pub(crate) fn parse(warp: WarpCommand, config: Option<&str>, json: bool) -> Result<(), CliError> {
     match warp.command {
@@ -127,6 +697,7 @@ pub(crate) fn parse(warp: WarpCommand, config: Option<&str>, json: bool) -> Resu
             extended,
         } => navigate(
             &warp.network,
+            &warp.node_endpoints,
             &source_chain,
             &from_block,
             &to_block,
@@ -134,5 +705,67 @@ pub(crate) fn parse(warp: WarpCommand, config: Option<&str>, json: bool) -> Resu
             config,
             json,
         ),
+        WarpSubcommands::Watch {
+            source_chain,
+            from_block,
+            cursor_file,
+            interval,
+            max_blocks,
+            extended,
+        } => watch(
+            &warp.network,
+            &warp.node_endpoints,
+            &source_chain,
+            &from_block,
+            cursor_file.as_deref(),
+            interval,
+            max_blocks,
+            extended,
+            config,
+            json,
+        ),
+        WarpSubcommands::Send {
+            source_chain,
+            destination_chain,
+            destination_address,
+            payload,
+            signer_args,
+        } => send(
+            &warp.network,
+            &source_chain,
+            &destination_chain,
+            &destination_address,
+            &payload,
+            &signer_args,
+            config,
+            json,
+        ),
+        WarpSubcommands::Relay {
+            message_id,
+            source_chain,
+            destination_chain,
+            from_block,
+            to_block,
+            calldata,
+            quorum,
+            signature_timeout,
+            signature_retries,
+            signer_args,
+        } => relay(
+            &warp.network,
+            &warp.node_endpoints,
+            &message_id,
+            &source_chain,
+            destination_chain.as_deref(),
+            &from_block,
+            &to_block,
+            &calldata,
+            quorum,
+            signature_timeout,
+            signature_retries,
+            &signer_args,
+            config,
+            json,
+        ),
     }
 }
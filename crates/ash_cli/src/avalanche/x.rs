@@ -1,16 +1,16 @@
 // SPDX-License-Identifier: BSD-3-Clause
 // Copyright (c) 2023, E36 Knots
 
+mod asset;
+
 // Module that contains the x subcommand parser
 
 use crate::{
-    avalanche::{wallet::*, *},
-    utils::templating::template_xchain_balance,
+    avalanche::{tx::*, wallet::*, *},
     utils::{error::CliError, templating::*, version_tx_cmd},
 };
 use async_std::task;
 use clap::{Parser, Subcommand};
-use rust_decimal::prelude::{Decimal, FromPrimitive, ToPrimitive};
 
 /// Interact with Avalanche X-Chain
 #[derive(Parser)]
@@ -31,51 +31,68 @@ pub(crate) struct XCommand {
 
 #[derive(Subcommand)]
 enum XSubcommands {
-    /// Get the balance of an address for a given asset
+    /// Get the balance of an address for every asset it holds, or for a given asset
     #[command(version = version_tx_cmd(false))]
     Balance {
         /// Address to get the balance of
         address: String,
-        /// Asset ID to get the balance of
-        #[arg(long, short = 'a', default_value = "AVAX")]
-        asset_id: String,
+        /// Asset ID (or alias, e.g. AVAX) to get the balance of, defaults to all the assets
+        #[arg(long, short = 'a')]
+        asset_id: Option<String>,
+    },
+    /// List the UTXOs of an address
+    #[command(version = version_tx_cmd(false))]
+    Utxos {
+        /// Address to list the UTXOs of
+        address: String,
     },
+    Asset(asset::AssetCommand),
     /// Transfer any amount of a given asset to an address
     #[command(version = version_tx_cmd(true))]
     Transfer {
         /// Amount of asset to send (in AVAX equivalent, 1 AVAX = 10^9 nAVAX)
-        amount: f64,
+        amount: String,
         /// Address to send the asset to
         to: String,
         /// Asset ID to send
         #[arg(long, short = 'a', default_value = "AVAX")]
         asset_id: String,
-        /// Private key to sign the transaction with
-        #[arg(long, short = 'p', env = "AVALANCHE_PRIVATE_KEY")]
-        private_key: String,
-        /// Private key encoding (cb58 or hex)
-        #[arg(
-            long,
-            short = 'e',
-            default_value = "cb58",
-            env = "AVALANCHE_KEY_ENCODING"
-        )]
-        key_encoding: PrivateKeyEncoding,
+        #[command(flatten)]
+        signer_args: SignerArgs,
         /// Whether to wait for transaction acceptance
         #[arg(long, short = 'w')]
         wait: bool,
+        /// Write the unsigned transaction to a file instead of issuing it, to be signed with `ash avalanche tx sign`
+        #[arg(long, conflicts_with = "wait")]
+        unsigned_out: Option<String>,
     },
 }
 
 fn balance(
     network_name: &str,
     address: &str,
-    asset_id: &str,
+    asset_id: Option<&str>,
     config: Option<&str>,
     json: bool,
 ) -> Result<(), CliError> {
     let network = load_network(network_name, config)?;
 
+    let Some(asset_id) = asset_id else {
+        let balances = network.get_xchain_balances(address).map_err(|e| {
+            CliError::dataerr(format!("Error getting balances for address {address}: {e}"))
+        })?;
+
+        if json {
+            println!("{}", serde_json::to_string(&balances).unwrap());
+            return Ok(());
+        }
+
+        println!("Balances of '{}' on X-Chain:", type_colorize(&address));
+        println!("{}", template_xchain_balances_table(&balances, 0));
+
+        return Ok(());
+    };
+
     let balance = network.get_xchain_balance(address, asset_id).map_err(|e| {
         CliError::dataerr(format!("Error getting balance for address {address}: {e}"))
     })?;
@@ -85,10 +102,40 @@ fn balance(
         return Ok(());
     }
 
+    let asset = network.get_xchain_asset(asset_id).map_err(|e| {
+        CliError::dataerr(format!(
+            "Error getting description of asset {asset_id}: {e}"
+        ))
+    })?;
+
+    println!("{}", template_xchain_balance(address, &asset, &balance, 0));
+
+    Ok(())
+}
+
+fn utxos(
+    network_name: &str,
+    address: &str,
+    config: Option<&str>,
+    json: bool,
+) -> Result<(), CliError> {
+    let network = load_network(network_name, config)?;
+
+    let utxos = network.get_xchain_utxos(address).map_err(|e| {
+        CliError::dataerr(format!("Error getting UTXOs for address {address}: {e}"))
+    })?;
+
+    if json {
+        println!("{}", serde_json::to_string(&utxos).unwrap());
+        return Ok(());
+    }
+
     println!(
-        "{}",
-        template_xchain_balance(address, asset_id, &balance, 0)
+        "Found {} UTXOs for '{}' on X-Chain:",
+        type_colorize(&utxos.len()),
+        type_colorize(&address)
     );
+    println!("{}", template_utxos_table(&utxos, 0));
 
     Ok(())
 }
@@ -97,10 +144,10 @@ fn transfer(
     network_name: &str,
     to: &str,
     asset_id: &str,
-    amount: f64,
-    private_key: &str,
-    key_encoding: PrivateKeyEncoding,
+    amount: &str,
+    signer_args: &SignerArgs,
     wait: bool,
+    unsigned_out: Option<&str>,
     config: Option<&str>,
     json: bool,
 ) -> Result<(), CliError> {
@@ -111,30 +158,36 @@ fn transfer(
         ));
     }
 
+    let amount_navax = parse_avax_decimal_amount(amount)?;
+
     let network = load_network(network_name, config)?;
 
-    let wallet = create_wallet(&network, private_key, key_encoding)?;
+    let wallet = create_wallet(&network, signer_args)?;
+
+    // Write the transaction to be signed later (e.g. on an air-gapped machine)
+    if let Some(unsigned_out) = unsigned_out {
+        let partial_tx =
+            task::block_on(async { wallet.build_transfer_avax_xchain_tx(to, amount_navax).await })
+                .map_err(|e| {
+                    CliError::dataerr(format!(
+                        "Error building transfer of {amount} of asset '{asset_id}' to '{to}': {e}"
+                    ))
+                })?;
+
+        write_partial_tx(unsigned_out, &partial_tx)?;
+        return print_partial_tx(unsigned_out, &partial_tx, TxEncoding::Hex, json);
+    }
 
     if wait {
         eprintln!("Waiting for transaction to be accepted...");
     }
 
-    let tx_id = task::block_on(async {
-        wallet
-            .transfer_avax_xchain(
-                to,
-                (Decimal::from_f64(amount).unwrap() * Decimal::from_f64(1_000_000_000.0).unwrap())
-                    .to_u64()
-                    .unwrap(),
-                wait,
-            )
-            .await
-    })
-    .map_err(|e| {
-        CliError::dataerr(format!(
-            "Error transferring {amount} of asset '{asset_id}' to '{to}': {e}"
-        ))
-    })?;
+    let tx_id = task::block_on(async { wallet.transfer_avax_xchain(to, amount_navax, wait).await })
+        .map_err(|e| {
+            CliError::dataerr(format!(
+                "Error transferring {amount} of asset '{asset_id}' to '{to}': {e}"
+            ))
+        })?;
 
     if json {
         println!("{}", serde_json::json!({ "txID": tx_id.to_string() }));
@@ -153,23 +206,25 @@ fn transfer(
 pub(crate) fn parse(x: XCommand, config: Option<&str>, json: bool) -> Result<(), CliError> {
     match x.command {
         XSubcommands::Balance { address, asset_id } => {
-            balance(&x.network, &address, &asset_id, config, json)
+            balance(&x.network, &address, asset_id.as_deref(), config, json)
         }
+        XSubcommands::Utxos { address } => utxos(&x.network, &address, config, json),
+        XSubcommands::Asset(asset) => asset::parse(asset, &x.network, config, json),
         XSubcommands::Transfer {
             to,
             asset_id,
             amount,
-            private_key,
-            key_encoding,
+            signer_args,
             wait,
+            unsigned_out,
         } => transfer(
             &x.network,
             &to,
             &asset_id,
-            amount,
-            &private_key,
-            key_encoding,
+            &amount,
+            &signer_args,
             wait,
+            unsigned_out.as_deref(),
             config,
             json,
         ),
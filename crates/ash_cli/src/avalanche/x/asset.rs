@@ -0,0 +1,348 @@
+// SPDX-License-Identifier: BSD-3-Clause
+// Copyright (c) 2023, E36 Knots
+
+// Module that contains the x asset subcommand parser
+
+use crate::{
+    avalanche::{wallet::*, *},
+    utils::{error::CliError, parsing::*, templating::*, version_tx_cmd},
+};
+use ash_sdk::avalanche::{address_to_short_id, txs::x::AvalancheXChainAssetInitialState};
+use async_std::task;
+use clap::{Parser, Subcommand, ValueEnum};
+use rust_decimal::prelude::{Decimal, ToPrimitive};
+
+/// Supply model of an X-Chain asset
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
+pub(crate) enum AssetCap {
+    Fixed,
+    Variable,
+    Nft,
+}
+
+/// Create, mint and inspect X-Chain assets
+#[derive(Parser)]
+#[command()]
+pub(crate) struct AssetCommand {
+    #[command(subcommand)]
+    command: AssetSubcommands,
+}
+
+#[derive(Subcommand)]
+enum AssetSubcommands {
+    /// Create a fixed-cap asset, a variable-cap asset or an NFT family
+    #[command(version = version_tx_cmd(true))]
+    Create {
+        /// Name of the asset
+        name: String,
+        /// Symbol of the asset (up to 4 characters)
+        symbol: String,
+        /// Supply model of the asset
+        #[arg(long, short = 't', default_value = "fixed")]
+        cap: AssetCap,
+        /// Number of decimal places of the asset, up to 32 (ignored for NFT families)
+        #[arg(long, short = 'd', default_value = "9", value_parser = clap::value_parser!(u8).range(0..=32))]
+        denomination: u8,
+        /// Initial holders of a fixed-cap asset (comma-separated 'address=amount' pairs, amounts in asset units)
+        #[arg(long, short = 'H', value_delimiter = ',')]
+        holders: Vec<String>,
+        /// Minters of a variable-cap asset or an NFT family (comma-separated X-Chain addresses)
+        #[arg(long, short = 'm', value_delimiter = ',')]
+        minters: Vec<String>,
+        /// Number of minters that have to sign each mint
+        #[arg(long, default_value = "1")]
+        mint_threshold: u32,
+        /// Number of groups of an NFT family (one NFT can be minted per group)
+        #[arg(long, short = 'g', default_value = "1")]
+        groups: u32,
+        #[command(flatten)]
+        signer_args: SignerArgs,
+        /// Whether to wait for transaction acceptance
+        #[arg(long, short = 'w')]
+        wait: bool,
+    },
+    /// Mint units of a variable-cap asset or an NFT of an NFT family
+    #[command(version = version_tx_cmd(true))]
+    Mint {
+        /// Asset ID
+        asset_id: String,
+        /// Address to send the minted units or NFT to
+        to: String,
+        /// Amount to mint (in asset units) of a variable-cap asset
+        #[arg(
+            long,
+            short = 'a',
+            required_unless_present = "payload",
+            conflicts_with = "payload"
+        )]
+        amount: Option<String>,
+        /// Payload of the NFT to mint (up to 1024 bytes)
+        #[arg(long)]
+        payload: Option<String>,
+        #[command(flatten)]
+        signer_args: SignerArgs,
+        /// Whether to wait for transaction acceptance
+        #[arg(long, short = 'w')]
+        wait: bool,
+    },
+    /// Show asset information
+    #[command(version = version_tx_cmd(false))]
+    Info {
+        /// Asset ID (or alias, e.g. AVAX)
+        asset_id: String,
+    },
+}
+
+// Convert an amount in asset units to the smallest unit of the asset
+// The amount is parsed as a decimal to keep every digit of the smallest unit
+fn parse_asset_amount(amount: &str, denomination: u8) -> Result<u64, CliError> {
+    let amount_decimal = Decimal::from_str_exact(amount.trim())
+        .map_err(|e| CliError::dataerr(format!("Error parsing amount '{amount}': {e}")))?;
+
+    let amount_units = (0..denomination)
+        .try_fold(amount_decimal, |amount, _| amount.checked_mul(Decimal::TEN))
+        .filter(|amount_units| amount_units.fract().is_zero())
+        .and_then(|amount_units| amount_units.to_u64());
+
+    amount_units.ok_or_else(|| {
+        CliError::dataerr(format!(
+            "Error parsing amount '{amount}': out of range or too precise with a denomination of {denomination}"
+        ))
+    })
+}
+
+// Parse the 'address=amount' pairs of the initial holders of a fixed-cap asset
+fn parse_holders(
+    holders: &[String],
+    denomination: u8,
+) -> Result<AvalancheXChainAssetInitialState, CliError> {
+    let holders = holders
+        .iter()
+        .map(|holder| {
+            let Some((address, amount)) = holder.split_once('=') else {
+                return Err(CliError::dataerr(format!(
+                    "Error parsing holder '{holder}': expected 'address=amount'"
+                )));
+            };
+            let address = address_to_short_id(address, "X")
+                .map_err(|e| CliError::dataerr(format!("Error parsing holder '{holder}': {e}")))?;
+
+            Ok((address, parse_asset_amount(amount, denomination)?))
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(AvalancheXChainAssetInitialState::FixedCap(holders))
+}
+
+fn create(
+    network_name: &str,
+    name: &str,
+    symbol: &str,
+    cap: AssetCap,
+    denomination: u8,
+    holders: &[String],
+    minters: &[String],
+    mint_threshold: u32,
+    groups: u32,
+    signer_args: &SignerArgs,
+    wait: bool,
+    config: Option<&str>,
+    json: bool,
+) -> Result<(), CliError> {
+    let minters = minters
+        .iter()
+        .map(|minter| {
+            address_to_short_id(minter, "X")
+                .map_err(|e| CliError::dataerr(format!("Error parsing minter '{minter}': {e}")))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let (initial_state, denomination) = match cap {
+        AssetCap::Fixed => (parse_holders(holders, denomination)?, denomination),
+        AssetCap::Variable => (
+            AvalancheXChainAssetInitialState::VariableCap {
+                minters,
+                threshold: mint_threshold,
+            },
+            denomination,
+        ),
+        AssetCap::Nft => (
+            AvalancheXChainAssetInitialState::Nft {
+                minters,
+                threshold: mint_threshold,
+                groups,
+            },
+            0,
+        ),
+    };
+
+    let network = load_network(network_name, config)?;
+    let wallet = create_wallet(&network, signer_args)?;
+
+    if wait {
+        eprintln!("Waiting for transaction to be accepted...");
+    }
+
+    let asset_id = task::block_on(async {
+        wallet
+            .create_xchain_asset(name, symbol, denomination, initial_state, wait)
+            .await
+    })
+    .map_err(|e| CliError::dataerr(format!("Error creating asset '{name}': {e}")))?;
+
+    if json {
+        println!("{}", serde_json::json!({ "assetID": asset_id.to_string() }));
+        return Ok(());
+    }
+
+    println!(
+        "{}",
+        template_xchain_asset_create(&asset_id.to_string(), name, symbol, wait, 0)
+    );
+
+    Ok(())
+}
+
+fn mint(
+    network_name: &str,
+    asset_id: &str,
+    to: &str,
+    amount: Option<String>,
+    payload: Option<String>,
+    signer_args: &SignerArgs,
+    wait: bool,
+    config: Option<&str>,
+    json: bool,
+) -> Result<(), CliError> {
+    let asset_id_parsed = parse_id(asset_id)?;
+    let network = load_network(network_name, config)?;
+    let wallet = create_wallet(&network, signer_args)?;
+
+    if wait {
+        eprintln!("Waiting for transaction to be accepted...");
+    }
+
+    let (tx_id, minted) = match (amount, payload) {
+        (Some(amount), _) => {
+            let asset = network.get_xchain_asset(asset_id).map_err(|e| {
+                CliError::dataerr(format!(
+                    "Error getting description of asset {asset_id}: {e}"
+                ))
+            })?;
+            let amount_units = parse_asset_amount(&amount, asset.denomination)?;
+
+            let tx_id = task::block_on(async {
+                wallet
+                    .mint_xchain_asset(asset_id_parsed, amount_units, to, wait)
+                    .await
+            })
+            .map_err(|e| CliError::dataerr(format!("Error minting asset '{asset_id}': {e}")))?;
+            (tx_id, format!("{amount} {}", asset.symbol))
+        }
+        (None, payload) => {
+            let tx_id = task::block_on(async {
+                wallet
+                    .mint_xchain_nft(
+                        asset_id_parsed,
+                        payload.unwrap_or_default().into_bytes(),
+                        to,
+                        wait,
+                    )
+                    .await
+            })
+            .map_err(|e| CliError::dataerr(format!("Error minting NFT '{asset_id}': {e}")))?;
+            (tx_id, "an NFT".to_string())
+        }
+    };
+
+    if json {
+        println!("{}", serde_json::json!({ "txID": tx_id.to_string() }));
+        return Ok(());
+    }
+
+    println!(
+        "{}",
+        template_xchain_asset_mint(&tx_id.to_string(), asset_id, &minted, to, wait, 0)
+    );
+
+    Ok(())
+}
+
+fn info(
+    network_name: &str,
+    asset_id: &str,
+    config: Option<&str>,
+    json: bool,
+) -> Result<(), CliError> {
+    let network = load_network(network_name, config)?;
+
+    let asset = network.get_xchain_asset(asset_id).map_err(|e| {
+        CliError::dataerr(format!(
+            "Error getting description of asset {asset_id}: {e}"
+        ))
+    })?;
+
+    if json {
+        println!("{}", serde_json::to_string(&asset).unwrap());
+        return Ok(());
+    }
+
+    println!("{}", template_xchain_asset_info(&asset, 0));
+
+    Ok(())
+}
+
+// Parse x asset subcommand
+pub(crate) fn parse(
+    asset: AssetCommand,
+    network_name: &str,
+    config: Option<&str>,
+    json: bool,
+) -> Result<(), CliError> {
+    match asset.command {
+        AssetSubcommands::Create {
+            name,
+            symbol,
+            cap,
+            denomination,
+            holders,
+            minters,
+            mint_threshold,
+            groups,
+            signer_args,
+            wait,
+        } => create(
+            network_name,
+            &name,
+            &symbol,
+            cap,
+            denomination,
+            &holders,
+            &minters,
+            mint_threshold,
+            groups,
+            &signer_args,
+            wait,
+            config,
+            json,
+        ),
+        AssetSubcommands::Mint {
+            asset_id,
+            to,
+            amount,
+            payload,
+            signer_args,
+            wait,
+        } => mint(
+            network_name,
+            &asset_id,
+            &to,
+            amount,
+            payload,
+            &signer_args,
+            wait,
+            config,
+            json,
+        ),
+        AssetSubcommands::Info { asset_id } => info(network_name, &asset_id, config, json),
+    }
+}
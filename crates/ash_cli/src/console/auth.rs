@@ -75,6 +75,7 @@ pub(crate) fn refresh_keyring_access_token(console: &mut AshConsole) -> Result<(
         KEYRING_TARGET,
         KEYRING_REFRESH_TOKEN_SERVICE,
         KEYRING_FALLBACK_FILES_DIR,
+        "refresh token",
     )?;
 
     // Exchange the refresh token for a new access token
@@ -100,6 +101,7 @@ pub(crate) fn get_keyring_access_token() -> Result<String, CliError> {
         KEYRING_TARGET,
         KEYRING_ACCESS_TOKEN_SERVICE,
         KEYRING_FALLBACK_FILES_DIR,
+        "access token",
     )
 }
 
@@ -263,7 +263,7 @@ pub(crate) fn parse(
 
     // Get the current project ID for the subcommands that require it
     match region.command {
-        RegionSubcommands::Available {} => (),
+        RegionSubcommands::Available => (),
         _ => {
             if project_id_or_name == "current" {
                 project_id_or_name = get_current_project_id_or_name()?;
@@ -33,7 +33,7 @@ struct Cli {
 
 #[derive(Subcommand)]
 enum CliCommands {
-    Avalanche(avalanche::AvalancheCommand),
+    Avalanche(Box<avalanche::AvalancheCommand>),
     Conf(conf::ConfCommand),
     Console(console::ConsoleCommand),
 }
@@ -43,7 +43,7 @@ fn main() {
 
     match cli.command {
         CliCommands::Avalanche(avalanche) => {
-            avalanche::parse(avalanche, cli.config.as_deref(), cli.json)
+            avalanche::parse(*avalanche, cli.config.as_deref(), cli.json)
         }
         CliCommands::Conf(conf) => conf::parse(conf),
         CliCommands::Console(console) => console::parse(console, cli.config.as_deref(), cli.json),
@@ -32,20 +32,22 @@ pub(crate) fn set_keyring_value(
 }
 
 /// Get a value from the device keyring
+/// The value name is used in the error messages (e.g. "access token")
 pub(crate) fn get_keyring_value(
     target: &str,
     service: &str,
     fallback_files_dir: &str,
+    value_name: &str,
 ) -> Result<String, CliError> {
     let new_entry = Entry::new_with_target(target, service, &whoami::username())
-        .map_err(|e| CliError::dataerr(format!("Error getting access token: {e}")))?
+        .map_err(|e| CliError::dataerr(format!("Error getting {value_name}: {e}")))?
         .get_password();
 
     match new_entry {
         Ok(entry) => Ok(entry),
         Err(Error::PlatformFailure(_)) => read_plaintext_file(service, fallback_files_dir),
         Err(e) => Err(CliError::dataerr(format!(
-            "Error getting access token: {e}"
+            "Error getting {value_name}: {e}"
         ))),
     }
 }
@@ -4,8 +4,12 @@
 // Module that contains parsing utility functions
 
 use crate::utils::error::CliError;
-use ash_sdk::ids::{node::Id as NodeId, Id};
-use chrono::{DateTime, Utc};
+use ash_sdk::{
+    avalanche::nodes::AvalancheNodeEndpoint,
+    ids::{node::Id as NodeId, Id},
+};
+use chrono::{DateTime, Duration, Utc};
+use rust_decimal::prelude::{Decimal, ToPrimitive};
 use std::str::FromStr;
 
 // Parse an ID from a string
@@ -29,9 +33,95 @@ pub(crate) fn parse_node_id(id: &str) -> Result<NodeId, CliError> {
     Ok(id)
 }
 
+// Parse a node endpoint from a string in the format <node_id>=<http_endpoint>
+pub(crate) fn parse_node_endpoint(node_endpoint: &str) -> Result<AvalancheNodeEndpoint, CliError> {
+    let (node_id, http_endpoint) =
+        node_endpoint
+            .split_once('=')
+            .ok_or(CliError::dataerr(format!(
+                "Error parsing node endpoint '{node_endpoint}': expected <node_id>=<http_endpoint>"
+            )))?;
+
+    Ok(AvalancheNodeEndpoint {
+        node_id: parse_node_id(node_id)?,
+        http_endpoint: http_endpoint.to_string(),
+    })
+}
+
 // Parse a DateTime from a string
 pub(crate) fn parse_datetime(datetime: &str) -> Result<DateTime<Utc>, CliError> {
     let datetime = DateTime::parse_from_rfc3339(datetime)
         .map_err(|e| CliError::dataerr(format!("Error parsing DateTime: {e}")))?;
     Ok(datetime.with_timezone(&Utc))
 }
+
+// Convert a number of days to a Duration
+pub(crate) fn parse_days(days: u64) -> Result<Duration, CliError> {
+    days.checked_mul(86_400)
+        .and_then(|seconds| Duration::from_std(std::time::Duration::from_secs(seconds)).ok())
+        .ok_or_else(|| {
+            CliError::dataerr(format!(
+                "Error parsing duration: {days} days is out of range"
+            ))
+        })
+}
+
+// Convert an amount of AVAX to nAVAX
+pub(crate) fn parse_avax_amount(amount: u64) -> Result<u64, CliError> {
+    amount.checked_mul(1_000_000_000).ok_or_else(|| {
+        CliError::dataerr(format!(
+            "Error parsing amount: {amount} AVAX is out of range"
+        ))
+    })
+}
+
+// Convert a decimal amount of AVAX to nAVAX
+// The amount is parsed as a decimal to keep every digit, fractions of nAVAX are rejected
+pub(crate) fn parse_avax_decimal_amount(amount: &str) -> Result<u64, CliError> {
+    let amount_decimal = Decimal::from_str_exact(amount.trim())
+        .map_err(|e| CliError::dataerr(format!("Error parsing amount '{amount}': {e}")))?;
+    if amount_decimal.normalize().scale() > 9 {
+        return Err(CliError::dataerr(format!(
+            "Error parsing amount '{amount}': AVAX amounts have at most 9 decimals"
+        )));
+    }
+
+    amount_decimal
+        .checked_mul(Decimal::from(1_000_000_000u64))
+        .and_then(|amount| amount.to_u64())
+        .ok_or_else(|| {
+            CliError::dataerr(format!(
+                "Error parsing amount: {amount} AVAX is out of range"
+            ))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_avax_decimal_amount() {
+        assert_eq!(parse_avax_decimal_amount("0.3").ok(), Some(300_000_000));
+        assert_eq!(parse_avax_decimal_amount("1.1").ok(), Some(1_100_000_000));
+        assert_eq!(parse_avax_decimal_amount("12").ok(), Some(12_000_000_000));
+        assert_eq!(parse_avax_decimal_amount("0.000000001").ok(), Some(1));
+        assert_eq!(
+            parse_avax_decimal_amount("123.456789012").ok(),
+            Some(123_456_789_012)
+        );
+
+        // Fractions of nAVAX are rejected instead of truncated
+        assert!(parse_avax_decimal_amount("0.0000000001").is_err());
+        assert!(parse_avax_decimal_amount("1.1234567891").is_err());
+        assert_eq!(
+            parse_avax_decimal_amount("1.100000000000").ok(),
+            Some(1_100_000_000)
+        );
+        // Negative, out of range and invalid amounts are rejected
+        assert!(parse_avax_decimal_amount("-1").is_err());
+        assert!(parse_avax_decimal_amount("18446744074").is_err());
+        assert!(parse_avax_decimal_amount("1e9").is_err());
+        assert!(parse_avax_decimal_amount("abc").is_err());
+    }
+}
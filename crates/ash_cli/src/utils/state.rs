@@ -1,12 +1,15 @@
 // SPDX-License-Identifier: BSD-3-Clause
 // Copyright (c) 2023, E36 Knots
 
+use ash_sdk::avalanche::subnets::AvalancheValidatorRenewal;
 use serde::{Deserialize, Serialize};
 use std::{fs, path::Path};
 
 use crate::utils::error::CliError;
 
 pub const ASH_CLI_STATE_FILE: &str = "~/.local/state/ash/state.json";
+pub const ASH_CLI_WARP_CURSORS_DIR: &str = "~/.local/state/ash/warp";
+pub const ASH_CLI_VALIDATOR_RENEWALS_DIR: &str = "~/.local/state/ash/renewals";
 
 /// Ash CLI state to be stored in a JSON file
 #[derive(Default, Debug, Serialize, Deserialize)]
@@ -54,3 +57,130 @@ impl CliState {
         Ok(())
     }
 }
+
+/// Warp watch cursor to be stored in a JSON file
+/// Keeps track of the last block processed on a source chain
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct WarpWatchCursor {
+    #[serde(rename = "blockchainID")]
+    pub(crate) blockchain_id: String,
+    pub(crate) last_processed_block: u64,
+}
+
+impl WarpWatchCursor {
+    /// Get the default cursor file path for a blockchain of a network
+    pub(crate) fn default_file(network_name: &str, blockchain_id: &str) -> String {
+        format!("{ASH_CLI_WARP_CURSORS_DIR}/{network_name}-{blockchain_id}.json")
+    }
+
+    /// Load the cursor from the cursor file
+    /// Returns None if the cursor file does not exist
+    pub(crate) fn load(cursor_file: &str) -> Result<Option<Self>, CliError> {
+        let cursor_file = shellexpand::tilde(cursor_file).to_string();
+        let cursor_file = Path::new(&cursor_file);
+
+        if !cursor_file.exists() {
+            return Ok(None);
+        }
+
+        let cursor_file = fs::File::open(cursor_file)
+            .map_err(|e| CliError::dataerr(format!("Error opening cursor file: {e}")))?;
+        let cursor: Self = serde_json::from_reader(cursor_file)
+            .map_err(|e| CliError::dataerr(format!("Error parsing cursor file: {e}")))?;
+
+        Ok(Some(cursor))
+    }
+
+    /// Save the cursor to the cursor file
+    pub(crate) fn save(&self, cursor_file: &str) -> Result<(), CliError> {
+        let cursor_file = shellexpand::tilde(cursor_file).to_string();
+        let cursor_file = Path::new(&cursor_file);
+
+        // Create the cursor file parent directory if it doesn't exist
+        if let Some(parent) = cursor_file.parent() {
+            if !parent.exists() {
+                fs::create_dir_all(parent)
+                    .map_err(|e| CliError::dataerr(format!("Error creating cursor file: {e}")))?;
+            }
+        }
+
+        // Write to a temporary file and rename it so that a crash never leaves a truncated cursor
+        let tmp_cursor_file = format!("{}.tmp", cursor_file.display());
+        let tmp_file = fs::File::create(&tmp_cursor_file)
+            .map_err(|e| CliError::dataerr(format!("Error creating cursor file: {e}")))?;
+        serde_json::to_writer_pretty(&tmp_file, self)
+            .map_err(|e| CliError::dataerr(format!("Error writing cursor file: {e}")))?;
+        tmp_file
+            .sync_all()
+            .map_err(|e| CliError::dataerr(format!("Error writing cursor file: {e}")))?;
+        fs::rename(&tmp_cursor_file, cursor_file)
+            .map_err(|e| CliError::dataerr(format!("Error writing cursor file: {e}")))?;
+
+        Ok(())
+    }
+}
+
+/// Validator renewals plan to be stored in a JSON file
+/// Keeps track of the planned renewals of a Subnet's validators after they leave the Subnet
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ValidatorRenewalsPlan {
+    #[serde(rename = "subnetID")]
+    pub(crate) subnet_id: String,
+    pub(crate) renewals: Vec<AvalancheValidatorRenewal>,
+}
+
+impl ValidatorRenewalsPlan {
+    /// Get the default plan file path for a Subnet of a network
+    pub(crate) fn default_file(network_name: &str, subnet_id: &str) -> String {
+        format!("{ASH_CLI_VALIDATOR_RENEWALS_DIR}/{network_name}-{subnet_id}.json")
+    }
+
+    /// Load the plan from the plan file
+    /// Returns None if the plan file does not exist
+    pub(crate) fn load(plan_file: &str) -> Result<Option<Self>, CliError> {
+        let plan_file = shellexpand::tilde(plan_file).to_string();
+        let plan_file = Path::new(&plan_file);
+
+        if !plan_file.exists() {
+            return Ok(None);
+        }
+
+        let plan_file = fs::File::open(plan_file)
+            .map_err(|e| CliError::dataerr(format!("Error opening renewals plan file: {e}")))?;
+        let plan: Self = serde_json::from_reader(plan_file)
+            .map_err(|e| CliError::dataerr(format!("Error parsing renewals plan file: {e}")))?;
+
+        Ok(Some(plan))
+    }
+
+    /// Save the plan to the plan file
+    pub(crate) fn save(&self, plan_file: &str) -> Result<(), CliError> {
+        let plan_file = shellexpand::tilde(plan_file).to_string();
+        let plan_file = Path::new(&plan_file);
+
+        // Create the plan file parent directory if it doesn't exist
+        if let Some(parent) = plan_file.parent() {
+            if !parent.exists() {
+                fs::create_dir_all(parent).map_err(|e| {
+                    CliError::dataerr(format!("Error creating renewals plan file: {e}"))
+                })?;
+            }
+        }
+
+        // Write to a temporary file and rename it so that a crash never leaves a truncated plan
+        let tmp_plan_file = format!("{}.tmp", plan_file.display());
+        let tmp_file = fs::File::create(&tmp_plan_file)
+            .map_err(|e| CliError::dataerr(format!("Error creating renewals plan file: {e}")))?;
+        serde_json::to_writer_pretty(&tmp_file, self)
+            .map_err(|e| CliError::dataerr(format!("Error writing renewals plan file: {e}")))?;
+        tmp_file
+            .sync_all()
+            .map_err(|e| CliError::dataerr(format!("Error writing renewals plan file: {e}")))?;
+        fs::rename(&tmp_plan_file, plan_file)
+            .map_err(|e| CliError::dataerr(format!("Error writing renewals plan file: {e}")))?;
+
+        Ok(())
+    }
+}
@@ -6,14 +6,23 @@ use ash_sdk::{
     avalanche::{
         blockchains::AvalancheBlockchain,
         nodes::AvalancheNode,
-        subnets::{AvalancheSubnet, AvalancheSubnetType, AvalancheSubnetValidator},
-        vms::subnet_evm::warp::{AddressedPayload, SubnetEVMWarpMessage},
-        wallets::AvalancheWalletInfo,
+        subnets::{
+            AvalancheSubnet, AvalancheSubnetDelegator, AvalancheSubnetType,
+            AvalancheSubnetValidator, AvalancheValidatorRenewal, AvalancheValidatorReport,
+        },
+        txs::{
+            p::AvalancheTxSimulation, partial::AvalanchePartialTx, AvalanchePrimaryNetworkChain,
+            AvalancheTx,
+        },
+        vms::subnet_evm::warp::{AddressedPayload, BlockHashPayload, SubnetEVMWarpMessage},
+        wallets::{AvalancheMnemonicAccount, AvalancheWalletInfo},
         warp::{
+            payloads::{AddressedCall, HashPayload, PlatformVMWarpMessage},
             VerifiedWarpMessage, WarpMessage, WarpMessageNodeSignature, WarpMessagePayload,
-            WarpMessageStatus,
+            WarpMessageStatus, WarpSignedMessage, WarpUnsignedMessage,
         },
-        AvalancheXChainBalance,
+        AvalanchePChainBalance, AvalanchePChainStake, AvalancheUtxo, AvalancheXChainAsset,
+        AvalancheXChainAssetBalance, AvalancheXChainBalance,
     },
     console,
 };
@@ -23,6 +32,7 @@ use indicatif::ProgressBar;
 use indoc::formatdoc;
 use prettytable::{format, Table};
 use std::collections::HashMap;
+use std::path::Path;
 use std::time::Duration;
 
 // Module that contains templating functions for info strings
@@ -38,7 +48,7 @@ pub(crate) fn type_colorize<T>(var: &T) -> ColoredString
 where
     T: std::fmt::Display,
 {
-    match type_of(var).split(':').last().unwrap() {
+    match type_of(var).split(':').next_back().unwrap() {
         "String" | "&&str" => var.to_string().yellow(),
         "&u64" | "&u32" | "&u16" | "&u8" | "&usize" => var.to_string().cyan(),
         "&i64" | "&i32" | "&i16" | "&i8" | "&isize" => var.to_string().cyan(),
@@ -320,6 +330,14 @@ pub(crate) fn template_subnet_info(
         ));
     }
 
+    let mut pending_validators_info = String::new();
+    for validator in subnet.pending_validators.iter() {
+        pending_validators_info.push_str(&format!(
+            "\n{}",
+            template_validator_info(validator, subnet, true, extended, subindent)
+        ));
+    }
+
     let permissioned_subnet_info = &formatdoc!(
         "
         Control keys: {}
@@ -356,7 +374,8 @@ pub(crate) fn template_subnet_info(
             Subnet '{}':
               Type: {}
             {}  Blockchains list ({}): {}
-              Validators list ({}): {}",
+              Validators list ({}): {}
+              Pending validators list ({}): {}",
             type_colorize(&subnet.id),
             type_colorize(&subnet.subnet_type.to_string()),
             match subnet.subnet_type {
@@ -373,6 +392,11 @@ pub(crate) fn template_subnet_info(
             match validators_info.is_empty() {
                 true => String::from("[]"),
                 false => validators_info,
+            },
+            type_colorize(&subnet.pending_validators.len()),
+            match pending_validators_info.is_empty() {
+                true => String::from("[]"),
+                false => pending_validators_info,
             }
         ));
     }
@@ -380,6 +404,29 @@ pub(crate) fn template_subnet_info(
     indent::indent_all_by(indent, info_str)
 }
 
+pub(crate) fn template_validators_at(
+    height: u64,
+    validators: &[AvalancheSubnetValidator],
+    indent: usize,
+) -> String {
+    let mut info_str = String::new();
+
+    info_str.push_str(&format!(
+        "Validators at P-Chain height {} ({}):",
+        type_colorize(&height),
+        type_colorize(&validators.len())
+    ));
+    for validator in validators.iter() {
+        info_str.push_str(&format!(
+            "\n  - {}: weight {}",
+            type_colorize(&validator.node_id),
+            type_colorize(&validator.weight.unwrap_or_default())
+        ));
+    }
+
+    indent::indent_all_by(indent, info_str)
+}
+
 pub(crate) fn template_subnet_creation(subnet: &AvalancheSubnet, wait: bool) -> String {
     if wait {
         formatdoc!(
@@ -444,6 +491,143 @@ pub(crate) fn template_validator_add(
     }
 }
 
+pub(crate) fn template_validators_report_table(
+    reports: &[AvalancheValidatorReport],
+    indent: usize,
+) -> String {
+    let mut reports_table = Table::new();
+
+    reports_table.set_titles(row![
+        "Node ID".bold(),
+        "Connected".bold(),
+        "Uptime".bold(),
+        "Reward eligible".bold(),
+        "Expected reward (AVAX)".bold(),
+        "Days until expiry".bold(),
+        "Flags".bold(),
+    ]);
+
+    for report in reports {
+        let mut flags = vec![];
+        if !report.connected {
+            flags.push("DISCONNECTED");
+        }
+        if report.reward_eligible == Some(false) {
+            flags.push("LOW UPTIME");
+        }
+        if report.expiring {
+            flags.push("EXPIRING");
+        }
+
+        reports_table.add_row(row![
+            type_colorize(&report.node_id),
+            type_colorize(&report.connected),
+            type_colorize(&match report.uptime {
+                Some(uptime) => format!("{uptime:.2}%"),
+                None => String::from("N/A"),
+            }),
+            type_colorize(&match report.reward_eligible {
+                Some(reward_eligible) => reward_eligible.to_string(),
+                None => String::from("N/A"),
+            }),
+            type_colorize(&match report.expected_reward {
+                // Divide by 1 billion to convert from nAVAX to AVAX
+                Some(expected_reward) => (expected_reward as f64 / 1_000_000_000.0).to_string(),
+                None => String::from("N/A"),
+            }),
+            type_colorize(&format!("{:.1}", report.days_until_expiry)),
+            match flags.is_empty() {
+                true => "OK".green(),
+                false => flags.join(", ").red(),
+            },
+        ]);
+    }
+
+    indent::indent_all_by(indent, reports_table.to_string())
+}
+
+pub(crate) fn template_validator_renewals_table(
+    renewals: &[AvalancheValidatorRenewal],
+    indent: usize,
+) -> String {
+    let mut renewals_table = Table::new();
+
+    renewals_table.set_titles(row![
+        "Node ID".bold(),
+        "Weight".bold(),
+        "Current end (UTC)".bold(),
+        "New start (UTC)".bold(),
+        "New end (UTC)".bold(),
+        "Status".bold(),
+    ]);
+
+    for renewal in renewals {
+        renewals_table.add_row(row![
+            type_colorize(&renewal.node_id),
+            type_colorize(&renewal.weight),
+            type_colorize(&human_readable_timestamp(renewal.current_end_time)),
+            type_colorize(&human_readable_timestamp(renewal.start_time)),
+            type_colorize(&human_readable_timestamp(renewal.end_time)),
+            match (&renewal.tx_id, &renewal.error) {
+                (_, Some(error)) => error.red(),
+                (Some(tx_id), None) => format!("Tx ID: {tx_id}").green(),
+                (None, None) => "Planned".yellow(),
+            },
+        ]);
+    }
+
+    indent::indent_all_by(indent, renewals_table.to_string())
+}
+
+pub(crate) fn template_validator_remove(
+    tx_id: &str,
+    node_id: &str,
+    subnet_id: &str,
+    wait: bool,
+) -> String {
+    formatdoc!(
+        "
+        {} (Tx ID: '{}')
+        Removed validator '{}' from Subnet '{}'",
+        match wait {
+            true => "Validator removed from Subnet!",
+            false => "Initiated validator removal from Subnet!",
+        },
+        type_colorize(&tx_id),
+        type_colorize(&node_id),
+        type_colorize(&subnet_id),
+    )
+}
+
+pub(crate) fn template_delegator_add(delegator: &AvalancheSubnetDelegator, wait: bool) -> String {
+    let reward_owner = delegator.reward_owner.clone().unwrap_or_default();
+
+    formatdoc!(
+        "
+        {} (Tx ID: '{}')
+        Delegator to '{}':
+          Start time (UTC): {}
+          End time (UTC):   {}
+          Stake amount:     {}
+          Reward owner:
+            Locktime: {}
+            Threshold: {}
+            Addresses: {}",
+        match wait {
+            true => "Delegation added to the Primary Network!",
+            false => "Initiated delegation to the Primary Network!",
+        },
+        type_colorize(&delegator.tx_id),
+        type_colorize(&delegator.node_id),
+        type_colorize(&human_readable_timestamp(delegator.start_time)),
+        type_colorize(&human_readable_timestamp(delegator.end_time)),
+        type_colorize(&delegator.stake_amount),
+        type_colorize(&reward_owner.locktime),
+        type_colorize(&reward_owner.threshold),
+        type_colorize(&format!("{:?}", reward_owner.addresses)),
+    )
+}
+
 pub(crate) fn template_avalanche_node_info(node: &AvalancheNode, indent: usize) -> String {
     let mut info_str = String::new();
 
@@ -554,47 +738,487 @@ pub(crate) fn template_generate_private_key(
 pub(crate) fn template_wallet_info(wallet_info: &AvalancheWalletInfo, indent: usize) -> String {
     let mut info_str = String::new();
 
-    info_str.push_str(&formatdoc!(
-        "
-        Wallet information:
-          Hex private key:  {}
-          CB58 private key: {}
-          X-Chain address:  {}
-          P-Chain address:  {}
-          EVM address:      {}",
-        type_colorize(&wallet_info.hex_private_key),
-        type_colorize(&wallet_info.cb58_private_key),
+    info_str.push_str("Wallet information:");
+
+    if let (Some(hex_private_key), Some(cb58_private_key)) =
+        (&wallet_info.hex_private_key, &wallet_info.cb58_private_key)
+    {
+        info_str.push_str(&format!(
+            "\n  Hex private key:  {}\n  CB58 private key: {}",
+            type_colorize(hex_private_key),
+            type_colorize(cb58_private_key),
+        ));
+    }
+
+    info_str.push_str(&format!(
+        "\n  Hex public key:   {}\n  X-Chain address:  {}\n  P-Chain address:  {}\n  EVM address:      {}",
+        type_colorize(&wallet_info.hex_public_key),
         type_colorize(&wallet_info.xchain_address),
         type_colorize(&wallet_info.pchain_address),
         type_colorize(&wallet_info.evm_address),
     ));
 
+    if let Some(hex_evm_private_key) = &wallet_info.hex_evm_private_key {
+        info_str.push_str(&format!(
+            "\n  EVM private key:  {}",
+            type_colorize(hex_evm_private_key)
+        ));
+    }
+
+    indent::indent_all_by(indent, info_str)
+}
+
+pub(crate) fn template_mnemonic_accounts_table(
+    accounts: &[AvalancheMnemonicAccount],
+    indent: usize,
+) -> String {
+    let mut accounts_table = Table::new();
+
+    accounts_table.set_titles(row![
+        "Index".bold(),
+        "X-Chain address".bold(),
+        "P-Chain address".bold(),
+        "EVM address".bold(),
+    ]);
+
+    for account in accounts {
+        accounts_table.add_row(row![
+            type_colorize(&account.index),
+            type_colorize(&account.xchain_address),
+            type_colorize(&account.pchain_address),
+            type_colorize(&account.evm_address),
+        ]);
+    }
+
+    indent::indent_all_by(indent, accounts_table.to_string())
+}
+
+pub(crate) fn template_wallets_table(
+    names: &[String],
+    wallets_dir: &Path,
+    indent: usize,
+) -> String {
+    let mut wallets_table = Table::new();
+
+    wallets_table.set_titles(row!["Name".bold(), "Keystore".bold()]);
+
+    for name in names {
+        wallets_table.add_row(row![
+            type_colorize(name),
+            type_colorize(&wallets_dir.join(name).display().to_string()),
+        ]);
+    }
+
+    indent::indent_all_by(indent, wallets_table.to_string())
+}
+
+pub(crate) fn template_wallet_move(
+    amount: &str,
+    from: &str,
+    to: &str,
+    export_tx_id: &str,
+    import_tx_id: &str,
+    wait: bool,
+    indent: usize,
+) -> String {
+    let mut move_str = String::new();
+
+    move_str.push_str(&formatdoc!(
+        "
+        {} {} AVAX from {}-Chain to {}-Chain!
+        Export transaction ID: {}
+        Import transaction ID: {}",
+        match wait {
+            true => "Moved",
+            false => "Initiated moving",
+        },
+        type_colorize(&amount),
+        type_colorize(&from),
+        type_colorize(&to),
+        type_colorize(&export_tx_id),
+        type_colorize(&import_tx_id),
+    ));
+
+    indent::indent_all_by(indent, move_str)
+}
+
+pub(crate) fn template_partial_tx(
+    partial_tx: &AvalanchePartialTx,
+    path: &str,
+    unsigned_tx: &(String, String),
+    signed_tx: Option<&(String, String)>,
+    indent: usize,
+) -> String {
+    let mut partial_tx_str = String::new();
+
+    let signatures = partial_tx.credentials.iter().flatten().collect::<Vec<_>>();
+    let signed_count = signatures
+        .iter()
+        .filter(|signature| signature.signature.is_some())
+        .count();
+
+    partial_tx_str.push_str(&formatdoc!(
+        "
+        Partially signed '{}' transaction written to '{}':
+          Chain:      {}
+          Unsigned tx (hex):  {}
+          Unsigned tx (CB58): {}
+          Signatures: {}/{}",
+        type_colorize(&partial_tx.content.tx_type()),
+        type_colorize(&path),
+        type_colorize(&partial_tx.content.chain().name()),
+        type_colorize(&unsigned_tx.0),
+        type_colorize(&unsigned_tx.1),
+        type_colorize(&signed_count),
+        type_colorize(&signatures.len()),
+    ));
+
+    match partial_tx.is_fully_signed() {
+        true => {
+            partial_tx_str.push_str(&format!(
+                "\n  {}",
+                "Fully signed, ready to be issued with `ash avalanche tx broadcast`".green()
+            ));
+            if let Some((tx_id, encoded_tx)) = signed_tx {
+                partial_tx_str.push_str(&format!(
+                    "\n  Tx ID:      {}\n  Signed tx:  {}",
+                    type_colorize(tx_id),
+                    type_colorize(encoded_tx),
+                ));
+            }
+        }
+        false => {
+            partial_tx_str.push_str("\n  Missing signers:");
+            for address in partial_tx.missing_signers() {
+                partial_tx_str.push_str(&format!("\n  - {}", type_colorize(&address)));
+            }
+        }
+    }
+
+    indent::indent_all_by(indent, partial_tx_str)
+}
+
+pub(crate) fn template_partial_tx_content(
+    partial_tx: &AvalanchePartialTx,
+    unsigned_tx: &(String, String),
+    indent: usize,
+) -> String {
+    let mut content_str = String::new();
+
+    content_str.push_str(&formatdoc!(
+        "
+        Transaction to sign:
+          Type:       {}
+          Chain:      {}
+          Network ID: {}
+          Signers:    {}
+          Unsigned tx (hex):  {}
+          Unsigned tx (CB58): {}
+          Content:
+        ",
+        type_colorize(&partial_tx.content.tx_type()),
+        type_colorize(&partial_tx.content.chain().name()),
+        type_colorize(&partial_tx.content.network_id()),
+        type_colorize(&partial_tx.credentials.iter().flatten().count()),
+        type_colorize(&unsigned_tx.0),
+        type_colorize(&unsigned_tx.1),
+    ));
+    content_str.push_str(&indent::indent_all_by(
+        4,
+        serde_json::to_string_pretty(&partial_tx.content).unwrap(),
+    ));
+
+    indent::indent_all_by(indent, content_str)
+}
+
+pub(crate) fn template_tx_simulation(simulation: &AvalancheTxSimulation, indent: usize) -> String {
+    let mut simulation_str = String::new();
+    let to_avax = |amount: u64| amount as f64 / 1_000_000_000.0;
+
+    simulation_str.push_str(&formatdoc!(
+        "
+        Dry run of '{}' transaction (nothing was issued):
+          Balance before: {} AVAX
+          Fee:            {} AVAX
+          Stake:          {} AVAX
+          Balance after:  {} AVAX
+          Consumed UTXOs ({}):",
+        type_colorize(&simulation.tx_type),
+        type_colorize(&to_avax(simulation.balance_before)),
+        type_colorize(&to_avax(simulation.fee)),
+        type_colorize(&to_avax(simulation.stake)),
+        type_colorize(&to_avax(simulation.balance_after)),
+        type_colorize(&simulation.consumed_utxos.len()),
+    ));
+    for input in simulation.consumed_utxos.iter() {
+        simulation_str.push_str(&format!(
+            "\n  - {}:{} ({} AVAX)",
+            type_colorize(&input.tx_id),
+            type_colorize(&input.output_index),
+            type_colorize(&to_avax(input.amount)),
+        ));
+    }
+
+    simulation_str.push_str(&format!(
+        "\n  Change outputs ({}):",
+        type_colorize(&simulation.change_outputs.len())
+    ));
+    for output in simulation.change_outputs.iter() {
+        simulation_str.push_str(&format!(
+            "\n  - {} AVAX to {}",
+            type_colorize(&to_avax(output.amount)),
+            type_colorize(&output.addresses.join(", ")),
+        ));
+    }
+
+    indent::indent_all_by(indent, simulation_str)
+}
+
+pub(crate) fn template_tx_broadcast(
+    tx_type: &str,
+    tx_id: &str,
+    wait: bool,
+    indent: usize,
+) -> String {
+    let mut broadcast_str = String::new();
+
+    broadcast_str.push_str(&format!(
+        "{} '{}' transaction! (Tx ID: '{}')",
+        match wait {
+            true => "Issued",
+            false => "Initiated issuance of",
+        },
+        type_colorize(&tx_type),
+        type_colorize(&tx_id),
+    ));
+
+    indent::indent_all_by(indent, broadcast_str)
+}
+
+pub(crate) fn template_tx_status(
+    tx_id: &str,
+    chain: AvalanchePrimaryNetworkChain,
+    status: &str,
+    indent: usize,
+) -> String {
+    let status_str = format!(
+        "Transaction '{}' on {}: {}",
+        type_colorize(&tx_id),
+        chain.name(),
+        match status {
+            "Accepted" | "Committed" => status.green(),
+            "Rejected" | "Aborted" | "Dropped" => status.red(),
+            _ => type_colorize(&status),
+        }
+    );
+
+    indent::indent_all_by(indent, status_str)
+}
+
+pub(crate) fn template_tx_info(tx: &AvalancheTx, status: &str, indent: usize) -> String {
+    let mut info_str = String::new();
+
+    info_str.push_str(&formatdoc!(
+        "
+        Transaction '{}' on {}:
+          Type:          {}
+          Status:        {}
+          Network ID:    {}
+          Blockchain ID: {}
+          Memo:          {}
+          Inputs ({}):",
+        type_colorize(&tx.id),
+        tx.chain.name(),
+        type_colorize(&tx.tx_type),
+        type_colorize(&status),
+        type_colorize(&tx.network_id),
+        type_colorize(&tx.blockchain_id),
+        type_colorize(&tx.memo),
+        type_colorize(&tx.inputs.len()),
+    ));
+
+    for input in tx.inputs.iter() {
+        info_str.push_str(&format!(
+            "\n  - UTXO:      {}:{}\n    Asset ID:  {}\n    Amount:    {}",
+            type_colorize(&input.tx_id),
+            type_colorize(&input.output_index),
+            type_colorize(&input.asset_id),
+            type_colorize(&input.amount),
+        ));
+        if input.stakeable_locktime > 0 {
+            info_str.push_str(&format!(
+                "\n    Stakeable until: {}",
+                type_colorize(&human_readable_timestamp(input.stakeable_locktime)),
+            ));
+        }
+    }
+
+    info_str.push_str(&format!(
+        "\n  Outputs ({}):",
+        type_colorize(&tx.outputs.len())
+    ));
+    for output in tx.outputs.iter() {
+        info_str.push_str(&format!(
+            "\n  - Asset ID:  {}\n    Amount:    {}\n    Locktime:  {}\n    Threshold: {}\n    Addresses: {}",
+            type_colorize(&output.asset_id),
+            type_colorize(&output.amount),
+            type_colorize(&output.locktime),
+            type_colorize(&output.threshold),
+            type_colorize(&format!("{:?}", output.addresses)),
+        ));
+        if output.stakeable_locktime > 0 {
+            info_str.push_str(&format!(
+                "\n    Stakeable until: {}",
+                type_colorize(&human_readable_timestamp(output.stakeable_locktime)),
+            ));
+        }
+    }
+
+    if tx
+        .details
+        .as_object()
+        .is_some_and(|details| !details.is_empty())
+    {
+        info_str.push_str(&format!(
+            "\n  Details:\n{}",
+            indent::indent_all_by(4, serde_json::to_string_pretty(&tx.details).unwrap())
+        ));
+    }
+
     indent::indent_all_by(indent, info_str)
 }
 
 pub(crate) fn template_xchain_balance(
     address: &str,
-    asset_id: &str,
+    asset: &AvalancheXChainAsset,
     balance: &AvalancheXChainBalance,
     indent: usize,
 ) -> String {
     let mut balance_str = String::new();
 
     balance_str.push_str(&formatdoc!(
-        "Balance of '{}' on X-Chain (asset '{}'):  {}",
+        "Balance of '{}' on X-Chain (asset '{}'):  {} {}",
         type_colorize(&address),
-        type_colorize(&asset_id),
-        type_colorize(&(balance.balance as f64 / 1_000_000_000.0)),
+        type_colorize(&asset.id),
+        type_colorize(&asset.denominate(balance.balance)),
+        type_colorize(&asset.symbol),
     ));
 
     indent::indent_all_by(indent, balance_str)
 }
 
+pub(crate) fn template_xchain_balances_table(
+    balances: &[AvalancheXChainAssetBalance],
+    indent: usize,
+) -> String {
+    let mut balances_table = Table::new();
+
+    balances_table.set_titles(row![
+        "Asset ID".bold(),
+        "Name".bold(),
+        "Symbol".bold(),
+        "Balance".bold(),
+    ]);
+
+    for balance in balances {
+        balances_table.add_row(row![
+            type_colorize(&balance.asset.id),
+            type_colorize(&balance.asset.name),
+            type_colorize(&balance.asset.symbol),
+            type_colorize(&balance.asset.denominate(balance.balance)),
+        ]);
+    }
+
+    indent::indent_all_by(indent, balances_table.to_string())
+}
+
+pub(crate) fn template_pchain_balance(
+    address: &str,
+    balance: &AvalanchePChainBalance,
+    indent: usize,
+) -> String {
+    let mut balance_str = String::new();
+    let to_avax = |amount: u64| amount as f64 / 1_000_000_000.0;
+
+    balance_str.push_str(&formatdoc!(
+        "
+        Balance of '{}' on P-Chain: {} AVAX
+          Unlocked:               {} AVAX
+          Locked (stakeable):     {} AVAX
+          Locked (not stakeable): {} AVAX
+          UTXOs:                  {}",
+        type_colorize(&address),
+        type_colorize(&to_avax(balance.balance)),
+        type_colorize(&to_avax(balance.unlocked)),
+        type_colorize(&to_avax(balance.locked_stakeable)),
+        type_colorize(&to_avax(balance.locked_not_stakeable)),
+        type_colorize(&balance.utxos_ids.len()),
+    ));
+
+    indent::indent_all_by(indent, balance_str)
+}
+
+pub(crate) fn template_pchain_stake(
+    address: &str,
+    stake: &AvalanchePChainStake,
+    indent: usize,
+) -> String {
+    let mut stake_str = String::new();
+
+    stake_str.push_str(&formatdoc!(
+        "
+        Stake of '{}' on P-Chain: {} AVAX
+          Staked outputs: {}",
+        type_colorize(&address),
+        type_colorize(&(stake.staked as f64 / 1_000_000_000.0)),
+        type_colorize(&stake.staked_outputs_count),
+    ));
+
+    indent::indent_all_by(indent, stake_str)
+}
+
+pub(crate) fn template_utxos_table(utxos: &[AvalancheUtxo], indent: usize) -> String {
+    let mut utxos_table = Table::new();
+
+    utxos_table.set_titles(row![
+        "UTXO".bold(),
+        "Asset ID".bold(),
+        "Amount".bold(),
+        "Threshold".bold(),
+        "Owners".bold(),
+        "Stakeable until".bold(),
+    ]);
+
+    for utxo in utxos {
+        utxos_table.add_row(row![
+            format!(
+                "{}:{}",
+                type_colorize(&utxo.tx_id),
+                type_colorize(&utxo.output_index)
+            ),
+            type_colorize(&utxo.asset_id),
+            type_colorize(&utxo.amount),
+            type_colorize(&utxo.threshold),
+            utxo.addresses
+                .iter()
+                .map(|address| type_colorize(address).to_string())
+                .collect::<Vec<_>>()
+                .join("\n"),
+            match utxo.stakeable_locktime {
+                0 => type_colorize(&"None"),
+                locktime => type_colorize(&human_readable_timestamp(locktime)),
+            },
+        ]);
+    }
+
+    indent::indent_all_by(indent, utxos_table.to_string())
+}
+
 pub(crate) fn template_xchain_transfer(
     tx_id: &str,
     to: &str,
     asset_id: &str,
-    amount: f64,
+    amount: &str,
     wait: bool,
     indent: usize,
 ) -> String {
@@ -625,6 +1249,114 @@ pub(crate) fn template_xchain_transfer(
     indent::indent_all_by(indent, transfer_str)
 }
 
+pub(crate) fn template_xchain_asset_info(asset: &AvalancheXChainAsset, indent: usize) -> String {
+    let mut info_str = String::new();
+
+    info_str.push_str(&formatdoc!(
+        "
+        Asset '{}' on X-Chain:
+          Name:         {}
+          Symbol:       {}
+          Denomination: {}",
+        type_colorize(&asset.id),
+        type_colorize(&asset.name),
+        type_colorize(&asset.symbol),
+        type_colorize(&asset.denomination),
+    ));
+
+    indent::indent_all_by(indent, info_str)
+}
+
+pub(crate) fn template_xchain_asset_create(
+    asset_id: &str,
+    name: &str,
+    symbol: &str,
+    wait: bool,
+    indent: usize,
+) -> String {
+    let mut create_str = String::new();
+
+    create_str.push_str(&formatdoc!(
+        "
+        {} asset '{}' ({}) on X-Chain!
+        Asset ID: {}",
+        if wait {
+            "Created"
+        } else {
+            "Initiated creating"
+        },
+        type_colorize(&name),
+        type_colorize(&symbol),
+        type_colorize(&asset_id),
+    ));
+
+    indent::indent_all_by(indent, create_str)
+}
+
+pub(crate) fn template_xchain_asset_mint(
+    tx_id: &str,
+    asset_id: &str,
+    minted: &str,
+    to: &str,
+    wait: bool,
+    indent: usize,
+) -> String {
+    let mut mint_str = String::new();
+
+    mint_str.push_str(&formatdoc!(
+        "
+        {} {} of asset '{}' to '{}'!
+        Transaction ID: {}",
+        if wait { "Minted" } else { "Initiated minting" },
+        type_colorize(&minted),
+        type_colorize(&asset_id),
+        type_colorize(&to),
+        type_colorize(&tx_id),
+    ));
+
+    indent::indent_all_by(indent, mint_str)
+}
+
+pub(crate) fn template_evm_balance(
+    address: &str,
+    blockchain: &AvalancheBlockchain,
+    balance: &str,
+    indent: usize,
+) -> String {
+    let mut balance_str = String::new();
+
+    balance_str.push_str(&formatdoc!(
+        "Balance of '{}' on '{}':  {}",
+        type_colorize(&address),
+        type_colorize(&blockchain.name),
+        type_colorize(&balance),
+    ));
+
+    indent::indent_all_by(indent, balance_str)
+}
+
+pub(crate) fn template_evm_transfer(
+    tx_hash: &str,
+    to: &str,
+    blockchain: &AvalancheBlockchain,
+    amount: f64,
+    indent: usize,
+) -> String {
+    let mut transfer_str = String::new();
+
+    transfer_str.push_str(&formatdoc!(
+        "
+        Transfered {} of native token to '{}' on '{}'!
+        Transaction hash: {}",
+        type_colorize(&amount),
+        type_colorize(&to),
+        type_colorize(&blockchain.name),
+        type_colorize(&tx_hash),
+    ));
+
+    indent::indent_all_by(indent, transfer_str)
+}
+
 pub(crate) fn template_genesis_encoded(genesis_bytes: Vec<u8>, indent: usize) -> String {
     let mut genesis_str = String::new();
 
@@ -676,6 +1408,14 @@ pub(crate) fn template_warp_message(
             match &message.unsigned_message.payload {
                 WarpMessagePayload::SubnetEVMAddressedPayload(addressed_payload) =>
                     template_warp_addressed_payload(addressed_payload, 2),
+                WarpMessagePayload::SubnetEVMBlockHashPayload(block_hash_payload) =>
+                    template_warp_block_hash_payload(block_hash_payload, 2),
+                WarpMessagePayload::Hash(hash_payload) =>
+                    template_warp_hash_payload(hash_payload, 2),
+                WarpMessagePayload::AddressedCall(addressed_call) =>
+                    template_warp_addressed_call(addressed_call, 2),
+                WarpMessagePayload::PlatformVM(platformvm_message) =>
+                    template_warp_platformvm_message(platformvm_message, 2),
                 WarpMessagePayload::Unknown(payload) => format!(
                     "Payload (Unknown): {}",
                     type_colorize(&format!("0x{}", hex::encode(payload)))
@@ -736,6 +1476,133 @@ pub(crate) fn template_warp_addressed_payload(payload: &AddressedPayload, indent
     indent::indent_all_by(indent, payload_str)
 }
 
+pub(crate) fn template_warp_block_hash_payload(
+    payload: &BlockHashPayload,
+    indent: usize,
+) -> String {
+    let mut payload_str = String::new();
+
+    payload_str.push_str(&formatdoc!(
+        "
+        Payload ({}):
+          BlockHash: {}",
+        type_colorize(&"BlockHashPayload".to_string()),
+        type_colorize(&format!("{:?}", payload.block_hash)),
+    ));
+
+    indent::indent_all_by(indent, payload_str)
+}
+
+pub(crate) fn template_warp_hash_payload(payload: &HashPayload, indent: usize) -> String {
+    let mut payload_str = String::new();
+
+    payload_str.push_str(&formatdoc!(
+        "
+        Payload ({}):
+          Hash: {}",
+        type_colorize(&"Hash".to_string()),
+        type_colorize(&payload.hash),
+    ));
+
+    indent::indent_all_by(indent, payload_str)
+}
+
+pub(crate) fn template_warp_addressed_call(payload: &AddressedCall, indent: usize) -> String {
+    let mut payload_str = String::new();
+
+    payload_str.push_str(&formatdoc!(
+        "
+        Payload ({}):
+          SourceAddress: {}
+          Payload:       {}",
+        type_colorize(&"AddressedCall".to_string()),
+        type_colorize(&payload.source_address),
+        type_colorize(&payload.payload),
+    ));
+
+    indent::indent_all_by(indent, payload_str)
+}
+
+pub(crate) fn template_warp_platformvm_message(
+    message: &PlatformVMWarpMessage,
+    indent: usize,
+) -> String {
+    let mut message_str = String::new();
+
+    message_str.push_str(&match message {
+        PlatformVMWarpMessage::SubnetToL1Conversion(conversion) => formatdoc!(
+            "
+            Payload ({}):
+              ConversionID: {}",
+            type_colorize(&"SubnetToL1Conversion".to_string()),
+            type_colorize(&conversion.conversion_id),
+        ),
+        PlatformVMWarpMessage::RegisterL1Validator(registration) => formatdoc!(
+            "
+            Payload ({}):
+              SubnetID:     {}
+              NodeID:       {}
+              BLSPublicKey: {}
+              Expiry:       {}
+              Weight:       {}
+              RemainingBalanceOwner:
+                Threshold: {}
+                Addresses: {}
+              DisableOwner:
+                Threshold: {}
+                Addresses: {}",
+            type_colorize(&"RegisterL1Validator".to_string()),
+            type_colorize(&registration.subnet_id),
+            type_colorize(&registration.node_id),
+            type_colorize(&format!("0x{}", hex::encode(&registration.bls_public_key))),
+            type_colorize(&human_readable_timestamp(registration.expiry)),
+            type_colorize(&registration.weight),
+            type_colorize(&registration.remaining_balance_owner.threshold),
+            type_colorize(&format!(
+                "{:?}",
+                registration
+                    .remaining_balance_owner
+                    .addresses
+                    .iter()
+                    .map(|address| address.to_string())
+                    .collect::<Vec<_>>()
+            )),
+            type_colorize(&registration.disable_owner.threshold),
+            type_colorize(&format!(
+                "{:?}",
+                registration
+                    .disable_owner
+                    .addresses
+                    .iter()
+                    .map(|address| address.to_string())
+                    .collect::<Vec<_>>()
+            )),
+        ),
+        PlatformVMWarpMessage::L1ValidatorRegistration(registration) => formatdoc!(
+            "
+            Payload ({}):
+              ValidationID: {}
+              Registered:   {}",
+            type_colorize(&"L1ValidatorRegistration".to_string()),
+            type_colorize(&registration.validation_id),
+            type_colorize(&registration.registered),
+        ),
+        PlatformVMWarpMessage::L1ValidatorWeight(weight) => formatdoc!(
+            "
+            Payload ({}):
+              ValidationID: {}
+              Nonce:        {}
+              Weight:       {}",
+            type_colorize(&"L1ValidatorWeight".to_string()),
+            type_colorize(&weight.validation_id),
+            type_colorize(&weight.nonce),
+            type_colorize(&weight.weight),
+        ),
+    });
+
+    indent::indent_all_by(indent, message_str)
+}
+
 pub(crate) fn template_warp_subnet_evm_message(
     message: &SubnetEVMWarpMessage,
     indent: usize,
@@ -790,6 +1657,54 @@ pub(crate) fn template_warp_node_signatures(
     indent::indent_all_by(indent, signatures_str)
 }
 
+pub(crate) fn template_warp_relay(
+    message: &WarpMessage,
+    signed_message: &WarpSignedMessage,
+    destination_blockchain: &AvalancheBlockchain,
+    tx_hash: &str,
+    indent: usize,
+) -> String {
+    let mut relay_str = String::new();
+
+    relay_str.push_str(&formatdoc!(
+        "
+        Delivered Warp message '{}' to '{}'!
+        Signed by {} validator nodes
+        Signers bitset: {}
+        Transaction hash: {}",
+        type_colorize(&message.unsigned_message.id),
+        type_colorize(&destination_blockchain.name),
+        type_colorize(&message.node_signatures.len()),
+        type_colorize(&format!(
+            "0x{}",
+            hex::encode(&signed_message.signature.signers)
+        )),
+        type_colorize(&tx_hash),
+    ));
+
+    indent::indent_all_by(indent, relay_str)
+}
+
+pub(crate) fn template_warp_send(
+    unsigned_message: &WarpUnsignedMessage,
+    source_blockchain: &AvalancheBlockchain,
+    tx_hash: &str,
+    indent: usize,
+) -> String {
+    let mut send_str = String::new();
+
+    send_str.push_str(&formatdoc!(
+        "
+        Sent Warp message '{}' from '{}'!
+        Transaction hash: {}",
+        type_colorize(&unsigned_message.id),
+        type_colorize(&source_blockchain.name),
+        type_colorize(&tx_hash),
+    ));
+
+    indent::indent_all_by(indent, send_str)
+}
+
 pub(crate) fn truncate_uuid(uuid: &str) -> String {
     format!("{}...{}", &uuid[..4], &uuid[uuid.len() - 4..])
 }
@@ -15,22 +15,35 @@ pub mod warp;
 use crate::{
     avalanche::{
         blockchains::AvalancheBlockchain,
-        jsonrpc::{avm, platformvm},
-        subnets::AvalancheSubnet,
-        wallets::AvalancheWallet,
+        jsonrpc::{
+            avm,
+            info::{self, AVAX_INFO_API_ENDPOINT},
+            platformvm,
+        },
+        nodes::AvalancheNodeEndpoint,
+        subnets::{AvalancheSubnet, AvalancheSubnetValidator},
+        wallets::{
+            derive_mnemonic_accounts, AvalancheMnemonicAccount, AvalancheSignerKey,
+            AvalancheWallet, WatchOnlySigner,
+        },
+        warp::endpoints::{PeersEndpointResolver, StaticEndpointResolver},
     },
     conf::AshConfig,
     errors::*,
 };
 use async_std::task;
 use avalanche_types::{
+    formatting,
     ids::{short::Id as ShortId, Id},
-    jsonrpc::{avm::GetBalanceResult, platformvm::ApiOwner},
+    jsonrpc::{
+        avm::{GetAssetDescriptionResult, GetBalanceResult},
+        platformvm::{ApiOwner, GetBalanceResult as PlatformVmGetBalanceResult},
+    },
     key::secp256k1::address::avax_address_to_short_bytes,
     txs::utxo,
 };
 use serde::{Deserialize, Serialize};
-use std::str::FromStr;
+use std::{collections::HashMap, str::FromStr};
 
 /// Avalanche Primary Network ID
 /// This Subnet contains the P-Chain that is used for all Subnet operations
@@ -38,7 +51,7 @@ use std::str::FromStr;
 pub const AVAX_PRIMARY_NETWORK_ID: &str = "11111111111111111111111111111111LpoYY";
 
 /// Convert a human readable address to a ShortId
-fn address_to_short_id(address: &str, chain_alias: &str) -> Result<ShortId, AshError> {
+pub fn address_to_short_id(address: &str, chain_alias: &str) -> Result<ShortId, AshError> {
     let (_, addr_bytes) = avax_address_to_short_bytes(chain_alias, address).map_err(|e| {
         AvalancheNetworkError::InvalidAddress {
             address: address.to_string(),
@@ -49,6 +62,33 @@ fn address_to_short_id(address: &str, chain_alias: &str) -> Result<ShortId, AshE
     Ok(ShortId::from_slice(&addr_bytes))
 }
 
+/// Convert a ShortId to a human readable address (e.g. `P-avax1...`)
+fn short_id_to_address(
+    short_id: &ShortId,
+    chain_alias: &str,
+    hrp: &str,
+) -> Result<String, AshError> {
+    formatting::address(chain_alias, hrp, short_id.as_ref()).map_err(|e| {
+        AvalancheNetworkError::InvalidAddress {
+            address: short_id.to_string(),
+            msg: e.to_string(),
+        }
+        .into()
+    })
+}
+
+/// Get the human readable part of an address (e.g. `avax` for `P-avax1...`)
+fn address_hrp(address: &str, chain_alias: &str) -> Result<String, AshError> {
+    let (hrp, _) = avax_address_to_short_bytes(chain_alias, address).map_err(|e| {
+        AvalancheNetworkError::InvalidAddress {
+            address: address.to_string(),
+            msg: e.to_string(),
+        }
+    })?;
+
+    Ok(hrp)
+}
+
 /// Avalanche network
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
@@ -60,6 +100,9 @@ pub struct AvalancheNetwork {
     pub primary_network_id: Id,
     /// List of the network's Subnets
     pub subnets: Vec<AvalancheSubnet>,
+    /// HTTP endpoints of the nodes that cannot be reached through their public IP
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub node_endpoints: Vec<AvalancheNodeEndpoint>,
 }
 
 impl Default for AvalancheNetwork {
@@ -68,6 +111,7 @@ impl Default for AvalancheNetwork {
             name: "mainnet".to_string(),
             primary_network_id: Id::from_str(AVAX_PRIMARY_NETWORK_ID).unwrap(),
             subnets: vec![],
+            node_endpoints: vec![],
         }
     }
 }
@@ -295,6 +339,100 @@ impl AvalancheNetwork {
         Ok(())
     }
 
+    /// Update the validators of a Subnet along with their BLS public key (signer) by querying an API endpoint
+    /// Subnet validators do not expose their BLS public key, so it is taken from the Primary Network validator of the same node
+    pub fn update_subnet_validators_with_signers(&mut self, subnet_id: Id) -> Result<(), AshError> {
+        self.update_subnet_validators(subnet_id)?;
+        if subnet_id == self.primary_network_id {
+            return Ok(());
+        }
+
+        let rpc_url = &self.get_pchain()?.rpc_url;
+        let primary_validators =
+            platformvm::get_current_validators(rpc_url, self.primary_network_id)?;
+
+        let mut subnet = self.get_subnet(subnet_id)?.clone();
+        subnet.set_validators_signers(&primary_validators);
+
+        // Get the index of the Subnet
+        let subnet_index = self
+            .subnets
+            .iter()
+            .position(|subnet| subnet.id == subnet_id)
+            .ok_or(AvalancheNetworkError::NotFound {
+                network: self.name.clone(),
+                target_type: "Subnet".to_string(),
+                target_value: subnet_id.to_string(),
+            })?;
+
+        // Replace the Subnet
+        self.subnets[subnet_index] = subnet;
+
+        Ok(())
+    }
+
+    /// Update the pending validators of a Subnet by querying an API endpoint
+    /// This is kept separate from the current validators as `platform.getPendingValidators` is deprecated
+    /// and may not be served by every node
+    pub fn update_subnet_pending_validators(&mut self, subnet_id: Id) -> Result<(), AshError> {
+        let rpc_url = &self.get_pchain()?.rpc_url;
+
+        let pending_validators = platformvm::get_pending_validators(rpc_url, subnet_id)?;
+
+        // Replace the pending validators of the Subnet
+        let mut subnet = self.get_subnet(subnet_id)?.clone();
+
+        subnet.pending_validators = pending_validators;
+
+        // Get the index of the Subnet
+        let subnet_index = self
+            .subnets
+            .iter()
+            .position(|subnet| subnet.id == subnet_id)
+            .ok_or(AvalancheNetworkError::NotFound {
+                network: self.name.clone(),
+                target_type: "Subnet".to_string(),
+                target_value: subnet_id.to_string(),
+            })?;
+
+        // Replace the Subnet
+        self.subnets[subnet_index] = subnet;
+
+        Ok(())
+    }
+
+    /// Get the validators of a Subnet at a past P-Chain height, with their weight
+    pub fn get_subnet_validators_at(
+        &self,
+        subnet_id: Id,
+        height: u64,
+    ) -> Result<Vec<AvalancheSubnetValidator>, AshError> {
+        let rpc_url = &self.get_pchain()?.rpc_url;
+
+        Ok(platformvm::get_validators_at(rpc_url, subnet_id, height)?)
+    }
+
+    /// Get the resolver of the validator nodes RPC endpoints of a blockchain
+    /// Nodes with a configured endpoint are resolved from it,
+    /// the others from the peers of the node behind the blockchain RPC URL
+    pub fn get_validator_endpoint_resolver(
+        &self,
+        blockchain: &AvalancheBlockchain,
+    ) -> Result<StaticEndpointResolver, AshError> {
+        let node_ids = self
+            .get_subnet(blockchain.subnet_id)?
+            .validators
+            .iter()
+            .map(|validator| validator.node_id)
+            .collect();
+        let peers_resolver = PeersEndpointResolver::new(blockchain, node_ids)?;
+
+        Ok(StaticEndpointResolver::new(
+            &self.node_endpoints,
+            Some(Box::new(peers_resolver)),
+        ))
+    }
+
     /// Check if the operation is allowed on the network
     /// If not, return an error
     fn check_operation_allowed(
@@ -343,26 +481,89 @@ impl AvalancheNetwork {
         Ok(wallet)
     }
 
-    // Disabled for now because it has no concrete use case
-    /// Create a new wallet for the network from a mnemonic
+    /// Create a new wallet for the network from a BIP-39 mnemonic phrase
+    /// For security reasons, wallets cannot be created on the mainnet
+    pub fn create_wallet_from_mnemonic(
+        &self,
+        phrase: &str,
+        account_index: u32,
+    ) -> Result<AvalancheWallet, AshError> {
+        self.check_operation_allowed("wallet creation", vec!["mainnet"])?;
+
+        let xchain_url = &self.get_xchain()?.rpc_url;
+        let pchain_url = &self.get_pchain()?.rpc_url;
+
+        let wallet = task::block_on(async {
+            AvalancheWallet::new_from_mnemonic_phrase(phrase, account_index, xchain_url, pchain_url)
+                .await
+        })?;
+
+        Ok(wallet)
+    }
+
+    /// Create a new wallet for the network that signs transactions with a signer
     /// For security reasons, wallets cannot be created on the mainnet
-    // pub fn create_wallet_from_mnemonic_phrase(
-    //     &self,
-    //     phrase: &str,
-    //     account_index: u32,
-    // ) -> Result<AvalancheWallet, AshError> {
-    //     self.check_operation_allowed("wallet creation", vec!["mainnet"])?;
+    pub fn create_wallet_from_signer(
+        &self,
+        signer: impl Into<AvalancheSignerKey>,
+    ) -> Result<AvalancheWallet, AshError> {
+        self.check_operation_allowed("wallet creation", vec!["mainnet"])?;
+
+        let xchain_url = &self.get_xchain()?.rpc_url;
+        let pchain_url = &self.get_pchain()?.rpc_url;
+
+        let wallet = task::block_on(async {
+            AvalancheWallet::new_from_signer(signer, xchain_url, pchain_url).await
+        })?;
+
+        Ok(wallet)
+    }
+
+    /// Create a new watch-only wallet for the network from an hex-encoded public key
+    /// Watch-only wallets cannot sign, so they can be created on the mainnet to build unsigned transactions
+    pub fn create_wallet_from_public_key(
+        &self,
+        public_key: &str,
+    ) -> Result<AvalancheWallet, AshError> {
+        let signer = WatchOnlySigner::from_hex(public_key)?;
+
+        let xchain_url = &self.get_xchain()?.rpc_url;
+        let pchain_url = &self.get_pchain()?.rpc_url;
+
+        let wallet = task::block_on(async {
+            AvalancheWallet::new_from_signer(signer, xchain_url, pchain_url).await
+        })?;
+
+        Ok(wallet)
+    }
 
-    //     let xchain_url = &self.get_xchain()?.rpc_url;
-    //     let pchain_url = &self.get_pchain()?.rpc_url;
+    /// Get the network ID from the Info API of the X-Chain node
+    pub fn get_network_id(&self) -> Result<u32, AshError> {
+        let xchain_url = &self.get_xchain()?.rpc_url;
+        // The Info API is served next to the chains APIs (e.g. https://api.avax.network/ext/info)
+        let base_url = xchain_url
+            .split_once("/ext/")
+            .map_or(xchain_url.as_str(), |(base_url, _)| base_url);
+
+        let network_id = info::get_network_id(&format!("{base_url}/{AVAX_INFO_API_ENDPOINT}"))?;
 
-    //     let wallet = task::block_on(async {
-    //         AvalancheWallet::new_from_mnemonic_phrase(phrase, account_index, xchain_url, pchain_url)
-    //             .await
-    //     })?;
+        Ok(network_id)
+    }
 
-    //     Ok(wallet)
-    // }
+    /// Discover the accounts derived from a BIP-39 mnemonic phrase on the network
+    /// Return the addresses of `count` accounts starting at index `start_index`
+    pub fn discover_mnemonic_accounts(
+        &self,
+        phrase: &str,
+        start_index: u32,
+        count: u32,
+    ) -> Result<Vec<AvalancheMnemonicAccount>, AshError> {
+        derive_mnemonic_accounts(
+            phrase,
+            self.get_network_id()?,
+            start_index..start_index.saturating_add(count),
+        )
+    }
 
     /// Get the balance of an address on the X-Chain
     pub fn get_xchain_balance(
@@ -376,6 +577,94 @@ impl AvalancheNetwork {
 
         Ok(balance)
     }
+
+    /// Get the balance of every asset held by an address on the X-Chain, with the asset descriptions
+    /// If the node does not support `avm.getAllBalances`, the balances are computed from the UTXOs
+    pub fn get_xchain_balances(
+        &self,
+        address: &str,
+    ) -> Result<Vec<AvalancheXChainAssetBalance>, AshError> {
+        let xchain_url = &self.get_xchain()?.rpc_url;
+
+        let balances = match avm::get_all_balances(xchain_url, address) {
+            Ok(balances) => balances,
+            Err(RpcError::ResponseError { .. }) => {
+                let mut balances: Vec<(String, u64)> = vec![];
+                for utxo in self.get_xchain_utxos(address)? {
+                    let asset_id = utxo.asset_id.to_string();
+                    match balances.iter_mut().find(|(asset, _)| *asset == asset_id) {
+                        Some((_, balance)) => *balance += utxo.amount,
+                        None => balances.push((asset_id, utxo.amount)),
+                    }
+                }
+                balances
+            }
+            Err(e) => return Err(e.into()),
+        };
+
+        balances
+            .into_iter()
+            .map(|(asset_id, balance)| {
+                Ok(AvalancheXChainAssetBalance {
+                    asset: avm::get_asset_description(xchain_url, &asset_id)?,
+                    balance,
+                })
+            })
+            .collect()
+    }
+
+    /// Get the description of an asset on the X-Chain (by ID or alias, e.g. `AVAX`)
+    pub fn get_xchain_asset(&self, asset_id: &str) -> Result<AvalancheXChainAsset, AshError> {
+        let xchain_url = &self.get_xchain()?.rpc_url;
+
+        let asset = avm::get_asset_description(xchain_url, asset_id)?;
+
+        Ok(asset)
+    }
+
+    /// Get the UTXOs of an address on the X-Chain
+    pub fn get_xchain_utxos(&self, address: &str) -> Result<Vec<AvalancheUtxo>, AshError> {
+        let xchain_url = &self.get_xchain()?.rpc_url;
+        let hrp = address_hrp(address, "X")?;
+
+        let utxos = avm::get_utxos(xchain_url, &[address.to_string()], None)?
+            .iter()
+            .map(|utxo| AvalancheUtxo::from_utxo(utxo, "X", &hrp))
+            .collect::<Result<_, _>>()?;
+
+        Ok(utxos)
+    }
+
+    /// Get the AVAX balance of an address on the P-Chain
+    pub fn get_pchain_balance(&self, address: &str) -> Result<AvalanchePChainBalance, AshError> {
+        let pchain_url = &self.get_pchain()?.rpc_url;
+
+        let balance = platformvm::get_balance(pchain_url, address)?;
+
+        Ok(balance)
+    }
+
+    /// Get the UTXOs of an address on the P-Chain
+    pub fn get_pchain_utxos(&self, address: &str) -> Result<Vec<AvalancheUtxo>, AshError> {
+        let pchain_url = &self.get_pchain()?.rpc_url;
+        let hrp = address_hrp(address, "P")?;
+
+        let utxos = platformvm::get_utxos(pchain_url, &[address.to_string()], None)?
+            .iter()
+            .map(|utxo| AvalancheUtxo::from_utxo(utxo, "P", &hrp))
+            .collect::<Result<_, _>>()?;
+
+        Ok(utxos)
+    }
+
+    /// Get the amount staked by an address on the P-Chain
+    pub fn get_pchain_stake(&self, address: &str) -> Result<AvalanchePChainStake, AshError> {
+        let pchain_url = &self.get_pchain()?.rpc_url;
+
+        let stake = platformvm::get_stake(pchain_url, &[address.to_string()])?;
+
+        Ok(stake)
+    }
 }
 
 /// Avalanche output owners
@@ -415,10 +704,148 @@ impl From<GetBalanceResult> for AvalancheXChainBalance {
     }
 }
 
+/// Avalanche X-Chain asset description
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AvalancheXChainAsset {
+    #[serde(rename = "assetID")]
+    pub id: Id,
+    pub name: String,
+    pub symbol: String,
+    /// Number of decimal places of the asset (e.g. 9 for AVAX)
+    pub denomination: u8,
+}
+
+impl AvalancheXChainAsset {
+    /// Convert an amount in the smallest unit of the asset to its denominated value (e.g. nAVAX to AVAX)
+    pub fn denominate(&self, amount: u64) -> f64 {
+        amount as f64 / 10_f64.powi(self.denomination as i32)
+    }
+}
+
+impl From<GetAssetDescriptionResult> for AvalancheXChainAsset {
+    fn from(result: GetAssetDescriptionResult) -> Self {
+        Self {
+            id: result.asset_id,
+            name: result.name,
+            symbol: result.symbol,
+            denomination: result.denomination as u8,
+        }
+    }
+}
+
+/// Avalanche X-Chain balance of an asset, in the smallest unit of the asset
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AvalancheXChainAssetBalance {
+    pub asset: AvalancheXChainAsset,
+    pub balance: u64,
+}
+
+/// Avalanche P-Chain balance (in nAVAX)
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AvalanchePChainBalance {
+    pub balance: u64,
+    pub unlocked: u64,
+    /// Locked AVAX that can be used to stake
+    pub locked_stakeable: u64,
+    pub locked_not_stakeable: u64,
+    #[serde(rename = "utxoIDs")]
+    pub utxos_ids: Vec<utxo::Id>,
+}
+
+impl From<PlatformVmGetBalanceResult> for AvalanchePChainBalance {
+    fn from(result: PlatformVmGetBalanceResult) -> Self {
+        Self {
+            balance: result.balance,
+            unlocked: result.unlocked,
+            locked_stakeable: result.locked_stakeable.unwrap_or_default(),
+            locked_not_stakeable: result.locked_not_stakeable.unwrap_or_default(),
+            utxos_ids: result.utxo_ids.unwrap_or_default(),
+        }
+    }
+}
+
+/// Avalanche P-Chain staked amount (in nAVAX)
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AvalanchePChainStake {
+    /// Staked AVAX
+    pub staked: u64,
+    /// Staked amount of each asset, by asset ID
+    pub stakeds: HashMap<String, u64>,
+    /// Number of staked outputs
+    pub staked_outputs_count: usize,
+}
+
+impl From<platformvm::GetStakeResult> for AvalanchePChainStake {
+    fn from(result: platformvm::GetStakeResult) -> Self {
+        Self {
+            staked: result.staked,
+            stakeds: result
+                .stakeds
+                .into_iter()
+                .map(|(asset_id, amount)| (asset_id, amount.parse().unwrap_or_default()))
+                .collect(),
+            staked_outputs_count: result.staked_outputs.len(),
+        }
+    }
+}
+
+/// Avalanche UTXO, with its owners as human readable addresses
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AvalancheUtxo {
+    #[serde(rename = "txID")]
+    pub tx_id: Id,
+    pub output_index: u32,
+    #[serde(rename = "assetID")]
+    pub asset_id: Id,
+    pub amount: u64,
+    pub locktime: u64,
+    pub threshold: u32,
+    pub addresses: Vec<String>,
+    /// Locktime until which the UTXO can only be staked (0 if not locked)
+    pub stakeable_locktime: u64,
+}
+
+impl AvalancheUtxo {
+    /// Convert a UTXO, formatting its owners as addresses of a chain (e.g. `P-avax1...`)
+    pub fn from_utxo(utxo: &utxo::Utxo, chain_alias: &str, hrp: &str) -> Result<Self, AshError> {
+        let (transfer_output, stakeable_locktime) =
+            match (&utxo.transfer_output, &utxo.stakeable_lock_out) {
+                (Some(transfer_output), _) => (transfer_output.clone(), 0),
+                (None, Some(stakeable_lock_out)) => (
+                    stakeable_lock_out.transfer_output.clone(),
+                    stakeable_lock_out.locktime,
+                ),
+                (None, None) => Default::default(),
+            };
+
+        Ok(Self {
+            tx_id: utxo.utxo_id.tx_id,
+            output_index: utxo.utxo_id.output_index,
+            asset_id: utxo.asset_id,
+            amount: transfer_output.amount,
+            locktime: transfer_output.output_owners.locktime,
+            threshold: transfer_output.output_owners.threshold,
+            addresses: transfer_output
+                .output_owners
+                .addresses
+                .iter()
+                .map(|short_id| short_id_to_address(short_id, chain_alias, hrp))
+                .collect::<Result<_, _>>()?,
+            stakeable_locktime,
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::avalanche::{blockchains::AvalancheBlockchain, vms::AvalancheVmType};
+    use avalanche_types::{key, platformvm};
     use std::env;
 
     const AVAX_FUJI_CCHAIN_ID: &str = "yH8D7ThNJkxmtkuv2jgBa4P1Rn3Qpr4pPr7QYNfcdoS6k6HWp";
@@ -433,9 +860,10 @@ mod tests {
     const AVAX_HEX_PRIVATE_KEY: &str =
         "0x56289e99c94b6912bfc12adc093c9b51124f0dc54ac7a766b2bc5ccf558d8027";
     // This mnemonic phrase is not linked to the ewoq account
-    // const AVAX_MNEMONIC_PHRASE: &str =
-    //     "vehicle arrive more spread busy regret onion fame argue nice grocery humble vocal slot quit toss learn artwork theory fault tip belt cloth disorder";
+    const AVAX_MNEMONIC_PHRASE: &str =
+        "vehicle arrive more spread busy regret onion fame argue nice grocery humble vocal slot quit toss learn artwork theory fault tip belt cloth disorder";
     const AVAX_EWOQ_XCHAIN_ADDR: &str = "X-custom18jma8ppw3nhx5r4ap8clazz0dps7rv5u9xde7p";
+    const AVAX_EWOQ_PCHAIN_ADDR: &str = "P-custom18jma8ppw3nhx5r4ap8clazz0dps7rv5u9xde7p";
 
     // Load the test network from the ASH_TEST_CONFIG file
     fn load_test_network() -> AvalancheNetwork {
@@ -600,11 +1028,11 @@ mod tests {
         assert!(primary_network
             .blockchains
             .iter()
-            .any(|blockchain| blockchain.name.to_string() == "X-Chain"));
+            .any(|blockchain| blockchain.name == "X-Chain"));
         assert!(primary_network
             .blockchains
             .iter()
-            .any(|blockchain| blockchain.name.to_string() == "C-Chain"));
+            .any(|blockchain| blockchain.name == "C-Chain"));
     }
 
     #[test]
@@ -624,7 +1052,7 @@ mod tests {
 
         // Test that the Primary Network has validators
         let primary_network = fuji.get_subnet(fuji.primary_network_id).unwrap();
-        assert!(primary_network.validators.len() > 0);
+        assert!(!primary_network.validators.is_empty());
     }
 
     #[test]
@@ -636,7 +1064,10 @@ mod tests {
             .create_wallet_from_cb58(AVAX_CB58_PRIVATE_KEY)
             .unwrap();
 
-        assert_eq!(wallet.private_key.to_cb58(), AVAX_CB58_PRIVATE_KEY);
+        assert_eq!(
+            wallet.export_private_key_cb58().unwrap(),
+            AVAX_CB58_PRIVATE_KEY
+        );
     }
 
     #[test]
@@ -648,23 +1079,44 @@ mod tests {
             .create_wallet_from_hex(AVAX_HEX_PRIVATE_KEY)
             .unwrap();
 
-        assert_eq!(wallet.private_key.to_hex(), AVAX_HEX_PRIVATE_KEY);
+        assert_eq!(
+            wallet.export_private_key_hex().unwrap(),
+            AVAX_HEX_PRIVATE_KEY
+        );
+    }
+
+    #[test]
+    #[ignore]
+    fn test_avalanche_network_create_wallet_from_mnemonic() {
+        let local_network = load_avalanche_network_runner();
+
+        let wallet = local_network
+            .create_wallet_from_mnemonic(AVAX_MNEMONIC_PHRASE, 0)
+            .unwrap();
+
+        assert_eq!(
+            wallet.export_private_key_hex().unwrap(),
+            "0xc960efb06a74e53a3f7d2c4ab7aa3b3504457e37e54651f6591d615ff41315c1"
+        );
     }
 
-    // #[test]
-    // #[ignore]
-    // fn test_avalanche_network_create_wallet_from_mnemonic() {
-    //     let local_network = load_avalanche_network_runner();
+    #[test]
+    #[ignore]
+    fn test_avalanche_network_discover_mnemonic_accounts() {
+        let local_network = load_avalanche_network_runner();
 
-    //     let wallet = local_network
-    //         .create_wallet_from_mnemonic_phrase(AVAX_MNEMONIC_PHRASE, 0)
-    //         .unwrap();
+        let accounts = local_network
+            .discover_mnemonic_accounts(AVAX_MNEMONIC_PHRASE, 2, 3)
+            .unwrap();
 
-    //     assert_eq!(
-    //         wallet.private_key.to_hex(),
-    //         "0xf88975995ec2c83832dc7fb071b78d015ffc1bc4474810c1f05f60738f4ffd26"
-    //     );
-    // }
+        assert_eq!(
+            accounts
+                .iter()
+                .map(|account| account.index)
+                .collect::<Vec<_>>(),
+            vec![2, 3, 4]
+        );
+    }
 
     #[test]
     #[ignore]
@@ -676,4 +1128,110 @@ mod tests {
             .unwrap();
         assert!(balance.balance > 0);
     }
+
+    #[test]
+    #[ignore]
+    fn test_avalanche_network_get_xchain_balances() {
+        let local_network = load_avalanche_network_runner();
+
+        let balances = local_network
+            .get_xchain_balances(AVAX_EWOQ_XCHAIN_ADDR)
+            .unwrap();
+        let avax_balance = balances
+            .iter()
+            .find(|balance| balance.asset.symbol == "AVAX")
+            .unwrap();
+        assert!(avax_balance.balance > 0);
+
+        // The balance is the sum of the UTXOs of the address
+        let utxos = local_network
+            .get_xchain_utxos(AVAX_EWOQ_XCHAIN_ADDR)
+            .unwrap();
+        assert_eq!(
+            utxos
+                .iter()
+                .filter(|utxo| utxo.asset_id == avax_balance.asset.id)
+                .map(|utxo| utxo.amount)
+                .sum::<u64>(),
+            avax_balance.balance
+        );
+    }
+
+    #[test]
+    fn test_avalanche_xchain_asset_denominate() {
+        let avax = AvalancheXChainAsset {
+            symbol: "AVAX".to_string(),
+            denomination: 9,
+            ..Default::default()
+        };
+        assert_eq!(avax.denominate(1_500_000_000), 1.5);
+
+        let indivisible = AvalancheXChainAsset {
+            denomination: 0,
+            ..Default::default()
+        };
+        assert_eq!(indivisible.denominate(42), 42.0);
+    }
+
+    #[test]
+    #[ignore]
+    fn test_avalanche_network_get_pchain_balance() {
+        let local_network = load_avalanche_network_runner();
+
+        let balance = local_network
+            .get_pchain_balance(AVAX_EWOQ_PCHAIN_ADDR)
+            .unwrap();
+        assert!(balance.balance > 0);
+        assert!(balance.unlocked <= balance.balance);
+
+        // The balance is the sum of the UTXOs of the address
+        let utxos = local_network
+            .get_pchain_utxos(AVAX_EWOQ_PCHAIN_ADDR)
+            .unwrap();
+        assert_eq!(
+            utxos.iter().map(|utxo| utxo.amount).sum::<u64>(),
+            balance.balance
+        );
+        assert!(utxos
+            .iter()
+            .all(|utxo| utxo.addresses.contains(&AVAX_EWOQ_PCHAIN_ADDR.to_string())));
+
+        local_network
+            .get_pchain_stake(AVAX_EWOQ_PCHAIN_ADDR)
+            .unwrap();
+    }
+
+    #[test]
+    fn test_avalanche_utxo_from_utxo() {
+        let short_id = address_to_short_id(AVAX_EWOQ_PCHAIN_ADDR, "P").unwrap();
+        let transfer_output = key::secp256k1::txs::transfer::Output {
+            amount: 1_000,
+            output_owners: key::secp256k1::txs::OutputOwners {
+                locktime: 0,
+                threshold: 1,
+                addresses: vec![short_id],
+            },
+        };
+        let utxo = utxo::Utxo {
+            utxo_id: utxo::Id {
+                tx_id: Id::empty(),
+                output_index: 2,
+                ..Default::default()
+            },
+            stakeable_lock_out: Some(platformvm::txs::StakeableLockOut {
+                locktime: 1_700_000_000,
+                transfer_output,
+            }),
+            ..Default::default()
+        };
+
+        let avalanche_utxo = AvalancheUtxo::from_utxo(&utxo, "P", "custom").unwrap();
+        assert_eq!(avalanche_utxo.output_index, 2);
+        assert_eq!(avalanche_utxo.amount, 1_000);
+        assert_eq!(avalanche_utxo.stakeable_locktime, 1_700_000_000);
+        assert_eq!(
+            avalanche_utxo.addresses,
+            vec![AVAX_EWOQ_PCHAIN_ADDR.to_string()]
+        );
+    }
 }
@@ -10,7 +10,7 @@ use crate::{
             subnet_evm::{precompiles::WarpMessengerHttp, warp::SubnetEVMWarpMessage},
             AvalancheVmType,
         },
-        wallets::AvalancheWallet,
+        wallets::{AvalancheEvmSigner, AvalancheWallet},
         warp::*,
     },
     errors::*,
@@ -18,10 +18,11 @@ use crate::{
 };
 use avalanche_types::{ids::Id, jsonrpc::platformvm::Blockchain};
 use ethers::{
-    providers::{Http, Provider},
-    types::H256,
+    providers::{Http, Middleware, Provider},
+    types::{Address, Eip1559TransactionRequest, Log, H256, U256},
 };
 use serde::{Deserialize, Serialize};
+use std::sync::Arc;
 
 /// Avalanche blockchain
 #[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -92,6 +93,84 @@ impl AvalancheBlockchain {
         }
     }
 
+    /// Get the number of the latest block of this blockchain
+    /// Only works for EVM blockchains
+    pub async fn get_latest_block_number(&self) -> Result<u64, AshError> {
+        let provider = self.get_ethers_provider()?;
+
+        let block_number = provider
+            .get_block_number()
+            .await
+            .map_err(|e| RpcError::GetFailure {
+                data_type: "latest block number".to_string(),
+                target_type: "blockchain".to_string(),
+                target_value: self.id.to_string(),
+                msg: e.to_string(),
+            })?;
+
+        Ok(block_number.as_u64())
+    }
+
+    /// Get an ethers client that signs transactions on this blockchain with the wallet private key
+    /// The client should be created once and reused for all the transactions sent in a row
+    /// Only works for EVM blockchains
+    pub async fn get_evm_signer(
+        &self,
+        wallet: &AvalancheWallet,
+    ) -> Result<AvalancheEvmSigner, AshError> {
+        wallet.get_evm_signer(self.get_ethers_provider()?).await
+    }
+
+    /// Get the native token balance of an address on this blockchain (in wei)
+    /// Only works for EVM blockchains
+    pub async fn get_evm_balance(&self, address: Address) -> Result<U256, AshError> {
+        let provider = self.get_ethers_provider()?;
+
+        let balance = provider
+            .get_balance(address, None)
+            .await
+            .map_err(|e| RpcError::GetFailure {
+                data_type: "balance".to_string(),
+                target_type: "address".to_string(),
+                target_value: format!("{address:?}"),
+                msg: e.to_string(),
+            })?;
+
+        Ok(balance)
+    }
+
+    /// Transfer an amount of native token (in wei) to an address on this blockchain
+    /// The transaction is signed by the client created with `get_evm_signer`
+    /// Return the hash of the transaction once it is mined
+    /// Only works for EVM blockchains
+    pub async fn transfer_evm_native(
+        &self,
+        client: Arc<AvalancheEvmSigner>,
+        to: Address,
+        amount: U256,
+    ) -> Result<H256, AshError> {
+        let tx = Eip1559TransactionRequest::new().to(to).value(amount);
+
+        let receipt = client
+            .send_transaction(tx, None)
+            .await
+            .map_err(|e| RpcError::EthTxFailure {
+                to_addr: format!("{to:?}"),
+                msg: e.to_string(),
+            })?
+            .await
+            .map_err(|e| RpcError::EthTxFailure {
+                to_addr: format!("{to:?}"),
+                msg: e.to_string(),
+            })?
+            .ok_or(RpcError::EthTxFailure {
+                to_addr: format!("{to:?}"),
+                msg: "transaction was dropped from the mempool".to_string(),
+            })?;
+
+        Ok(receipt.transaction_hash)
+    }
+
     /// Get the blockchain ID as seen by the Warp Messenger
     pub async fn get_warp_blockchain_id(&self) -> Result<H256, AshError> {
         let warp_blockchain_id = match self.vm_type {
@@ -110,17 +189,19 @@ impl AvalancheBlockchain {
     }
 
     /// Get the Warp messages sent from this blockchain between 2 blocks
+    /// The logs that cannot be parsed as Warp messages are returned apart with the hash of their transaction
+    /// so that they do not prevent reading the other messages of the range
     pub async fn get_warp_messages(
         &self,
         from_block: &str,
         to_block: &str,
-    ) -> Result<Vec<WarpMessage>, AshError> {
-        let warp_messages = match self.vm_type {
+    ) -> Result<(Vec<WarpMessage>, Vec<(H256, AshError)>), AshError> {
+        match self.vm_type {
             AvalancheVmType::SubnetEVM => {
                 let parsed_from_block = parse_evm_block_number(from_block)?;
                 let parsed_to_block = parse_evm_block_number(to_block)?;
                 let warp_messenger = WarpMessengerHttp::new(self)?;
-                warp_messenger
+                let logs = warp_messenger
                     .get_send_warp_message_logs(
                         parsed_from_block,
                         parsed_to_block,
@@ -128,31 +209,91 @@ impl AvalancheBlockchain {
                         None,
                         None,
                     )
-                    .await?
-                    .into_iter()
-                    .map(|log| WarpMessage {
-                        unsigned_message: WarpUnsignedMessage::try_from_subnet_evm_log_data(
-                            &log.data.to_vec()[..],
-                        )
-                        .or_else::<Result<WarpUnsignedMessage, AshError>, _>(|_| {
-                            Ok(WarpUnsignedMessage::from(&log.data.to_vec()[..]))
-                        })
-                        .unwrap(),
-                        verified_message: VerifiedWarpMessage::SubnetEVM(
-                            SubnetEVMWarpMessage::from(log),
-                        ),
-                        ..Default::default()
-                    })
-                    .collect::<Vec<_>>()
+                    .await?;
+
+                Ok(parse_warp_message_logs(logs))
             }
             _ => Err(AvalancheBlockchainError::OperationNotAllowed {
                 blockchain_id: self.id.to_string(),
                 vm_type: self.vm_type.to_string(),
                 operation: "get Warp messages".to_string(),
             })?,
+        }
+    }
+
+    /// Deliver a signed Warp message to this blockchain
+    /// The transaction is sent to the destination address of the message with the given calldata
+    /// The transaction is signed by the client created with `get_evm_signer`
+    /// Return the hash of the delivery transaction
+    pub async fn deliver_warp_message(
+        &self,
+        client: Arc<AvalancheEvmSigner>,
+        signed_message: &WarpSignedMessage,
+        calldata: Vec<u8>,
+    ) -> Result<H256, AshError> {
+        let tx_hash = match self.vm_type {
+            AvalancheVmType::SubnetEVM => {
+                let destination_address = match &signed_message.unsigned_message.payload {
+                    WarpMessagePayload::SubnetEVMAddressedPayload(addressed_payload) => {
+                        addressed_payload.destination_address
+                    }
+                    _ => Err(AvalancheWarpMessagingError::ParseFailure {
+                        property: "payload".to_string(),
+                        msg: "cannot get the destination address of a non-addressed payload"
+                            .to_string(),
+                    })?,
+                };
+
+                let warp_messenger = WarpMessengerHttp::new(self)?;
+                warp_messenger
+                    .deliver_warp_message(
+                        client,
+                        signed_message,
+                        destination_address,
+                        calldata.into(),
+                    )
+                    .await?
+            }
+            _ => Err(AvalancheBlockchainError::OperationNotAllowed {
+                blockchain_id: self.id.to_string(),
+                vm_type: self.vm_type.to_string(),
+                operation: "deliver Warp message".to_string(),
+            })?,
         };
 
-        Ok(warp_messages)
+        Ok(tx_hash)
+    }
+
+    /// Send a Warp message from this blockchain to a destination address on a destination chain
+    /// The transaction is signed by the client created with `get_evm_signer`
+    /// Return the hash of the transaction and the unsigned Warp message that was sent
+    pub async fn send_warp_message(
+        &self,
+        client: Arc<AvalancheEvmSigner>,
+        destination_chain_id: Id,
+        destination_address: Address,
+        payload: Vec<u8>,
+    ) -> Result<(H256, WarpUnsignedMessage), AshError> {
+        let sent_message = match self.vm_type {
+            AvalancheVmType::SubnetEVM => {
+                let warp_messenger = WarpMessengerHttp::new(self)?;
+                warp_messenger
+                    .send_warp_message(
+                        client,
+                        H256::from_slice(&destination_chain_id.to_vec()),
+                        destination_address,
+                        payload.into(),
+                    )
+                    .await?
+            }
+            _ => Err(AvalancheBlockchainError::OperationNotAllowed {
+                blockchain_id: self.id.to_string(),
+                vm_type: self.vm_type.to_string(),
+                operation: "send Warp message".to_string(),
+            })?,
+        };
+
+        Ok(sent_message)
     }
 }
 
@@ -168,6 +309,41 @@ impl From<Blockchain> for AvalancheBlockchain {
     }
 }
 
+// Parse Subnet-EVM SendWarpMessage logs as Warp messages
+// Return the parsed messages and the logs that failed to parse with the hash of their transaction
+fn parse_warp_message_logs(logs: Vec<Log>) -> (Vec<WarpMessage>, Vec<(H256, AshError)>) {
+    let mut warp_messages = vec![];
+    let mut failures = vec![];
+
+    for log in logs {
+        let log_tx_hash = log.transaction_hash.unwrap_or_default();
+        let log_data = log.data.to_vec();
+        let parsed_message = WarpUnsignedMessage::try_from_subnet_evm_log_data(&log_data[..])
+            .or_else(|_| WarpUnsignedMessage::try_from(&log_data[..]))
+            .and_then(|unsigned_message| {
+                Ok((unsigned_message, SubnetEVMWarpMessage::try_from(log)?))
+            });
+
+        match parsed_message {
+            Ok((unsigned_message, subnet_evm_message)) => warp_messages.push(WarpMessage {
+                unsigned_message,
+                verified_message: VerifiedWarpMessage::SubnetEVM(subnet_evm_message),
+                ..Default::default()
+            }),
+            Err(e) => failures.push((
+                log_tx_hash,
+                AvalancheWarpMessagingError::ParseFailure {
+                    property: format!("log of transaction {log_tx_hash:?}"),
+                    msg: e.to_string(),
+                }
+                .into(),
+            )),
+        }
+    }
+
+    (warp_messages, failures)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -212,6 +388,42 @@ mod tests {
         assert!(fuji.get_pchain().unwrap().get_ethers_provider().is_err());
     }
 
+    #[test]
+    fn test_parse_warp_message_logs() {
+        let valid_log = Log {
+            address: Address::from_str("0x0200000000000000000000000000000000000005").unwrap(),
+            topics: vec![
+                H256::from_str("0x3e6ad4991eb8370644656486297eb0bf6a7792ef369dfd9eda2c51ec82b67b59").unwrap(),
+                H256::from_str("0xffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff").unwrap(),
+                H256::from_str("0x0000000000000000000000008db97c7cece249c2b98bdc0226cc4c2a57bf52fc").unwrap(),
+                H256::from_str("0x0000000000000000000000008db97c7cece249c2b98bdc0226cc4c2a57bf52fc").unwrap(),
+            ],
+            data: ethers::types::Bytes::from_str("0x00000000303976dccb39c21a43aad4ffa98d4dd86a9ca29f5038a13b87658ef856bc161dbb470000005e0000000000008db97c7cece249c2b98bdc0226cc4c2a57bf52fcffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff8db97c7cece249c2b98bdc0226cc4c2a57bf52fc0000000c48656c6c6f20776f726c6421").unwrap(),
+            transaction_hash: Some(H256::repeat_byte(1)),
+            ..Default::default()
+        };
+        let invalid_log = Log {
+            data: ethers::types::Bytes::from_str("0x0000").unwrap(),
+            transaction_hash: Some(H256::repeat_byte(2)),
+            ..valid_log.clone()
+        };
+
+        // The invalid log does not prevent parsing the valid one
+        let (warp_messages, failures) =
+            parse_warp_message_logs(vec![invalid_log, valid_log.clone()]);
+        assert_eq!(warp_messages.len(), 1);
+        assert_eq!(
+            warp_messages[0].verified_message,
+            VerifiedWarpMessage::SubnetEVM(SubnetEVMWarpMessage::try_from(valid_log).unwrap())
+        );
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0].0, H256::repeat_byte(2));
+        assert!(matches!(
+            failures[0].1,
+            AshError::AvalancheWarpMessagingError(AvalancheWarpMessagingError::ParseFailure { .. })
+        ));
+    }
+
     #[async_std::test]
     #[serial_test::serial]
     #[ignore]
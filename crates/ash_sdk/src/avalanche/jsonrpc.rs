@@ -2,14 +2,24 @@
 // Copyright (c) 2023, E36 Knots
 
 pub mod avm;
+pub mod coreth;
 pub mod info;
 pub mod platformvm;
 pub mod subnet_evm;
 
 // Module that contains code to interact with the Avalanche JSON RPC endpoints
 
-use crate::errors::*;
-use avalanche_types::jsonrpc::ResponseError;
+use crate::{
+    avalanche::txs::{AvalanchePrimaryNetworkChain, AvalancheTx},
+    errors::*,
+};
+use avalanche_types::{ids::Id, jsonrpc::ResponseError};
+use serde::{Deserialize, Serialize};
+use serde_aux::prelude::*;
+use std::time::Duration;
+
+/// Maximum number of UTXOs returned by a single `getUTXOs` request
+pub const UTXOS_PAGE_SIZE: u32 = 1024;
 
 /// Trait that defines the methods to get the result and error of a JSON RPC response
 /// This is used to avoid code duplication when posting JSON RPC requests
@@ -38,6 +48,25 @@ macro_rules! impl_json_rpc_response {
     };
 }
 
+/// Result of the `getTx` methods of the P-Chain and X-Chain APIs
+/// The transaction is kept as a JSON value as its format depends on the encoding
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+pub struct GetTxValueResult {
+    pub tx: serde_json::Value,
+    pub encoding: String,
+}
+
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+pub struct GetTxValueResponse {
+    pub jsonrpc: String,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub id: u32,
+    pub result: Option<GetTxValueResult>,
+    pub error: Option<ResponseError>,
+}
+
+impl_json_rpc_response!(GetTxValueResponse, GetTxValueResult);
+
 /// Get the result of a response from a JSON RPC request
 /// If the response contains an error, return an error instead
 pub fn get_json_rpc_req_result<Resp, Res>(
@@ -50,7 +79,39 @@ where
     Res: serde::de::DeserializeOwned,
     Resp: JsonRpcResponse<Resp, Res>,
 {
-    let resp: Resp = ureq::post(rpc_url)
+    post_json_rpc_req::<Resp, Res>(ureq::post(rpc_url), method, params)
+}
+
+/// Get the result of a response from a JSON RPC request that must complete before a timeout
+/// If the response contains an error, return an error instead
+pub fn get_json_rpc_req_result_with_timeout<Resp, Res>(
+    rpc_url: &str,
+    method: &str,
+    params: Option<ureq::serde_json::Value>,
+    timeout: Duration,
+) -> Result<Res, RpcError>
+where
+    Resp: serde::de::DeserializeOwned,
+    Res: serde::de::DeserializeOwned,
+    Resp: JsonRpcResponse<Resp, Res>,
+{
+    let agent = ureq::AgentBuilder::new().timeout(timeout).build();
+
+    post_json_rpc_req::<Resp, Res>(agent.post(rpc_url), method, params)
+}
+
+// Post a JSON RPC request and get the result of the response
+fn post_json_rpc_req<Resp, Res>(
+    request: ureq::Request,
+    method: &str,
+    params: Option<ureq::serde_json::Value>,
+) -> Result<Res, RpcError>
+where
+    Resp: serde::de::DeserializeOwned,
+    Res: serde::de::DeserializeOwned,
+    Resp: JsonRpcResponse<Resp, Res>,
+{
+    let resp: Resp = request
         .send_json(ureq::json!({
             "jsonrpc": "2.0",
             "method": method,
@@ -71,3 +132,34 @@ where
         Ok(resp.get_result().unwrap())
     }
 }
+
+// Get a transaction from the `getTx` method of a chain of the Primary Network
+// The transaction is requested twice: hex-encoded to get its type and JSON-encoded to get its content
+pub(crate) fn get_primary_network_tx(
+    rpc_url: &str,
+    method: &str,
+    chain: AvalanchePrimaryNetworkChain,
+    tx_id: Id,
+) -> Result<AvalancheTx, RpcError> {
+    let get_tx = |encoding: &str| {
+        get_json_rpc_req_result::<GetTxValueResponse, GetTxValueResult>(
+            rpc_url,
+            method,
+            Some(ureq::json!({
+                "txID": tx_id.to_string(),
+                "encoding": encoding,
+            })),
+        )
+    };
+    let hex_tx = get_tx("hex")?.tx;
+    let json_tx = get_tx("json")?.tx;
+
+    AvalancheTx::from_api(tx_id, chain, hex_tx.as_str().unwrap_or_default(), &json_tx).map_err(
+        |e| RpcError::GetFailure {
+            data_type: "content".to_string(),
+            target_type: "transaction".to_string(),
+            target_value: tx_id.to_string(),
+            msg: e.to_string(),
+        },
+    )
+}
@@ -5,18 +5,82 @@
 
 use crate::{
     avalanche::{
-        jsonrpc::{get_json_rpc_req_result, JsonRpcResponse},
-        AvalancheXChainBalance,
+        jsonrpc::{
+            get_json_rpc_req_result, get_primary_network_tx, JsonRpcResponse, UTXOS_PAGE_SIZE,
+        },
+        txs::{AvalanchePrimaryNetworkChain, AvalancheTx},
+        AvalancheXChainAsset, AvalancheXChainBalance,
     },
     errors::*,
     impl_json_rpc_response,
 };
-use avalanche_types::jsonrpc::{avm::*, ResponseError};
+use avalanche_types::{
+    choices::status::Status,
+    formatting::{decode_hex_with_checksum, encode_hex_with_checksum},
+    ids::Id,
+    jsonrpc::{avm::*, EndIndex, ResponseError},
+    txs::utxo::Utxo,
+};
+use serde::{Deserialize, Serialize};
+use serde_aux::prelude::*;
 
 /// Info API endpoint
 pub const AVAX_INFO_API_ENDPOINT: &str = "ext/info";
+/// Type ID of the secp256k1fx transfer output in the X-Chain codec
+pub const TRANSFER_OUTPUT_TYPE_ID: u32 = 7;
+
+/// Balance of an asset in the result of `avm.getAllBalances`
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+pub struct ApiAssetBalance {
+    pub asset: String,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub balance: u64,
+}
+
+/// Result of `avm.getAllBalances`, which is not implemented by avalanche-types
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+pub struct GetAllBalancesResult {
+    #[serde(default)]
+    pub balances: Vec<ApiAssetBalance>,
+}
+
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+pub struct GetAllBalancesResponse {
+    pub jsonrpc: String,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub id: u32,
+    pub result: Option<GetAllBalancesResult>,
+    pub error: Option<ResponseError>,
+}
+
+/// Result of `avm.getUTXOs` with the UTXOs left encoded
+/// avalanche-types fails to decode the UTXOs holding mint or NFT outputs
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetRawUtxosResult {
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub num_fetched: u32,
+    #[serde(default)]
+    pub utxos: Vec<String>,
+    pub end_index: Option<EndIndex>,
+}
+
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+pub struct GetRawUtxosResponse {
+    pub jsonrpc: String,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub id: u32,
+    pub result: Option<GetRawUtxosResult>,
+    pub error: Option<ResponseError>,
+}
 
 impl_json_rpc_response!(GetBalanceResponse, GetBalanceResult);
+impl_json_rpc_response!(GetAllBalancesResponse, GetAllBalancesResult);
+impl_json_rpc_response!(GetAssetDescriptionResponse, GetAssetDescriptionResult);
+impl_json_rpc_response!(GetUtxosResponse, GetUtxosResult);
+impl_json_rpc_response!(GetRawUtxosResponse, GetRawUtxosResult);
+impl_json_rpc_response!(IssueTxResponse, IssueTxResult);
+impl_json_rpc_response!(GetTxStatusResponse, GetTxStatusResult);
 
 /// Get the balance of an address by querying the X-Chain API
 pub fn get_balance(
@@ -37,6 +101,149 @@ pub fn get_balance(
     Ok(balance)
 }
 
+/// Get the balance of every asset held by an address by querying the X-Chain API
+/// Return the asset IDs (or aliases, e.g. `AVAX`) and their balances
+pub fn get_all_balances(rpc_url: &str, address: &str) -> Result<Vec<(String, u64)>, RpcError> {
+    let balances = get_json_rpc_req_result::<GetAllBalancesResponse, GetAllBalancesResult>(
+        rpc_url,
+        "avm.getAllBalances",
+        Some(ureq::json!({ "address": address })),
+    )?
+    .balances
+    .into_iter()
+    .map(|balance| (balance.asset, balance.balance))
+    .collect();
+
+    Ok(balances)
+}
+
+/// Get the description of an asset by querying the X-Chain API
+pub fn get_asset_description(
+    rpc_url: &str,
+    asset_id: &str,
+) -> Result<AvalancheXChainAsset, RpcError> {
+    let asset = get_json_rpc_req_result::<GetAssetDescriptionResponse, GetAssetDescriptionResult>(
+        rpc_url,
+        "avm.getAssetDescription",
+        Some(ureq::json!({ "assetID": asset_id })),
+    )?
+    .into();
+
+    Ok(asset)
+}
+
+/// Get the UTXOs of addresses by querying the X-Chain API
+/// If a source chain is provided, get the atomic UTXOs exported from this chain to the X-Chain
+/// Only the UTXOs holding a transfer output are returned, mint and NFT outputs are skipped
+pub fn get_utxos(
+    rpc_url: &str,
+    addresses: &[String],
+    source_chain_id: Option<Id>,
+) -> Result<Vec<Utxo>, RpcError> {
+    get_raw_utxos(rpc_url, addresses, source_chain_id)?
+        .iter()
+        .filter(|utxo_bytes| utxo_output_type_id(utxo_bytes) == Some(TRANSFER_OUTPUT_TYPE_ID))
+        .map(|utxo_bytes| {
+            Utxo::unpack(utxo_bytes).map_err(|e| RpcError::GetFailure {
+                data_type: "UTXOs".to_string(),
+                target_type: "addresses".to_string(),
+                target_value: addresses.join(", "),
+                msg: format!("failed to decode UTXO: {e}"),
+            })
+        })
+        .collect()
+}
+
+/// Get the encoded UTXOs of addresses by querying the X-Chain API
+/// If a source chain is provided, get the atomic UTXOs exported from this chain to the X-Chain
+/// The UTXOs are fetched page by page until all of them are retrieved
+pub fn get_raw_utxos(
+    rpc_url: &str,
+    addresses: &[String],
+    source_chain_id: Option<Id>,
+) -> Result<Vec<Vec<u8>>, RpcError> {
+    let mut params = ureq::json!({
+        "addresses": addresses,
+        "limit": UTXOS_PAGE_SIZE,
+        "encoding": "hex",
+    });
+    if let Some(source_chain_id) = source_chain_id {
+        params["sourceChain"] = ureq::json!(source_chain_id.to_string());
+    }
+
+    let mut utxos = Vec::new();
+    loop {
+        let utxos_page = get_json_rpc_req_result::<GetRawUtxosResponse, GetRawUtxosResult>(
+            rpc_url,
+            "avm.getUTXOs",
+            Some(params.clone()),
+        )?;
+        for utxo in utxos_page.utxos.iter() {
+            let utxo_bytes = decode_hex_with_checksum(utxo.trim_start_matches("0x").as_bytes())
+                .map_err(|e| RpcError::GetFailure {
+                    data_type: "UTXOs".to_string(),
+                    target_type: "addresses".to_string(),
+                    target_value: addresses.join(", "),
+                    msg: format!("failed to decode UTXO '{utxo}': {e}"),
+                })?;
+            utxos.push(utxo_bytes);
+        }
+
+        match utxos_page.end_index {
+            Some(end_index) if utxos_page.num_fetched >= UTXOS_PAGE_SIZE => {
+                params["startIndex"] = ureq::json!({
+                    "address": end_index.address,
+                    "utxo": end_index.utxo,
+                });
+            }
+            _ => break,
+        }
+    }
+
+    Ok(utxos)
+}
+
+/// Get the type ID of the output held by an encoded UTXO
+/// The output comes after the codec version, the UTXO ID and the asset ID
+pub fn utxo_output_type_id(utxo_bytes: &[u8]) -> Option<u32> {
+    let type_id_bytes = utxo_bytes.get(70..74)?;
+
+    Some(u32::from_be_bytes(type_id_bytes.try_into().unwrap()))
+}
+
+/// Issue a signed transaction on the X-Chain
+/// Return the ID of the transaction
+pub fn issue_tx(rpc_url: &str, tx_bytes: &[u8]) -> Result<Id, RpcError> {
+    let tx_id = get_json_rpc_req_result::<IssueTxResponse, IssueTxResult>(
+        rpc_url,
+        "avm.issueTx",
+        Some(ureq::json!({
+            "tx": encode_hex_with_checksum(tx_bytes),
+            "encoding": "hex",
+        })),
+    )?
+    .tx_id;
+
+    Ok(tx_id)
+}
+
+/// Get the status of a transaction by querying the X-Chain API
+pub fn get_tx_status(rpc_url: &str, tx_id: Id) -> Result<Status, RpcError> {
+    let status = get_json_rpc_req_result::<GetTxStatusResponse, GetTxStatusResult>(
+        rpc_url,
+        "avm.getTxStatus",
+        Some(ureq::json!({ "txID": tx_id.to_string() })),
+    )?
+    .status;
+
+    Ok(status)
+}
+
+/// Get a transaction by querying the X-Chain API
+pub fn get_tx(rpc_url: &str, tx_id: Id) -> Result<AvalancheTx, RpcError> {
+    get_primary_network_tx(rpc_url, "avm.getTx", AvalanchePrimaryNetworkChain::X, tx_id)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -56,7 +263,40 @@ mod tests {
         let local_network = load_test_network();
         let rpc_url = &local_network.get_xchain().unwrap().rpc_url;
 
-        let balance = get_balance(&rpc_url, AVAX_EWOQ_XCHAIN_ADDR, "AVAX").unwrap();
+        let balance = get_balance(rpc_url, AVAX_EWOQ_XCHAIN_ADDR, "AVAX").unwrap();
         assert!(balance.balance > 0);
     }
+
+    #[test]
+    #[ignore]
+    fn test_get_all_balances() {
+        let local_network = load_test_network();
+        let rpc_url = &local_network.get_xchain().unwrap().rpc_url;
+
+        let balances = get_all_balances(rpc_url, AVAX_EWOQ_XCHAIN_ADDR).unwrap();
+        assert!(balances
+            .iter()
+            .any(|(asset, balance)| asset == "AVAX" && *balance > 0));
+    }
+
+    #[test]
+    #[ignore]
+    fn test_get_asset_description() {
+        let local_network = load_test_network();
+        let rpc_url = &local_network.get_xchain().unwrap().rpc_url;
+
+        let asset = get_asset_description(rpc_url, "AVAX").unwrap();
+        assert_eq!(asset.symbol, "AVAX");
+        assert_eq!(asset.denomination, 9);
+    }
+
+    #[test]
+    #[ignore]
+    fn test_get_utxos() {
+        let local_network = load_test_network();
+        let rpc_url = &local_network.get_xchain().unwrap().rpc_url;
+
+        let utxos = get_utxos(rpc_url, &[AVAX_EWOQ_XCHAIN_ADDR.to_string()], None).unwrap();
+        assert!(!utxos.is_empty());
+    }
 }
@@ -0,0 +1,123 @@
+// SPDX-License-Identifier: BSD-3-Clause
+// Copyright (c) 2023, E36 Knots
+
+// Module that contains code to interact with Avalanche Coreth (C-Chain) atomic API
+// The atomic API is served on `/ext/bc/C/avax` and not on the EVM RPC endpoint
+
+use crate::{
+    avalanche::jsonrpc::{get_json_rpc_req_result, JsonRpcResponse},
+    errors::*,
+    impl_json_rpc_response,
+};
+use avalanche_types::{
+    choices::status::Status,
+    formatting::encode_hex_with_checksum,
+    ids::Id,
+    jsonrpc::{
+        avm::{GetUtxosResponse, GetUtxosResult, IssueTxResponse, IssueTxResult},
+        ResponseError,
+    },
+    txs::utxo::Utxo,
+};
+use serde::{Deserialize, Serialize};
+use serde_aux::prelude::*;
+
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetAtomicTxStatusResult {
+    pub status: String,
+    #[serde(default, deserialize_with = "deserialize_option_number_from_string")]
+    pub block_height: Option<u64>,
+}
+
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+pub struct GetAtomicTxStatusResponse {
+    pub jsonrpc: String,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub id: u32,
+    pub result: Option<GetAtomicTxStatusResult>,
+    pub error: Option<ResponseError>,
+}
+
+impl_json_rpc_response!(GetAtomicTxStatusResponse, GetAtomicTxStatusResult);
+
+/// Get the URL of the atomic API from the C-Chain EVM RPC URL
+pub fn get_avax_rpc_url(evm_rpc_url: &str) -> Result<String, RpcError> {
+    match evm_rpc_url.trim_end_matches('/').strip_suffix("/rpc") {
+        Some(base_url) => Ok(format!("{base_url}/avax")),
+        None => Err(RpcError::UrlParseFailure {
+            rpc_url: evm_rpc_url.to_string(),
+            msg: "the C-Chain RPC URL should end with '/rpc'".to_string(),
+        }),
+    }
+}
+
+/// Get the atomic UTXOs exported to the C-Chain from a source chain
+/// The addresses are Bech32 addresses prefixed with `C-`
+pub fn get_utxos(
+    rpc_url: &str,
+    addresses: &[String],
+    source_chain_id: Id,
+) -> Result<Vec<Utxo>, RpcError> {
+    let utxos = get_json_rpc_req_result::<GetUtxosResponse, GetUtxosResult>(
+        rpc_url,
+        "avax.getUTXOs",
+        Some(ureq::json!({
+            "addresses": addresses,
+            "sourceChain": source_chain_id.to_string(),
+            "limit": 1024,
+            "encoding": "hex",
+        })),
+    )?
+    .utxos
+    .unwrap_or_default();
+
+    Ok(utxos)
+}
+
+/// Issue a signed atomic transaction on the C-Chain
+/// Return the ID of the transaction
+pub fn issue_tx(rpc_url: &str, tx_bytes: &[u8]) -> Result<Id, RpcError> {
+    let tx_id = get_json_rpc_req_result::<IssueTxResponse, IssueTxResult>(
+        rpc_url,
+        "avax.issueTx",
+        Some(ureq::json!({
+            "tx": encode_hex_with_checksum(tx_bytes),
+            "encoding": "hex",
+        })),
+    )?
+    .tx_id;
+
+    Ok(tx_id)
+}
+
+/// Get the status of an atomic transaction by querying the C-Chain atomic API
+/// Dropped transactions have an `Unknown("Dropped")` status
+pub fn get_atomic_tx_status(rpc_url: &str, tx_id: Id) -> Result<Status, RpcError> {
+    let status = get_json_rpc_req_result::<GetAtomicTxStatusResponse, GetAtomicTxStatusResult>(
+        rpc_url,
+        "avax.getAtomicTxStatus",
+        Some(ureq::json!({ "txID": tx_id.to_string() })),
+    )?
+    .status;
+
+    Ok(Status::from(status.as_str()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_avax_rpc_url() {
+        assert_eq!(
+            get_avax_rpc_url("https://api.avax-test.network/ext/bc/C/rpc").unwrap(),
+            "https://api.avax-test.network/ext/bc/C/avax"
+        );
+        assert_eq!(
+            get_avax_rpc_url("http://127.0.0.1:9650/ext/bc/C/rpc/").unwrap(),
+            "http://127.0.0.1:9650/ext/bc/C/avax"
+        );
+        assert!(get_avax_rpc_url("https://rpc.ankr.com/avalanche-c").is_err());
+    }
+}
@@ -24,8 +24,10 @@ impl_json_rpc_response!(GetNodeIpResponse, GetNodeIpResult);
 impl_json_rpc_response!(GetNodeVersionResponse, GetNodeVersionResult);
 impl_json_rpc_response!(UptimeResponse, UptimeResult);
 impl_json_rpc_response!(GetNetworkNameResponse, GetNetworkNameResult);
+impl_json_rpc_response!(GetNetworkIdResponse, GetNetworkIdResult);
 impl_json_rpc_response!(IsBootstrappedResponse, IsBootstrappedResult);
 impl_json_rpc_response!(PeersResponse, PeersResult);
+impl_json_rpc_response!(GetTxFeeResponse, GetTxFeeResult);
 
 /// Get the ID of a node by querying the Info API
 pub fn get_node_id(rpc_url: &str) -> Result<(NodeId, Option<ProofOfPossession>), RpcError> {
@@ -83,6 +85,18 @@ pub fn get_network_name(rpc_url: &str) -> Result<String, RpcError> {
     Ok(network_name)
 }
 
+/// Get the ID of the network a node is participating in by querying the Info API
+pub fn get_network_id(rpc_url: &str) -> Result<u32, RpcError> {
+    let network_id = get_json_rpc_req_result::<GetNetworkIdResponse, GetNetworkIdResult>(
+        rpc_url,
+        "info.getNetworkID",
+        None,
+    )?
+    .network_id;
+
+    Ok(network_id)
+}
+
 /// Check if a given chain is done boostrapping by querying the Info API
 /// `chain` is the chain ID or alias of the chain to check
 pub fn is_bootstrapped(rpc_url: &str, chain: &str) -> Result<bool, RpcError> {
@@ -113,6 +127,17 @@ pub fn peers(rpc_url: &str, node_ids: Option<Vec<NodeId>>) -> Result<Vec<Peer>,
     Ok(peers)
 }
 
+/// Get the transaction fees of the Primary Network by querying the Info API
+pub fn get_tx_fee(rpc_url: &str) -> Result<GetTxFeeResult, RpcError> {
+    let tx_fee = get_json_rpc_req_result::<GetTxFeeResponse, GetTxFeeResult>(
+        rpc_url,
+        "info.getTxFee",
+        None,
+    )?;
+
+    Ok(tx_fee)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -182,6 +207,32 @@ mod tests {
         assert!(node_uptime.weighted_average_percentage > 0.0);
     }
 
+    #[test]
+    #[ignore]
+    fn test_get_network_id() {
+        let rpc_url = format!(
+            "http://{}:{}/{}",
+            ASH_TEST_HTTP_HOST, ASH_TEST_HTTP_PORT, AVAX_INFO_API_ENDPOINT
+        );
+        let network_id = get_network_id(&rpc_url).unwrap();
+
+        // avalanche-network-runner uses the local network ID
+        assert_eq!(network_id, 1337);
+    }
+
+    #[test]
+    #[ignore]
+    fn test_get_tx_fee() {
+        let rpc_url = format!(
+            "http://{}:{}/{}",
+            ASH_TEST_HTTP_HOST, ASH_TEST_HTTP_PORT, AVAX_INFO_API_ENDPOINT
+        );
+        let tx_fee = get_tx_fee(&rpc_url).unwrap();
+
+        assert!(tx_fee.tx_fee > 0);
+        assert!(tx_fee.create_asset_tx_fee > 0);
+    }
+
     #[test]
     #[ignore]
     fn test_peers() {
@@ -5,16 +5,22 @@
 
 use crate::avalanche::{
     blockchains::AvalancheBlockchain,
-    jsonrpc::{get_json_rpc_req_result, JsonRpcResponse},
+    jsonrpc::{get_json_rpc_req_result, get_primary_network_tx, JsonRpcResponse, UTXOS_PAGE_SIZE},
     subnets::{AvalancheSubnet, AvalancheSubnetValidator},
+    txs::{AvalanchePrimaryNetworkChain, AvalancheTx},
+    AvalanchePChainBalance, AvalanchePChainStake,
 };
 use crate::{errors::*, impl_json_rpc_response};
 use avalanche_types::{
-    ids::Id,
+    formatting::encode_hex_with_checksum,
+    ids::{node::Id as NodeId, Id},
     jsonrpc::{platformvm::*, ResponseError},
+    platformvm::txs::status::Status,
+    txs::utxo::Utxo,
 };
 use serde::{Deserialize, Serialize};
 use serde_aux::prelude::*;
+use std::collections::HashMap;
 
 /// Subnet with control keys as addresses
 /// This is done to avoid having to retransform the control keys to addresses later
@@ -45,12 +51,103 @@ pub struct GetSubnetsResponseStringControlKeys {
     pub error: Option<ResponseError>,
 }
 
+/// Result of `platform.getStake`, which is not implemented by avalanche-types
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetStakeResult {
+    /// Staked AVAX (deprecated in favor of `stakeds`)
+    #[serde(default, deserialize_with = "deserialize_number_from_string")]
+    pub staked: u64,
+    /// Staked amount of each asset
+    #[serde(default)]
+    pub stakeds: HashMap<String, String>,
+    /// Hex-encoded staked outputs
+    #[serde(default)]
+    pub staked_outputs: Vec<String>,
+}
+
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+pub struct GetStakeResponse {
+    pub jsonrpc: String,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub id: u32,
+    pub result: Option<GetStakeResult>,
+    pub error: Option<ResponseError>,
+}
+
+/// Result of `platform.getMinStake`, which is not implemented by avalanche-types
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetMinStakeResult {
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub min_validator_stake: u64,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub min_delegator_stake: u64,
+}
+
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+pub struct GetMinStakeResponse {
+    pub jsonrpc: String,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub id: u32,
+    pub result: Option<GetMinStakeResult>,
+    pub error: Option<ResponseError>,
+}
+
+/// Validator of a Subnet at a given P-Chain height, as returned by `platform.getValidatorsAt`
+/// Older AvalancheGo versions only return the weight of each validator
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ValidatorAt {
+    Weight(#[serde(deserialize_with = "deserialize_number_from_string")] u64),
+    Validator {
+        #[serde(default, rename = "publicKey")]
+        public_key: Option<String>,
+        #[serde(deserialize_with = "deserialize_number_from_string")]
+        weight: u64,
+    },
+}
+
+impl ValidatorAt {
+    /// Get the weight of the validator
+    pub fn weight(&self) -> u64 {
+        match self {
+            ValidatorAt::Weight(weight) => *weight,
+            ValidatorAt::Validator { weight, .. } => *weight,
+        }
+    }
+}
+
+/// Result of `platform.getValidatorsAt`, which is not implemented by avalanche-types
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+pub struct GetValidatorsAtResult {
+    pub validators: HashMap<NodeId, ValidatorAt>,
+}
+
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+pub struct GetValidatorsAtResponse {
+    pub jsonrpc: String,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub id: u32,
+    pub result: Option<GetValidatorsAtResult>,
+    pub error: Option<ResponseError>,
+}
+
 impl_json_rpc_response!(
     GetSubnetsResponseStringControlKeys,
     GetSubnetsResultStringControlKeys
 );
 impl_json_rpc_response!(GetBlockchainsResponse, GetBlockchainsResult);
 impl_json_rpc_response!(GetCurrentValidatorsResponse, GetCurrentValidatorsResult);
+impl_json_rpc_response!(GetPendingValidatorsResponse, GetPendingValidatorsResult);
+impl_json_rpc_response!(GetUtxosResponse, GetUtxosResult);
+impl_json_rpc_response!(IssueTxResponse, IssueTxResult);
+impl_json_rpc_response!(GetTxStatusResponse, GetTxStatusResult);
+impl_json_rpc_response!(GetBalanceResponse, GetBalanceResult);
+impl_json_rpc_response!(GetStakeResponse, GetStakeResult);
+impl_json_rpc_response!(GetMinStakeResponse, GetMinStakeResult);
+impl_json_rpc_response!(GetHeightResponse, GetHeightResult);
+impl_json_rpc_response!(GetValidatorsAtResponse, GetValidatorsAtResult);
 
 /// Get the Subnets of the network by querying the P-Chain API
 pub fn get_network_subnets(
@@ -123,11 +220,189 @@ pub fn get_current_validators(
     Ok(current_validators)
 }
 
+/// Get the pending validators of a Subnet by querying the P-Chain API
+/// Pending validators are the ones added with a start time in the future
+pub fn get_pending_validators(
+    rpc_url: &str,
+    subnet_id: Id,
+) -> Result<Vec<AvalancheSubnetValidator>, RpcError> {
+    let pending_validators =
+        get_json_rpc_req_result::<GetPendingValidatorsResponse, GetPendingValidatorsResult>(
+            rpc_url,
+            "platform.getPendingValidators",
+            Some(ureq::json!({ "subnetID": subnet_id.to_string() })),
+        )?
+        .validators
+        .iter()
+        .map(|validator| AvalancheSubnetValidator::from_api_primary_validator(validator, subnet_id))
+        .collect();
+
+    Ok(pending_validators)
+}
+
+/// Get the validators of a Subnet at a given P-Chain height by querying the P-Chain API
+/// Only the node ID and the weight of the validators are known at a past height
+/// The validators are sorted by decreasing weight
+pub fn get_validators_at(
+    rpc_url: &str,
+    subnet_id: Id,
+    height: u64,
+) -> Result<Vec<AvalancheSubnetValidator>, RpcError> {
+    let mut validators_at =
+        get_json_rpc_req_result::<GetValidatorsAtResponse, GetValidatorsAtResult>(
+            rpc_url,
+            "platform.getValidatorsAt",
+            Some(ureq::json!({
+                "height": height.to_string(),
+                "subnetID": subnet_id.to_string(),
+            })),
+        )?
+        .validators
+        .into_iter()
+        .map(|(node_id, validator)| AvalancheSubnetValidator {
+            node_id,
+            subnet_id,
+            weight: Some(validator.weight()),
+            ..Default::default()
+        })
+        .collect::<Vec<_>>();
+    validators_at.sort_by(|a, b| b.weight.cmp(&a.weight).then(a.node_id.cmp(&b.node_id)));
+
+    Ok(validators_at)
+}
+
+/// Get the current height of the P-Chain by querying the P-Chain API
+pub fn get_height(rpc_url: &str) -> Result<u64, RpcError> {
+    let height = get_json_rpc_req_result::<GetHeightResponse, GetHeightResult>(
+        rpc_url,
+        "platform.getHeight",
+        None,
+    )?
+    .height;
+
+    Ok(height)
+}
+
+/// Get the UTXOs of addresses by querying the P-Chain API
+/// If a source chain is provided, get the atomic UTXOs exported from this chain to the P-Chain
+/// The UTXOs are fetched page by page until all of them are retrieved
+pub fn get_utxos(
+    rpc_url: &str,
+    addresses: &[String],
+    source_chain_id: Option<Id>,
+) -> Result<Vec<Utxo>, RpcError> {
+    let mut params = ureq::json!({
+        "addresses": addresses,
+        "limit": UTXOS_PAGE_SIZE,
+        "encoding": "hex",
+    });
+    if let Some(source_chain_id) = source_chain_id {
+        params["sourceChain"] = ureq::json!(source_chain_id.to_string());
+    }
+
+    let mut utxos = Vec::new();
+    loop {
+        let utxos_page = get_json_rpc_req_result::<GetUtxosResponse, GetUtxosResult>(
+            rpc_url,
+            "platform.getUTXOs",
+            Some(params.clone()),
+        )?;
+        utxos.extend(utxos_page.utxos.unwrap_or_default());
+
+        match utxos_page.end_index {
+            Some(end_index) if utxos_page.num_fetched >= UTXOS_PAGE_SIZE => {
+                params["startIndex"] = ureq::json!({
+                    "address": end_index.address,
+                    "utxo": end_index.utxo,
+                });
+            }
+            _ => break,
+        }
+    }
+
+    Ok(utxos)
+}
+
+/// Get the balance of an address by querying the P-Chain API
+pub fn get_balance(rpc_url: &str, address: &str) -> Result<AvalanchePChainBalance, RpcError> {
+    let balance = get_json_rpc_req_result::<GetBalanceResponse, GetBalanceResult>(
+        rpc_url,
+        "platform.getBalance",
+        Some(ureq::json!({ "addresses": [address] })),
+    )?
+    .into();
+
+    Ok(balance)
+}
+
+/// Get the amount staked by addresses by querying the P-Chain API
+pub fn get_stake(rpc_url: &str, addresses: &[String]) -> Result<AvalanchePChainStake, RpcError> {
+    let stake = get_json_rpc_req_result::<GetStakeResponse, GetStakeResult>(
+        rpc_url,
+        "platform.getStake",
+        Some(ureq::json!({
+            "addresses": addresses,
+            "encoding": "hex",
+        })),
+    )?
+    .into();
+
+    Ok(stake)
+}
+
+/// Get the minimum stake (in nAVAX) of the validators and delegators of a Subnet by querying the P-Chain API
+pub fn get_min_stake(rpc_url: &str, subnet_id: Id) -> Result<GetMinStakeResult, RpcError> {
+    let min_stake = get_json_rpc_req_result::<GetMinStakeResponse, GetMinStakeResult>(
+        rpc_url,
+        "platform.getMinStake",
+        Some(ureq::json!({ "subnetID": subnet_id.to_string() })),
+    )?;
+
+    Ok(min_stake)
+}
+
+/// Issue a signed transaction on the P-Chain
+/// Return the ID of the transaction
+pub fn issue_tx(rpc_url: &str, tx_bytes: &[u8]) -> Result<Id, RpcError> {
+    let tx_id = get_json_rpc_req_result::<IssueTxResponse, IssueTxResult>(
+        rpc_url,
+        "platform.issueTx",
+        Some(ureq::json!({
+            "tx": encode_hex_with_checksum(tx_bytes),
+            "encoding": "hex",
+        })),
+    )?
+    .tx_id;
+
+    Ok(tx_id)
+}
+
+/// Get the status of a transaction by querying the P-Chain API
+pub fn get_tx_status(rpc_url: &str, tx_id: Id) -> Result<Status, RpcError> {
+    let status = get_json_rpc_req_result::<GetTxStatusResponse, GetTxStatusResult>(
+        rpc_url,
+        "platform.getTxStatus",
+        Some(ureq::json!({ "txID": tx_id.to_string() })),
+    )?
+    .status;
+
+    Ok(status)
+}
+
+/// Get a transaction by querying the P-Chain API
+pub fn get_tx(rpc_url: &str, tx_id: Id) -> Result<AvalancheTx, RpcError> {
+    get_primary_network_tx(
+        rpc_url,
+        "platform.getTx",
+        AvalanchePrimaryNetworkChain::P,
+        tx_id,
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::avalanche::AvalancheNetwork;
-    use avalanche_types::ids::node::Id as NodeId;
     use std::{env, str::FromStr};
 
     const AVAX_PRIMARY_NETWORK_ID: &str = "11111111111111111111111111111111LpoYY";
@@ -204,4 +479,69 @@ mod tests {
         // Test that the node has a non-zero delegation fee
         assert!(ava_labs_node.delegation_fee > Some(0.0));
     }
+
+    #[test]
+    #[ignore]
+    fn test_get_pending_validators() {
+        let fuji = AvalancheNetwork::load("fuji-ankr", None).unwrap();
+        let rpc_url = &fuji.get_pchain().unwrap().rpc_url;
+
+        let pending_validators = get_pending_validators(rpc_url, fuji.primary_network_id).unwrap();
+
+        // Pending validators all start in the future
+        let now = chrono::Utc::now().timestamp() as u64;
+        assert!(pending_validators
+            .iter()
+            .all(|validator| validator.start_time > now));
+    }
+
+    #[test]
+    fn test_get_validators_at_result() {
+        // AvalancheGo returns either the weight of each validator or its weight and BLS public key
+        let result: GetValidatorsAtResult = serde_json::from_str(
+            r#"{"validators":{
+                "NodeID-7Xhw2mDxuDS44j42TCB6U5579esbSt3Lg":"2000",
+                "NodeID-MFrZFVCXPv5iCn6M9K6XduxGTYp891xXZ":{"publicKey":"0x8f95","weight":"3000"}
+            }}"#,
+        )
+        .unwrap();
+
+        let weight_of = |node_id: &str| {
+            result
+                .validators
+                .get(&NodeId::from_str(node_id).unwrap())
+                .unwrap()
+                .weight()
+        };
+        assert_eq!(weight_of("NodeID-7Xhw2mDxuDS44j42TCB6U5579esbSt3Lg"), 2000);
+        assert_eq!(weight_of("NodeID-MFrZFVCXPv5iCn6M9K6XduxGTYp891xXZ"), 3000);
+    }
+
+    #[test]
+    #[ignore]
+    fn test_get_validators_at() {
+        let fuji = AvalancheNetwork::load("fuji-ankr", None).unwrap();
+        let rpc_url = &fuji.get_pchain().unwrap().rpc_url;
+
+        let height = get_height(rpc_url).unwrap();
+        let validators_at = get_validators_at(rpc_url, fuji.primary_network_id, height).unwrap();
+
+        // Test that the node operated by Ava Labs is present
+        assert!(validators_at
+            .iter()
+            .any(|validator| validator.node_id == NodeId::from_str(AVAX_FUJI_NODE_ID).unwrap()));
+    }
+
+    #[test]
+    #[ignore]
+    fn test_get_min_stake() {
+        let fuji = load_test_network();
+        let rpc_url = &fuji.get_pchain().unwrap().rpc_url;
+
+        let min_stake = get_min_stake(rpc_url, fuji.primary_network_id).unwrap();
+
+        // 1 AVAX on Fuji
+        assert_eq!(min_stake.min_delegator_stake, 1_000_000_000);
+        assert!(min_stake.min_validator_stake > min_stake.min_delegator_stake);
+    }
 }
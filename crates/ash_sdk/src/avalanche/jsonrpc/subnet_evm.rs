@@ -4,7 +4,9 @@
 // Module that contains code to interact with Avalanche Subnet-EVM API
 
 use crate::{
-    avalanche::jsonrpc::{get_json_rpc_req_result, JsonRpcResponse},
+    avalanche::jsonrpc::{
+        get_json_rpc_req_result, get_json_rpc_req_result_with_timeout, JsonRpcResponse,
+    },
     errors::*,
     impl_json_rpc_response,
 };
@@ -12,6 +14,7 @@ use avalanche_types::{ids::Id, jsonrpc::ResponseError};
 use ethers::types::Bytes;
 use serde::{Deserialize, Serialize};
 use serde_aux::prelude::*;
+use std::time::Duration;
 
 #[derive(Default, Debug, Clone, Serialize, Deserialize)]
 pub struct WarpGetSignatureResponse {
@@ -32,6 +35,28 @@ pub fn get_warp_signature(rpc_url: &str, warp_message_id: Id) -> Result<[u8; 96]
         Some(ureq::json!([warp_message_id])),
     )?;
 
+    parse_warp_signature(signature)
+}
+
+/// Get the signature of a Warp message by querying the Subnet-EVM API
+/// Fail if the request does not complete before the timeout
+pub fn get_warp_signature_with_timeout(
+    rpc_url: &str,
+    warp_message_id: Id,
+    timeout: Duration,
+) -> Result<[u8; 96], AshError> {
+    let signature = get_json_rpc_req_result_with_timeout::<WarpGetSignatureResponse, Bytes>(
+        rpc_url,
+        "warp_getSignature",
+        Some(ureq::json!([warp_message_id])),
+        timeout,
+    )?;
+
+    parse_warp_signature(signature)
+}
+
+// Check the length of a Warp message signature
+fn parse_warp_signature(signature: Bytes) -> Result<[u8; 96], AshError> {
     if signature.len() != 96 {
         return Err(AvalancheWarpMessagingError::InvalidSignature(format!(
             "Invalid signature length: {}",
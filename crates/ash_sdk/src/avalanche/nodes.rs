@@ -195,6 +195,17 @@ impl From<UptimeResult> for AvalancheNodeUptime {
     }
 }
 
+/// HTTP endpoint of an Avalanche node
+/// Used to reach nodes whose APIs are not exposed on their public IP (e.g. behind a load balancer)
+#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AvalancheNodeEndpoint {
+    #[serde(rename = "nodeID")]
+    pub node_id: NodeId,
+    /// Base URL of the node APIs (e.g. https://validator-1.example.com)
+    pub http_endpoint: String,
+}
+
 /// Compute the node ID from the DER-encoded certificate bytes
 pub fn node_id_from_cert_der(cert_bytes: &[u8]) -> Result<NodeId, AshError> {
     let node_id = NodeId::from_cert_der_bytes(cert_bytes)
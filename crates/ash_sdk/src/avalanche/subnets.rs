@@ -6,27 +6,48 @@
 use crate::{
     avalanche::{
         blockchains::AvalancheBlockchain,
-        jsonrpc::{info, platformvm::SubnetStringControlKeys, subnet_evm},
-        nodes::AvalancheNode,
+        jsonrpc::{
+            platformvm::{self, SubnetStringControlKeys},
+            subnet_evm,
+        },
         txs::p,
         wallets::AvalancheWallet,
-        warp::WarpMessageNodeSignature,
+        warp::{
+            endpoints::ValidatorEndpointResolver, get_canonical_validator_set,
+            WarpMessageNodeSignature, WarpNodeSignatureFailure, WarpNodeSignaturesCollection,
+            WarpSignatureRequestPolicy,
+        },
         AvalancheOutputOwners, AVAX_PRIMARY_NETWORK_ID,
     },
     errors::*,
 };
+use async_std::task;
 use avalanche_types::{
     ids::{node::Id as NodeId, Id},
     jsonrpc::platformvm::{ApiPrimaryDelegator, ApiPrimaryValidator},
     key::bls::ProofOfPossession,
-    utils::urls::extract_scheme_host_port_path_chain_alias,
+    platformvm::txs::Validator as PlatformVmValidator,
 };
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, Utc};
 use serde::{Deserialize, Serialize};
-use std::fmt::Display;
+use std::{
+    collections::VecDeque,
+    fmt::Display,
+    sync::{mpsc, Arc, Mutex},
+    thread,
+};
 
 use super::warp::WarpMessage;
 
+// Delay between the issuance of a validator renewal and the start of the new validation (in seconds)
+const VALIDATOR_RENEWAL_START_DELAY: u64 = 60;
+// Minimum interval between the end times of the renewed validations so that they do not expire together (in seconds)
+const VALIDATOR_RENEWAL_END_TIME_INTERVAL: u64 = 3600;
+// Interval between two checks of whether a renewed validator has left the current validators
+const VALIDATOR_RENEWAL_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_secs(10);
+// Maximum time to wait for a validator to leave the current validators after its end time (in seconds)
+const VALIDATOR_RENEWAL_REMOVAL_TIMEOUT: u64 = 600;
+
 /// Avalanche Subnet types
 #[derive(Default, Debug, Display, Clone, Serialize, Deserialize, PartialEq)]
 pub enum AvalancheSubnetType {
@@ -104,6 +125,51 @@ impl AvalancheSubnet {
             )
     }
 
+    /// Get a pending validator of the Subnet by its ID
+    pub fn get_pending_validator(&self, id: NodeId) -> Result<&AvalancheSubnetValidator, AshError> {
+        self.pending_validators
+            .iter()
+            .find(|&validator| validator.node_id == id)
+            .ok_or(
+                AvalancheSubnetError::NotFound {
+                    subnet_id: self.id.to_string(),
+                    target_type: "pending validator".to_string(),
+                    target_value: id.to_string(),
+                }
+                .into(),
+            )
+    }
+
+    /// Set the BLS public key (signer) of the Subnet validators from the Primary Network validators of the same nodes
+    /// Subnet validators do not expose their BLS public key, which is needed to collect Warp signatures
+    pub fn set_validators_signers(&mut self, primary_validators: &[AvalancheSubnetValidator]) {
+        for validator in self.validators.iter_mut() {
+            if let Some(signer) = primary_validators
+                .iter()
+                .find(|primary_validator| primary_validator.node_id == validator.node_id)
+                .and_then(|primary_validator| primary_validator.signer.clone())
+            {
+                validator.signer = Some(signer);
+            }
+        }
+    }
+
+    /// Get the current or pending validators of the Subnet
+    /// If node IDs are provided, only the validators with one of these IDs are returned
+    pub fn filter_validators(
+        &self,
+        pending: bool,
+        node_ids: &[NodeId],
+    ) -> Vec<&AvalancheSubnetValidator> {
+        match pending {
+            true => &self.pending_validators,
+            false => &self.validators,
+        }
+        .iter()
+        .filter(|validator| node_ids.is_empty() || node_ids.contains(&validator.node_id))
+        .collect()
+    }
+
     /// Create a new Subnet
     /// TODO: Add control keys and threshold as parameters
     /// See: https://github.com/ava-labs/avalanche-types-rs/pull/76
@@ -123,6 +189,7 @@ impl AvalancheSubnet {
     }
 
     /// Add a validator a permissionless Subnet
+    /// The validation and delegation rewards are sent to the reward addresses (P-Chain addresses), defaulting to the wallet address
     pub async fn add_validator_permissionless(
         &self,
         wallet: &AvalancheWallet,
@@ -133,8 +200,11 @@ impl AvalancheSubnet {
         end_time: DateTime<Utc>,
         reward_fee_percent: u32,
         signer: Option<ProofOfPossession>,
+        reward_addresses: &[String],
+        reward_threshold: u32,
         check_acceptance: bool,
     ) -> Result<AvalancheSubnetValidator, AshError> {
+        let rewards_owner = p::rewards_owner(wallet, reward_addresses, reward_threshold)?;
         let tx_id = p::add_permissionless_subnet_validator(
             wallet,
             node_id,
@@ -144,9 +214,11 @@ impl AvalancheSubnet {
             end_time,
             reward_fee_percent,
             signer,
+            &rewards_owner,
             check_acceptance,
         )
         .await?;
+        let rewards_owner_addresses = p::rewards_owner_addresses(wallet, &rewards_owner)?;
 
         Ok(AvalancheSubnetValidator {
             tx_id,
@@ -156,16 +228,8 @@ impl AvalancheSubnet {
             end_time: end_time.timestamp() as u64,
             stake_amount: Some(stake_amount),
             delegation_fee: Some(reward_fee_percent as f32),
-            validation_reward_owner: Some(AvalancheOutputOwners {
-                locktime: 0,
-                threshold: 1,
-                addresses: vec![wallet.pchain_wallet.p_address.clone()],
-            }),
-            delegation_reward_owner: Some(AvalancheOutputOwners {
-                locktime: 0,
-                threshold: 1,
-                addresses: vec![wallet.pchain_wallet.p_address.clone()],
-            }),
+            validation_reward_owner: Some(rewards_owner_addresses.clone()),
+            delegation_reward_owner: Some(rewards_owner_addresses),
             ..Default::default()
         })
     }
@@ -192,7 +256,7 @@ impl AvalancheSubnet {
 
         let tx_id = p::add_permissioned_subnet_validator(
             wallet,
-            self.id,
+            self,
             node_id,
             weight,
             start_time,
@@ -212,113 +276,565 @@ impl AvalancheSubnet {
         })
     }
 
-    /// Get the validator nodes signatures of a Warp message
-    /// Tries to get the signatures from a provided number of the Subnet's validators
-    /// If the number of validators is not provided, tries to get the signatures from all the Subnet's validators
-    /// If the number of validators is provided, stops after reaching the said number of validators
-    /// Note: for now, the validator nodes queried are the ones that are part of the Subnet at the current height
-    pub fn get_warp_message_node_signatures(
+    /// Remove a validator from a permissioned Subnet
+    pub async fn remove_validator_permissioned(
         &self,
-        warp_message: &WarpMessage,
-        signatures_threshold: Option<u32>,
-    ) -> Result<Vec<WarpMessageNodeSignature>, AshError> {
-        let mut signatures = vec![];
+        wallet: &AvalancheWallet,
+        node_id: NodeId,
+        check_acceptance: bool,
+    ) -> Result<Id, AshError> {
+        // Check if the Subnet is permissioned
+        if self.subnet_type != AvalancheSubnetType::Permissioned {
+            return Err(AvalancheSubnetError::OperationNotAllowed {
+                operation: "remove_validator_permissioned".to_string(),
+                subnet_id: self.id.to_string(),
+                subnet_type: self.subnet_type.to_string(),
+            }
+            .into());
+        }
 
-        let source_chain = self.get_blockchain(warp_message.unsigned_message.source_chain_id)?;
+        // Check that the node is a current or pending validator of the Subnet
+        self.get_validator(node_id)
+            .or_else(|_| self.get_pending_validator(node_id))?;
 
-        // Parse the RPC URL to get the scheme, host, and port
-        let (scheme, endpoint_host, port, path, ..) =
-            extract_scheme_host_port_path_chain_alias(&source_chain.rpc_url).map_err(|e| {
-                RpcError::UrlParseFailure {
-                    rpc_url: source_chain.rpc_url.to_string(),
-                    msg: e.to_string(),
-                }
-            })?;
-        let endpoint_scheme = scheme.unwrap_or("http".to_string());
-        let endpoint_path = path.unwrap_or("/ext/bc/C/rpc".to_string());
-        let endpoint_port = port.unwrap_or(9650);
-
-        // Get the node information from the info endpoint
-        let mut endpoint_node = AvalancheNode {
-            http_host: endpoint_host.clone(),
-            http_port: endpoint_port,
-            https_enabled: matches!(endpoint_scheme.as_str(), "https"),
-            ..Default::default()
+        p::remove_subnet_validator(wallet, self, node_id, check_acceptance).await
+    }
+
+    /// Build the report of the Subnet's current validators
+    pub fn validators_report(&self, expiry_threshold_days: u64) -> Vec<AvalancheValidatorReport> {
+        let now = Utc::now().timestamp() as u64;
+
+        self.validators
+            .iter()
+            .map(|validator| validator.report(now, expiry_threshold_days))
+            .collect()
+    }
+
+    /// Plan the renewal of the validators whose validation ends within `window`
+    /// Each validator is renewed with the same weight for `validation_duration`, starting when its current validation ends
+    /// The renewals of `planned_renewals` (e.g. from a previous run) whose validator has since left the Subnet without being renewed are planned again
+    /// The new validation is capped by the end of the node's validation of the Primary Network
+    /// The new validations are shortened so that they do not end at the same time as each other or as the other validations,
+    /// but never below `min_stake_duration` (in seconds)
+    /// Validators that cannot be renewed are planned with the reason as error
+    pub fn plan_validator_renewals(
+        &self,
+        primary_validators: &[AvalancheSubnetValidator],
+        planned_renewals: &[AvalancheValidatorRenewal],
+        window: Duration,
+        validation_duration: Duration,
+        min_stake_duration: u64,
+    ) -> Result<Vec<AvalancheValidatorRenewal>, AshError> {
+        // Check if the Subnet is permissioned
+        if self.subnet_type != AvalancheSubnetType::Permissioned {
+            return Err(AvalancheSubnetError::OperationNotAllowed {
+                operation: "plan_validator_renewals".to_string(),
+                subnet_id: self.id.to_string(),
+                subnet_type: self.subnet_type.to_string(),
+            }
+            .into());
+        }
+
+        // A window beyond the representable dates includes all the validators
+        let now = Utc::now().timestamp() as u64;
+        let window_end = Utc::now()
+            .checked_add_signed(window)
+            .map_or(u64::MAX, |window_end| window_end.timestamp().max(0) as u64);
+
+        // Validators that have left the Subnet are not in the current validators anymore,
+        // so their renewal can only be planned from the previously planned renewals
+        let is_validator = |node_id: &NodeId| {
+            self.validators
+                .iter()
+                .chain(self.pending_validators.iter())
+                .any(|validator| validator.node_id == *node_id)
         };
-        endpoint_node.update_info()?;
+        let renewable: Vec<(NodeId, Option<u64>, u64)> = self
+            .validators
+            .iter()
+            .filter(|validator| validator.end_time <= window_end)
+            .map(|validator| (validator.node_id, validator.weight, validator.end_time))
+            .chain(
+                planned_renewals
+                    .iter()
+                    .filter(|renewal| {
+                        renewal.tx_id.is_none()
+                            && renewal.current_end_time <= now
+                            && !is_validator(&renewal.node_id)
+                    })
+                    .map(|renewal| {
+                        (
+                            renewal.node_id,
+                            Some(renewal.weight),
+                            renewal.current_end_time,
+                        )
+                    }),
+            )
+            .collect();
 
-        // Construct the RPC URL to query the info.peers endpoint
-        let info_rpc_url = format!(
-            "{}/{}",
-            endpoint_node.get_http_endpoint(),
-            info::AVAX_INFO_API_ENDPOINT
-        );
+        let mut renewals: Vec<AvalancheValidatorRenewal> = renewable
+            .into_iter()
+            .map(|(node_id, weight, current_end_time)| {
+                let Some(weight) = weight else {
+                    return AvalancheValidatorRenewal {
+                        node_id,
+                        current_end_time,
+                        error: Some(format!("the weight of '{node_id}' is unknown")),
+                        ..Default::default()
+                    };
+                };
+                // A validation that has already ended is renewed as soon as possible
+                let start_time = current_end_time
+                    .saturating_add(VALIDATOR_RENEWAL_START_DELAY)
+                    .max(now.saturating_add(VALIDATOR_RENEWAL_START_DELAY));
+                let Some(end_time) = u64::try_from(validation_duration.num_seconds())
+                    .ok()
+                    .and_then(|duration| start_time.checked_add(duration))
+                else {
+                    return AvalancheValidatorRenewal {
+                        node_id,
+                        weight,
+                        current_end_time,
+                        start_time,
+                        error: Some(format!(
+                            "the new validation duration of {}s is out of range",
+                            validation_duration.num_seconds()
+                        )),
+                        ..Default::default()
+                    };
+                };
+                let mut renewal = AvalancheValidatorRenewal {
+                    node_id,
+                    weight,
+                    current_end_time,
+                    start_time,
+                    end_time,
+                    ..Default::default()
+                };
 
-        // Get the peers information from the info.peers endpoint (notably the nodes public IP addresses)
-        let peers = info::peers(
-            &info_rpc_url,
-            Some(
-                self.validators
+                // A Subnet validator has to validate the Primary Network during its whole validation
+                match primary_validators
                     .iter()
-                    .map(|validator| validator.node_id)
-                    .collect(),
-            ),
-        )?;
+                    .find(|primary_validator| primary_validator.node_id == node_id)
+                {
+                    Some(primary_validator) if primary_validator.end_time > start_time => {
+                        renewal.end_time = renewal.end_time.min(primary_validator.end_time);
+                    }
+                    Some(primary_validator) => {
+                        renewal.end_time = primary_validator.end_time;
+                        renewal.error = Some(format!(
+                            "the Primary Network validation of '{node_id}' ends before the renewal starts"
+                        ));
+                    }
+                    None => {
+                        renewal.error =
+                            Some(format!("'{node_id}' is not a Primary Network validator"));
+                    }
+                }
+                if renewal.error.is_none()
+                    && renewal.end_time - renewal.start_time < min_stake_duration
+                {
+                    renewal.error = Some(format!(
+                        "the new validation of '{node_id}' is shorter than the minimum staking duration of {min_stake_duration}s"
+                    ));
+                }
+
+                renewal
+            })
+            .collect();
+        renewals.sort_by_key(|renewal| renewal.current_end_time);
+
+        // Validators expiring together can make the Subnet lose its liveness,
+        // so each new validation is shortened until it ends apart from the other ones
+        let mut end_times: Vec<u64> = self
+            .validators
+            .iter()
+            .filter(|validator| validator.end_time > window_end)
+            .map(|validator| validator.end_time)
+            .collect();
+        for renewal in renewals
+            .iter_mut()
+            .filter(|renewal| renewal.error.is_none())
+        {
+            let min_end_time = renewal.start_time.saturating_add(min_stake_duration);
+            while renewal.end_time >= min_end_time
+                && end_times.iter().any(|end_time| {
+                    end_time.abs_diff(renewal.end_time) < VALIDATOR_RENEWAL_END_TIME_INTERVAL
+                })
+            {
+                renewal.end_time = renewal
+                    .end_time
+                    .saturating_sub(VALIDATOR_RENEWAL_END_TIME_INTERVAL);
+            }
+            if renewal.end_time < min_end_time || renewal.end_time <= renewal.start_time {
+                renewal.error = Some(format!(
+                    "the new validation of '{}' cannot end apart from the other validations without being shorter than the minimum staking duration of {min_stake_duration}s",
+                    renewal.node_id
+                ));
+                continue;
+            }
+            end_times.push(renewal.end_time);
+        }
+
+        Ok(renewals)
+    }
+
+    /// Issue in batch the planned renewals of the validations that have already ended
+    /// The P-Chain rejects a node that is still a current or pending validator of the Subnet,
+    /// so this has to be called once the current validations have ended
+    /// (the renewals of validators that have already left the Subnet can be planned again with `plan_validator_renewals`)
+    /// It only waits for the ended validators to leave the current validators, the other renewals are returned unchanged
+    /// Renewals that fail are reported with the reason as error instead of aborting the batch
+    pub async fn renew_validators(
+        &self,
+        wallet: &AvalancheWallet,
+        renewals: &[AvalancheValidatorRenewal],
+        check_acceptance: bool,
+    ) -> Result<Vec<AvalancheValidatorRenewal>, AshError> {
+        // Check if the Subnet is permissioned
+        if self.subnet_type != AvalancheSubnetType::Permissioned {
+            return Err(AvalancheSubnetError::OperationNotAllowed {
+                operation: "renew_validators".to_string(),
+                subnet_id: self.id.to_string(),
+                subnet_type: self.subnet_type.to_string(),
+            }
+            .into());
+        }
+
+        let mut renewed = renewals.to_vec();
+        renewed.sort_by_key(|renewal| renewal.current_end_time);
+
+        let now = Utc::now().timestamp() as u64;
+        let ended = renewed.partition_point(|renewal| renewal.current_end_time <= now);
+        self.renew_ended_validators(wallet, &mut renewed[..ended], check_acceptance)
+            .await;
 
-        // Until we have enough signatures or we have queried all the validators
-        let validators_threshold = match signatures_threshold {
-            Some(threshold) => threshold,
-            None => self.validators.len() as u32,
+        Ok(renewed)
+    }
+
+    // Renew validators whose current validation has ended
+    // The errors are recorded in the renewals
+    async fn renew_ended_validators(
+        &self,
+        wallet: &AvalancheWallet,
+        ended_renewals: &mut [AvalancheValidatorRenewal],
+        check_acceptance: bool,
+    ) {
+        let rpc_url = &format!("{}/ext/bc/P", wallet.pchain_wallet.pick_base_http_url().1);
+        let renewal_failure = |renewal: &AvalancheValidatorRenewal, msg: String| {
+            AshError::from(AvalancheSubnetError::ValidatorRenewalFailure {
+                subnet_id: self.id.to_string(),
+                node_id: renewal.node_id.to_string(),
+                msg,
+            })
+            .to_string()
         };
-        let mut validators_index = 0_u32;
-        while signatures.len() < validators_threshold as usize
-            && validators_index < self.validators.len() as u32
+
+        let mut renewals: Vec<&mut AvalancheValidatorRenewal> = ended_renewals
+            .iter_mut()
+            .filter(|renewal| renewal.error.is_none() && renewal.tx_id.is_none())
+            .collect();
+        if renewals.is_empty() {
+            return;
+        }
+
+        // Wait for the nodes to leave the current validators
+        loop {
+            let current_validators = match platformvm::get_current_validators(rpc_url, self.id) {
+                Ok(current_validators) => current_validators,
+                Err(e) => {
+                    for renewal in renewals.iter_mut() {
+                        renewal.error = Some(renewal_failure(
+                            renewal,
+                            format!("failed to get the current validators: {e}"),
+                        ));
+                    }
+                    return;
+                }
+            };
+            let is_validating = |renewal: &AvalancheValidatorRenewal| {
+                current_validators
+                    .iter()
+                    .any(|validator| validator.node_id == renewal.node_id)
+            };
+
+            let now = Utc::now().timestamp() as u64;
+            for renewal in renewals.iter_mut() {
+                if is_validating(renewal)
+                    && now
+                        > renewal
+                            .current_end_time
+                            .saturating_add(VALIDATOR_RENEWAL_REMOVAL_TIMEOUT)
+                {
+                    renewal.error = Some(renewal_failure(
+                        renewal,
+                        format!(
+                            "still a validator {VALIDATOR_RENEWAL_REMOVAL_TIMEOUT}s after the end of its validation"
+                        ),
+                    ));
+                }
+            }
+            renewals.retain(|renewal| renewal.error.is_none());
+
+            if !renewals.iter().any(|renewal| is_validating(renewal)) {
+                break;
+            }
+            task::sleep(VALIDATOR_RENEWAL_POLL_INTERVAL).await;
+        }
+
+        // The new validations have to start in the future
+        let earliest_start_time = Utc::now().timestamp() as u64 + VALIDATOR_RENEWAL_START_DELAY;
+        for renewal in renewals.iter_mut() {
+            renewal.start_time = renewal.start_time.max(earliest_start_time);
+            if renewal.start_time >= renewal.end_time {
+                renewal.error = Some(renewal_failure(
+                    renewal,
+                    "the new validation would end before it starts".to_string(),
+                ));
+            }
+        }
+        renewals.retain(|renewal| renewal.error.is_none());
+        if renewals.is_empty() {
+            return;
+        }
+
+        let validators: Vec<PlatformVmValidator> = renewals
+            .iter()
+            .map(|renewal| PlatformVmValidator {
+                node_id: renewal.node_id,
+                start: renewal.start_time,
+                end: renewal.end_time,
+                weight: renewal.weight,
+            })
+            .collect();
+        match p::add_permissioned_subnet_validators(wallet, self, &validators, check_acceptance)
+            .await
         {
-            // Get the validator node
-            let validator = &self.validators[validators_index as usize];
-
-            let signature = match validator.node_id {
-                // If the validator node is the node being used as endpoint, get the signature from the node
-                node_id if node_id == endpoint_node.id => subnet_evm::get_warp_signature(
-                    &source_chain.rpc_url,
-                    warp_message.unsigned_message.id,
-                )?,
-                // If the validator node is a peer of the node being used as endpoint
+            Ok(tx_ids) => {
+                for (renewal, tx_id) in renewals.iter_mut().zip(tx_ids) {
+                    match tx_id {
+                        Ok(tx_id) => renewal.tx_id = Some(tx_id),
+                        Err(e) => renewal.error = Some(renewal_failure(renewal, e.to_string())),
+                    }
+                }
+            }
+            Err(e) => {
+                for renewal in renewals.iter_mut() {
+                    renewal.error = Some(renewal_failure(renewal, e.to_string()));
+                }
+            }
+        }
+    }
+
+    /// Delegate stake to a validator of the Primary Network
+    /// The rewards are sent to the reward addresses, defaulting to the wallet address
+    pub async fn add_delegator(
+        &self,
+        wallet: &AvalancheWallet,
+        node_id: NodeId,
+        stake_amount: u64,
+        start_time: DateTime<Utc>,
+        end_time: DateTime<Utc>,
+        reward_addresses: &[String],
+        reward_threshold: u32,
+        check_acceptance: bool,
+    ) -> Result<AvalancheSubnetDelegator, AshError> {
+        // Check if the Subnet is the Primary Network
+        if self.subnet_type != AvalancheSubnetType::PrimaryNetwork {
+            return Err(AvalancheSubnetError::OperationNotAllowed {
+                operation: "add_delegator".to_string(),
+                subnet_id: self.id.to_string(),
+                subnet_type: self.subnet_type.to_string(),
+            }
+            .into());
+        }
+
+        let validator = self.get_validator(node_id)?;
+        let rewards_owner = p::rewards_owner(wallet, reward_addresses, reward_threshold)?;
+        let tx_id = p::add_permissionless_delegator(
+            wallet,
+            validator,
+            stake_amount,
+            start_time,
+            end_time,
+            &rewards_owner,
+            check_acceptance,
+        )
+        .await?;
+
+        Ok(AvalancheSubnetDelegator {
+            tx_id,
+            node_id,
+            start_time: start_time.timestamp() as u64,
+            end_time: end_time.timestamp() as u64,
+            stake_amount,
+            reward_owner: Some(p::rewards_owner_addresses(wallet, &rewards_owner)?),
+            ..Default::default()
+        })
+    }
+
+    /// Get the validator nodes signatures of a Warp message
+    /// The validator nodes are queried concurrently, following the request policy (timeout, retries and concurrency)
+    /// The RPC URL of each validator node is resolved by the endpoint resolver
+    /// Each signature is verified against the BLS public key of its validator before its weight is counted
+    /// If a weight threshold (in percent of the Subnet weight) is provided, stops as soon as
+    /// the validators that returned a valid signature reach it, otherwise waits for all the validators
+    /// Nodes that could not be queried or returned an invalid signature are reported as failures
+    /// instead of aborting the collection
+    /// Note: for now, the validator nodes queried are the ones that are part of the Subnet at the current height
+    pub fn get_warp_message_node_signatures(
+        &self,
+        warp_message: &WarpMessage,
+        weight_threshold: Option<u8>,
+        policy: &WarpSignatureRequestPolicy,
+        resolver: &dyn ValidatorEndpointResolver,
+    ) -> Result<WarpNodeSignaturesCollection, AshError> {
+        let mut collection = WarpNodeSignaturesCollection {
+            total_weight: self
+                .validators
+                .iter()
+                .map(|validator| validator.weight.unwrap_or_default())
+                .sum(),
+            ..Default::default()
+        };
+
+        let source_chain = self.get_blockchain(warp_message.unsigned_message.source_chain_id)?;
+        let canonical_validators = get_canonical_validator_set(&self.validators);
+
+        // Only validators with a BLS public key can sign the message
+        let mut requests = VecDeque::new();
+        for validator in self.validators.iter() {
+            let public_key = match &validator.signer {
+                Some(signer) if !signer.public_key.is_empty() => signer.public_key.clone(),
                 _ => {
-                    // Get the validator node's IP address
-                    let peer = peers
-                        .iter()
-                        .find(|&peer| peer.node_id == validator.node_id)
-                        .ok_or(AvalancheSubnetError::NotFound {
-                            subnet_id: self.id.to_string(),
-                            target_type: "validator node".to_string(),
-                            target_value: validator.node_id.to_string(),
-                        })?;
-
-                    // Construct the RPC URL to query the warp_getSignature endpoint
-                    let warp_rpc_url = format!(
-                        "{}://{}:{}{}",
-                        endpoint_scheme,
-                        peer.public_ip.ip(),
-                        peer.public_ip.port() - 1,
-                        endpoint_path
-                    );
-
-                    // Get the validator node's signature for the Warp message
-                    subnet_evm::get_warp_signature(&warp_rpc_url, warp_message.unsigned_message.id)?
+                    collection.failures.push(WarpNodeSignatureFailure {
+                        node_id: validator.node_id,
+                        attempts: 0,
+                        error: "validator has no BLS public key".to_string(),
+                    });
+                    continue;
                 }
             };
 
-            signatures.push(WarpMessageNodeSignature {
-                node_id: validator.node_id,
-                signature,
+            match resolver.resolve_rpc_url(validator.node_id, source_chain) {
+                Ok(warp_rpc_url) => {
+                    requests.push_back((validator.node_id, public_key, warp_rpc_url))
+                }
+                Err(e) => collection.failures.push(WarpNodeSignatureFailure {
+                    node_id: validator.node_id,
+                    attempts: 0,
+                    error: e.to_string(),
+                }),
+            }
+        }
+
+        // Query the validator nodes from a bounded pool of threads and collect the results as they arrive
+        let workers_count = policy.max_concurrent_requests.max(1).min(requests.len());
+        let requests = Arc::new(Mutex::new(requests));
+        let (sender, receiver) = mpsc::channel();
+        for _ in 0..workers_count {
+            let requests = Arc::clone(&requests);
+            let sender = sender.clone();
+            let policy = policy.clone();
+            let warp_message_id = warp_message.unsigned_message.id;
+            thread::spawn(move || loop {
+                let request = requests
+                    .lock()
+                    .ok()
+                    .and_then(|mut requests| requests.pop_front());
+                let (node_id, public_key, warp_rpc_url) = match request {
+                    Some(request) => request,
+                    None => break,
+                };
+
+                let (attempts, signature) =
+                    request_warp_signature(&warp_rpc_url, warp_message_id, &policy);
+                // The receiver is dropped once the weight threshold is reached
+                if sender
+                    .send((node_id, public_key, attempts, signature))
+                    .is_err()
+                {
+                    break;
+                }
             });
+        }
+        drop(sender);
+
+        let mut signed_public_keys = vec![];
+        for (node_id, public_key, attempts, signature) in receiver {
+            let signature = match signature {
+                Ok(signature)
+                    if warp_message
+                        .unsigned_message
+                        .verify_signature(&signature, &public_key) =>
+                {
+                    signature
+                }
+                Ok(_) => {
+                    collection.failures.push(WarpNodeSignatureFailure {
+                        node_id,
+                        attempts,
+                        error: "signature does not match the validator BLS public key".to_string(),
+                    });
+                    continue;
+                }
+                Err(e) => {
+                    collection.failures.push(WarpNodeSignatureFailure {
+                        node_id,
+                        attempts,
+                        error: e.to_string(),
+                    });
+                    continue;
+                }
+            };
+
+            collection
+                .signatures
+                .push(WarpMessageNodeSignature { node_id, signature });
+            // The weight of validators sharing the same BLS public key is only counted once
+            if !signed_public_keys.contains(&public_key) {
+                collection.collected_weight += canonical_validators
+                    .iter()
+                    .find(|canonical_validator| canonical_validator.public_key == public_key)
+                    .map(|canonical_validator| canonical_validator.weight)
+                    .unwrap_or_default();
+                signed_public_keys.push(public_key);
+            }
+
+            if let Some(threshold) = weight_threshold {
+                if collection.is_weight_threshold_reached(threshold) {
+                    break;
+                }
+            }
+        }
+
+        // Do not query the remaining nodes once the collection is over
+        if let Ok(mut requests) = requests.lock() {
+            requests.clear();
+        }
+
+        Ok(collection)
+    }
+}
 
-            // Increment the validator index
-            validators_index += 1;
+// Request the signature of a Warp message from a node, retrying on failure
+// Return the number of attempts along with the signature or the last error
+fn request_warp_signature(
+    rpc_url: &str,
+    warp_message_id: Id,
+    policy: &WarpSignatureRequestPolicy,
+) -> (u32, Result<[u8; 96], AshError>) {
+    let mut attempts = 0;
+    loop {
+        attempts += 1;
+        let signature =
+            subnet_evm::get_warp_signature_with_timeout(rpc_url, warp_message_id, policy.timeout);
+
+        if signature.is_ok() || attempts > policy.retries {
+            return (attempts, signature);
         }
 
-        Ok(signatures)
+        thread::sleep(policy.retry_delay);
     }
 }
 
@@ -414,6 +930,78 @@ impl AvalancheSubnetValidator {
                 .map(AvalancheOutputOwners::from),
         }
     }
+
+    /// Build the report of the validator at a given time (UNIX timestamp)
+    /// The validator is flagged as expiring if its validation ends within `expiry_threshold_days`
+    pub fn report(&self, now: u64, expiry_threshold_days: u64) -> AvalancheValidatorReport {
+        // Only the validators of rewarding Subnets have a potential reward
+        // Permissioned Subnet validators also have an uptime but are never rewarded
+        let reward_eligible = self
+            .potential_reward
+            .and(self.uptime)
+            .map(|uptime| uptime >= AVAX_REWARD_UPTIME_THRESHOLD);
+        let seconds_until_expiry = self.end_time.saturating_sub(now);
+
+        AvalancheValidatorReport {
+            node_id: self.node_id,
+            connected: self.connected,
+            uptime: self.uptime,
+            reward_eligible,
+            days_until_expiry: seconds_until_expiry as f64 / 86_400.0,
+            expected_reward: match reward_eligible {
+                Some(false) => Some(0),
+                _ => self.potential_reward,
+            },
+            expiring: seconds_until_expiry <= expiry_threshold_days.saturating_mul(86_400),
+        }
+    }
+}
+
+/// Renewal of a permissioned Subnet validator for a new validation period
+#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AvalancheValidatorRenewal {
+    #[serde(rename = "nodeID")]
+    pub node_id: NodeId,
+    pub weight: u64,
+    /// End time of the current validation
+    pub current_end_time: u64,
+    pub start_time: u64,
+    pub end_time: u64,
+    /// ID of the transaction that added the validator for the new period
+    #[serde(rename = "txID", skip_serializing_if = "Option::is_none")]
+    pub tx_id: Option<Id>,
+    /// Reason why the validator cannot be renewed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Minimum uptime (in percent) of a validator to be rewarded
+pub const AVAX_REWARD_UPTIME_THRESHOLD: f32 = 80.0;
+
+/// Report of the reward eligibility and expiry of a Subnet validator
+#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AvalancheValidatorReport {
+    #[serde(rename = "nodeID")]
+    pub node_id: NodeId,
+    pub connected: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uptime: Option<f32>,
+    /// Whether the uptime reaches the reward threshold (None if the Subnet does not reward its validators)
+    pub reward_eligible: Option<bool>,
+    pub days_until_expiry: f64,
+    /// Potential reward of the validator (in nAVAX), 0 if it is not eligible to rewards
+    pub expected_reward: Option<u64>,
+    /// Whether the validation ends soon
+    pub expiring: bool,
+}
+
+impl AvalancheValidatorReport {
+    /// Whether the validator needs attention (disconnected, not eligible to rewards or expiring)
+    pub fn is_flagged(&self) -> bool {
+        !self.connected || self.reward_eligible == Some(false) || self.expiring
+    }
 }
 
 /// Avalanche Subnet delegator
@@ -450,8 +1038,16 @@ impl From<ApiPrimaryDelegator> for AvalancheSubnetDelegator {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::avalanche::AvalancheNetwork;
-    use std::str::FromStr;
+    use crate::avalanche::{
+        nodes::AvalancheNodeEndpoint, warp::endpoints::StaticEndpointResolver,
+        warp::WarpUnsignedMessage, AvalancheNetwork,
+    };
+    use avalanche_types::key::bls::private_key::Key as BlsPrivateKey;
+    use std::{
+        io::{Read, Write},
+        net::TcpListener,
+        str::FromStr,
+    };
 
     const NETWORK_RUNNER_CCHAIN_ID: &str = "VctwH3nkmztWbkdNXbuo6eCYndsUuemtM9ZFmEUZ5QpA1Fu8G";
     const NETWORK_RUNNER_NODE_ID: &str = "NodeID-MFrZFVCXPv5iCn6M9K6XduxGTYp891xXZ";
@@ -513,6 +1109,82 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_avalanche_subnet_get_pending_validator() {
+        let current_node_id = NodeId::from_str(NETWORK_RUNNER_NODE_ID).unwrap();
+        let pending_node_id = NodeId::from_str("NodeID-7Xhw2mDxuDS44j42TCB6U5579esbSt3Lg").unwrap();
+        let subnet = AvalancheSubnet {
+            validators: vec![AvalancheSubnetValidator {
+                node_id: current_node_id,
+                ..Default::default()
+            }],
+            pending_validators: vec![AvalancheSubnetValidator {
+                node_id: pending_node_id,
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        assert_eq!(
+            subnet
+                .get_pending_validator(pending_node_id)
+                .unwrap()
+                .node_id,
+            pending_node_id
+        );
+        assert!(subnet.get_validator(pending_node_id).is_err());
+        assert!(subnet.get_pending_validator(current_node_id).is_err());
+    }
+
+    #[test]
+    fn test_avalanche_subnet_filter_validators() {
+        let node_ids: Vec<NodeId> = [
+            "NodeID-7Xhw2mDxuDS44j42TCB6U5579esbSt3Lg",
+            "NodeID-MFrZFVCXPv5iCn6M9K6XduxGTYp891xXZ",
+            "NodeID-NFBbbJ4qCmNaCzeW7sxErhvWqvEQMnYcN",
+        ]
+        .iter()
+        .map(|id| NodeId::from_str(id).unwrap())
+        .collect();
+        let validator = |node_id: NodeId| AvalancheSubnetValidator {
+            node_id,
+            ..Default::default()
+        };
+        let subnet = AvalancheSubnet {
+            validators: vec![validator(node_ids[0]), validator(node_ids[1])],
+            pending_validators: vec![validator(node_ids[2])],
+            ..Default::default()
+        };
+
+        let node_ids_of = |validators: Vec<&AvalancheSubnetValidator>| {
+            validators
+                .iter()
+                .map(|validator| validator.node_id)
+                .collect::<Vec<_>>()
+        };
+
+        // Without node IDs, all the current or pending validators are returned
+        assert_eq!(
+            node_ids_of(subnet.filter_validators(false, &[])),
+            node_ids[..2]
+        );
+        assert_eq!(
+            node_ids_of(subnet.filter_validators(true, &[])),
+            node_ids[2..]
+        );
+
+        // With node IDs, only the matching validators are returned
+        assert_eq!(
+            node_ids_of(subnet.filter_validators(false, &[node_ids[1], node_ids[2]])),
+            [node_ids[1]]
+        );
+        assert_eq!(
+            node_ids_of(subnet.filter_validators(true, &[node_ids[2]])),
+            [node_ids[2]]
+        );
+        assert!(subnet.filter_validators(true, &[node_ids[0]]).is_empty());
+    }
+
     #[async_std::test]
     #[serial_test::serial]
     #[ignore]
@@ -558,4 +1230,627 @@ mod tests {
             .await
             .is_err());
     }
+
+    #[async_std::test]
+    #[serial_test::serial]
+    #[ignore]
+    async fn test_avalanche_subnet_remove_validator_permissioned_pending() {
+        let mut local_network = load_test_network();
+        let wallet = local_network
+            .create_wallet_from_cb58(AVAX_EWOQ_PRIVATE_KEY)
+            .unwrap();
+        let node_id = NodeId::from_str(NETWORK_RUNNER_NODE_ID).unwrap();
+
+        // Create a Subnet and add a validator that only starts validating in an hour
+        let subnet = AvalancheSubnet::create(&wallet, true).await.unwrap();
+        local_network.update_subnets().unwrap();
+        let subnet = local_network.get_subnet(subnet.id).unwrap().clone();
+        subnet
+            .add_validator_permissioned(
+                &wallet,
+                node_id,
+                100,
+                Utc::now() + Duration::hours(1),
+                Utc::now() + Duration::days(1),
+                true,
+            )
+            .await
+            .unwrap();
+
+        local_network.update_subnets().unwrap();
+        local_network.update_subnet_validators(subnet.id).unwrap();
+        local_network
+            .update_subnet_pending_validators(subnet.id)
+            .unwrap();
+        let subnet = local_network.get_subnet(subnet.id).unwrap().clone();
+        assert!(subnet.get_validator(node_id).is_err());
+        assert!(subnet.get_pending_validator(node_id).is_ok());
+
+        // Remove the pending validator before it starts validating
+        subnet
+            .remove_validator_permissioned(&wallet, node_id, true)
+            .await
+            .unwrap();
+
+        local_network
+            .update_subnet_pending_validators(subnet.id)
+            .unwrap();
+        assert!(local_network
+            .get_subnet(subnet.id)
+            .unwrap()
+            .get_pending_validator(node_id)
+            .is_err());
+    }
+
+    #[test]
+    fn test_avalanche_subnet_validator_report() {
+        let now = 1_700_000_000;
+        let validator = AvalancheSubnetValidator {
+            node_id: NodeId::from_str(NETWORK_RUNNER_NODE_ID).unwrap(),
+            end_time: now + 3 * 86_400,
+            connected: true,
+            uptime: Some(99.5),
+            potential_reward: Some(1_000_000_000),
+            ..Default::default()
+        };
+
+        let report = validator.report(now, 7);
+        assert_eq!(report.reward_eligible, Some(true));
+        assert_eq!(report.days_until_expiry, 3.0);
+        assert_eq!(report.expected_reward, Some(1_000_000_000));
+        assert!(report.expiring);
+        assert!(report.is_flagged());
+
+        // Healthy validator
+        let report = validator.report(now, 2);
+        assert!(!report.expiring);
+        assert!(!report.is_flagged());
+
+        // A very large threshold flags every validator instead of overflowing
+        assert!(validator.report(now, u64::MAX).expiring);
+
+        // Disconnected validator below the uptime threshold
+        let validator = AvalancheSubnetValidator {
+            connected: false,
+            uptime: Some(75.0),
+            ..validator
+        };
+        let report = validator.report(now, 2);
+        assert_eq!(report.reward_eligible, Some(false));
+        assert_eq!(report.expected_reward, Some(0));
+        assert!(report.is_flagged());
+
+        // Permissioned Subnet validator below the uptime threshold
+        let validator = AvalancheSubnetValidator {
+            connected: true,
+            uptime: Some(50.0),
+            potential_reward: None,
+            ..validator
+        };
+        let report = validator.report(now, 2);
+        assert_eq!(report.uptime, Some(50.0));
+        assert_eq!(report.reward_eligible, None);
+        assert_eq!(report.expected_reward, None);
+        assert!(!report.is_flagged());
+
+        // Permissioned Subnet validator without uptime
+        let validator = AvalancheSubnetValidator {
+            uptime: None,
+            end_time: now - 10,
+            ..validator
+        };
+        let report = validator.report(now, 2);
+        assert_eq!(report.reward_eligible, None);
+        assert_eq!(report.expected_reward, None);
+        assert_eq!(report.days_until_expiry, 0.0);
+        assert!(report.expiring);
+    }
+
+    #[test]
+    fn test_avalanche_subnet_plan_validator_renewals() {
+        let now = Utc::now().timestamp() as u64;
+        let validator = |node_id: &str, end_time: u64| AvalancheSubnetValidator {
+            node_id: NodeId::from_str(node_id).unwrap(),
+            end_time,
+            weight: Some(100),
+            ..Default::default()
+        };
+        let subnet = AvalancheSubnet {
+            subnet_type: AvalancheSubnetType::Permissioned,
+            validators: vec![
+                validator("NodeID-MFrZFVCXPv5iCn6M9K6XduxGTYp891xXZ", now + 2 * 86_400),
+                validator("NodeID-7Xhw2mDxuDS44j42TCB6U5579esbSt3Lg", now + 86_400),
+                validator("NodeID-NFBbbJ4qCmNaCzeW7sxErhvWqvEQMnYcN", now + 86_400),
+                validator("NodeID-4KXitMCoE9p2BHA6VzXtaTxLoEjNDo2Pt", now + 2 * 86_400),
+                validator("NodeID-FhFWdWodxktJYq884nrJjWD8faLTk9jmp", now + 2 * 86_400),
+                // Out of the renewal window
+                validator(
+                    "NodeID-GWPcbFJZFfZreETSoWjPimr846mXEKCtu",
+                    now + 30 * 86_400,
+                ),
+                // Unknown weight
+                AvalancheSubnetValidator {
+                    weight: None,
+                    ..validator("NodeID-P7oB2McjBGgW2NXXWVYjV8JEDFoW9xDE5", now + 3 * 86_400)
+                },
+            ],
+            ..Default::default()
+        };
+        let primary_validators = vec![
+            validator(
+                "NodeID-MFrZFVCXPv5iCn6M9K6XduxGTYp891xXZ",
+                now + 365 * 86_400,
+            ),
+            validator(
+                "NodeID-7Xhw2mDxuDS44j42TCB6U5579esbSt3Lg",
+                now + 10 * 86_400,
+            ),
+            validator(
+                "NodeID-GWPcbFJZFfZreETSoWjPimr846mXEKCtu",
+                now + 365 * 86_400,
+            ),
+            validator(
+                "NodeID-4KXitMCoE9p2BHA6VzXtaTxLoEjNDo2Pt",
+                now + 365 * 86_400,
+            ),
+            validator(
+                "NodeID-FhFWdWodxktJYq884nrJjWD8faLTk9jmp",
+                now + 365 * 86_400,
+            ),
+        ];
+
+        let min_stake_duration = 86_400;
+
+        let renewals = subnet
+            .plan_validator_renewals(
+                &primary_validators,
+                &[],
+                Duration::days(7),
+                Duration::days(30),
+                min_stake_duration,
+            )
+            .unwrap();
+
+        // Renewals are sorted by end of the current validation
+        assert_eq!(renewals.len(), 6);
+        assert_eq!(renewals[0].current_end_time, now + 86_400);
+        assert_eq!(renewals[1].current_end_time, now + 86_400);
+        assert_eq!(renewals[2].current_end_time, now + 2 * 86_400);
+        assert_eq!(renewals[3].current_end_time, now + 2 * 86_400);
+        assert_eq!(renewals[4].current_end_time, now + 2 * 86_400);
+        assert_eq!(renewals[5].current_end_time, now + 3 * 86_400);
+
+        // The new validation starts when the current one ends
+        let renewal = &renewals[2];
+        assert_eq!(renewal.weight, 100);
+        assert_eq!(
+            renewal.start_time,
+            now + 2 * 86_400 + VALIDATOR_RENEWAL_START_DELAY
+        );
+        assert_eq!(renewal.end_time, renewal.start_time + 30 * 86_400);
+        assert_eq!(renewal.error, None);
+
+        // The new validations of validators expiring together do not end together
+        assert_eq!(renewals[3].start_time, renewal.start_time);
+        assert_eq!(
+            renewals[3].end_time,
+            renewal.end_time - VALIDATOR_RENEWAL_END_TIME_INTERVAL
+        );
+        assert_eq!(
+            renewals[4].end_time,
+            renewal.end_time - 2 * VALIDATOR_RENEWAL_END_TIME_INTERVAL
+        );
+
+        // The new validation is capped by the Primary Network validation
+        let renewal = renewals
+            .iter()
+            .find(|renewal| {
+                renewal.node_id
+                    == NodeId::from_str("NodeID-7Xhw2mDxuDS44j42TCB6U5579esbSt3Lg").unwrap()
+            })
+            .unwrap();
+        assert_eq!(renewal.end_time, now + 10 * 86_400);
+        assert_eq!(renewal.error, None);
+
+        // Nodes that do not validate the Primary Network cannot be renewed
+        let renewal = renewals
+            .iter()
+            .find(|renewal| {
+                renewal.node_id
+                    == NodeId::from_str("NodeID-NFBbbJ4qCmNaCzeW7sxErhvWqvEQMnYcN").unwrap()
+            })
+            .unwrap();
+        assert!(renewal.error.is_some());
+
+        // Validators with an unknown weight cannot be renewed
+        assert!(renewals[5].error.is_some());
+
+        // Out of range windows and durations do not overflow
+        let renewals = subnet
+            .plan_validator_renewals(
+                &primary_validators,
+                &[],
+                Duration::max_value(),
+                Duration::days(30),
+                min_stake_duration,
+            )
+            .unwrap();
+        assert_eq!(renewals.len(), 7);
+        let renewals = subnet
+            .plan_validator_renewals(
+                &primary_validators,
+                &[],
+                Duration::days(7),
+                Duration::max_value(),
+                min_stake_duration,
+            )
+            .unwrap();
+        assert!(renewals
+            .iter()
+            .all(|renewal| renewal.end_time <= now + 365 * 86_400 || renewal.error.is_some()));
+        assert!(subnet
+            .plan_validator_renewals(
+                &primary_validators,
+                &[],
+                Duration::days(7),
+                Duration::seconds(-1),
+                min_stake_duration,
+            )
+            .unwrap()
+            .iter()
+            .all(|renewal| renewal.error.is_some()));
+
+        // New validations are never shortened below the minimum staking duration
+        let min_stake_duration = 30 * 86_400 - VALIDATOR_RENEWAL_END_TIME_INTERVAL;
+        let renewals = subnet
+            .plan_validator_renewals(
+                &primary_validators,
+                &[],
+                Duration::days(7),
+                Duration::days(30),
+                min_stake_duration,
+            )
+            .unwrap();
+        assert_eq!(renewals[2].error, None);
+        assert_eq!(renewals[3].error, None);
+        assert_eq!(
+            renewals[3].end_time - renewals[3].start_time,
+            min_stake_duration
+        );
+        assert!(renewals[4].error.is_some());
+        let renewal = renewals
+            .iter()
+            .find(|renewal| {
+                renewal.node_id
+                    == NodeId::from_str("NodeID-7Xhw2mDxuDS44j42TCB6U5579esbSt3Lg").unwrap()
+            })
+            .unwrap();
+        assert!(renewal.error.is_some());
+
+        // Validators of the Primary Network are not renewed
+        let primary_network = AvalancheSubnet {
+            subnet_type: AvalancheSubnetType::PrimaryNetwork,
+            ..subnet
+        };
+        assert!(primary_network
+            .plan_validator_renewals(
+                &primary_validators,
+                &[],
+                Duration::days(7),
+                Duration::days(30),
+                86_400
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn test_avalanche_subnet_plan_validator_renewals_of_left_validators() {
+        let now = Utc::now().timestamp() as u64;
+        let node_id = |node_id: &str| NodeId::from_str(node_id).unwrap();
+        let validator = |node_id: &str, end_time: u64| AvalancheSubnetValidator {
+            node_id: NodeId::from_str(node_id).unwrap(),
+            end_time,
+            weight: Some(100),
+            ..Default::default()
+        };
+        let planned_renewal = |node_id: &str, current_end_time: u64| AvalancheValidatorRenewal {
+            node_id: NodeId::from_str(node_id).unwrap(),
+            weight: 200,
+            current_end_time,
+            start_time: current_end_time + VALIDATOR_RENEWAL_START_DELAY,
+            end_time: current_end_time + VALIDATOR_RENEWAL_START_DELAY + 30 * 86_400,
+            ..Default::default()
+        };
+        let subnet = AvalancheSubnet {
+            subnet_type: AvalancheSubnetType::Permissioned,
+            validators: vec![validator(
+                "NodeID-MFrZFVCXPv5iCn6M9K6XduxGTYp891xXZ",
+                now + 2 * 86_400,
+            )],
+            pending_validators: vec![validator(
+                "NodeID-GWPcbFJZFfZreETSoWjPimr846mXEKCtu",
+                now + 30 * 86_400,
+            )],
+            ..Default::default()
+        };
+        let primary_validators: Vec<AvalancheSubnetValidator> = [
+            "NodeID-MFrZFVCXPv5iCn6M9K6XduxGTYp891xXZ",
+            "NodeID-4KXitMCoE9p2BHA6VzXtaTxLoEjNDo2Pt",
+            "NodeID-FhFWdWodxktJYq884nrJjWD8faLTk9jmp",
+            "NodeID-GWPcbFJZFfZreETSoWjPimr846mXEKCtu",
+        ]
+        .iter()
+        .map(|node_id| validator(node_id, now + 365 * 86_400))
+        .collect();
+        let planned_renewals = vec![
+            // Still a current validator
+            planned_renewal("NodeID-MFrZFVCXPv5iCn6M9K6XduxGTYp891xXZ", now + 2 * 86_400),
+            // Has left the Subnet without being renewed
+            planned_renewal("NodeID-4KXitMCoE9p2BHA6VzXtaTxLoEjNDo2Pt", now - 3600),
+            // Already renewed
+            AvalancheValidatorRenewal {
+                tx_id: Some(Id::empty()),
+                ..planned_renewal("NodeID-FhFWdWodxktJYq884nrJjWD8faLTk9jmp", now - 3600)
+            },
+            // Already a pending validator again
+            planned_renewal("NodeID-GWPcbFJZFfZreETSoWjPimr846mXEKCtu", now - 3600),
+        ];
+
+        let renewals = subnet
+            .plan_validator_renewals(
+                &primary_validators,
+                &planned_renewals,
+                Duration::days(7),
+                Duration::days(30),
+                86_400,
+            )
+            .unwrap();
+        assert_eq!(renewals.len(), 2);
+
+        // The validator that has left the Subnet is renewed as soon as possible with its planned weight
+        let renewal = &renewals[0];
+        assert_eq!(
+            renewal.node_id,
+            node_id("NodeID-4KXitMCoE9p2BHA6VzXtaTxLoEjNDo2Pt")
+        );
+        assert_eq!(renewal.weight, 200);
+        assert_eq!(renewal.current_end_time, now - 3600);
+        assert!(renewal.start_time >= now + VALIDATOR_RENEWAL_START_DELAY);
+        assert_eq!(renewal.end_time, renewal.start_time + 30 * 86_400);
+        assert_eq!(renewal.error, None);
+
+        // The current validator is planned from the current validators
+        let renewal = &renewals[1];
+        assert_eq!(
+            renewal.node_id,
+            node_id("NodeID-MFrZFVCXPv5iCn6M9K6XduxGTYp891xXZ")
+        );
+        assert_eq!(renewal.weight, 100);
+        assert_eq!(renewal.error, None);
+    }
+
+    // Serve a Warp message signature over JSON-RPC on a local port and return the node HTTP endpoint
+    fn serve_warp_signature(signature: [u8; 96]) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let http_endpoint = format!("http://{}", listener.local_addr().unwrap());
+
+        thread::spawn(move || {
+            for mut stream in listener.incoming().flatten() {
+                // Read the request headers and body before answering
+                let mut request = vec![];
+                let mut buffer = [0; 1024];
+                while let Ok(read) = stream.read(&mut buffer) {
+                    request.extend_from_slice(&buffer[..read]);
+                    let request_str = String::from_utf8_lossy(&request);
+                    if let Some((headers, body)) = request_str.split_once("\r\n\r\n") {
+                        let content_length = headers
+                            .lines()
+                            .find_map(|line| {
+                                line.to_lowercase()
+                                    .strip_prefix("content-length:")
+                                    .map(|length| length.trim().parse::<usize>().unwrap())
+                            })
+                            .unwrap_or_default();
+                        if body.len() >= content_length {
+                            break;
+                        }
+                    }
+                    if read == 0 {
+                        break;
+                    }
+                }
+
+                let response = format!(
+                    r#"{{"jsonrpc":"2.0","id":1,"result":"0x{}"}}"#,
+                    hex::encode(signature)
+                );
+                let _ = write!(
+                    stream,
+                    "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{response}",
+                    response.len()
+                );
+            }
+        });
+
+        http_endpoint
+    }
+
+    #[test]
+    fn test_avalanche_subnet_get_warp_message_node_signatures() {
+        let blockchain_id = Id::from_str(NETWORK_RUNNER_CCHAIN_ID).unwrap();
+        let unsigned_message = WarpUnsignedMessage {
+            source_chain_id: blockchain_id,
+            bytes: b"Hello world!".to_vec(),
+            ..Default::default()
+        };
+        let warp_message = WarpMessage {
+            unsigned_message: unsigned_message.clone(),
+            ..Default::default()
+        };
+
+        // Node 1 signs the message, node 2 signs it with another key, node 3 has no BLS key
+        let bls_keys = (0..3)
+            .map(|_| BlsPrivateKey::generate().unwrap())
+            .collect::<Vec<_>>();
+        let validators = (0..3)
+            .map(|i| AvalancheSubnetValidator {
+                node_id: NodeId::from_slice(&[i as u8 + 1; 20]),
+                weight: Some(100),
+                signer: match i {
+                    2 => None,
+                    _ => Some(bls_keys[i].to_proof_of_possession()),
+                },
+                ..Default::default()
+            })
+            .collect::<Vec<_>>();
+        let node_endpoints = vec![
+            AvalancheNodeEndpoint {
+                node_id: validators[0].node_id,
+                http_endpoint: serve_warp_signature(
+                    bls_keys[0]
+                        .sign(&unsigned_message.bytes)
+                        .to_compressed_bytes(),
+                ),
+            },
+            AvalancheNodeEndpoint {
+                node_id: validators[1].node_id,
+                http_endpoint: serve_warp_signature(
+                    bls_keys[2]
+                        .sign(&unsigned_message.bytes)
+                        .to_compressed_bytes(),
+                ),
+            },
+        ];
+
+        let subnet = AvalancheSubnet {
+            blockchains: vec![AvalancheBlockchain {
+                id: blockchain_id,
+                ..Default::default()
+            }],
+            validators,
+            ..Default::default()
+        };
+        let policy = WarpSignatureRequestPolicy {
+            retries: 0,
+            max_concurrent_requests: 1,
+            ..Default::default()
+        };
+
+        // The invalid signature does not count towards the weight threshold
+        let collection = subnet
+            .get_warp_message_node_signatures(
+                &warp_message,
+                Some(60),
+                &policy,
+                &StaticEndpointResolver::new(&node_endpoints, None),
+            )
+            .unwrap();
+
+        assert_eq!(collection.signatures.len(), 1);
+        assert_eq!(
+            collection.signatures[0].node_id,
+            subnet.validators[0].node_id
+        );
+        assert_eq!(collection.collected_weight, 100);
+        assert_eq!(collection.total_weight, 300);
+        assert_eq!(collection.failures.len(), 2);
+        assert!(collection.failures.iter().any(|failure| failure.node_id
+            == subnet.validators[1].node_id
+            && failure.attempts == 1));
+        assert!(collection.failures.iter().any(|failure| failure.node_id
+            == subnet.validators[2].node_id
+            && failure.attempts == 0));
+        assert!(!collection.is_weight_threshold_reached(60));
+    }
+
+    #[test]
+    fn test_avalanche_subnet_get_warp_message_node_signatures_subnet_validators() {
+        let blockchain_id = Id::from_str(NETWORK_RUNNER_CCHAIN_ID).unwrap();
+        let unsigned_message = WarpUnsignedMessage {
+            source_chain_id: blockchain_id,
+            bytes: b"Hello world!".to_vec(),
+            ..Default::default()
+        };
+        let warp_message = WarpMessage {
+            unsigned_message: unsigned_message.clone(),
+            ..Default::default()
+        };
+
+        // Validators of a permissioned Subnet do not expose their BLS public key
+        let bls_keys = (0..2)
+            .map(|_| BlsPrivateKey::generate().unwrap())
+            .collect::<Vec<_>>();
+        let validators = (0..2)
+            .map(|i| AvalancheSubnetValidator {
+                node_id: NodeId::from_slice(&[i as u8 + 1; 20]),
+                weight: Some(100),
+                ..Default::default()
+            })
+            .collect::<Vec<_>>();
+        let primary_validators = (0..2)
+            .map(|i| AvalancheSubnetValidator {
+                signer: Some(bls_keys[i].to_proof_of_possession()),
+                ..validators[i].clone()
+            })
+            .collect::<Vec<_>>();
+        let node_endpoints = (0..2)
+            .map(|i| AvalancheNodeEndpoint {
+                node_id: validators[i].node_id,
+                http_endpoint: serve_warp_signature(
+                    bls_keys[i]
+                        .sign(&unsigned_message.bytes)
+                        .to_compressed_bytes(),
+                ),
+            })
+            .collect::<Vec<_>>();
+
+        let mut subnet = AvalancheSubnet {
+            subnet_type: AvalancheSubnetType::Permissioned,
+            blockchains: vec![AvalancheBlockchain {
+                id: blockchain_id,
+                ..Default::default()
+            }],
+            validators,
+            ..Default::default()
+        };
+        subnet.set_validators_signers(&primary_validators);
+        assert!(subnet
+            .validators
+            .iter()
+            .all(|validator| validator.signer.is_some()));
+
+        let collection = subnet
+            .get_warp_message_node_signatures(
+                &warp_message,
+                None,
+                &WarpSignatureRequestPolicy {
+                    retries: 0,
+                    ..Default::default()
+                },
+                &StaticEndpointResolver::new(&node_endpoints, None),
+            )
+            .unwrap();
+
+        assert_eq!(collection.signatures.len(), 2);
+        assert!(collection.failures.is_empty());
+        assert_eq!(collection.collected_weight, 200);
+        assert!(collection.is_weight_threshold_reached(100));
+    }
+
+    #[test]
+    fn test_request_warp_signature_retries() {
+        let policy = WarpSignatureRequestPolicy {
+            timeout: std::time::Duration::from_secs(1),
+            retries: 2,
+            retry_delay: std::time::Duration::from_millis(10),
+            ..Default::default()
+        };
+
+        // Nothing listens on this port so every attempt fails
+        let (attempts, signature) =
+            request_warp_signature("http://127.0.0.1:1/ext/bc/C/rpc", Id::empty(), &policy);
+
+        assert_eq!(attempts, 3);
+        assert!(signature.is_err());
+    }
 }
@@ -1,7 +1,916 @@
 // SPDX-License-Identifier: BSD-3-Clause
 // Copyright (c) 2023, E36 Knots
 
+pub mod c;
 pub mod p;
+pub mod partial;
 pub mod x;
 
 // Module that contains code to issue transactions
+
+use crate::{
+    avalanche::{
+        jsonrpc::{avm, platformvm},
+        wallets::{AvalancheSignerKey, AvalancheWallet},
+        AvalancheNetwork,
+    },
+    errors::*,
+};
+use async_std::task;
+use avalanche_types::{
+    codec::{P_TYPES, X_TYPES},
+    formatting::{
+        decode_cb58_with_checksum, decode_hex_with_checksum, encode_cb58_with_checksum_string,
+        encode_hex_with_checksum,
+    },
+    ids::Id,
+    key::secp256k1::{
+        txs::{transfer, OutputOwners},
+        SignOnly,
+    },
+    txs::{transferable, utxo::Utxo},
+    wallet::Wallet,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use std::{
+    fmt::Display,
+    str::FromStr,
+    time::{Duration, Instant, SystemTime},
+};
+
+/// Interval between two queries of the status of a transaction
+pub const TX_ACCEPTANCE_POLL_INTERVAL: Duration = Duration::from_secs(1);
+/// Maximum duration to wait for a transaction to be accepted
+pub const TX_ACCEPTANCE_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// Chains of the Primary Network between which AVAX can be moved with atomic transactions
+#[derive(Debug, Display, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AvalanchePrimaryNetworkChain {
+    X,
+    P,
+    C,
+}
+
+impl AvalanchePrimaryNetworkChain {
+    /// Get the name of the chain (e.g. `X-Chain`)
+    pub fn name(&self) -> String {
+        format!("{self}-Chain")
+    }
+
+    /// Get the ID of the chain on a network
+    pub fn blockchain_id(&self, network: &AvalancheNetwork) -> Result<Id, AshError> {
+        match self {
+            AvalanchePrimaryNetworkChain::X => Ok(network.get_xchain()?.id),
+            AvalanchePrimaryNetworkChain::P => Ok(network.get_pchain()?.id),
+            AvalanchePrimaryNetworkChain::C => Ok(network.get_cchain()?.id),
+        }
+    }
+}
+
+/// Encodings of the transaction bytes, as used by the Avalanche APIs (both include a checksum)
+#[derive(Debug, Display, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AvalancheTxEncoding {
+    Hex,
+    Cb58,
+}
+
+impl AvalancheTxEncoding {
+    /// Encode transaction bytes (e.g. `0x...` for hex)
+    pub fn encode(&self, tx_bytes: &[u8]) -> String {
+        match self {
+            AvalancheTxEncoding::Hex => format!("0x{}", encode_hex_with_checksum(tx_bytes)),
+            AvalancheTxEncoding::Cb58 => encode_cb58_with_checksum_string(tx_bytes),
+        }
+    }
+
+    /// Decode transaction bytes, the encoding is detected from the `0x` prefix of hex strings
+    pub fn decode(encoded_tx: &str) -> Result<Vec<u8>, AshError> {
+        let encoded_tx = encoded_tx.trim();
+        let tx_bytes = match encoded_tx.strip_prefix("0x") {
+            // The 4-byte checksum is required
+            Some(hex_tx) if hex_tx.len() < 8 => {
+                return Err(AvalancheWalletError::TxDecodingFailure(
+                    "hex-encoded transaction is too short".to_string(),
+                )
+                .into())
+            }
+            Some(hex_tx) => decode_hex_with_checksum(hex_tx.as_bytes()),
+            None => decode_cb58_with_checksum(encoded_tx),
+        }
+        .map_err(|e| AvalancheWalletError::TxDecodingFailure(e.to_string()))?;
+
+        Ok(tx_bytes)
+    }
+}
+
+/// Transaction of a chain of the Primary Network, as returned by the `getTx` API methods
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AvalancheTx {
+    pub id: Id,
+    pub chain: AvalanchePrimaryNetworkChain,
+    /// Name of the transaction type in the codec (e.g. `platformvm.CreateSubnetTx`)
+    pub tx_type: String,
+    #[serde(rename = "networkID")]
+    pub network_id: u32,
+    #[serde(rename = "blockchainID")]
+    pub blockchain_id: Id,
+    pub inputs: Vec<AvalancheTxInput>,
+    pub outputs: Vec<AvalancheTxOutput>,
+    pub memo: String,
+    /// Fields specific to the transaction type (e.g. the owner of a Subnet)
+    pub details: Value,
+}
+
+/// Input of a transaction, consuming a UTXO
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AvalancheTxInput {
+    #[serde(rename = "txID")]
+    pub tx_id: Id,
+    pub output_index: u32,
+    #[serde(rename = "assetID")]
+    pub asset_id: Id,
+    pub amount: u64,
+    /// Locktime of the stakeable UTXO being consumed (0 if not locked)
+    pub stakeable_locktime: u64,
+}
+
+/// Output of a transaction, creating a UTXO
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AvalancheTxOutput {
+    #[serde(rename = "assetID")]
+    pub asset_id: Id,
+    pub amount: u64,
+    pub addresses: Vec<String>,
+    pub threshold: u32,
+    pub locktime: u64,
+    /// Locktime until which the output can only be staked (0 if not locked)
+    pub stakeable_locktime: u64,
+}
+
+impl AvalancheTx {
+    /// Decode a transaction from the results of the `getTx` API methods
+    /// The type is read from the hex-encoded bytes, the content from the JSON-encoded transaction
+    pub fn from_api(
+        id: Id,
+        chain: AvalanchePrimaryNetworkChain,
+        hex_tx: &str,
+        json_tx: &Value,
+    ) -> Result<Self, AshError> {
+        let tx_bytes = AvalancheTxEncoding::decode(hex_tx)?;
+        let tx_type = tx_type_name(chain, &tx_bytes)?;
+
+        let unsigned_tx = json_tx.get("unsignedTx").ok_or_else(|| {
+            AvalancheWalletError::TxDecodingFailure("missing 'unsignedTx' field".to_string())
+        })?;
+        let mut details = unsigned_tx.clone();
+        let Some(details_map) = details.as_object_mut() else {
+            return Err(AvalancheWalletError::TxDecodingFailure(
+                "'unsignedTx' is not an object".to_string(),
+            )
+            .into());
+        };
+        for field in ["networkID", "blockchainID", "inputs", "outputs", "memo"] {
+            details_map.remove(field);
+        }
+
+        Ok(AvalancheTx {
+            id,
+            chain,
+            tx_type,
+            network_id: json_u64(&unsigned_tx["networkID"]).unwrap_or_default() as u32,
+            blockchain_id: json_id(&unsigned_tx["blockchainID"])?,
+            inputs: json_array(&unsigned_tx["inputs"])
+                .iter()
+                .map(AvalancheTxInput::from_json)
+                .collect::<Result<_, _>>()?,
+            outputs: json_array(&unsigned_tx["outputs"])
+                .iter()
+                .map(AvalancheTxOutput::from_json)
+                .collect::<Result<_, _>>()?,
+            memo: unsigned_tx["memo"].as_str().unwrap_or_default().to_string(),
+            details,
+        })
+    }
+}
+
+impl AvalancheTxInput {
+    // Parse a JSON-encoded input, unwrapping stakeable lock inputs
+    fn from_json(input: &Value) -> Result<Self, AshError> {
+        let (inner_input, stakeable_locktime) = unwrap_stakeable(&input["input"], "input");
+
+        Ok(AvalancheTxInput {
+            tx_id: json_id(&input["txID"])?,
+            output_index: json_u64(&input["outputIndex"]).unwrap_or_default() as u32,
+            asset_id: json_id(&input["assetID"])?,
+            amount: json_u64(&inner_input["amount"]).unwrap_or_default(),
+            stakeable_locktime,
+        })
+    }
+}
+
+impl AvalancheTxOutput {
+    // Parse a JSON-encoded output, unwrapping stakeable lock outputs
+    fn from_json(output: &Value) -> Result<Self, AshError> {
+        let (inner_output, stakeable_locktime) = unwrap_stakeable(&output["output"], "output");
+
+        Ok(AvalancheTxOutput {
+            asset_id: json_id(&output["assetID"])?,
+            amount: json_u64(&inner_output["amount"]).unwrap_or_default(),
+            addresses: json_array(&inner_output["addresses"])
+                .iter()
+                .filter_map(|address| address.as_str().map(String::from))
+                .collect(),
+            threshold: json_u64(&inner_output["threshold"]).unwrap_or_default() as u32,
+            locktime: json_u64(&inner_output["locktime"]).unwrap_or_default(),
+            stakeable_locktime,
+        })
+    }
+}
+
+// Get the name of the type of transaction bytes from the codec type ID (after the 2-byte codec version)
+fn tx_type_name(chain: AvalanchePrimaryNetworkChain, tx_bytes: &[u8]) -> Result<String, AshError> {
+    let type_id_bytes: [u8; 4] = tx_bytes
+        .get(2..6)
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or_else(|| {
+            AvalancheWalletError::TxDecodingFailure("transaction is too short".to_string())
+        })?;
+    let type_id = u32::from_be_bytes(type_id_bytes) as usize;
+
+    let types = match chain {
+        AvalanchePrimaryNetworkChain::X => &*X_TYPES,
+        AvalanchePrimaryNetworkChain::P => &*P_TYPES,
+        AvalanchePrimaryNetworkChain::C => {
+            return Err(AvalancheWalletError::TxDecodingFailure(
+                "C-Chain transactions are not supported".to_string(),
+            )
+            .into())
+        }
+    };
+
+    Ok(types
+        .iter()
+        .find(|(name, id)| **id == type_id && name.ends_with("Tx"))
+        .map(|(name, _)| name.clone())
+        .unwrap_or_else(|| format!("unknown ({type_id})")))
+}
+
+// Unwrap the content of a stakeable lock input/output, returning its locktime
+fn unwrap_stakeable<'a>(value: &'a Value, field: &str) -> (&'a Value, u64) {
+    match value.get(field) {
+        Some(inner_value) => (
+            inner_value,
+            json_u64(&value["locktime"]).unwrap_or_default(),
+        ),
+        None => (value, 0),
+    }
+}
+
+// Numbers are encoded either as JSON numbers or as strings depending on the API
+fn json_u64(value: &Value) -> Option<u64> {
+    value
+        .as_u64()
+        .or_else(|| value.as_str().and_then(|value| value.parse().ok()))
+}
+
+fn json_id(value: &Value) -> Result<Id, AshError> {
+    let id_str = value.as_str().unwrap_or_default();
+    Id::from_str(id_str).map_err(|e| {
+        AvalancheWalletError::TxDecodingFailure(format!("invalid ID '{id_str}': {e}")).into()
+    })
+}
+
+fn json_array(value: &Value) -> Vec<Value> {
+    value.as_array().cloned().unwrap_or_default()
+}
+
+/// Get the status of a transaction on a chain of the Primary Network (e.g. `Committed` or `Accepted`)
+/// Only the X-Chain and the P-Chain are supported
+pub fn get_tx_status(
+    network: &AvalancheNetwork,
+    chain: AvalanchePrimaryNetworkChain,
+    tx_id: Id,
+) -> Result<String, AshError> {
+    match chain {
+        AvalanchePrimaryNetworkChain::X => {
+            Ok(avm::get_tx_status(&network.get_xchain()?.rpc_url, tx_id)?.to_string())
+        }
+        AvalanchePrimaryNetworkChain::P => {
+            Ok(platformvm::get_tx_status(&network.get_pchain()?.rpc_url, tx_id)?.to_string())
+        }
+        AvalanchePrimaryNetworkChain::C => Err(unsupported_chain_failure(chain).into()),
+    }
+}
+
+/// Get a transaction on a chain of the Primary Network
+/// Only the X-Chain and the P-Chain are supported
+pub fn get_tx(
+    network: &AvalancheNetwork,
+    chain: AvalanchePrimaryNetworkChain,
+    tx_id: Id,
+) -> Result<AvalancheTx, AshError> {
+    match chain {
+        AvalanchePrimaryNetworkChain::X => Ok(avm::get_tx(&network.get_xchain()?.rpc_url, tx_id)?),
+        AvalanchePrimaryNetworkChain::P => {
+            Ok(platformvm::get_tx(&network.get_pchain()?.rpc_url, tx_id)?)
+        }
+        AvalanchePrimaryNetworkChain::C => Err(unsupported_chain_failure(chain).into()),
+    }
+}
+
+/// Wait for transactions of a chain of the Primary Network to be accepted
+/// Fail as soon as one of them is rejected or is not accepted before the timeout
+pub async fn wait_txs_acceptance(
+    network: &AvalancheNetwork,
+    chain: AvalanchePrimaryNetworkChain,
+    tx_ids: &[Id],
+) -> Result<(), AshError> {
+    match chain {
+        AvalanchePrimaryNetworkChain::X => {
+            let rpc_url = &network.get_xchain()?.rpc_url;
+            for tx_id in tx_ids.iter() {
+                wait_tx_acceptance(&chain.name(), *tx_id, || {
+                    avm::get_tx_status(rpc_url, *tx_id)
+                })
+                .await?;
+            }
+        }
+        AvalanchePrimaryNetworkChain::P => {
+            let rpc_url = &network.get_pchain()?.rpc_url;
+            for tx_id in tx_ids.iter() {
+                wait_tx_acceptance(&chain.name(), *tx_id, || {
+                    platformvm::get_tx_status(rpc_url, *tx_id)
+                })
+                .await?;
+            }
+        }
+        AvalanchePrimaryNetworkChain::C => return Err(unsupported_chain_failure(chain).into()),
+    }
+
+    Ok(())
+}
+
+// Transactions can only be queried on the X-Chain and the P-Chain
+fn unsupported_chain_failure(chain: AvalanchePrimaryNetworkChain) -> RpcError {
+    RpcError::GetFailure {
+        data_type: "transactions".to_string(),
+        target_type: "chain".to_string(),
+        target_value: chain.name(),
+        msg: "only the X-Chain and the P-Chain are supported".to_string(),
+    }
+}
+
+/// Issue signed transaction bytes on a chain of the Primary Network
+/// Only the X-Chain and the P-Chain are supported
+/// Return the ID of the transaction
+pub async fn issue_signed_tx(
+    network: &AvalancheNetwork,
+    chain: AvalanchePrimaryNetworkChain,
+    tx_type: &str,
+    tx_bytes: &[u8],
+    check_acceptance: bool,
+) -> Result<Id, AshError> {
+    let issue_tx_failure = |msg: String| AvalancheWalletError::IssueTx {
+        blockchain_name: chain.name(),
+        tx_type: tx_type.to_string(),
+        msg,
+    };
+
+    match chain {
+        AvalanchePrimaryNetworkChain::X => {
+            let rpc_url = &network.get_xchain()?.rpc_url;
+            let tx_id =
+                avm::issue_tx(rpc_url, tx_bytes).map_err(|e| issue_tx_failure(e.to_string()))?;
+
+            if check_acceptance {
+                wait_tx_acceptance(&chain.name(), tx_id, || avm::get_tx_status(rpc_url, tx_id))
+                    .await?;
+            }
+
+            Ok(tx_id)
+        }
+        AvalanchePrimaryNetworkChain::P => {
+            let rpc_url = &network.get_pchain()?.rpc_url;
+            let tx_id = platformvm::issue_tx(rpc_url, tx_bytes)
+                .map_err(|e| issue_tx_failure(e.to_string()))?;
+
+            if check_acceptance {
+                wait_tx_acceptance(&chain.name(), tx_id, || {
+                    platformvm::get_tx_status(rpc_url, tx_id)
+                })
+                .await?;
+            }
+
+            Ok(tx_id)
+        }
+        AvalanchePrimaryNetworkChain::C => Err(issue_tx_failure(
+            "issuing signed transactions is not supported on the C-Chain".to_string(),
+        )
+        .into()),
+    }
+}
+
+/// Move AVAX from a chain of the Primary Network to another one
+/// The export transaction is issued on the source chain and has to be accepted before
+/// the import transaction is issued on the destination chain
+/// Only the UTXOs created by the export transaction are imported
+/// The fees of both transactions are paid on top of the amount (in nAVAX)
+/// Return the IDs of the export and import transactions
+pub async fn move_avax(
+    network: &AvalancheNetwork,
+    wallet: &AvalancheWallet,
+    from: AvalanchePrimaryNetworkChain,
+    to: AvalanchePrimaryNetworkChain,
+    amount: u64,
+    check_acceptance: bool,
+) -> Result<(Id, Id), AshError> {
+    if from == to {
+        return Err(AvalancheWalletError::IssueTx {
+            blockchain_name: from.name(),
+            tx_type: "export".to_string(),
+            msg: "source and destination chains must be different".to_string(),
+        }
+        .into());
+    }
+
+    let source_chain_id = from.blockchain_id(network)?;
+    let destination_chain_id = to.blockchain_id(network)?;
+
+    let export_tx_id = match from {
+        AvalanchePrimaryNetworkChain::X => {
+            x::export_avax(wallet, destination_chain_id, amount, true).await?
+        }
+        AvalanchePrimaryNetworkChain::P => {
+            p::export_avax(wallet, destination_chain_id, amount, true).await?
+        }
+        AvalanchePrimaryNetworkChain::C => {
+            c::export_avax(
+                wallet,
+                network.get_cchain()?,
+                destination_chain_id,
+                amount,
+                true,
+            )
+            .await?
+        }
+    };
+
+    let import_tx_id = match to {
+        AvalanchePrimaryNetworkChain::X => {
+            x::import_avax(
+                wallet,
+                source_chain_id,
+                Some(export_tx_id),
+                check_acceptance,
+            )
+            .await?
+        }
+        AvalanchePrimaryNetworkChain::P => {
+            p::import_avax(
+                wallet,
+                source_chain_id,
+                Some(export_tx_id),
+                check_acceptance,
+            )
+            .await?
+        }
+        AvalanchePrimaryNetworkChain::C => {
+            c::import_avax(
+                wallet,
+                network.get_cchain()?,
+                source_chain_id,
+                Some(export_tx_id),
+                check_acceptance,
+            )
+            .await?
+        }
+    };
+
+    Ok((export_tx_id, import_tx_id))
+}
+
+// Keep only the atomic UTXOs created by an export transaction
+// If no export transaction ID is provided, all the UTXOs are kept
+pub(crate) fn filter_export_utxos(utxos: Vec<Utxo>, export_tx_id: Option<Id>) -> Vec<Utxo> {
+    match export_tx_id {
+        Some(export_tx_id) => utxos
+            .into_iter()
+            .filter(|utxo| utxo.utxo_id.tx_id == export_tx_id)
+            .collect(),
+        None => utxos,
+    }
+}
+
+// Spend the AVAX UTXOs that can be spent by the wallet key, until the amount is reached
+// If no amount is provided, all the UTXOs are spent
+// Return the inputs sorted as expected by the codec, the keys that must sign each input and the spent amount
+pub(crate) fn spend_avax_utxos(
+    wallet: &Wallet<AvalancheSignerKey>,
+    utxos: &[Utxo],
+    amount: Option<u64>,
+) -> (Vec<transferable::Input>, Vec<Vec<AvalancheSignerKey>>, u64) {
+    let now = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+
+    let mut inputs_with_signers = Vec::new();
+    let mut spent_amount = 0;
+
+    for utxo in utxos.iter() {
+        if amount.is_some_and(|amount| spent_amount >= amount) {
+            break;
+        }
+        if utxo.asset_id != wallet.avax_asset_id {
+            continue;
+        }
+        // Locked stake outputs are ignored
+        let Some(output) = &utxo.transfer_output else {
+            continue;
+        };
+        let Some((transfer_input, signers)) = wallet.keychain.spend(output, now) else {
+            continue;
+        };
+
+        spent_amount += transfer_input.amount;
+        inputs_with_signers.push((
+            transferable::Input {
+                utxo_id: utxo.utxo_id.clone(),
+                asset_id: utxo.asset_id,
+                transfer_input: Some(transfer_input),
+                ..Default::default()
+            },
+            signers,
+        ));
+    }
+
+    inputs_with_signers.sort_by(|(a, _), (b, _)| a.cmp(b));
+    let (inputs, signers) = inputs_with_signers.into_iter().unzip();
+
+    (inputs, signers, spent_amount)
+}
+
+// Create an AVAX output owned by the wallet key
+pub(crate) fn wallet_avax_output(
+    wallet: &Wallet<AvalancheSignerKey>,
+    amount: u64,
+) -> transferable::Output {
+    transferable::Output {
+        asset_id: wallet.avax_asset_id,
+        transfer_output: Some(transfer::Output {
+            amount,
+            output_owners: OutputOwners {
+                locktime: 0,
+                threshold: 1,
+                addresses: vec![wallet.short_address.clone()],
+            },
+        }),
+        ..Default::default()
+    }
+}
+
+// Type IDs of the secp256k1fx types, which are the same in the X-Chain and P-Chain codecs
+pub(crate) const SECP256K1FX_TRANSFER_OUTPUT_TYPE_ID: u32 = 7;
+pub(crate) const SECP256K1FX_CREDENTIAL_TYPE_ID: u32 = 9;
+
+// Pack the owners of a secp256k1fx output
+pub(crate) fn pack_output_owners(bytes: &mut Vec<u8>, owners: &OutputOwners) {
+    bytes.extend_from_slice(&owners.locktime.to_be_bytes());
+    bytes.extend_from_slice(&owners.threshold.to_be_bytes());
+    bytes.extend_from_slice(&(owners.addresses.len() as u32).to_be_bytes());
+    for address in owners.addresses.iter() {
+        bytes.extend_from_slice(address.as_ref());
+    }
+}
+
+// Pack a secp256k1fx transfer output, prefixed with its type ID
+pub(crate) fn pack_transfer_output(bytes: &mut Vec<u8>, output: &transfer::Output) {
+    bytes.extend_from_slice(&SECP256K1FX_TRANSFER_OUTPUT_TYPE_ID.to_be_bytes());
+    bytes.extend_from_slice(&output.amount.to_be_bytes());
+    pack_output_owners(bytes, &output.output_owners);
+}
+
+// Sign a transaction packed by hand (for the types not supported by avalanche-types)
+// with one credential per group of signers, each prefixed with the type ID of its Fx
+// Return the packed signed transaction
+pub(crate) async fn sign_packed_tx<T: SignOnly>(
+    unsigned_tx_bytes: Vec<u8>,
+    credentials: &[(u32, Vec<T>)],
+    blockchain_name: &str,
+    tx_type: &str,
+) -> Result<Vec<u8>, AshError> {
+    let tx_hash = Sha256::digest(&unsigned_tx_bytes);
+
+    let mut bytes = unsigned_tx_bytes;
+    bytes.extend_from_slice(&(credentials.len() as u32).to_be_bytes());
+    for (type_id, keys) in credentials.iter() {
+        bytes.extend_from_slice(&type_id.to_be_bytes());
+        bytes.extend_from_slice(&(keys.len() as u32).to_be_bytes());
+        for key in keys.iter() {
+            let signature =
+                key.sign_digest(&tx_hash)
+                    .await
+                    .map_err(|e| AvalancheWalletError::IssueTx {
+                        blockchain_name: blockchain_name.to_string(),
+                        tx_type: tx_type.to_string(),
+                        msg: format!("failed to sign transaction: {e}"),
+                    })?;
+            bytes.extend_from_slice(&signature);
+        }
+    }
+
+    Ok(bytes)
+}
+
+// Poll the status of a transaction until it is accepted
+// Fail if the transaction is rejected or if it is not accepted before the timeout
+pub(crate) async fn wait_tx_acceptance<S, F>(
+    blockchain_name: &str,
+    tx_id: Id,
+    get_tx_status: F,
+) -> Result<(), AshError>
+where
+    S: Display,
+    F: Fn() -> Result<S, RpcError>,
+{
+    let start = Instant::now();
+
+    loop {
+        let status = get_tx_status()?.to_string();
+        match status.as_str() {
+            "Accepted" | "Committed" => return Ok(()),
+            "Rejected" | "Aborted" | "Dropped" => {
+                return Err(AvalancheWalletError::TxAcceptanceFailure {
+                    blockchain_name: blockchain_name.to_string(),
+                    tx_id: tx_id.to_string(),
+                    msg: format!("transaction status is '{status}'"),
+                }
+                .into())
+            }
+            _ => {}
+        }
+
+        if start.elapsed() > TX_ACCEPTANCE_TIMEOUT {
+            return Err(AvalancheWalletError::TxAcceptanceFailure {
+                blockchain_name: blockchain_name.to_string(),
+                tx_id: tx_id.to_string(),
+                msg: format!(
+                    "transaction status is still '{status}' after {}s",
+                    TX_ACCEPTANCE_TIMEOUT.as_secs()
+                ),
+            }
+            .into());
+        }
+
+        task::sleep(TX_ACCEPTANCE_POLL_INTERVAL).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const AVAX_EWOQ_PRIVATE_KEY: &str =
+        "PrivateKey-ewoqjP7PxY4yr3iLTpLisriqt94hdyDFNgchSxGGztUrTXtNN";
+
+    // Load the test network using avalanche-network-runner
+    fn load_test_network() -> AvalancheNetwork {
+        AvalancheNetwork::load("local", Some("tests/conf/avalanche-network-runner.yml")).unwrap()
+    }
+
+    #[test]
+    fn test_filter_export_utxos() {
+        let export_tx_id = Id::from_slice(&[1; 32]);
+        let utxos = [export_tx_id, Id::from_slice(&[2; 32]), export_tx_id]
+            .iter()
+            .enumerate()
+            .map(|(index, tx_id)| Utxo {
+                utxo_id: avalanche_types::txs::utxo::Id {
+                    tx_id: *tx_id,
+                    output_index: index as u32,
+                    ..Default::default()
+                },
+                ..Default::default()
+            })
+            .collect::<Vec<_>>();
+
+        let export_utxos = filter_export_utxos(utxos.clone(), Some(export_tx_id));
+        assert_eq!(export_utxos.len(), 2);
+        assert!(export_utxos
+            .iter()
+            .all(|utxo| utxo.utxo_id.tx_id == export_tx_id));
+
+        // Without export transaction ID, all the UTXOs are swept
+        assert_eq!(filter_export_utxos(utxos, None).len(), 3);
+    }
+
+    #[async_std::test]
+    #[serial_test::serial]
+    #[ignore]
+    async fn test_move_avax() {
+        let local_network = load_test_network();
+        let local_wallet = local_network
+            .create_wallet_from_cb58(AVAX_EWOQ_PRIVATE_KEY)
+            .unwrap();
+
+        move_avax(
+            &local_network,
+            &local_wallet,
+            AvalanchePrimaryNetworkChain::X,
+            AvalanchePrimaryNetworkChain::P,
+            1_000_000_000,
+            true,
+        )
+        .await
+        .unwrap();
+
+        let (export_tx_id, import_tx_id) = move_avax(
+            &local_network,
+            &local_wallet,
+            AvalanchePrimaryNetworkChain::P,
+            AvalanchePrimaryNetworkChain::X,
+            500_000_000,
+            true,
+        )
+        .await
+        .unwrap();
+
+        let pchain_rpc_url = &local_network.get_pchain().unwrap().rpc_url;
+        let xchain_rpc_url = &local_network.get_xchain().unwrap().rpc_url;
+        assert_eq!(
+            platformvm::get_tx_status(pchain_rpc_url, export_tx_id)
+                .unwrap()
+                .to_string(),
+            "Committed"
+        );
+        assert_eq!(
+            avm::get_tx_status(xchain_rpc_url, import_tx_id)
+                .unwrap()
+                .to_string(),
+            "Accepted"
+        );
+    }
+
+    #[async_std::test]
+    #[serial_test::serial]
+    #[ignore]
+    async fn test_get_tx() {
+        let local_network = load_test_network();
+        let local_wallet = local_network
+            .create_wallet_from_cb58(AVAX_EWOQ_PRIVATE_KEY)
+            .unwrap();
+
+        let transfer_tx_id = x::transfer_avax(
+            &local_wallet,
+            local_wallet.xchain_wallet.short_address.clone(),
+            1_000_000,
+            false,
+        )
+        .await
+        .unwrap();
+        let create_subnet_tx_id = p::create_subnet(&local_wallet, false).await.unwrap();
+
+        wait_txs_acceptance(
+            &local_network,
+            AvalanchePrimaryNetworkChain::X,
+            &[transfer_tx_id],
+        )
+        .await
+        .unwrap();
+        wait_txs_acceptance(
+            &local_network,
+            AvalanchePrimaryNetworkChain::P,
+            &[create_subnet_tx_id],
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            get_tx_status(
+                &local_network,
+                AvalanchePrimaryNetworkChain::P,
+                create_subnet_tx_id
+            )
+            .unwrap(),
+            "Committed"
+        );
+
+        let transfer_tx = get_tx(
+            &local_network,
+            AvalanchePrimaryNetworkChain::X,
+            transfer_tx_id,
+        )
+        .unwrap();
+        assert_eq!(transfer_tx.tx_type, "avm.BaseTx");
+        assert!(transfer_tx
+            .outputs
+            .iter()
+            .any(|output| output.amount == 1_000_000));
+
+        let create_subnet_tx = get_tx(
+            &local_network,
+            AvalanchePrimaryNetworkChain::P,
+            create_subnet_tx_id,
+        )
+        .unwrap();
+        assert_eq!(create_subnet_tx.tx_type, "platformvm.CreateSubnetTx");
+        assert!(create_subnet_tx.details.get("owner").is_some());
+    }
+
+    #[test]
+    fn test_tx_from_api() {
+        // Codec version 0 and type ID 16 (CreateSubnetTx), the rest of the bytes is not used
+        let hex_tx = AvalancheTxEncoding::Hex.encode(&[0, 0, 0, 0, 0, 16, 0, 0, 0, 1]);
+        let json_tx = serde_json::json!({
+            "unsignedTx": {
+                "networkID": 5,
+                "blockchainID": "11111111111111111111111111111111LpoYY",
+                "outputs": [{
+                    "assetID": "U8iRqJoiJm8xZHAacmvYyZVwqQx6uDNtQeP3CQ6fcgQk3JqnK",
+                    "fxID": "spdxUxVJQbX85MGxMHbKw1sHxMnSqJ3QBzDyDYEP3h6TLuxqQ",
+                    "output": {
+                        "addresses": ["P-fuji1udpqdsrf5hydtl96d7qexgvkvc8tgn0d3fgrym"],
+                        "amount": "4900000000",
+                        "locktime": 0,
+                        "threshold": 1
+                    }
+                }],
+                "inputs": [{
+                    "txID": "2JVSBoinj9C2J33VntvzYtVJNZdN2NKiwwKjcumHUWEb5DbBrm",
+                    "outputIndex": 1,
+                    "assetID": "U8iRqJoiJm8xZHAacmvYyZVwqQx6uDNtQeP3CQ6fcgQk3JqnK",
+                    "fxID": "spdxUxVJQbX85MGxMHbKw1sHxMnSqJ3QBzDyDYEP3h6TLuxqQ",
+                    "input": {
+                        "locktime": 1700000000,
+                        "input": { "amount": 5000000000u64, "signatureIndices": [0] }
+                    }
+                }],
+                "memo": "0x",
+                "owner": {
+                    "addresses": ["P-fuji1udpqdsrf5hydtl96d7qexgvkvc8tgn0d3fgrym"],
+                    "locktime": 0,
+                    "threshold": 1
+                }
+            },
+            "credentials": []
+        });
+
+        let tx = AvalancheTx::from_api(
+            Id::empty(),
+            AvalanchePrimaryNetworkChain::P,
+            &hex_tx,
+            &json_tx,
+        )
+        .unwrap();
+
+        assert_eq!(tx.tx_type, "platformvm.CreateSubnetTx");
+        assert_eq!(tx.network_id, 5);
+        assert_eq!(tx.inputs.len(), 1);
+        assert_eq!(tx.inputs[0].output_index, 1);
+        assert_eq!(tx.inputs[0].amount, 5_000_000_000);
+        assert_eq!(tx.inputs[0].stakeable_locktime, 1_700_000_000);
+        assert_eq!(tx.outputs.len(), 1);
+        assert_eq!(tx.outputs[0].amount, 4_900_000_000);
+        assert_eq!(tx.outputs[0].threshold, 1);
+        assert_eq!(tx.outputs[0].stakeable_locktime, 0);
+        assert_eq!(
+            tx.outputs[0].addresses,
+            vec!["P-fuji1udpqdsrf5hydtl96d7qexgvkvc8tgn0d3fgrym"]
+        );
+        // Only the fields specific to the transaction type are kept in the details
+        assert!(tx.details.get("owner").is_some());
+        assert!(tx.details.get("inputs").is_none());
+
+        // C-Chain transactions are not supported
+        assert!(AvalancheTx::from_api(
+            Id::empty(),
+            AvalanchePrimaryNetworkChain::C,
+            &hex_tx,
+            &json_tx
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_tx_encoding() {
+        let tx_bytes = vec![0, 0, 0, 0, 0, 16, 0, 0, 0, 1, 255];
+
+        for encoding in [AvalancheTxEncoding::Hex, AvalancheTxEncoding::Cb58] {
+            let encoded_tx = encoding.encode(&tx_bytes);
+            assert_eq!(AvalancheTxEncoding::decode(&encoded_tx).unwrap(), tx_bytes);
+        }
+        assert!(AvalancheTxEncoding::Hex.encode(&tx_bytes).starts_with("0x"));
+
+        // The checksum is verified
+        let mut encoded_tx = AvalancheTxEncoding::Cb58.encode(&tx_bytes);
+        encoded_tx.pop();
+        assert!(AvalancheTxEncoding::decode(&encoded_tx).is_err());
+        assert!(AvalancheTxEncoding::decode("0x0000").is_err());
+        assert!(AvalancheTxEncoding::decode("0x0000000000").is_err());
+    }
+}
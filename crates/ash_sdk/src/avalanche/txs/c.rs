@@ -0,0 +1,531 @@
+// SPDX-License-Identifier: BSD-3-Clause
+// Copyright (c) 2023, E36 Knots
+
+// Module that contains code to issue atomic transactions on the C-Chain
+// avalanche-types does not support Coreth atomic transactions, so they are encoded here
+// See https://github.com/ava-labs/coreth/blob/master/plugin/evm/codec.go
+
+use crate::{
+    avalanche::{
+        blockchains::AvalancheBlockchain,
+        jsonrpc::coreth,
+        txs::{filter_export_utxos, spend_avax_utxos, wait_tx_acceptance, wallet_avax_output},
+        wallets::AvalancheWallet,
+    },
+    errors::*,
+};
+use avalanche_types::{ids::Id, key::secp256k1::SignOnly, txs::transferable};
+use ethers::{
+    providers::{Http, Middleware, Provider},
+    types::{Address, U256},
+};
+use sha2::{Digest, Sha256};
+
+/// Version of the Coreth codec
+const CORETH_CODEC_VERSION: u16 = 0;
+// Type IDs registered in the Coreth codec
+const IMPORT_TX_TYPE_ID: u32 = 0;
+const EXPORT_TX_TYPE_ID: u32 = 1;
+const TRANSFER_INPUT_TYPE_ID: u32 = 5;
+const TRANSFER_OUTPUT_TYPE_ID: u32 = 7;
+const CREDENTIAL_TYPE_ID: u32 = 9;
+
+/// Gas charged for each signature of an atomic transaction
+pub const ATOMIC_TX_SIGNATURE_GAS: u64 = 1_000;
+/// Gas charged for every atomic transaction on top of its size (since Apricot Phase 5)
+pub const ATOMIC_TX_BASE_GAS: u64 = 10_000;
+/// Number of wei in one nAVAX (amounts of atomic transactions are in nAVAX)
+pub const WEI_PER_NAVAX: u64 = 1_000_000_000;
+
+// Input of an export transaction, debited from an EVM address
+struct EvmInput {
+    address: Address,
+    amount: u64,
+    asset_id: Id,
+    nonce: u64,
+}
+
+// Output of an import transaction, credited to an EVM address
+struct EvmOutput {
+    address: Address,
+    amount: u64,
+    asset_id: Id,
+}
+
+// Unsigned C-Chain export transaction
+// The exported outputs must have a transfer output
+struct UnsignedExportTx {
+    network_id: u32,
+    blockchain_id: Id,
+    destination_chain_id: Id,
+    ins: Vec<EvmInput>,
+    exported_outputs: Vec<transferable::Output>,
+}
+
+impl UnsignedExportTx {
+    // Pack the transaction with the Coreth codec
+    fn pack(&self) -> Vec<u8> {
+        let mut bytes = pack_atomic_tx_header(
+            EXPORT_TX_TYPE_ID,
+            self.network_id,
+            self.blockchain_id,
+            self.destination_chain_id,
+        );
+
+        bytes.extend_from_slice(&(self.ins.len() as u32).to_be_bytes());
+        for input in self.ins.iter() {
+            bytes.extend_from_slice(input.address.as_bytes());
+            bytes.extend_from_slice(&input.amount.to_be_bytes());
+            bytes.extend_from_slice(input.asset_id.as_ref());
+            bytes.extend_from_slice(&input.nonce.to_be_bytes());
+        }
+
+        bytes.extend_from_slice(&(self.exported_outputs.len() as u32).to_be_bytes());
+        for output in self.exported_outputs.iter() {
+            pack_transferable_output(&mut bytes, output);
+        }
+
+        bytes
+    }
+}
+
+// Unsigned C-Chain import transaction
+// The imported inputs must have a transfer input
+struct UnsignedImportTx {
+    network_id: u32,
+    blockchain_id: Id,
+    source_chain_id: Id,
+    imported_inputs: Vec<transferable::Input>,
+    outs: Vec<EvmOutput>,
+}
+
+impl UnsignedImportTx {
+    // Pack the transaction with the Coreth codec
+    fn pack(&self) -> Vec<u8> {
+        let mut bytes = pack_atomic_tx_header(
+            IMPORT_TX_TYPE_ID,
+            self.network_id,
+            self.blockchain_id,
+            self.source_chain_id,
+        );
+
+        bytes.extend_from_slice(&(self.imported_inputs.len() as u32).to_be_bytes());
+        for input in self.imported_inputs.iter() {
+            pack_transferable_input(&mut bytes, input);
+        }
+
+        bytes.extend_from_slice(&(self.outs.len() as u32).to_be_bytes());
+        for output in self.outs.iter() {
+            bytes.extend_from_slice(output.address.as_bytes());
+            bytes.extend_from_slice(&output.amount.to_be_bytes());
+            bytes.extend_from_slice(output.asset_id.as_ref());
+        }
+
+        bytes
+    }
+}
+
+// Pack the fields common to import and export transactions
+// The chain ID is the source chain for imports and the destination chain for exports
+fn pack_atomic_tx_header(
+    type_id: u32,
+    network_id: u32,
+    blockchain_id: Id,
+    chain_id: Id,
+) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    bytes.extend_from_slice(&CORETH_CODEC_VERSION.to_be_bytes());
+    bytes.extend_from_slice(&type_id.to_be_bytes());
+    bytes.extend_from_slice(&network_id.to_be_bytes());
+    bytes.extend_from_slice(blockchain_id.as_ref());
+    bytes.extend_from_slice(chain_id.as_ref());
+
+    bytes
+}
+
+fn pack_transferable_output(bytes: &mut Vec<u8>, output: &transferable::Output) {
+    let transfer_output = output
+        .transfer_output
+        .as_ref()
+        .expect("exported outputs must have a transfer output");
+
+    bytes.extend_from_slice(output.asset_id.as_ref());
+    bytes.extend_from_slice(&TRANSFER_OUTPUT_TYPE_ID.to_be_bytes());
+    bytes.extend_from_slice(&transfer_output.amount.to_be_bytes());
+    bytes.extend_from_slice(&transfer_output.output_owners.locktime.to_be_bytes());
+    bytes.extend_from_slice(&transfer_output.output_owners.threshold.to_be_bytes());
+    bytes.extend_from_slice(&(transfer_output.output_owners.addresses.len() as u32).to_be_bytes());
+    for address in transfer_output.output_owners.addresses.iter() {
+        bytes.extend_from_slice(address.as_ref());
+    }
+}
+
+fn pack_transferable_input(bytes: &mut Vec<u8>, input: &transferable::Input) {
+    let transfer_input = input
+        .transfer_input
+        .as_ref()
+        .expect("imported inputs must have a transfer input");
+
+    bytes.extend_from_slice(input.utxo_id.tx_id.as_ref());
+    bytes.extend_from_slice(&input.utxo_id.output_index.to_be_bytes());
+    bytes.extend_from_slice(input.asset_id.as_ref());
+    bytes.extend_from_slice(&TRANSFER_INPUT_TYPE_ID.to_be_bytes());
+    bytes.extend_from_slice(&transfer_input.amount.to_be_bytes());
+    bytes.extend_from_slice(&(transfer_input.sig_indices.len() as u32).to_be_bytes());
+    for sig_index in transfer_input.sig_indices.iter() {
+        bytes.extend_from_slice(&sig_index.to_be_bytes());
+    }
+}
+
+// Sign the packed unsigned transaction with one credential per input
+// Return the packed signed transaction
+async fn sign_atomic_tx<T: SignOnly>(
+    unsigned_tx_bytes: Vec<u8>,
+    signers: &[Vec<T>],
+) -> Result<Vec<u8>, AshError> {
+    let tx_hash = Sha256::digest(&unsigned_tx_bytes);
+
+    let mut bytes = unsigned_tx_bytes;
+    bytes.extend_from_slice(&(signers.len() as u32).to_be_bytes());
+    for keys in signers.iter() {
+        bytes.extend_from_slice(&CREDENTIAL_TYPE_ID.to_be_bytes());
+        bytes.extend_from_slice(&(keys.len() as u32).to_be_bytes());
+        for key in keys.iter() {
+            let signature =
+                key.sign_digest(&tx_hash)
+                    .await
+                    .map_err(|e| AvalancheWalletError::IssueTx {
+                        blockchain_name: "C-Chain".to_string(),
+                        tx_type: "atomic".to_string(),
+                        msg: format!("failed to sign transaction: {e}"),
+                    })?;
+            bytes.extend_from_slice(&signature);
+        }
+    }
+
+    Ok(bytes)
+}
+
+/// Compute the gas used by an atomic transaction from its unsigned size and its number of signatures
+pub fn atomic_tx_gas_used(unsigned_tx_len: usize, num_signatures: usize) -> u64 {
+    unsigned_tx_len as u64 + num_signatures as u64 * ATOMIC_TX_SIGNATURE_GAS + ATOMIC_TX_BASE_GAS
+}
+
+/// Compute the fee (in nAVAX) of an atomic transaction from its gas usage and the base fee (in wei)
+/// The fee is rounded up to the next nAVAX
+pub fn atomic_tx_fee(gas_used: u64, base_fee: U256) -> Result<u64, AshError> {
+    let fee = (U256::from(gas_used) * base_fee + WEI_PER_NAVAX - 1) / WEI_PER_NAVAX;
+
+    if fee > U256::from(u64::MAX) {
+        return Err(AvalancheWalletError::IssueTx {
+            blockchain_name: "C-Chain".to_string(),
+            tx_type: "atomic".to_string(),
+            msg: format!("fee of {fee} nAVAX is too high"),
+        }
+        .into());
+    }
+
+    Ok(fee.as_u64())
+}
+
+// Get the base fee (in wei) of the next C-Chain block
+async fn get_base_fee(provider: &Provider<Http>) -> Result<U256, AshError> {
+    let base_fee = provider
+        .request::<_, U256>("eth_baseFee", ())
+        .await
+        .map_err(|e| RpcError::GetFailure {
+            data_type: "base fee".to_string(),
+            target_type: "provider".to_string(),
+            target_value: provider.url().to_string(),
+            msg: e.to_string(),
+        })?;
+
+    Ok(base_fee)
+}
+
+/// Export AVAX from the C-Chain to another chain of the Primary Network
+/// The amount (in nAVAX) and the fee are debited from the wallet EVM address
+/// The exported UTXOs are owned by the wallet and have to be imported on the destination chain
+pub async fn export_avax(
+    wallet: &AvalancheWallet,
+    cchain: &AvalancheBlockchain,
+    destination_chain_id: Id,
+    amount: u64,
+    check_acceptance: bool,
+) -> Result<Id, AshError> {
+    let xchain_wallet = &wallet.xchain_wallet;
+    let rpc_url = &coreth::get_avax_rpc_url(&cchain.rpc_url)?;
+    let provider = cchain.get_ethers_provider()?;
+    let evm_address = wallet.get_evm_address()?;
+
+    let nonce = provider
+        .get_transaction_count(evm_address, None)
+        .await
+        .map_err(|e| RpcError::GetFailure {
+            data_type: "nonce".to_string(),
+            target_type: "address".to_string(),
+            target_value: format!("{evm_address:?}"),
+            msg: e.to_string(),
+        })?;
+    let base_fee = get_base_fee(&provider).await?;
+
+    let mut tx = UnsignedExportTx {
+        network_id: xchain_wallet.network_id,
+        blockchain_id: cchain.id,
+        destination_chain_id,
+        ins: vec![EvmInput {
+            address: evm_address,
+            amount,
+            asset_id: xchain_wallet.avax_asset_id,
+            nonce: nonce.as_u64(),
+        }],
+        exported_outputs: vec![wallet_avax_output(xchain_wallet, amount)],
+    };
+
+    // The fee does not depend on the input amount, which has the same size whatever its value
+    let fee = atomic_tx_fee(atomic_tx_gas_used(tx.pack().len(), tx.ins.len()), base_fee)?;
+    tx.ins[0].amount += fee;
+
+    let signed_tx_bytes = sign_atomic_tx(tx.pack(), &[vec![wallet.evm_signer.clone()]]).await?;
+
+    let tx_id =
+        coreth::issue_tx(rpc_url, &signed_tx_bytes).map_err(|e| AvalancheWalletError::IssueTx {
+            blockchain_name: "C-Chain".to_string(),
+            tx_type: "export".to_string(),
+            msg: format!("failed to export {amount} AVAX to '{destination_chain_id}': {e}"),
+        })?;
+
+    if check_acceptance {
+        wait_tx_acceptance("C-Chain", tx_id, || {
+            coreth::get_atomic_tx_status(rpc_url, tx_id)
+        })
+        .await?;
+    }
+
+    Ok(tx_id)
+}
+
+/// Import to the C-Chain the AVAX exported to the wallet from another chain of the Primary Network
+/// If an export transaction ID is provided, only the UTXOs created by this export are imported,
+/// otherwise all the atomic UTXOs of the wallet on the source chain are swept
+/// The imported AVAX are credited to the wallet EVM address, minus the fee
+pub async fn import_avax(
+    wallet: &AvalancheWallet,
+    cchain: &AvalancheBlockchain,
+    source_chain_id: Id,
+    export_tx_id: Option<Id>,
+    check_acceptance: bool,
+) -> Result<Id, AshError> {
+    let xchain_wallet = &wallet.xchain_wallet;
+    let rpc_url = &coreth::get_avax_rpc_url(&cchain.rpc_url)?;
+    let provider = cchain.get_ethers_provider()?;
+
+    // The atomic UTXOs of the C-Chain are owned by the Bech32 address of the wallet
+    let cchain_address = format!("C-{}", xchain_wallet.x_address.trim_start_matches("X-"));
+    let utxos = coreth::get_utxos(rpc_url, &[cchain_address], source_chain_id)?;
+    let utxos = filter_export_utxos(utxos, export_tx_id);
+    let (inputs, signers, imported_amount) = spend_avax_utxos(xchain_wallet, &utxos, None);
+    let base_fee = get_base_fee(&provider).await?;
+
+    let mut tx = UnsignedImportTx {
+        network_id: xchain_wallet.network_id,
+        blockchain_id: cchain.id,
+        source_chain_id,
+        imported_inputs: inputs,
+        outs: vec![EvmOutput {
+            address: wallet.get_evm_address()?,
+            amount: imported_amount,
+            asset_id: xchain_wallet.avax_asset_id,
+        }],
+    };
+
+    let num_signatures = signers.iter().map(Vec::len).sum();
+    let fee = atomic_tx_fee(
+        atomic_tx_gas_used(tx.pack().len(), num_signatures),
+        base_fee,
+    )?;
+
+    if imported_amount <= fee {
+        return Err(AvalancheWalletError::IssueTx {
+            blockchain_name: "C-Chain".to_string(),
+            tx_type: "import".to_string(),
+            msg: format!(
+                "not enough AVAX to import from '{source_chain_id}' to pay the fee of {fee} nAVAX: {imported_amount} nAVAX available"
+            ),
+        }
+        .into());
+    }
+    tx.outs[0].amount = imported_amount - fee;
+
+    let signed_tx_bytes = sign_atomic_tx(tx.pack(), &signers).await?;
+
+    let tx_id =
+        coreth::issue_tx(rpc_url, &signed_tx_bytes).map_err(|e| AvalancheWalletError::IssueTx {
+            blockchain_name: "C-Chain".to_string(),
+            tx_type: "import".to_string(),
+            msg: format!("failed to import AVAX from '{source_chain_id}': {e}"),
+        })?;
+
+    if check_acceptance {
+        wait_tx_acceptance("C-Chain", tx_id, || {
+            coreth::get_atomic_tx_status(rpc_url, tx_id)
+        })
+        .await?;
+    }
+
+    Ok(tx_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use avalanche_types::{
+        ids::short::Id as ShortId,
+        key::secp256k1::{
+            private_key::Key as PrivateKey,
+            txs::{transfer, OutputOwners},
+        },
+        txs::utxo,
+    };
+    use std::str::FromStr;
+
+    const AVAX_EWOQ_PRIVATE_KEY: &str =
+        "PrivateKey-ewoqjP7PxY4yr3iLTpLisriqt94hdyDFNgchSxGGztUrTXtNN";
+    const AVAX_FUJI_CCHAIN_ID: &str = "yH8D7ThNJkxmtkuv2jgBa4P1Rn3Qpr4pPr7QYNfcdoS6k6HWp";
+    const AVAX_FUJI_XCHAIN_ID: &str = "2JVSBoinj9C2J33VntvzYtVJNZdN2NKiwwKjcumHUWEb5DbBrm";
+    const AVAX_FUJI_ASSET_ID: &str = "U8iRqJoiJm8xZHAacmvYyZVwqQx6uDNtQeP3CQ6fcgQk3JqnK";
+    const EWOQ_EVM_ADDRESS: &str = "0x8db97C7cEcE249c2b98bDC0226Cc4C2A57BF52FC";
+    const EWOQ_SHORT_ADDRESS: &str = "6Y3kysjF9jnHnYkdS9yGAuoHyae2eNmeV";
+
+    fn export_tx() -> UnsignedExportTx {
+        UnsignedExportTx {
+            network_id: 5,
+            blockchain_id: Id::from_str(AVAX_FUJI_CCHAIN_ID).unwrap(),
+            destination_chain_id: Id::empty(),
+            ins: vec![EvmInput {
+                address: Address::from_str(EWOQ_EVM_ADDRESS).unwrap(),
+                amount: 1_000_000_000,
+                asset_id: Id::from_str(AVAX_FUJI_ASSET_ID).unwrap(),
+                nonce: 3,
+            }],
+            exported_outputs: vec![transferable::Output {
+                asset_id: Id::from_str(AVAX_FUJI_ASSET_ID).unwrap(),
+                transfer_output: Some(transfer::Output {
+                    amount: 1_000_000_000,
+                    output_owners: OutputOwners {
+                        locktime: 0,
+                        threshold: 1,
+                        addresses: vec![ShortId::from_str(EWOQ_SHORT_ADDRESS).unwrap()],
+                    },
+                }),
+                ..Default::default()
+            }],
+        }
+    }
+
+    fn import_tx() -> UnsignedImportTx {
+        UnsignedImportTx {
+            network_id: 5,
+            blockchain_id: Id::from_str(AVAX_FUJI_CCHAIN_ID).unwrap(),
+            source_chain_id: Id::from_str(AVAX_FUJI_XCHAIN_ID).unwrap(),
+            imported_inputs: vec![transferable::Input {
+                utxo_id: utxo::Id {
+                    tx_id: Id::from_str(AVAX_FUJI_XCHAIN_ID).unwrap(),
+                    output_index: 1,
+                    ..Default::default()
+                },
+                asset_id: Id::from_str(AVAX_FUJI_ASSET_ID).unwrap(),
+                transfer_input: Some(transfer::Input {
+                    amount: 1_000_000_000,
+                    sig_indices: vec![0],
+                }),
+                ..Default::default()
+            }],
+            outs: vec![EvmOutput {
+                address: Address::from_str(EWOQ_EVM_ADDRESS).unwrap(),
+                amount: 999_000_000,
+                asset_id: Id::from_str(AVAX_FUJI_ASSET_ID).unwrap(),
+            }],
+        }
+    }
+
+    #[test]
+    fn test_unsigned_export_tx_pack() {
+        let bytes = export_tx().pack();
+
+        // Header (74) + 1 EVM input (4 + 68) + 1 transfer output (4 + 80)
+        assert_eq!(bytes.len(), 230);
+        assert_eq!(bytes[..6], [0, 0, 0, 0, 0, 1]);
+        assert_eq!(bytes[6..10], 5u32.to_be_bytes());
+        assert_eq!(
+            bytes[10..42],
+            *Id::from_str(AVAX_FUJI_CCHAIN_ID).unwrap().as_ref()
+        );
+        assert_eq!(bytes[42..74], [0; 32]);
+        assert_eq!(bytes[74..78], 1u32.to_be_bytes());
+        assert_eq!(
+            bytes[78..98],
+            *Address::from_str(EWOQ_EVM_ADDRESS).unwrap().as_bytes()
+        );
+        assert_eq!(bytes[98..106], 1_000_000_000u64.to_be_bytes());
+        assert_eq!(bytes[138..146], 3u64.to_be_bytes());
+        assert_eq!(bytes[182..186], TRANSFER_OUTPUT_TYPE_ID.to_be_bytes());
+        assert_eq!(
+            bytes[210..],
+            *ShortId::from_str(EWOQ_SHORT_ADDRESS).unwrap().as_ref()
+        );
+    }
+
+    #[test]
+    fn test_unsigned_import_tx_pack() {
+        let bytes = import_tx().pack();
+
+        // Header (74) + 1 transfer input with 1 signature (4 + 88) + 1 EVM output (4 + 60)
+        assert_eq!(bytes.len(), 230);
+        assert_eq!(bytes[..6], [0, 0, 0, 0, 0, 0]);
+        assert_eq!(
+            bytes[42..74],
+            *Id::from_str(AVAX_FUJI_XCHAIN_ID).unwrap().as_ref()
+        );
+        assert_eq!(bytes[110..114], 1u32.to_be_bytes());
+        assert_eq!(bytes[146..150], TRANSFER_INPUT_TYPE_ID.to_be_bytes());
+        assert_eq!(bytes[158..162], 1u32.to_be_bytes());
+        assert_eq!(bytes[162..166], 0u32.to_be_bytes());
+        assert_eq!(bytes[166..170], 1u32.to_be_bytes());
+        assert_eq!(bytes[190..198], 999_000_000u64.to_be_bytes());
+    }
+
+    #[async_std::test]
+    async fn test_sign_atomic_tx() {
+        let key = PrivateKey::from_cb58(AVAX_EWOQ_PRIVATE_KEY).unwrap();
+        let unsigned_tx_bytes = import_tx().pack();
+
+        let signed_tx_bytes = sign_atomic_tx(unsigned_tx_bytes.clone(), &[vec![key]])
+            .await
+            .unwrap();
+
+        // 1 credential with 1 signature
+        assert_eq!(signed_tx_bytes.len(), unsigned_tx_bytes.len() + 4 + 8 + 65);
+        assert_eq!(
+            signed_tx_bytes[..unsigned_tx_bytes.len()],
+            unsigned_tx_bytes
+        );
+        assert_eq!(
+            signed_tx_bytes[unsigned_tx_bytes.len()..unsigned_tx_bytes.len() + 12],
+            [0, 0, 0, 1, 0, 0, 0, 9, 0, 0, 0, 1]
+        );
+    }
+
+    #[test]
+    fn test_atomic_tx_fee() {
+        assert_eq!(atomic_tx_gas_used(230, 1), 11_230);
+        // 25 nAVAX base fee
+        assert_eq!(
+            atomic_tx_fee(11_230, U256::from(25_000_000_000u64)).unwrap(),
+            280_750
+        );
+        // The fee is rounded up to the next nAVAX
+        assert_eq!(atomic_tx_fee(11_230, U256::from(1)).unwrap(), 1);
+        assert!(atomic_tx_fee(u64::MAX, U256::from(u64::MAX) * WEI_PER_NAVAX * 2).is_err());
+    }
+}
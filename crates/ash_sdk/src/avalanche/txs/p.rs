@@ -4,172 +4,1512 @@
 // Module that contains code to issue transactions on the X-Chain
 
 use crate::{
-    avalanche::{wallets::AvalancheWallet, AVAX_PRIMARY_NETWORK_ID},
+    avalanche::{
+        address_to_short_id,
+        jsonrpc::platformvm,
+        short_id_to_address,
+        subnets::{AvalancheSubnet, AvalancheSubnetValidator},
+        txs::{
+            filter_export_utxos, pack_output_owners, pack_transfer_output,
+            partial::{AvalanchePartialTx, AvalanchePartialTxContent},
+            sign_packed_tx, spend_avax_utxos, wait_tx_acceptance, wallet_avax_output,
+            AvalanchePrimaryNetworkChain, AvalancheTxInput, AvalancheTxOutput,
+            SECP256K1FX_CREDENTIAL_TYPE_ID,
+        },
+        wallets::{AvalancheSignerKey, AvalancheWallet},
+        AvalancheOutputOwners, AVAX_PRIMARY_NETWORK_ID,
+    },
     errors::*,
 };
 use avalanche_types::{
+    constants::{FALLBACK_HRP, NETWORK_ID_TO_HRP},
     ids::{node::Id as NodeId, Id},
-    key::bls::ProofOfPossession,
+    key::{
+        bls::ProofOfPossession,
+        secp256k1::txs::{Input as SubnetAuth, OutputOwners},
+    },
+    platformvm::txs::{
+        add_permissionless_validator, add_subnet_validator, create_chain, create_subnet, export,
+        import, Validator as PlatformVmValidator,
+    },
+    txs::{self, transferable, utxo::Utxo},
     wallet::p,
 };
 use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
 
 /// Create a new subnet
+/// The transaction is the one simulated by `simulate_partial_tx` (see `build_create_subnet_partial_tx`)
 /// TODO: Add control keys and threshold as parameters
 /// See: https://github.com/ava-labs/avalanche-types-rs/pull/76
 pub async fn create_subnet(
     wallet: &AvalancheWallet,
     check_acceptance: bool,
 ) -> Result<Id, AshError> {
-    let tx_id = p::create_subnet::Tx::new(&wallet.pchain_wallet.p())
+    let partial_tx = build_create_subnet_partial_tx(wallet).await?;
+
+    issue_wallet_partial_tx(
+        wallet,
+        &partial_tx,
+        check_acceptance,
+        "failed to create subnet",
+    )
+    .await
+}
+
+/// Create a new blockchain
+pub async fn create_blockchain(
+    wallet: &AvalancheWallet,
+    subnet_id: Id,
+    genesis_data: Vec<u8>,
+    vm_id: Id,
+    name: &str,
+    check_acceptance: bool,
+) -> Result<Id, AshError> {
+    let tx_id = p::create_chain::Tx::new(&wallet.pchain_wallet.p())
+        .subnet_id(subnet_id)
+        .genesis_data(genesis_data)
+        .vm_id(vm_id)
+        .chain_name(name.to_string())
         .check_acceptance(check_acceptance)
         .issue()
         .await
         .map_err(|e| AvalancheWalletError::IssueTx {
             blockchain_name: "P-Chain".to_string(),
-            tx_type: "create_subnet".to_string(),
-            msg: format!("failed to create subnet: {e}"),
+            tx_type: "create_blockchain".to_string(),
+            msg: format!("failed to create blockchain on Subnet {subnet_id}: {e}"),
+        })?;
+
+    Ok(tx_id)
+}
+
+/// Add a validator to a permissioned Subnet
+/// The transaction is the one simulated by `simulate_partial_tx` (see `build_add_permissioned_subnet_validator_partial_tx`)
+/// The wallet key has to be enough to authorize the addition (e.g. the Subnet threshold is 1)
+pub async fn add_permissioned_subnet_validator(
+    wallet: &AvalancheWallet,
+    subnet: &AvalancheSubnet,
+    node_id: NodeId,
+    weight: u64,
+    start_time: DateTime<Utc>,
+    end_time: DateTime<Utc>,
+    check_acceptance: bool,
+) -> Result<Id, AshError> {
+    let subnet_id = subnet.id;
+    let is_validator = wallet
+        .pchain_wallet
+        .p()
+        .is_subnet_validator(&node_id, &subnet_id)
+        .await
+        .map_err(|e| AvalancheWalletError::IssueTx {
+            blockchain_name: "P-Chain".to_string(),
+            tx_type: "add_subnet_validator".to_string(),
+            msg: format!("failed to add '{node_id}' as validator to Subnet '{subnet_id}': {e}"),
+        })?;
+    if is_validator {
+        return Err(AvalancheWalletError::IssueTx {
+            blockchain_name: "P-Chain".to_string(),
+            tx_type: "add_validator".to_string(),
+            msg: format!("'{node_id}' is already a validator to Subnet '{subnet_id}'"),
+        }
+        .into());
+    }
+
+    let partial_tx = build_add_permissioned_subnet_validator_partial_tx(
+        wallet,
+        subnet,
+        node_id,
+        weight,
+        start_time,
+        end_time,
+        &[],
+    )
+    .await?;
+
+    issue_wallet_partial_tx(
+        wallet,
+        &partial_tx,
+        check_acceptance,
+        &format!("failed to add '{node_id}' as validator to Subnet '{subnet_id}'"),
+    )
+    .await
+}
+
+/// Add validators to a permissioned Subnet in batch
+/// Each transaction spends different UTXOs of the wallet, so that they are issued
+/// without waiting for the acceptance of the previous ones
+/// Once the wallet UTXOs are all spent, the issued transactions are waited for so that their change can be spent
+/// Return the ID of the transaction adding each validator or the reason of its failure, in the same order
+pub async fn add_permissioned_subnet_validators(
+    wallet: &AvalancheWallet,
+    subnet: &AvalancheSubnet,
+    validators: &[PlatformVmValidator],
+    check_acceptance: bool,
+) -> Result<Vec<Result<Id, AshError>>, AshError> {
+    let tx_type = "add_subnet_validator";
+    let pchain_wallet = &wallet.pchain_wallet;
+    let rpc_url = &format!("{}/ext/bc/P", pchain_wallet.pick_base_http_url().1);
+    let get_wallet_utxos = || {
+        platformvm::get_utxos(
+            rpc_url,
+            std::slice::from_ref(&pchain_wallet.p_address),
+            None,
+        )
+    };
+
+    let mut utxos = get_wallet_utxos()?;
+    // Indices of the transactions whose acceptance has not been waited for yet
+    let mut unaccepted_txs: Vec<usize> = Vec::new();
+
+    let mut tx_ids: Vec<Result<Id, AshError>> = Vec::new();
+    for validator in validators.iter() {
+        let failure_msg = format!(
+            "failed to add '{}' as validator to Subnet '{}'",
+            validator.node_id, subnet.id
+        );
+
+        let payment = match spend_unlocked_avax_utxos(wallet, &utxos, pchain_wallet.tx_fee, tx_type)
+        {
+            Ok(payment) => Ok(payment),
+            // The change of the issued transactions can only be spent once they are accepted
+            Err(_) if !unaccepted_txs.is_empty() => {
+                for index in unaccepted_txs.drain(..) {
+                    if let Ok(id) = tx_ids[index] {
+                        if let Err(e) = wait_tx_acceptance("P-Chain", id, || {
+                            platformvm::get_tx_status(rpc_url, id)
+                        })
+                        .await
+                        {
+                            tx_ids[index] = Err(e);
+                        }
+                    }
+                }
+                utxos = get_wallet_utxos()?;
+                spend_unlocked_avax_utxos(wallet, &utxos, pchain_wallet.tx_fee, tx_type)
+            }
+            Err(e) => Err(e),
+        };
+
+        let tx_id = async {
+            let (inputs, outputs, signers) = payment?;
+            // The spent UTXOs cannot be used by the next transactions
+            utxos.retain(|utxo| !inputs.iter().any(|input| input.utxo_id == utxo.utxo_id));
+
+            let inputs_signers = wallet_inputs_signers(wallet, &signers);
+            let partial_tx = add_subnet_validator_partial_tx(
+                wallet,
+                subnet,
+                validator.clone(),
+                (inputs, outputs, inputs_signers),
+                &[],
+            )
+            .await?;
+
+            issue_wallet_partial_tx(wallet, &partial_tx, false, &failure_msg).await
+        }
+        .await;
+        if tx_id.is_ok() {
+            unaccepted_txs.push(tx_ids.len());
+        }
+        tx_ids.push(tx_id);
+    }
+
+    // Only wait for acceptance once all the transactions are issued
+    if check_acceptance {
+        for index in unaccepted_txs {
+            if let Ok(id) = tx_ids[index] {
+                if let Err(e) =
+                    wait_tx_acceptance("P-Chain", id, || platformvm::get_tx_status(rpc_url, id))
+                        .await
+                {
+                    tx_ids[index] = Err(e);
+                }
+            }
+        }
+    }
+
+    Ok(tx_ids)
+}
+
+/// Build an unsigned transaction to create a new Subnet owned by the wallet key
+/// The transaction is signed later (e.g. on an air-gapped machine) with `AvalanchePartialTx::sign`
+pub async fn build_create_subnet_partial_tx(
+    wallet: &AvalancheWallet,
+) -> Result<AvalanchePartialTx, AshError> {
+    let pchain_wallet = &wallet.pchain_wallet;
+    let (inputs, outputs, inputs_signers) =
+        spend_unlocked_avax(wallet, pchain_wallet.create_subnet_tx_fee, "create_subnet")?;
+
+    let tx = create_subnet::Tx {
+        base_tx: txs::Tx {
+            network_id: pchain_wallet.network_id,
+            blockchain_id: pchain_wallet.blockchain_id_p,
+            transferable_outputs: Some(outputs),
+            transferable_inputs: Some(inputs),
+            ..Default::default()
+        },
+        owner: OutputOwners {
+            locktime: 0,
+            threshold: 1,
+            addresses: vec![pchain_wallet.short_address.clone()],
+        },
+        ..Default::default()
+    };
+
+    AvalanchePartialTx::new(AvalanchePartialTxContent::CreateSubnet(tx), inputs_signers).await
+}
+
+/// Build a partially signed transaction to create a new blockchain on a Subnet controlled by several keys
+/// The fee is paid by the wallet and the Subnet creation is authorized by `threshold` control keys
+/// If no control keys are provided, the wallet key (if it is a control key) and the first control keys are used
+pub async fn build_create_blockchain_partial_tx(
+    wallet: &AvalancheWallet,
+    subnet: &AvalancheSubnet,
+    genesis_data: Vec<u8>,
+    vm_id: Id,
+    name: &str,
+    subnet_auth_keys: &[String],
+) -> Result<AvalanchePartialTx, AshError> {
+    let pchain_wallet = &wallet.pchain_wallet;
+    let (inputs, outputs, inputs_signers) = spend_unlocked_avax(
+        wallet,
+        pchain_wallet.create_blockchain_tx_fee,
+        "create_chain",
+    )?;
+    let (subnet_auth, subnet_auth_signers) =
+        authorize_subnet(wallet, subnet, subnet_auth_keys, "create_chain")?;
+
+    let tx = create_chain::Tx {
+        base_tx: txs::Tx {
+            network_id: pchain_wallet.network_id,
+            blockchain_id: pchain_wallet.blockchain_id_p,
+            transferable_outputs: Some(outputs),
+            transferable_inputs: Some(inputs),
+            ..Default::default()
+        },
+        subnet_id: subnet.id,
+        chain_name: name.to_string(),
+        vm_id,
+        genesis_data,
+        subnet_auth,
+        ..Default::default()
+    };
+
+    AvalanchePartialTx::new(
+        AvalanchePartialTxContent::CreateChain(tx),
+        [inputs_signers, vec![subnet_auth_signers]].concat(),
+    )
+    .await
+}
+
+/// Build a partially signed transaction to add a validator to a permissioned Subnet controlled by several keys
+/// The fee is paid by the wallet and the validator addition is authorized by `threshold` control keys
+/// If no control keys are provided, the wallet key (if it is a control key) and the first control keys are used
+pub async fn build_add_permissioned_subnet_validator_partial_tx(
+    wallet: &AvalancheWallet,
+    subnet: &AvalancheSubnet,
+    node_id: NodeId,
+    weight: u64,
+    start_time: DateTime<Utc>,
+    end_time: DateTime<Utc>,
+    subnet_auth_keys: &[String],
+) -> Result<AvalanchePartialTx, AshError> {
+    let payment = spend_unlocked_avax(wallet, wallet.pchain_wallet.tx_fee, "add_subnet_validator")?;
+
+    add_subnet_validator_partial_tx(
+        wallet,
+        subnet,
+        PlatformVmValidator {
+            node_id,
+            start: start_time.timestamp() as u64,
+            end: end_time.timestamp() as u64,
+            weight,
+        },
+        payment,
+        subnet_auth_keys,
+    )
+    .await
+}
+
+// Build an unsigned transaction to add a validator to a permissioned Subnet, paid with the given payment
+async fn add_subnet_validator_partial_tx(
+    wallet: &AvalancheWallet,
+    subnet: &AvalancheSubnet,
+    validator: PlatformVmValidator,
+    payment: Payment,
+    subnet_auth_keys: &[String],
+) -> Result<AvalanchePartialTx, AshError> {
+    let pchain_wallet = &wallet.pchain_wallet;
+    let (inputs, outputs, inputs_signers) = payment;
+    let (subnet_auth, subnet_auth_signers) =
+        authorize_subnet(wallet, subnet, subnet_auth_keys, "add_subnet_validator")?;
+
+    let tx = add_subnet_validator::Tx {
+        base_tx: txs::Tx {
+            network_id: pchain_wallet.network_id,
+            blockchain_id: pchain_wallet.blockchain_id_p,
+            transferable_outputs: Some(outputs),
+            transferable_inputs: Some(inputs),
+            ..Default::default()
+        },
+        validator: add_subnet_validator::Validator {
+            validator,
+            subnet_id: subnet.id,
+        },
+        subnet_auth,
+        ..Default::default()
+    };
+
+    AvalanchePartialTx::new(
+        AvalanchePartialTxContent::AddSubnetValidator(tx),
+        [inputs_signers, vec![subnet_auth_signers]].concat(),
+    )
+    .await
+}
+
+/// Build an unsigned transaction to add a validator to a permissionless Subnet (e.g. Primary Network)
+/// Primary Network validations are checked against the staking rules of the network before being built
+/// The stake is taken from the wallet unlocked AVAX and the validation and delegation rewards are sent to the rewards owner
+/// The transaction is signed later (e.g. on an air-gapped machine) with `AvalanchePartialTx::sign`
+pub async fn build_add_permissionless_validator_partial_tx(
+    wallet: &AvalancheWallet,
+    node_id: NodeId,
+    subnet_id: Id,
+    stake_amount: u64,
+    start_time: DateTime<Utc>,
+    end_time: DateTime<Utc>,
+    reward_fee_percent: u32,
+    signer: Option<ProofOfPossession>,
+    rewards_owner: &OutputOwners,
+) -> Result<AvalanchePartialTx, AshError> {
+    let pchain_wallet = &wallet.pchain_wallet;
+    let is_primary_network = subnet_id.to_string() == AVAX_PRIMARY_NETWORK_ID;
+    if is_primary_network {
+        AvalancheStakingRules::primary_network(wallet)?.check_validation(
+            stake_amount,
+            start_time,
+            end_time,
+            reward_fee_percent,
+        )?;
+    }
+
+    let tx_type = "add_permissionless_validator";
+    let build_tx_failure = |msg: String| AvalancheWalletError::IssueTx {
+        blockchain_name: "P-Chain".to_string(),
+        tx_type: tx_type.to_string(),
+        msg,
+    };
+    let spent_amount = stake_amount
+        .checked_add(pchain_wallet.add_primary_network_validator_fee)
+        .ok_or_else(|| {
+            build_tx_failure(format!(
+                "stake of {stake_amount} nAVAX plus the fee overflows the maximum amount"
+            ))
+        })?;
+    // The delegation shares are expressed in parts per million
+    let delegation_shares = reward_fee_percent.checked_mul(10_000).ok_or_else(|| {
+        build_tx_failure(format!(
+            "delegation fee of {reward_fee_percent}% overflows the maximum delegation shares"
+        ))
+    })?;
+
+    let (inputs, outputs, inputs_signers) = spend_unlocked_avax(wallet, spent_amount, tx_type)?;
+
+    let tx = add_permissionless_validator::Tx {
+        base_tx: txs::Tx {
+            network_id: pchain_wallet.network_id,
+            blockchain_id: pchain_wallet.blockchain_id_p,
+            transferable_outputs: Some(outputs),
+            transferable_inputs: Some(inputs),
+            ..Default::default()
+        },
+        validator: PlatformVmValidator {
+            node_id,
+            start: start_time.timestamp() as u64,
+            end: end_time.timestamp() as u64,
+            weight: stake_amount,
+        },
+        // avalanche-types requires the subnet_id to be empty for the Primary Network
+        // Only Primary Network validators have a BLS signer
+        subnet_id: match is_primary_network {
+            true => Id::empty(),
+            false => subnet_id,
+        },
+        signer: match is_primary_network {
+            true => Some(signer.unwrap_or_default()),
+            false => None,
+        },
+        stake_transferable_outputs: Some(vec![wallet_avax_output(pchain_wallet, stake_amount)]),
+        validator_rewards_owner: rewards_owner.clone(),
+        delegator_rewards_owner: rewards_owner.clone(),
+        delegation_shares,
+        ..Default::default()
+    };
+
+    AvalanchePartialTx::new(
+        AvalanchePartialTxContent::AddPermissionlessValidator(tx),
+        inputs_signers,
+    )
+    .await
+}
+
+// Sign a P-Chain transaction built for a wallet with the wallet key and issue it
+// The wallet key has to be the only signer of the transaction
+async fn issue_wallet_partial_tx(
+    wallet: &AvalancheWallet,
+    partial_tx: &AvalanchePartialTx,
+    check_acceptance: bool,
+    failure_msg: &str,
+) -> Result<Id, AshError> {
+    let tx_type = partial_tx.content.tx_type();
+    let pchain_wallet = &wallet.pchain_wallet;
+    let rpc_url = &format!("{}/ext/bc/P", pchain_wallet.pick_base_http_url().1);
+    let issue_tx_failure = |msg: String| AvalancheWalletError::IssueTx {
+        blockchain_name: "P-Chain".to_string(),
+        tx_type: tx_type.clone(),
+        msg: format!("{failure_msg}: {msg}"),
+    };
+
+    let mut partial_tx = partial_tx.clone();
+    partial_tx
+        .sign(&wallet.signer)
+        .await
+        .map_err(|e| issue_tx_failure(e.to_string()))?;
+    let (_, signed_tx_bytes) = partial_tx
+        .signed_tx()
+        .await
+        .map_err(|e| issue_tx_failure(e.to_string()))?;
+
+    let tx_id = platformvm::issue_tx(rpc_url, &signed_tx_bytes)
+        .map_err(|e| issue_tx_failure(e.to_string()))?;
+
+    if check_acceptance {
+        wait_tx_acceptance("P-Chain", tx_id, || {
+            platformvm::get_tx_status(rpc_url, tx_id)
+        })
+        .await?;
+    }
+
+    Ok(tx_id)
+}
+
+/// Preview of the funds spent by a P-Chain transaction of a wallet, computed without issuing it
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AvalancheTxSimulation {
+    pub tx_type: String,
+    /// UTXOs consumed by the transaction
+    pub consumed_utxos: Vec<AvalancheTxInput>,
+    /// Fee burned by the transaction (in nAVAX)
+    pub fee: u64,
+    /// Amount locked as stake until the end of the validation (in nAVAX)
+    pub stake: u64,
+    /// Outputs sending the remaining AVAX back to the wallet
+    pub change_outputs: Vec<AvalancheTxOutput>,
+    /// Unlocked AVAX of the wallet on the P-Chain before the transaction (in nAVAX)
+    pub balance_before: u64,
+    /// Unlocked AVAX of the wallet on the P-Chain after the transaction (in nAVAX)
+    pub balance_after: u64,
+}
+
+/// Simulate a P-Chain transaction built for a wallet (e.g. with `build_create_subnet_partial_tx`)
+/// The UTXOs and the change are the ones of the unsigned transaction, nothing is issued
+/// `create_subnet` and `add_*_subnet_validator` issue the transactions built by the same functions
+/// The fee is the difference between the consumed AVAX and the AVAX sent back or staked
+pub fn simulate_partial_tx(
+    wallet: &AvalancheWallet,
+    partial_tx: &AvalanchePartialTx,
+) -> Result<AvalancheTxSimulation, AshError> {
+    let pchain_wallet = &wallet.pchain_wallet;
+    let tx_type = partial_tx.content.tx_type();
+    if partial_tx.content.chain() != AvalanchePrimaryNetworkChain::P {
+        return Err(AvalancheWalletError::PartialTxFailure {
+            tx_type,
+            msg: "only P-Chain transactions can be simulated".to_string(),
+        }
+        .into());
+    }
+
+    let rpc_url = &format!("{}/ext/bc/P", pchain_wallet.pick_base_http_url().1);
+    let utxos = platformvm::get_utxos(
+        rpc_url,
+        std::slice::from_ref(&pchain_wallet.p_address),
+        None,
+    )?;
+    let (_, _, balance_before) = spend_avax_utxos(pchain_wallet, &utxos, None);
+
+    let hrp = NETWORK_ID_TO_HRP
+        .get(&pchain_wallet.network_id)
+        .unwrap_or(&FALLBACK_HRP);
+    simulate_tx_content(&partial_tx.content, hrp, balance_before)
+}
+
+// Compute the funds spent by an unsigned P-Chain transaction from the unlocked balance of the wallet
+fn simulate_tx_content(
+    content: &AvalanchePartialTxContent,
+    hrp: &str,
+    balance_before: u64,
+) -> Result<AvalancheTxSimulation, AshError> {
+    let base_tx = content.base_tx();
+    let consumed_utxos: Vec<AvalancheTxInput> = base_tx
+        .transferable_inputs
+        .iter()
+        .flatten()
+        .map(|input| AvalancheTxInput {
+            tx_id: input.utxo_id.tx_id,
+            output_index: input.utxo_id.output_index,
+            asset_id: input.asset_id,
+            amount: input
+                .transfer_input
+                .as_ref()
+                .map(|transfer_input| transfer_input.amount)
+                .unwrap_or_default(),
+            stakeable_locktime: 0,
+        })
+        .collect();
+    let change_outputs: Vec<AvalancheTxOutput> = base_tx
+        .transferable_outputs
+        .iter()
+        .flatten()
+        .map(|output| simulated_output(hrp, output))
+        .collect::<Result<_, _>>()?;
+
+    let stake = match content {
+        AvalanchePartialTxContent::AddPermissionlessValidator(tx) => tx
+            .stake_transferable_outputs
+            .iter()
+            .flatten()
+            .filter_map(|output| output.transfer_output.as_ref())
+            .map(|output| output.amount)
+            .sum(),
+        _ => 0,
+    };
+    let consumed_amount: u64 = consumed_utxos.iter().map(|input| input.amount).sum();
+    let change_amount: u64 = change_outputs.iter().map(|output| output.amount).sum();
+
+    Ok(AvalancheTxSimulation {
+        tx_type: content.tx_type(),
+        consumed_utxos,
+        fee: consumed_amount.saturating_sub(change_amount + stake),
+        stake,
+        change_outputs,
+        balance_before,
+        balance_after: balance_before.saturating_sub(consumed_amount) + change_amount,
+    })
+}
+
+// Convert an output of an unsigned transaction to its simulated form
+fn simulated_output(
+    hrp: &str,
+    output: &transferable::Output,
+) -> Result<AvalancheTxOutput, AshError> {
+    let transfer_output = output.transfer_output.clone().unwrap_or_default();
+
+    let addresses = transfer_output
+        .output_owners
+        .addresses
+        .iter()
+        .map(|short_id| short_id_to_address(short_id, "P", hrp))
+        .collect::<Result<_, _>>()?;
+
+    Ok(AvalancheTxOutput {
+        asset_id: output.asset_id,
+        amount: transfer_output.amount,
+        addresses,
+        threshold: transfer_output.output_owners.threshold,
+        locktime: transfer_output.output_owners.locktime,
+        stakeable_locktime: 0,
+    })
+}
+
+// Inputs, change outputs and addresses that must sign each input of a payment
+type Payment = (
+    Vec<transferable::Input>,
+    Vec<transferable::Output>,
+    Vec<Vec<String>>,
+);
+// Inputs, change outputs and keys that must sign each input of a payment
+type KeysPayment = (
+    Vec<transferable::Input>,
+    Vec<transferable::Output>,
+    Vec<Vec<AvalancheSignerKey>>,
+);
+
+// Spend the wallet unlocked AVAX to pay an amount (e.g. a transaction fee)
+fn spend_unlocked_avax(
+    wallet: &AvalancheWallet,
+    amount: u64,
+    tx_type: &str,
+) -> Result<Payment, AshError> {
+    let (inputs, change_outputs, signers) = spend_unlocked_avax_keys(wallet, amount, tx_type)?;
+
+    Ok((
+        inputs,
+        change_outputs,
+        wallet_inputs_signers(wallet, &signers),
+    ))
+}
+
+// Get the addresses that must sign the inputs spending the wallet UTXOs
+// The wallet key is the only one able to spend the wallet UTXOs
+fn wallet_inputs_signers(
+    wallet: &AvalancheWallet,
+    signers: &[Vec<AvalancheSignerKey>],
+) -> Vec<Vec<String>> {
+    signers
+        .iter()
+        .map(|keys| vec![wallet.pchain_wallet.p_address.clone(); keys.len()])
+        .collect()
+}
+
+// Spend the wallet unlocked AVAX to pay an amount
+// Return the keys that must sign each input instead of their addresses
+fn spend_unlocked_avax_keys(
+    wallet: &AvalancheWallet,
+    amount: u64,
+    tx_type: &str,
+) -> Result<KeysPayment, AshError> {
+    let pchain_wallet = &wallet.pchain_wallet;
+    let rpc_url = &format!("{}/ext/bc/P", pchain_wallet.pick_base_http_url().1);
+
+    let utxos = platformvm::get_utxos(
+        rpc_url,
+        std::slice::from_ref(&pchain_wallet.p_address),
+        None,
+    )?;
+
+    spend_unlocked_avax_utxos(wallet, &utxos, amount, tx_type)
+}
+
+// Spend some of the wallet UTXOs to pay an amount
+// Return the keys that must sign each input instead of their addresses
+fn spend_unlocked_avax_utxos(
+    wallet: &AvalancheWallet,
+    utxos: &[Utxo],
+    amount: u64,
+    tx_type: &str,
+) -> Result<KeysPayment, AshError> {
+    let pchain_wallet = &wallet.pchain_wallet;
+    let (inputs, signers, spent_amount) = spend_avax_utxos(pchain_wallet, utxos, Some(amount));
+
+    if spent_amount < amount {
+        return Err(AvalancheWalletError::IssueTx {
+            blockchain_name: "P-Chain".to_string(),
+            tx_type: tx_type.to_string(),
+            msg: format!(
+                "not enough unlocked AVAX to spend {amount} nAVAX: {spent_amount} nAVAX available"
+            ),
+        }
+        .into());
+    }
+
+    let change_outputs = match spent_amount - amount {
+        0 => vec![],
+        change => vec![wallet_avax_output(pchain_wallet, change)],
+    };
+
+    Ok((inputs, change_outputs, signers))
+}
+
+// Select the control keys that authorize an operation on a Subnet
+// Exactly `threshold` control keys have to sign, in the order of the Subnet owners
+// Return the Subnet authorization and the addresses that must sign it
+fn authorize_subnet(
+    wallet: &AvalancheWallet,
+    subnet: &AvalancheSubnet,
+    subnet_auth_keys: &[String],
+    tx_type: &str,
+) -> Result<(SubnetAuth, Vec<String>), AshError> {
+    let subnet_auth_failure = |msg: String| AvalancheWalletError::PartialTxFailure {
+        tx_type: tx_type.to_string(),
+        msg,
+    };
+
+    // The Subnet owners are sorted by short ID on the P-Chain
+    let mut control_keys = subnet
+        .control_keys
+        .iter()
+        .map(|address| Ok((address_to_short_id(address, "P")?, address.clone())))
+        .collect::<Result<Vec<_>, AshError>>()?;
+    control_keys.sort();
+
+    let selected_keys = match subnet_auth_keys.is_empty() {
+        true => {
+            let wallet_address = &wallet.pchain_wallet.p_address;
+            let mut selected_keys: Vec<String> = subnet
+                .control_keys
+                .iter()
+                .filter(|address| *address == wallet_address)
+                .cloned()
+                .collect();
+            selected_keys.extend(
+                subnet
+                    .control_keys
+                    .iter()
+                    .filter(|address| *address != wallet_address)
+                    .cloned(),
+            );
+            selected_keys.truncate(subnet.threshold as usize);
+            selected_keys
+        }
+        false => subnet_auth_keys.to_vec(),
+    };
+
+    if selected_keys.len() != subnet.threshold as usize {
+        return Err(subnet_auth_failure(format!(
+            "{} control keys provided but Subnet '{}' requires {} signatures",
+            selected_keys.len(),
+            subnet.id,
+            subnet.threshold
+        ))
+        .into());
+    }
+
+    let mut sig_indices = vec![];
+    for selected_key in selected_keys.iter() {
+        let selected_short_id = address_to_short_id(selected_key, "P")?;
+        match control_keys
+            .iter()
+            .position(|(short_id, _)| *short_id == selected_short_id)
+        {
+            Some(index) if !sig_indices.contains(&(index as u32)) => sig_indices.push(index as u32),
+            Some(_) => {
+                return Err(subnet_auth_failure(format!(
+                    "control key '{selected_key}' is provided more than once"
+                ))
+                .into())
+            }
+            None => {
+                return Err(subnet_auth_failure(format!(
+                    "'{selected_key}' is not a control key of Subnet '{}'",
+                    subnet.id
+                ))
+                .into())
+            }
+        }
+    }
+    sig_indices.sort();
+
+    let signers = sig_indices
+        .iter()
+        .map(|index| control_keys[*index as usize].1.clone())
+        .collect();
+
+    Ok((SubnetAuth { sig_indices }, signers))
+}
+
+/// Add a validator to a permissionless Subnet (e.g. Primary Network)
+/// The transaction is the one simulated by `simulate_partial_tx` (see `build_add_permissionless_validator_partial_tx`)
+pub async fn add_permissionless_subnet_validator(
+    wallet: &AvalancheWallet,
+    node_id: NodeId,
+    subnet_id: Id,
+    stake_amount: u64,
+    start_time: DateTime<Utc>,
+    end_time: DateTime<Utc>,
+    reward_fee_percent: u32,
+    signer: Option<ProofOfPossession>,
+    rewards_owner: &OutputOwners,
+    check_acceptance: bool,
+) -> Result<Id, AshError> {
+    let add_validator_failure = |msg: String| AvalancheWalletError::IssueTx {
+        blockchain_name: "P-Chain".to_string(),
+        tx_type: "add_validator".to_string(),
+        msg,
+    };
+
+    let is_validator = match subnet_id.to_string().as_str() {
+        AVAX_PRIMARY_NETWORK_ID => {
+            wallet
+                .pchain_wallet
+                .p()
+                .is_primary_network_validator(&node_id)
+                .await
+        }
+        _ => {
+            wallet
+                .pchain_wallet
+                .p()
+                .is_subnet_validator(&node_id, &subnet_id)
+                .await
+        }
+    }
+    .map_err(|e| {
+        add_validator_failure(format!(
+            "failed to add '{node_id}' as Avalanche validator: {e}"
+        ))
+    })?;
+    if is_validator {
+        return Err(add_validator_failure(format!(
+            "'{node_id}' is already an Avalanche validator"
+        ))
+        .into());
+    }
+
+    let partial_tx = build_add_permissionless_validator_partial_tx(
+        wallet,
+        node_id,
+        subnet_id,
+        stake_amount,
+        start_time,
+        end_time,
+        reward_fee_percent,
+        signer,
+        rewards_owner,
+    )
+    .await?;
+
+    issue_wallet_partial_tx(
+        wallet,
+        &partial_tx,
+        check_acceptance,
+        &format!("failed to add '{node_id}' as Avalanche validator"),
+    )
+    .await
+}
+
+// Codec version and type IDs of the P-Chain types not supported by avalanche-types
+const PLATFORMVM_CODEC_VERSION: u16 = 0;
+const REMOVE_SUBNET_VALIDATOR_TX_TYPE_ID: u32 = 23;
+const ADD_PERMISSIONLESS_DELEGATOR_TX_TYPE_ID: u32 = 26;
+const SECP256K1FX_INPUT_TYPE_ID: u32 = 10;
+const SECP256K1FX_OUTPUT_OWNERS_TYPE_ID: u32 = 11;
+
+// Maximum weight of a Primary Network validator (3M AVAX)
+const PRIMARY_NETWORK_MAX_VALIDATOR_WEIGHT: u64 = 3_000_000_000_000_000;
+// Maximum ratio between the weight of a validator (with its delegations) and its own stake
+const PRIMARY_NETWORK_MAX_VALIDATOR_WEIGHT_FACTOR: u64 = 5;
+// Minimum and maximum fee charged by a validator on the rewards of its delegators (in percent)
+const PRIMARY_NETWORK_MIN_DELEGATION_FEE: u32 = 2;
+const PRIMARY_NETWORK_MAX_DELEGATION_FEE: u32 = 100;
+
+/// Staking rules of the Primary Network
+/// See https://docs.avax.network/nodes/validate/how-to-stake#staking-parameters-on-avalanche
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AvalancheStakingRules {
+    /// Minimum stake of a validator (in nAVAX)
+    pub min_validator_stake: u64,
+    /// Minimum stake of a delegator (in nAVAX)
+    pub min_delegator_stake: u64,
+    /// Maximum weight of a validator, including its delegations (in nAVAX)
+    pub max_validator_weight: u64,
+    /// Maximum ratio between the weight of a validator and its own stake
+    pub max_validator_weight_factor: u64,
+    /// Minimum duration of a validation or delegation (in seconds)
+    pub min_stake_duration: u64,
+    /// Maximum duration of a validation or delegation (in seconds)
+    pub max_stake_duration: u64,
+    /// Minimum fee charged by a validator on the rewards of its delegators (in percent)
+    pub min_delegation_fee: u32,
+    /// Maximum fee charged by a validator on the rewards of its delegators (in percent)
+    pub max_delegation_fee: u32,
+}
+
+impl AvalancheStakingRules {
+    /// Get the staking rules of the Primary Network the wallet is connected to
+    /// The minimum stakes are queried from the P-Chain API
+    pub fn primary_network(wallet: &AvalancheWallet) -> Result<Self, AshError> {
+        let pchain_wallet = &wallet.pchain_wallet;
+        let rpc_url = &format!("{}/ext/bc/P", pchain_wallet.pick_base_http_url().1);
+
+        let min_stake =
+            platformvm::get_min_stake(rpc_url, Id::from_str(AVAX_PRIMARY_NETWORK_ID).unwrap())?;
+
+        Ok(Self {
+            min_validator_stake: min_stake.min_validator_stake,
+            min_delegator_stake: min_stake.min_delegator_stake,
+            max_validator_weight: PRIMARY_NETWORK_MAX_VALIDATOR_WEIGHT,
+            max_validator_weight_factor: PRIMARY_NETWORK_MAX_VALIDATOR_WEIGHT_FACTOR,
+            // Staking periods are at least 2 weeks on Mainnet and 24 hours on the other networks
+            min_stake_duration: match pchain_wallet.network_id {
+                1 => Duration::days(14).num_seconds() as u64,
+                _ => Duration::hours(24).num_seconds() as u64,
+            },
+            max_stake_duration: Duration::days(365).num_seconds() as u64,
+            min_delegation_fee: PRIMARY_NETWORK_MIN_DELEGATION_FEE,
+            max_delegation_fee: PRIMARY_NETWORK_MAX_DELEGATION_FEE,
+        })
+    }
+
+    /// Check that a validation follows the staking rules
+    pub fn check_validation(
+        &self,
+        stake_amount: u64,
+        start_time: DateTime<Utc>,
+        end_time: DateTime<Utc>,
+        delegation_fee: u32,
+    ) -> Result<(), AshError> {
+        let violation = |msg: String| AvalancheSubnetError::StakingRulesViolation {
+            subnet_id: AVAX_PRIMARY_NETWORK_ID.to_string(),
+            msg,
+        };
+
+        if stake_amount < self.min_validator_stake {
+            return Err(violation(format!(
+                "validator stake of {stake_amount} nAVAX is lower than the minimum of {} nAVAX",
+                self.min_validator_stake
+            ))
+            .into());
+        }
+        if stake_amount > self.max_validator_weight {
+            return Err(violation(format!(
+                "validator stake of {stake_amount} nAVAX is higher than the maximum of {} nAVAX",
+                self.max_validator_weight
+            ))
+            .into());
+        }
+
+        let (start, end) = (start_time.timestamp(), end_time.timestamp());
+        if end <= start {
+            return Err(violation("end time must be after start time".to_string()).into());
+        }
+        let duration = (end - start) as u64;
+        if duration < self.min_stake_duration || duration > self.max_stake_duration {
+            return Err(violation(format!(
+                "validation duration of {duration}s is not between {}s and {}s",
+                self.min_stake_duration, self.max_stake_duration
+            ))
+            .into());
+        }
+
+        if delegation_fee < self.min_delegation_fee || delegation_fee > self.max_delegation_fee {
+            return Err(violation(format!(
+                "delegation fee of {delegation_fee}% is not between {}% and {}%",
+                self.min_delegation_fee, self.max_delegation_fee
+            ))
+            .into());
+        }
+
+        Ok(())
+    }
+
+    /// Check that a delegation to a validator follows the staking rules
+    pub fn check_delegation(
+        &self,
+        validator: &AvalancheSubnetValidator,
+        stake_amount: u64,
+        start_time: DateTime<Utc>,
+        end_time: DateTime<Utc>,
+    ) -> Result<(), AshError> {
+        let violation = |msg: String| AvalancheSubnetError::StakingRulesViolation {
+            subnet_id: AVAX_PRIMARY_NETWORK_ID.to_string(),
+            msg,
+        };
+
+        if stake_amount < self.min_delegator_stake {
+            return Err(violation(format!(
+                "delegator stake of {stake_amount} nAVAX is lower than the minimum of {} nAVAX",
+                self.min_delegator_stake
+            ))
+            .into());
+        }
+
+        let (start, end) = (start_time.timestamp(), end_time.timestamp());
+        if end <= start {
+            return Err(violation("end time must be after start time".to_string()).into());
+        }
+        let duration = (end - start) as u64;
+        if duration < self.min_stake_duration || duration > self.max_stake_duration {
+            return Err(violation(format!(
+                "delegation duration of {duration}s is not between {}s and {}s",
+                self.min_stake_duration, self.max_stake_duration
+            ))
+            .into());
+        }
+
+        if start < validator.start_time as i64 || end > validator.end_time as i64 {
+            return Err(violation(format!(
+                "delegation period is not within the validation period of '{}' ({} to {})",
+                validator.node_id, validator.start_time, validator.end_time
+            ))
+            .into());
+        }
+
+        let validator_stake = validator
+            .stake_amount
+            .or(validator.weight)
+            .unwrap_or_default();
+        let max_weight = self
+            .max_validator_weight
+            .min(validator_stake.saturating_mul(self.max_validator_weight_factor));
+        let weight = validator_stake
+            .saturating_add(validator.delegator_weight.unwrap_or_default())
+            .saturating_add(stake_amount);
+        if weight > max_weight {
+            return Err(violation(format!(
+                "delegating {stake_amount} nAVAX would bring the weight of '{}' to {weight} nAVAX, above the maximum of {max_weight} nAVAX",
+                validator.node_id
+            ))
+            .into());
+        }
+
+        Ok(())
+    }
+}
+
+// Unsigned AddPermissionlessDelegatorTx, which is not supported by avalanche-types
+// See https://docs.avax.network/reference/avalanchego/p-chain/txn-format#unsigned-add-permissionless-delegator-tx
+struct UnsignedAddPermissionlessDelegatorTx {
+    base_tx: txs::Tx,
+    validator: PlatformVmValidator,
+    // Empty for the Primary Network
+    subnet_id: Id,
+    stake_outputs: Vec<transferable::Output>,
+    rewards_owner: OutputOwners,
+}
+
+impl UnsignedAddPermissionlessDelegatorTx {
+    fn pack(&self) -> Result<Vec<u8>, AshError> {
+        let packer = self
+            .base_tx
+            .pack(
+                PLATFORMVM_CODEC_VERSION,
+                ADD_PERMISSIONLESS_DELEGATOR_TX_TYPE_ID,
+            )
+            .map_err(|e| AvalancheWalletError::IssueTx {
+                blockchain_name: "P-Chain".to_string(),
+                tx_type: "add_permissionless_delegator".to_string(),
+                msg: format!("failed to pack transaction: {e}"),
+            })?;
+        let mut bytes = packer.take_bytes().to_vec();
+
+        bytes.extend_from_slice(self.validator.node_id.as_ref());
+        bytes.extend_from_slice(&self.validator.start.to_be_bytes());
+        bytes.extend_from_slice(&self.validator.end.to_be_bytes());
+        bytes.extend_from_slice(&self.validator.weight.to_be_bytes());
+        bytes.extend_from_slice(self.subnet_id.as_ref());
+
+        bytes.extend_from_slice(&(self.stake_outputs.len() as u32).to_be_bytes());
+        for output in self.stake_outputs.iter() {
+            bytes.extend_from_slice(output.asset_id.as_ref());
+            // Only the secp256k1fx transfer outputs are staked by the wallet
+            pack_transfer_output(&mut bytes, output.transfer_output.as_ref().unwrap());
+        }
+
+        bytes.extend_from_slice(&SECP256K1FX_OUTPUT_OWNERS_TYPE_ID.to_be_bytes());
+        pack_output_owners(&mut bytes, &self.rewards_owner);
+
+        Ok(bytes)
+    }
+}
+
+// Unsigned RemoveSubnetValidatorTx, which is not supported by avalanche-types
+// See https://docs.avax.network/reference/avalanchego/p-chain/txn-format#unsigned-remove-subnet-validator-tx
+struct UnsignedRemoveSubnetValidatorTx {
+    base_tx: txs::Tx,
+    node_id: NodeId,
+    subnet_id: Id,
+    subnet_auth: SubnetAuth,
+}
+
+impl UnsignedRemoveSubnetValidatorTx {
+    fn pack(&self) -> Result<Vec<u8>, AshError> {
+        let packer = self
+            .base_tx
+            .pack(PLATFORMVM_CODEC_VERSION, REMOVE_SUBNET_VALIDATOR_TX_TYPE_ID)
+            .map_err(|e| AvalancheWalletError::IssueTx {
+                blockchain_name: "P-Chain".to_string(),
+                tx_type: "remove_subnet_validator".to_string(),
+                msg: format!("failed to pack transaction: {e}"),
+            })?;
+        let mut bytes = packer.take_bytes().to_vec();
+
+        bytes.extend_from_slice(self.node_id.as_ref());
+        bytes.extend_from_slice(self.subnet_id.as_ref());
+
+        bytes.extend_from_slice(&SECP256K1FX_INPUT_TYPE_ID.to_be_bytes());
+        bytes.extend_from_slice(&(self.subnet_auth.sig_indices.len() as u32).to_be_bytes());
+        for sig_index in self.subnet_auth.sig_indices.iter() {
+            bytes.extend_from_slice(&sig_index.to_be_bytes());
+        }
+
+        Ok(bytes)
+    }
+}
+
+/// Remove a validator from a permissioned Subnet before its end time
+/// The removal is authorized by the wallet key, which has to be enough to reach the Subnet threshold
+pub async fn remove_subnet_validator(
+    wallet: &AvalancheWallet,
+    subnet: &AvalancheSubnet,
+    node_id: NodeId,
+    check_acceptance: bool,
+) -> Result<Id, AshError> {
+    let tx_type = "remove_subnet_validator";
+    let pchain_wallet = &wallet.pchain_wallet;
+    let rpc_url = &format!("{}/ext/bc/P", pchain_wallet.pick_base_http_url().1);
+
+    let Some(wallet_key) = pchain_wallet.keychain.keys.first() else {
+        return Err(AvalancheWalletError::IssueTx {
+            blockchain_name: "P-Chain".to_string(),
+            tx_type: tx_type.to_string(),
+            msg: "the wallet has no key to authorize the removal".to_string(),
+        }
+        .into());
+    };
+
+    let (subnet_auth, subnet_auth_signers) = authorize_subnet(wallet, subnet, &[], tx_type)?;
+    if subnet_auth_signers
+        .iter()
+        .any(|address| *address != pchain_wallet.p_address)
+    {
+        return Err(AvalancheWalletError::IssueTx {
+            blockchain_name: "P-Chain".to_string(),
+            tx_type: tx_type.to_string(),
+            msg: format!(
+                "the wallet key alone cannot authorize the removal: Subnet '{}' requires {} signatures",
+                subnet.id, subnet.threshold
+            ),
+        }
+        .into());
+    }
+
+    let (inputs, change_outputs, signers) =
+        spend_unlocked_avax_keys(wallet, pchain_wallet.tx_fee, tx_type)?;
+
+    let unsigned_tx = UnsignedRemoveSubnetValidatorTx {
+        base_tx: txs::Tx {
+            network_id: pchain_wallet.network_id,
+            blockchain_id: pchain_wallet.blockchain_id_p,
+            transferable_outputs: Some(change_outputs),
+            transferable_inputs: Some(inputs),
+            ..Default::default()
+        },
+        node_id,
+        subnet_id: subnet.id,
+        subnet_auth,
+    };
+
+    // The Subnet authorization credential comes after the inputs credentials
+    let mut credentials: Vec<(u32, Vec<AvalancheSignerKey>)> = signers
+        .into_iter()
+        .map(|keys| (SECP256K1FX_CREDENTIAL_TYPE_ID, keys))
+        .collect();
+    credentials.push((
+        SECP256K1FX_CREDENTIAL_TYPE_ID,
+        vec![wallet_key.clone(); subnet_auth_signers.len()],
+    ));
+    let tx_bytes = sign_packed_tx(unsigned_tx.pack()?, &credentials, "P-Chain", tx_type).await?;
+
+    let tx_id =
+        platformvm::issue_tx(rpc_url, &tx_bytes).map_err(|e| AvalancheWalletError::IssueTx {
+            blockchain_name: "P-Chain".to_string(),
+            tx_type: tx_type.to_string(),
+            msg: format!(
+                "failed to remove '{node_id}' from the validators of Subnet '{}': {e}",
+                subnet.id
+            ),
+        })?;
+
+    if check_acceptance {
+        wait_tx_acceptance("P-Chain", tx_id, || {
+            platformvm::get_tx_status(rpc_url, tx_id)
+        })
+        .await?;
+    }
+
+    Ok(tx_id)
+}
+
+/// Build the owners of the validation or delegation rewards from P-Chain addresses
+/// The addresses are sorted and deduplicated as required by the P-Chain
+/// Default to the wallet address if no address is provided
+pub fn rewards_owner(
+    wallet: &AvalancheWallet,
+    reward_addresses: &[String],
+    reward_threshold: u32,
+) -> Result<OutputOwners, AshError> {
+    if reward_addresses.is_empty() {
+        return Ok(OutputOwners {
+            locktime: 0,
+            threshold: 1,
+            addresses: vec![wallet.pchain_wallet.short_address.clone()],
+        });
+    }
+
+    // The owners must be sorted and unique on the P-Chain
+    let mut addresses = reward_addresses
+        .iter()
+        .map(|address| address_to_short_id(address, "P"))
+        .collect::<Result<Vec<_>, _>>()?;
+    addresses.sort();
+    addresses.dedup();
+
+    if reward_threshold == 0 || reward_threshold as usize > addresses.len() {
+        return Err(AvalancheSubnetError::StakingRulesViolation {
+            subnet_id: AVAX_PRIMARY_NETWORK_ID.to_string(),
+            msg: format!(
+                "reward threshold must be between 1 and {} (the number of reward owners)",
+                addresses.len()
+            ),
+        }
+        .into());
+    }
+
+    Ok(OutputOwners {
+        locktime: 0,
+        threshold: reward_threshold,
+        addresses,
+    })
+}
+
+/// Convert the owners of rewards to their P-Chain addresses on the network of the wallet
+pub fn rewards_owner_addresses(
+    wallet: &AvalancheWallet,
+    rewards_owner: &OutputOwners,
+) -> Result<AvalancheOutputOwners, AshError> {
+    let hrp = NETWORK_ID_TO_HRP
+        .get(&wallet.pchain_wallet.network_id)
+        .unwrap_or(&FALLBACK_HRP);
+
+    Ok(AvalancheOutputOwners {
+        locktime: rewards_owner.locktime,
+        threshold: rewards_owner.threshold,
+        addresses: rewards_owner
+            .addresses
+            .iter()
+            .map(|short_id| short_id_to_address(short_id, "P", hrp))
+            .collect::<Result<_, _>>()?,
+    })
+}
+
+/// Delegate stake to a validator of the Primary Network
+/// The delegation is checked against the staking rules of the network before being issued
+/// The rewards are sent to the rewards owner (see `rewards_owner`)
+pub async fn add_permissionless_delegator(
+    wallet: &AvalancheWallet,
+    validator: &AvalancheSubnetValidator,
+    stake_amount: u64,
+    start_time: DateTime<Utc>,
+    end_time: DateTime<Utc>,
+    rewards_owner: &OutputOwners,
+    check_acceptance: bool,
+) -> Result<Id, AshError> {
+    let tx_type = "add_permissionless_delegator";
+    let pchain_wallet = &wallet.pchain_wallet;
+    let rpc_url = &format!("{}/ext/bc/P", pchain_wallet.pick_base_http_url().1);
+
+    AvalancheStakingRules::primary_network(wallet)?.check_delegation(
+        validator,
+        stake_amount,
+        start_time,
+        end_time,
+    )?;
+
+    let spent_amount = stake_amount
+        .checked_add(pchain_wallet.add_primary_network_validator_fee)
+        .ok_or_else(|| AvalancheWalletError::IssueTx {
+            blockchain_name: "P-Chain".to_string(),
+            tx_type: tx_type.to_string(),
+            msg: format!("stake of {stake_amount} nAVAX plus the fee overflows the maximum amount"),
+        })?;
+    let (inputs, change_outputs, signers) =
+        spend_unlocked_avax_keys(wallet, spent_amount, tx_type)?;
+
+    let unsigned_tx = UnsignedAddPermissionlessDelegatorTx {
+        base_tx: txs::Tx {
+            network_id: pchain_wallet.network_id,
+            blockchain_id: pchain_wallet.blockchain_id_p,
+            transferable_outputs: Some(change_outputs),
+            transferable_inputs: Some(inputs),
+            ..Default::default()
+        },
+        validator: PlatformVmValidator {
+            node_id: validator.node_id,
+            start: start_time.timestamp() as u64,
+            end: end_time.timestamp() as u64,
+            weight: stake_amount,
+        },
+        subnet_id: Id::empty(),
+        stake_outputs: vec![wallet_avax_output(pchain_wallet, stake_amount)],
+        rewards_owner: rewards_owner.clone(),
+    };
+
+    let credentials: Vec<(u32, Vec<AvalancheSignerKey>)> = signers
+        .into_iter()
+        .map(|keys| (SECP256K1FX_CREDENTIAL_TYPE_ID, keys))
+        .collect();
+    let tx_bytes = sign_packed_tx(unsigned_tx.pack()?, &credentials, "P-Chain", tx_type).await?;
+
+    let tx_id =
+        platformvm::issue_tx(rpc_url, &tx_bytes).map_err(|e| AvalancheWalletError::IssueTx {
+            blockchain_name: "P-Chain".to_string(),
+            tx_type: tx_type.to_string(),
+            msg: format!(
+                "failed to delegate {stake_amount} nAVAX to '{}': {e}",
+                validator.node_id
+            ),
         })?;
 
+    if check_acceptance {
+        wait_tx_acceptance("P-Chain", tx_id, || {
+            platformvm::get_tx_status(rpc_url, tx_id)
+        })
+        .await?;
+    }
+
     Ok(tx_id)
 }
 
-/// Create a new blockchain
-pub async fn create_blockchain(
+/// Export AVAX from the P-Chain to another chain of the Primary Network
+/// The exported UTXOs are owned by the wallet and have to be imported on the destination chain
+pub async fn export_avax(
     wallet: &AvalancheWallet,
-    subnet_id: Id,
-    genesis_data: Vec<u8>,
-    vm_id: Id,
-    name: &str,
+    destination_chain_id: Id,
+    amount: u64,
     check_acceptance: bool,
 ) -> Result<Id, AshError> {
-    let tx_id = p::create_chain::Tx::new(&wallet.pchain_wallet.p())
-        .subnet_id(subnet_id)
-        .genesis_data(genesis_data)
-        .vm_id(vm_id)
-        .chain_name(name.to_string())
-        .check_acceptance(check_acceptance)
-        .issue()
+    let pchain_wallet = &wallet.pchain_wallet;
+    let rpc_url = &format!("{}/ext/bc/P", pchain_wallet.pick_base_http_url().1);
+
+    // The avalanche-types export builder only spends the fee, so the inputs are selected here
+    let utxos = platformvm::get_utxos(
+        rpc_url,
+        std::slice::from_ref(&pchain_wallet.p_address),
+        None,
+    )?;
+    let export_failure = |msg: String| AvalancheWalletError::IssueTx {
+        blockchain_name: "P-Chain".to_string(),
+        tx_type: "export".to_string(),
+        msg,
+    };
+    let amount_to_spend = amount.checked_add(pchain_wallet.tx_fee).ok_or_else(|| {
+        export_failure(format!(
+            "amount of {amount} nAVAX plus the fee overflows the maximum amount"
+        ))
+    })?;
+    let (inputs, signers, spent_amount) =
+        spend_avax_utxos(pchain_wallet, &utxos, Some(amount_to_spend));
+
+    if spent_amount < amount_to_spend {
+        return Err(export_failure(format!(
+            "not enough unlocked AVAX to export {amount} nAVAX and pay the fee: {spent_amount} nAVAX available"
+        ))
+        .into());
+    }
+
+    let change_outputs = match spent_amount - amount_to_spend {
+        0 => vec![],
+        change => vec![wallet_avax_output(pchain_wallet, change)],
+    };
+
+    let mut tx = export::Tx {
+        base_tx: txs::Tx {
+            network_id: pchain_wallet.network_id,
+            blockchain_id: pchain_wallet.blockchain_id_p,
+            transferable_outputs: Some(change_outputs),
+            transferable_inputs: Some(inputs),
+            ..Default::default()
+        },
+        destination_chain_id,
+        destination_chain_transferable_outputs: Some(vec![wallet_avax_output(
+            pchain_wallet,
+            amount,
+        )]),
+        ..Default::default()
+    };
+    tx.sign(signers)
         .await
         .map_err(|e| AvalancheWalletError::IssueTx {
             blockchain_name: "P-Chain".to_string(),
-            tx_type: "create_blockchain".to_string(),
-            msg: format!("failed to create blockchain on Subnet {subnet_id}: {e}"),
+            tx_type: "export".to_string(),
+            msg: format!("failed to sign transaction: {e}"),
         })?;
 
+    let tx_id = platformvm::issue_tx(
+        rpc_url,
+        &tx.base_tx.metadata.unwrap().tx_bytes_with_signatures,
+    )
+    .map_err(|e| AvalancheWalletError::IssueTx {
+        blockchain_name: "P-Chain".to_string(),
+        tx_type: "export".to_string(),
+        msg: format!("failed to export {amount} AVAX to '{destination_chain_id}': {e}"),
+    })?;
+
+    if check_acceptance {
+        wait_tx_acceptance("P-Chain", tx_id, || {
+            platformvm::get_tx_status(rpc_url, tx_id)
+        })
+        .await?;
+    }
+
     Ok(tx_id)
 }
 
-/// Add a validator to a permissioned Subnet
-pub async fn add_permissioned_subnet_validator(
+/// Import to the P-Chain the AVAX exported to the wallet from another chain of the Primary Network
+/// If an export transaction ID is provided, only the UTXOs created by this export are imported,
+/// otherwise all the atomic UTXOs of the wallet on the source chain are swept
+/// The transaction fee is deducted from the imported amount
+pub async fn import_avax(
     wallet: &AvalancheWallet,
-    subnet_id: Id,
-    node_id: NodeId,
-    weight: u64,
-    start_time: DateTime<Utc>,
-    end_time: DateTime<Utc>,
+    source_chain_id: Id,
+    export_tx_id: Option<Id>,
     check_acceptance: bool,
 ) -> Result<Id, AshError> {
-    let (tx_id, success) = p::add_subnet_validator::Tx::new(&wallet.pchain_wallet.p())
-        .subnet_id(subnet_id)
-        .node_id(node_id)
-        .weight(weight)
-        .start_time(start_time)
-        .end_time(end_time)
-        .check_acceptance(check_acceptance)
-        .poll_initial_wait(Duration::seconds(1).to_std().unwrap())
-        .issue()
-        .await
-        .map_err(|e| AvalancheWalletError::IssueTx {
-            blockchain_name: "P-Chain".to_string(),
-            tx_type: "add_subnet_validator".to_string(),
-            msg: format!("failed to add '{node_id}' as validator to Subnet '{subnet_id}': {e}"),
-        })?;
+    let pchain_wallet = &wallet.pchain_wallet;
+    let rpc_url = &format!("{}/ext/bc/P", pchain_wallet.pick_base_http_url().1);
 
-    // Check if the validator was successfully added
-    // If the validator is already a validator, tx_id is empty and success false
-    match success {
-        true => Ok(tx_id),
-        false => {
-            if Id::is_empty(&tx_id) {
-                Err(AvalancheWalletError::IssueTx {
-                    blockchain_name: "P-Chain".to_string(),
-                    tx_type: "add_validator".to_string(),
-                    msg: format!("'{node_id}' is already a validator to Subnet '{subnet_id}'"),
-                }
-                .into())
-            } else {
-                // This is theoretically unreachable
-                Err(AvalancheWalletError::IssueTx {
-                    blockchain_name: "P-Chain".to_string(),
-                    tx_type: "add_validator".to_string(),
-                    msg: format!(
-                        "failed to add '{node_id}' as validator to Subnet '{subnet_id}': Unknown error"
-                    ),
-                }
-                .into())
-            }
+    let utxos = platformvm::get_utxos(
+        rpc_url,
+        std::slice::from_ref(&pchain_wallet.p_address),
+        Some(source_chain_id),
+    )?;
+    let utxos = filter_export_utxos(utxos, export_tx_id);
+    let (inputs, signers, imported_amount) = spend_avax_utxos(pchain_wallet, &utxos, None);
+
+    if imported_amount <= pchain_wallet.tx_fee {
+        return Err(AvalancheWalletError::IssueTx {
+            blockchain_name: "P-Chain".to_string(),
+            tx_type: "import".to_string(),
+            msg: format!(
+                "not enough AVAX to import from '{source_chain_id}' to pay the fee: {imported_amount} nAVAX available"
+            ),
         }
+        .into());
     }
-}
 
-/// Add a validator to a permissionless Subnet (e.g. Primary Network)
-pub async fn add_permissionless_subnet_validator(
-    wallet: &AvalancheWallet,
-    node_id: NodeId,
-    subnet_id: Id,
-    stake_amount: u64,
-    start_time: DateTime<Utc>,
-    end_time: DateTime<Utc>,
-    reward_fee_percent: u32,
-    signer: Option<ProofOfPossession>,
-    check_acceptance: bool,
-) -> Result<Id, AshError> {
-    let (tx_id, success) = p::add_permissionless_validator::Tx::new(&wallet.pchain_wallet.p())
-        .node_id(node_id)
-        // avalanche-types requires the subnet_id to be empty for the Primary Network
-        .subnet_id(match subnet_id.to_string().as_str() {
-            AVAX_PRIMARY_NETWORK_ID => Id::empty(),
-            _ => subnet_id,
-        })
-        .stake_amount(stake_amount)
-        .start_time(start_time)
-        .end_time(end_time)
-        .reward_fee_percent(reward_fee_percent)
-        .proof_of_possession(signer.unwrap_or_default())
-        .check_acceptance(check_acceptance)
-        .poll_initial_wait(Duration::seconds(1).to_std().unwrap())
-        .issue()
+    let mut tx = import::Tx {
+        base_tx: txs::Tx {
+            network_id: pchain_wallet.network_id,
+            blockchain_id: pchain_wallet.blockchain_id_p,
+            transferable_outputs: Some(vec![wallet_avax_output(
+                pchain_wallet,
+                imported_amount - pchain_wallet.tx_fee,
+            )]),
+            ..Default::default()
+        },
+        source_chain_id,
+        source_chain_transferable_inputs: Some(inputs),
+        ..Default::default()
+    };
+    tx.sign(signers)
         .await
         .map_err(|e| AvalancheWalletError::IssueTx {
             blockchain_name: "P-Chain".to_string(),
-            tx_type: "add_validator".to_string(),
-            msg: format!("failed to add '{node_id}' as Avalanche validator: {e}"),
+            tx_type: "import".to_string(),
+            msg: format!("failed to sign transaction: {e}"),
         })?;
 
-    // Check if the validator was successfully added
-    // If the validator is already a validator, tx_id is empty and success false
-    match success {
-        true => Ok(tx_id),
-        false => {
-            if Id::is_empty(&tx_id) {
-                Err(AvalancheWalletError::IssueTx {
-                    blockchain_name: "P-Chain".to_string(),
-                    tx_type: "add_validator".to_string(),
-                    msg: format!("'{node_id}' is already an Avalanche validator"),
-                }
-                .into())
-            } else {
-                // This is theoretically unreachable
-                Err(AvalancheWalletError::IssueTx {
-                    blockchain_name: "P-Chain".to_string(),
-                    tx_type: "add_validator".to_string(),
-                    msg: format!("failed to add '{node_id}' as Avalanche validator: Unknown error"),
-                }
-                .into())
-            }
-        }
+    let tx_id = platformvm::issue_tx(
+        rpc_url,
+        &tx.base_tx.metadata.unwrap().tx_bytes_with_signatures,
+    )
+    .map_err(|e| AvalancheWalletError::IssueTx {
+        blockchain_name: "P-Chain".to_string(),
+        tx_type: "import".to_string(),
+        msg: format!("failed to import AVAX from '{source_chain_id}': {e}"),
+    })?;
+
+    if check_acceptance {
+        wait_tx_acceptance("P-Chain", tx_id, || {
+            platformvm::get_tx_status(rpc_url, tx_id)
+        })
+        .await?;
     }
+
+    Ok(tx_id)
 }
 
 #[cfg(test)]
@@ -177,16 +1517,43 @@ mod tests {
     use super::*;
     use crate::avalanche::{
         nodes::generate_node_bls_key,
+        txs::SECP256K1FX_TRANSFER_OUTPUT_TYPE_ID,
         vms::{encode_genesis_data, subnet_evm::AVAX_SUBNET_EVM_ID, AvalancheVmType},
         AvalancheNetwork,
     };
-    use chrono::Duration;
-    use std::{fs, str::FromStr};
+    use avalanche_types::{
+        ids::short::Id as ShortId,
+        key::secp256k1::{private_key::Key as PrivateKey, txs::transfer},
+    };
+    use chrono::{Duration, TimeZone};
+    use std::fs;
 
     const AVAX_EWOQ_PRIVATE_KEY: &str =
         "PrivateKey-ewoqjP7PxY4yr3iLTpLisriqt94hdyDFNgchSxGGztUrTXtNN";
     const NETWORK_RUNNER_PCHAIN_ADDR: &str = "P-custom18jma8ppw3nhx5r4ap8clazz0dps7rv5u9xde7p";
     const NETWORK_RUNNER_NODE_ID: &str = "NodeID-7Xhw2mDxuDS44j42TCB6U5579esbSt3Lg";
+    const EWOQ_SHORT_ADDRESS: &str = "6Y3kysjF9jnHnYkdS9yGAuoHyae2eNmeV";
+
+    fn ewoq_owners() -> OutputOwners {
+        OutputOwners {
+            locktime: 0,
+            threshold: 1,
+            addresses: vec![ShortId::from_str(EWOQ_SHORT_ADDRESS).unwrap()],
+        }
+    }
+
+    fn fuji_staking_rules() -> AvalancheStakingRules {
+        AvalancheStakingRules {
+            min_validator_stake: 1_000_000_000,
+            min_delegator_stake: 1_000_000_000,
+            max_validator_weight: PRIMARY_NETWORK_MAX_VALIDATOR_WEIGHT,
+            max_validator_weight_factor: PRIMARY_NETWORK_MAX_VALIDATOR_WEIGHT_FACTOR,
+            min_stake_duration: 86_400,
+            max_stake_duration: 365 * 86_400,
+            min_delegation_fee: PRIMARY_NETWORK_MIN_DELEGATION_FEE,
+            max_delegation_fee: PRIMARY_NETWORK_MAX_DELEGATION_FEE,
+        }
+    }
 
     // Load the test network using avalanche-network-runner
     fn load_test_network() -> AvalancheNetwork {
@@ -251,6 +1618,202 @@ mod tests {
         assert_eq!(blockchain.vm_id, Id::from_str(AVAX_SUBNET_EVM_ID).unwrap());
     }
 
+    #[test]
+    fn test_simulate_tx_content() {
+        let avax_asset_id = Id::from_slice(&[2; 32]);
+        let input = |tx_id: u8, amount: u64| transferable::Input {
+            utxo_id: txs::utxo::Id {
+                tx_id: Id::from_slice(&[tx_id; 32]),
+                output_index: 1,
+                ..Default::default()
+            },
+            asset_id: avax_asset_id,
+            transfer_input: Some(transfer::Input {
+                amount,
+                sig_indices: vec![0],
+            }),
+            ..Default::default()
+        };
+        let output = |amount: u64| transferable::Output {
+            asset_id: avax_asset_id,
+            transfer_output: Some(transfer::Output {
+                amount,
+                output_owners: ewoq_owners(),
+            }),
+            ..Default::default()
+        };
+
+        // Validator staking 2 AVAX from 3 AVAX, with a 0.1 AVAX fee
+        let content = AvalanchePartialTxContent::AddPermissionlessValidator(
+            add_permissionless_validator::Tx {
+                base_tx: txs::Tx {
+                    network_id: 1337,
+                    transferable_inputs: Some(vec![
+                        input(1, 2_000_000_000),
+                        input(2, 1_000_000_000),
+                    ]),
+                    transferable_outputs: Some(vec![output(900_000_000)]),
+                    ..Default::default()
+                },
+                stake_transferable_outputs: Some(vec![output(2_000_000_000)]),
+                ..Default::default()
+            },
+        );
+        let simulation = simulate_tx_content(&content, "custom", 5_000_000_000).unwrap();
+
+        assert_eq!(simulation.tx_type, "add_permissionless_validator");
+        assert_eq!(
+            simulation
+                .consumed_utxos
+                .iter()
+                .map(|input| (input.tx_id, input.output_index, input.amount))
+                .collect::<Vec<_>>(),
+            vec![
+                (Id::from_slice(&[1; 32]), 1, 2_000_000_000),
+                (Id::from_slice(&[2; 32]), 1, 1_000_000_000)
+            ]
+        );
+        assert_eq!(simulation.stake, 2_000_000_000);
+        assert_eq!(simulation.fee, 100_000_000);
+        assert_eq!(simulation.change_outputs.len(), 1);
+        assert_eq!(simulation.change_outputs[0].amount, 900_000_000);
+        assert_eq!(
+            simulation.change_outputs[0].addresses,
+            vec![NETWORK_RUNNER_PCHAIN_ADDR.to_string()]
+        );
+        assert_eq!(simulation.balance_after, 2_900_000_000);
+    }
+
+    #[async_std::test]
+    #[serial_test::serial]
+    #[ignore]
+    async fn test_simulate_partial_tx() {
+        let local_network = load_test_network();
+        let local_wallet = local_network
+            .create_wallet_from_cb58(AVAX_EWOQ_PRIVATE_KEY)
+            .unwrap();
+
+        let partial_tx = build_create_subnet_partial_tx(&local_wallet).await.unwrap();
+        let simulation = simulate_partial_tx(&local_wallet, &partial_tx).unwrap();
+
+        assert_eq!(simulation.tx_type, "create_subnet");
+        assert_eq!(
+            simulation.fee,
+            local_wallet.pchain_wallet.create_subnet_tx_fee
+        );
+        assert_eq!(simulation.stake, 0);
+        assert!(!simulation.consumed_utxos.is_empty());
+        assert!(simulation
+            .change_outputs
+            .iter()
+            .all(|output| output.addresses == vec![NETWORK_RUNNER_PCHAIN_ADDR.to_string()]));
+        assert_eq!(
+            simulation.balance_after,
+            simulation.balance_before - simulation.fee
+        );
+
+        // Nothing is issued by the simulation
+        let simulation_again = simulate_partial_tx(&local_wallet, &partial_tx).unwrap();
+        assert_eq!(simulation_again.balance_before, simulation.balance_before);
+    }
+
+    #[async_std::test]
+    #[serial_test::serial]
+    #[ignore]
+    async fn test_create_subnet_partial_tx() {
+        let mut local_network = load_test_network();
+        let local_wallet = local_network
+            .create_wallet_from_cb58(AVAX_EWOQ_PRIVATE_KEY)
+            .unwrap();
+
+        // The transaction is built by a watch-only wallet and signed offline
+        let watch_only_wallet = local_network
+            .create_wallet_from_public_key(&hex::encode(
+                local_wallet.signer.public_key().to_compressed_bytes(),
+            ))
+            .unwrap();
+        let mut partial_tx = build_create_subnet_partial_tx(&watch_only_wallet)
+            .await
+            .unwrap();
+        assert!(partial_tx.sign(&watch_only_wallet.signer).await.is_err());
+        assert_eq!(
+            partial_tx.missing_signers(),
+            vec![NETWORK_RUNNER_PCHAIN_ADDR.to_string()]
+        );
+
+        partial_tx.sign(&local_wallet.signer).await.unwrap();
+        let tx_id = partial_tx.issue(&local_network, true).await.unwrap();
+
+        local_network.update_subnets().unwrap();
+        let subnet = local_network.get_subnet(tx_id).unwrap();
+        assert_eq!(
+            subnet.control_keys,
+            vec![NETWORK_RUNNER_PCHAIN_ADDR.to_string()]
+        );
+    }
+
+    #[async_std::test]
+    #[serial_test::serial]
+    #[ignore]
+    async fn test_create_blockchain_partial_tx() {
+        let mut local_network = load_test_network();
+        let local_wallet = local_network
+            .create_wallet_from_cb58(AVAX_EWOQ_PRIVATE_KEY)
+            .unwrap();
+        let genesis_str = fs::read_to_string("tests/genesis/subnet-evm.json").unwrap();
+        let genesis_data = encode_genesis_data(AvalancheVmType::SubnetEVM, &genesis_str).unwrap();
+
+        // Create a Subnet to create the Blockchain on
+        let subnet_id = create_subnet(&local_wallet, true).await.unwrap();
+        local_network.update_subnets().unwrap();
+        let subnet = local_network.get_subnet(subnet_id).unwrap();
+
+        let mut partial_tx = build_create_blockchain_partial_tx(
+            &local_wallet,
+            subnet,
+            genesis_data,
+            Id::from_str(AVAX_SUBNET_EVM_ID).unwrap(),
+            "testCreateBlockchainPartialTx",
+            &[],
+        )
+        .await
+        .unwrap();
+
+        // The wallet key pays the fee and is the only control key of the Subnet
+        assert_eq!(
+            partial_tx.missing_signers(),
+            vec![NETWORK_RUNNER_PCHAIN_ADDR.to_string()]
+        );
+        partial_tx.sign(&local_wallet.signer).await.unwrap();
+        assert!(partial_tx.is_fully_signed());
+
+        let tx_id = partial_tx.issue(&local_network, true).await.unwrap();
+
+        local_network.update_blockchains().unwrap();
+        let blockchain = local_network
+            .get_subnet(subnet_id)
+            .unwrap()
+            .get_blockchain(tx_id)
+            .unwrap();
+        assert_eq!(blockchain.name, "testCreateBlockchainPartialTx");
+
+        // Only the control keys of the Subnet can authorize the transaction
+        assert!(build_create_blockchain_partial_tx(
+            &local_wallet,
+            local_network.get_subnet(subnet_id).unwrap(),
+            vec![],
+            Id::from_str(AVAX_SUBNET_EVM_ID).unwrap(),
+            "testCreateBlockchainPartialTx",
+            &[PrivateKey::generate()
+                .unwrap()
+                .to_public_key()
+                .to_hrp_address(local_wallet.pchain_wallet.network_id, "P")
+                .unwrap()],
+        )
+        .await
+        .is_err());
+    }
+
     #[async_std::test]
     #[serial_test::serial]
     #[ignore]
@@ -267,9 +1830,11 @@ mod tests {
         // The validator is added with a start time of 20 seconds from now and an end time of 24 hours from now
         let start_time = Utc::now() + Duration::seconds(20);
         let end_time = Utc::now() + Duration::seconds(86420);
+        local_network.update_subnets().unwrap();
+        let subnet = local_network.get_subnet(subnet_id).unwrap().clone();
         add_permissioned_subnet_validator(
             &local_wallet,
-            subnet_id,
+            &subnet,
             NodeId::from_str(NETWORK_RUNNER_NODE_ID).unwrap(),
             100,
             start_time,
@@ -297,11 +1862,12 @@ mod tests {
             &local_wallet,
             NodeId::from_str(NETWORK_RUNNER_NODE_ID).unwrap(),
             Id::from_str(AVAX_PRIMARY_NETWORK_ID).unwrap(),
-            1 * 1_000_000_000,
+            1_000_000_000,
             start_time,
             end_time,
             2,
             Some(pop),
+            &ewoq_owners(),
             true,
         )
         .await;
@@ -321,4 +1887,319 @@ mod tests {
             ))
         )
     }
+
+    #[async_std::test]
+    #[serial_test::serial]
+    #[ignore]
+    async fn test_remove_subnet_validator() {
+        let mut local_network = load_test_network();
+        let local_wallet = local_network
+            .create_wallet_from_cb58(AVAX_EWOQ_PRIVATE_KEY)
+            .unwrap();
+        let node_id = NodeId::from_str(NETWORK_RUNNER_NODE_ID).unwrap();
+
+        // Create a Subnet and add a validator to it
+        let subnet_id = create_subnet(&local_wallet, true).await.unwrap();
+        let start_time = Utc::now() + Duration::seconds(20);
+        let end_time = Utc::now() + Duration::seconds(86420);
+        local_network.update_subnets().unwrap();
+        let subnet = local_network.get_subnet(subnet_id).unwrap().clone();
+        add_permissioned_subnet_validator(
+            &local_wallet,
+            &subnet,
+            node_id,
+            100,
+            start_time,
+            end_time,
+            true,
+        )
+        .await
+        .unwrap();
+
+        // Remove the validator before its end time
+        local_network.update_subnets().unwrap();
+        let subnet = local_network.get_subnet(subnet_id).unwrap().clone();
+        remove_subnet_validator(&local_wallet, &subnet, node_id, true)
+            .await
+            .unwrap();
+
+        // Check that the validator was removed
+        local_network.update_subnet_validators(subnet_id).unwrap();
+        assert!(local_network
+            .get_subnet(subnet_id)
+            .unwrap()
+            .get_validator(node_id)
+            .is_err());
+    }
+
+    #[test]
+    fn test_unsigned_add_permissionless_delegator_tx_pack() {
+        let tx = UnsignedAddPermissionlessDelegatorTx {
+            base_tx: txs::Tx {
+                network_id: 5,
+                transferable_outputs: Some(vec![]),
+                transferable_inputs: Some(vec![]),
+                ..Default::default()
+            },
+            validator: PlatformVmValidator {
+                node_id: NodeId::from_str(NETWORK_RUNNER_NODE_ID).unwrap(),
+                start: 1_000,
+                end: 2_000,
+                weight: 25_000_000_000,
+            },
+            subnet_id: Id::empty(),
+            stake_outputs: vec![transferable::Output {
+                transfer_output: Some(transfer::Output {
+                    amount: 25_000_000_000,
+                    output_owners: ewoq_owners(),
+                }),
+                ..Default::default()
+            }],
+            rewards_owner: ewoq_owners(),
+        };
+        let bytes = tx.pack().unwrap();
+
+        // Base tx (54) + validator (44) + Subnet ID (32) + 1 stake output (4 + 32 + 48)
+        // + rewards owner (4 + 36)
+        assert_eq!(bytes.len(), 254);
+        assert_eq!(bytes[..6], [0, 0, 0, 0, 0, 26]);
+        assert_eq!(
+            bytes[54..74],
+            *NodeId::from_str(NETWORK_RUNNER_NODE_ID).unwrap().as_ref()
+        );
+        assert_eq!(bytes[74..82], 1_000u64.to_be_bytes());
+        assert_eq!(bytes[82..90], 2_000u64.to_be_bytes());
+        assert_eq!(bytes[90..98], 25_000_000_000u64.to_be_bytes());
+        assert_eq!(bytes[98..130], [0; 32]);
+        assert_eq!(bytes[130..134], 1u32.to_be_bytes());
+        assert_eq!(
+            bytes[166..170],
+            SECP256K1FX_TRANSFER_OUTPUT_TYPE_ID.to_be_bytes()
+        );
+        assert_eq!(bytes[170..178], 25_000_000_000u64.to_be_bytes());
+        assert_eq!(
+            bytes[214..218],
+            SECP256K1FX_OUTPUT_OWNERS_TYPE_ID.to_be_bytes()
+        );
+        assert_eq!(bytes[226..230], 1u32.to_be_bytes());
+        assert_eq!(
+            bytes[234..],
+            *ShortId::from_str(EWOQ_SHORT_ADDRESS).unwrap().as_ref()
+        );
+    }
+
+    #[test]
+    fn test_unsigned_remove_subnet_validator_tx_pack() {
+        let tx = UnsignedRemoveSubnetValidatorTx {
+            base_tx: txs::Tx {
+                network_id: 5,
+                transferable_outputs: Some(vec![]),
+                transferable_inputs: Some(vec![]),
+                ..Default::default()
+            },
+            node_id: NodeId::from_str(NETWORK_RUNNER_NODE_ID).unwrap(),
+            subnet_id: Id::from_slice(&[1; 32]),
+            subnet_auth: SubnetAuth {
+                sig_indices: vec![0, 2],
+            },
+        };
+        let bytes = tx.pack().unwrap();
+
+        // Base tx (54) + node ID (20) + Subnet ID (32) + Subnet auth (4 + 4 + 8)
+        assert_eq!(bytes.len(), 122);
+        assert_eq!(bytes[..6], [0, 0, 0, 0, 0, 23]);
+        assert_eq!(
+            bytes[54..74],
+            *NodeId::from_str(NETWORK_RUNNER_NODE_ID).unwrap().as_ref()
+        );
+        assert_eq!(bytes[74..106], [1; 32]);
+        assert_eq!(bytes[106..110], SECP256K1FX_INPUT_TYPE_ID.to_be_bytes());
+        assert_eq!(bytes[110..114], 2u32.to_be_bytes());
+        assert_eq!(bytes[114..118], 0u32.to_be_bytes());
+        assert_eq!(bytes[118..122], 2u32.to_be_bytes());
+    }
+
+    #[test]
+    fn test_staking_rules_check_validation() {
+        let rules = fuji_staking_rules();
+        let start_time = Utc.timestamp_opt(1_700_000_000, 0).unwrap();
+
+        assert!(rules
+            .check_validation(1_000_000_000, start_time, start_time + Duration::days(7), 2)
+            .is_ok());
+
+        // Stake lower than the minimum
+        assert_eq!(
+            rules.check_validation(999_999_999, start_time, start_time + Duration::days(7), 2),
+            Err(AshError::AvalancheSubnetError(
+                AvalancheSubnetError::StakingRulesViolation {
+                    subnet_id: AVAX_PRIMARY_NETWORK_ID.to_string(),
+                    msg: "validator stake of 999999999 nAVAX is lower than the minimum of 1000000000 nAVAX".to_string(),
+                }
+            ))
+        );
+        // Stake higher than the maximum weight
+        assert!(rules
+            .check_validation(
+                PRIMARY_NETWORK_MAX_VALIDATOR_WEIGHT + 1,
+                start_time,
+                start_time + Duration::days(7),
+                2
+            )
+            .is_err());
+        // Duration shorter than the minimum or longer than the maximum
+        assert!(rules
+            .check_validation(
+                1_000_000_000,
+                start_time,
+                start_time + Duration::hours(1),
+                2
+            )
+            .is_err());
+        assert!(rules
+            .check_validation(
+                1_000_000_000,
+                start_time,
+                start_time + Duration::days(366),
+                2
+            )
+            .is_err());
+        // End time before the start time
+        assert!(rules
+            .check_validation(1_000_000_000, start_time, start_time - Duration::days(7), 2)
+            .is_err());
+        // Delegation fee lower than the minimum or higher than the maximum
+        assert_eq!(
+            rules.check_validation(1_000_000_000, start_time, start_time + Duration::days(7), 1),
+            Err(AshError::AvalancheSubnetError(
+                AvalancheSubnetError::StakingRulesViolation {
+                    subnet_id: AVAX_PRIMARY_NETWORK_ID.to_string(),
+                    msg: "delegation fee of 1% is not between 2% and 100%".to_string(),
+                }
+            ))
+        );
+        assert!(rules
+            .check_validation(
+                1_000_000_000,
+                start_time,
+                start_time + Duration::days(7),
+                100
+            )
+            .is_ok());
+        assert!(rules
+            .check_validation(
+                1_000_000_000,
+                start_time,
+                start_time + Duration::days(7),
+                u32::MAX
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn test_staking_rules_check_delegation() {
+        let rules = fuji_staking_rules();
+        let validator = AvalancheSubnetValidator {
+            node_id: NodeId::from_str(NETWORK_RUNNER_NODE_ID).unwrap(),
+            start_time: 1_700_000_000,
+            end_time: 1_700_000_000 + 30 * 86_400,
+            stake_amount: Some(2_000_000_000_000),
+            delegator_weight: Some(5_000_000_000_000),
+            ..Default::default()
+        };
+        let start_time = Utc.timestamp_opt(1_700_000_000 + 86_400, 0).unwrap();
+
+        assert!(rules
+            .check_delegation(
+                &validator,
+                1_000_000_000,
+                start_time,
+                start_time + Duration::days(7)
+            )
+            .is_ok());
+
+        // Stake lower than the minimum
+        assert!(rules
+            .check_delegation(
+                &validator,
+                100_000_000,
+                start_time,
+                start_time + Duration::days(7)
+            )
+            .is_err());
+        // Duration shorter than the minimum
+        assert!(rules
+            .check_delegation(
+                &validator,
+                1_000_000_000,
+                start_time,
+                start_time + Duration::hours(1)
+            )
+            .is_err());
+        // Delegation ending after the validation
+        assert!(rules
+            .check_delegation(
+                &validator,
+                1_000_000_000,
+                start_time,
+                start_time + Duration::days(60)
+            )
+            .is_err());
+        // Validator weight above 5 times its stake
+        assert_eq!(
+            rules.check_delegation(
+                &validator,
+                3_000_000_000_001,
+                start_time,
+                start_time + Duration::days(7)
+            ),
+            Err(AshError::AvalancheSubnetError(
+                AvalancheSubnetError::StakingRulesViolation {
+                    subnet_id: AVAX_PRIMARY_NETWORK_ID.to_string(),
+                    msg: format!(
+                        "delegating 3000000000001 nAVAX would bring the weight of '{}' to 10000000000001 nAVAX, above the maximum of 10000000000000 nAVAX",
+                        validator.node_id
+                    ),
+                }
+            ))
+        );
+    }
+
+    #[async_std::test]
+    #[serial_test::serial]
+    #[ignore]
+    async fn test_add_permissionless_delegator() {
+        let mut local_network = load_test_network();
+        let local_wallet = local_network
+            .create_wallet_from_cb58(AVAX_EWOQ_PRIVATE_KEY)
+            .unwrap();
+
+        local_network.update_subnets().unwrap();
+        local_network
+            .update_subnet_validators(Id::from_str(AVAX_PRIMARY_NETWORK_ID).unwrap())
+            .unwrap();
+        let validator = local_network
+            .get_subnet(Id::from_str(AVAX_PRIMARY_NETWORK_ID).unwrap())
+            .unwrap()
+            .get_validator(NodeId::from_str(NETWORK_RUNNER_NODE_ID).unwrap())
+            .unwrap()
+            .clone();
+
+        // Delegate for 24 hours starting 20 seconds from now
+        let start_time = Utc::now() + Duration::seconds(20);
+        let end_time = start_time + Duration::hours(24);
+        let tx_id = add_permissionless_delegator(
+            &local_wallet,
+            &validator,
+            25_000_000_000,
+            start_time,
+            end_time,
+            &rewards_owner(&local_wallet, &[NETWORK_RUNNER_PCHAIN_ADDR.to_string()], 1).unwrap(),
+            true,
+        )
+        .await
+        .unwrap();
+
+        assert_ne!(tx_id, Id::empty());
+    }
 }
@@ -0,0 +1,647 @@
+// SPDX-License-Identifier: BSD-3-Clause
+// Copyright (c) 2023, E36 Knots
+
+// Module that contains code to handle partially signed transactions
+// A partially signed transaction lists the addresses that have to sign each of its credentials,
+// so that several key holders can sign it independently (e.g. the control keys of a Subnet
+// or a key kept on an air-gapped machine) before it is issued once all the signatures are collected
+
+use crate::{
+    avalanche::{
+        address_to_short_id,
+        txs::{issue_signed_tx, AvalanchePrimaryNetworkChain, AvalancheTxEncoding},
+        wallets::AvalancheSignerKey,
+        AvalancheNetwork,
+    },
+    errors::*,
+};
+use async_trait::async_trait;
+use avalanche_types::{
+    avm,
+    errors::{Error as AvalancheTypesError, Result as AvalancheTypesResult},
+    hash,
+    ids::Id,
+    key::secp256k1::{public_key::Key as PublicKey, ReadOnly, SignOnly},
+    platformvm::txs::{
+        add_permissionless_validator, add_subnet_validator, create_chain, create_subnet,
+    },
+    txs,
+};
+use ethers::core::k256::ecdsa::SigningKey;
+use serde::{Deserialize, Serialize};
+
+/// Unsigned X-Chain or P-Chain transaction that has to be signed before being issued
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "type", content = "tx", rename_all = "camelCase")]
+pub enum AvalanchePartialTxContent {
+    CreateSubnet(create_subnet::Tx),
+    CreateChain(create_chain::Tx),
+    AddSubnetValidator(add_subnet_validator::Tx),
+    AddPermissionlessValidator(add_permissionless_validator::Tx),
+    /// X-Chain base transaction (e.g. AVAX transfer)
+    Transfer(avm::txs::Tx),
+}
+
+impl AvalanchePartialTxContent {
+    /// Get the type of the transaction (e.g. `create_chain`)
+    pub fn tx_type(&self) -> String {
+        match self {
+            AvalanchePartialTxContent::CreateSubnet(_) => "create_subnet".to_string(),
+            AvalanchePartialTxContent::CreateChain(_) => "create_chain".to_string(),
+            AvalanchePartialTxContent::AddSubnetValidator(_) => "add_subnet_validator".to_string(),
+            AvalanchePartialTxContent::AddPermissionlessValidator(_) => {
+                "add_permissionless_validator".to_string()
+            }
+            AvalanchePartialTxContent::Transfer(_) => "transfer".to_string(),
+        }
+    }
+
+    /// Get the chain the transaction is issued on
+    pub fn chain(&self) -> AvalanchePrimaryNetworkChain {
+        match self {
+            AvalanchePartialTxContent::Transfer(_) => AvalanchePrimaryNetworkChain::X,
+            _ => AvalanchePrimaryNetworkChain::P,
+        }
+    }
+
+    /// Get the ID of the network the transaction is issued on
+    pub fn network_id(&self) -> u32 {
+        self.base_tx().network_id
+    }
+
+    /// Get the base transaction, which holds the inputs and outputs of the transaction
+    pub fn base_tx(&self) -> &txs::Tx {
+        match self {
+            AvalanchePartialTxContent::CreateSubnet(tx) => &tx.base_tx,
+            AvalanchePartialTxContent::CreateChain(tx) => &tx.base_tx,
+            AvalanchePartialTxContent::AddSubnetValidator(tx) => &tx.base_tx,
+            AvalanchePartialTxContent::AddPermissionlessValidator(tx) => &tx.base_tx,
+            AvalanchePartialTxContent::Transfer(tx) => &tx.base_tx,
+        }
+    }
+
+    // Sign the transaction and return the ID and the bytes of the signed transaction
+    // The unsigned bytes are returned if no signers are provided
+    async fn sign<T: SignOnly + Clone + Send + Sync>(
+        &self,
+        signers: Vec<Vec<T>>,
+    ) -> AvalancheTypesResult<(Id, Vec<u8>, Vec<u8>)> {
+        let metadata = match self.clone() {
+            AvalanchePartialTxContent::CreateSubnet(mut tx) => {
+                tx.sign(signers).await?;
+                tx.base_tx.metadata
+            }
+            AvalanchePartialTxContent::CreateChain(mut tx) => {
+                tx.sign(signers).await?;
+                tx.base_tx.metadata
+            }
+            AvalanchePartialTxContent::AddSubnetValidator(mut tx) => {
+                tx.sign(signers).await?;
+                tx.base_tx.metadata
+            }
+            AvalanchePartialTxContent::AddPermissionlessValidator(mut tx) => {
+                tx.sign(signers).await?;
+                tx.base_tx.metadata
+            }
+            AvalanchePartialTxContent::Transfer(mut tx) => {
+                tx.sign(signers).await?;
+                tx.base_tx.metadata
+            }
+        }
+        .unwrap_or_default();
+
+        Ok((
+            metadata.id,
+            metadata.tx_bytes_with_no_signature,
+            metadata.tx_bytes_with_signatures,
+        ))
+    }
+}
+
+/// Signature expected from an address in a credential of a partially signed transaction
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AvalanchePartialTxSignature {
+    /// X-Chain or P-Chain address that has to sign (on the chain of the transaction)
+    pub address: String,
+    /// Hex-encoded 65-byte signature (if already signed)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signature: Option<String>,
+}
+
+/// Transaction that has to be signed by one or several keys before being issued
+/// The credentials are ordered as in the signed transaction: one per input then one for the Subnet authorization (if any)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AvalanchePartialTx {
+    /// Unsigned transaction
+    pub content: AvalanchePartialTxContent,
+    /// Hex-encoded unsigned transaction bytes, which hash is signed by every key
+    pub unsigned_tx_bytes: String,
+    /// CB58-encoded unsigned transaction bytes (with checksum)
+    #[serde(rename = "unsignedTxCB58")]
+    pub unsigned_tx_cb58: String,
+    /// Signatures expected for each credential of the transaction
+    pub credentials: Vec<Vec<AvalanchePartialTxSignature>>,
+}
+
+impl AvalanchePartialTx {
+    /// Create a new partially signed transaction from an unsigned transaction
+    /// and the addresses that have to sign each of its credentials
+    pub async fn new(
+        content: AvalanchePartialTxContent,
+        credentials_addresses: Vec<Vec<String>>,
+    ) -> Result<Self, AshError> {
+        let (_, unsigned_tx_bytes, _) = content
+            .sign::<AvalancheSignerKey>(vec![])
+            .await
+            .map_err(|e| partial_tx_failure(&content, &e.to_string()))?;
+
+        Ok(Self {
+            content,
+            unsigned_tx_bytes: hex::encode(&unsigned_tx_bytes),
+            unsigned_tx_cb58: AvalancheTxEncoding::Cb58.encode(&unsigned_tx_bytes),
+            credentials: credentials_addresses
+                .into_iter()
+                .map(|addresses| {
+                    addresses
+                        .into_iter()
+                        .map(|address| AvalanchePartialTxSignature {
+                            address,
+                            signature: None,
+                        })
+                        .collect()
+                })
+                .collect(),
+        })
+    }
+
+    /// Pack the unsigned transaction from its content and check that it matches the unsigned transaction bytes
+    /// Fail if the transaction file was tampered with (e.g. the content shown differs from the bytes signed)
+    /// Return the unsigned transaction bytes
+    pub async fn verify(&self) -> Result<Vec<u8>, AshError> {
+        let (_, unsigned_tx_bytes, _) = self
+            .content
+            .sign::<AvalancheSignerKey>(vec![])
+            .await
+            .map_err(|e| partial_tx_failure(&self.content, &e.to_string()))?;
+
+        if hex::encode(&unsigned_tx_bytes) != self.unsigned_tx_bytes.trim_start_matches("0x")
+            || AvalancheTxEncoding::Cb58.encode(&unsigned_tx_bytes) != self.unsigned_tx_cb58
+        {
+            return Err(partial_tx_failure(
+                &self.content,
+                "the unsigned transaction bytes do not match the transaction content",
+            )
+            .into());
+        }
+
+        Ok(unsigned_tx_bytes)
+    }
+
+    /// Get the SHA256 digest of the unsigned transaction, which is signed by every key
+    /// The unsigned transaction is packed from its content and checked against the unsigned transaction bytes
+    pub async fn digest(&self) -> Result<Vec<u8>, AshError> {
+        Ok(hash::sha256(self.verify().await?))
+    }
+
+    /// Get the addresses whose signature is still missing (without duplicates)
+    pub fn missing_signers(&self) -> Vec<String> {
+        let mut missing_signers: Vec<String> = vec![];
+
+        for signature in self.credentials.iter().flatten() {
+            if signature.signature.is_none() && !missing_signers.contains(&signature.address) {
+                missing_signers.push(signature.address.clone());
+            }
+        }
+
+        missing_signers
+    }
+
+    /// Check if all the signatures have been collected
+    pub fn is_fully_signed(&self) -> bool {
+        self.missing_signers().is_empty()
+    }
+
+    /// Sign the transaction with a key (e.g. the P-Chain key of a wallet)
+    /// Every credential expecting a signature from the key address is signed
+    /// Return the number of signatures added
+    pub async fn sign(&mut self, signer: &AvalancheSignerKey) -> Result<usize, AshError> {
+        let digest = self.digest().await?;
+        let chain_alias = self.content.chain().to_string();
+        let short_address = signer
+            .short_address()
+            .map_err(|e| partial_tx_failure(&self.content, &e.to_string()))?;
+
+        let mut expected_signatures = vec![];
+        for expected_signature in self.credentials.iter_mut().flatten() {
+            if address_to_short_id(&expected_signature.address, &chain_alias)? == short_address {
+                expected_signatures.push(expected_signature);
+            }
+        }
+
+        if expected_signatures.is_empty() {
+            return Err(partial_tx_failure(
+                &self.content,
+                &format!(
+                    "'{}' is not a signer of the transaction",
+                    signer
+                        .hrp_address(self.content.network_id(), &chain_alias)
+                        .unwrap_or(short_address.to_string())
+                ),
+            )
+            .into());
+        }
+
+        let signature = hex::encode(signer.0.sign_digest(&digest).await?);
+
+        let mut signatures_count = 0;
+        for expected_signature in expected_signatures {
+            if expected_signature.signature.is_none() {
+                expected_signature.signature = Some(signature.clone());
+                signatures_count += 1;
+            }
+        }
+
+        Ok(signatures_count)
+    }
+
+    /// Merge the signatures of another copy of the same transaction
+    /// Both copies are checked against their content and every signature is checked against the address it is expected from
+    /// Return the number of signatures added
+    pub async fn merge(&mut self, other: &AvalanchePartialTx) -> Result<usize, AshError> {
+        let digest = self.digest().await?;
+        if other.digest().await? != digest
+            || self.content != other.content
+            || self.credentials.len() != other.credentials.len()
+        {
+            return Err(partial_tx_failure(
+                &self.content,
+                "cannot merge signatures of different transactions",
+            )
+            .into());
+        }
+
+        let chain_alias = self.content.chain().to_string();
+        let mut signatures_count = 0;
+
+        for (credential, other_credential) in
+            self.credentials.iter_mut().zip(other.credentials.iter())
+        {
+            for (signature, other_signature) in credential.iter_mut().zip(other_credential.iter()) {
+                let Some(other_signature_hex) = &other_signature.signature else {
+                    continue;
+                };
+                if signature.signature.is_some() {
+                    continue;
+                }
+
+                verify_signature(
+                    &digest,
+                    &chain_alias,
+                    &signature.address,
+                    other_signature_hex,
+                )
+                .map_err(|msg| partial_tx_failure(&self.content, &msg))?;
+                signature.signature = Some(other_signature_hex.clone());
+                signatures_count += 1;
+            }
+        }
+
+        Ok(signatures_count)
+    }
+
+    /// Assemble the signed transaction once all the signatures are collected
+    /// Return the ID and the bytes of the signed transaction
+    pub async fn signed_tx(&self) -> Result<(Id, Vec<u8>), AshError> {
+        let missing_signers = self.missing_signers();
+        if !missing_signers.is_empty() {
+            return Err(partial_tx_failure(
+                &self.content,
+                &format!(
+                    "missing signatures from {}",
+                    missing_signers
+                        .iter()
+                        .map(|address| format!("'{address}'"))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+            )
+            .into());
+        }
+
+        let digest = self.digest().await?;
+        let chain_alias = self.content.chain().to_string();
+        let mut signers = vec![];
+        for credential in self.credentials.iter() {
+            let mut credential_signers = vec![];
+            for signature in credential.iter() {
+                let signature_hex = signature.signature.clone().unwrap_or_default();
+                let signature_bytes =
+                    verify_signature(&digest, &chain_alias, &signature.address, &signature_hex)
+                        .map_err(|msg| partial_tx_failure(&self.content, &msg))?;
+                credential_signers.push(CollectedSignature(signature_bytes));
+            }
+            signers.push(credential_signers);
+        }
+
+        let (tx_id, _, signed_tx_bytes) = self
+            .content
+            .sign(signers)
+            .await
+            .map_err(|e| partial_tx_failure(&self.content, &e.to_string()))?;
+
+        Ok((tx_id, signed_tx_bytes))
+    }
+
+    /// Issue the transaction on its chain once all the signatures are collected
+    /// Return the ID of the transaction
+    pub async fn issue(
+        &self,
+        network: &AvalancheNetwork,
+        check_acceptance: bool,
+    ) -> Result<Id, AshError> {
+        let (_, signed_tx_bytes) = self.signed_tx().await?;
+
+        issue_signed_tx(
+            network,
+            self.content.chain(),
+            &self.content.tx_type(),
+            &signed_tx_bytes,
+            check_acceptance,
+        )
+        .await
+    }
+}
+
+// Signature collected from a key holder, used to assemble the signed transaction
+#[derive(Debug, Clone)]
+struct CollectedSignature([u8; 65]);
+
+#[async_trait]
+impl SignOnly for CollectedSignature {
+    fn signing_key(&self) -> AvalancheTypesResult<SigningKey> {
+        Err(AvalancheTypesError::Other {
+            message: "the signing key of a collected signature is not available".to_string(),
+            retryable: false,
+        })
+    }
+
+    async fn sign_digest(&self, _digest: &[u8]) -> AvalancheTypesResult<[u8; 65]> {
+        Ok(self.0)
+    }
+}
+
+// Check that an hex-encoded signature of a digest was produced by the key of an address
+fn verify_signature(
+    digest: &[u8],
+    chain_alias: &str,
+    address: &str,
+    signature_hex: &str,
+) -> Result<[u8; 65], String> {
+    let signature: [u8; 65] = hex::decode(signature_hex.trim_start_matches("0x"))
+        .map_err(|e| format!("invalid signature from '{address}': {e}"))?
+        .try_into()
+        .map_err(|_| format!("invalid signature from '{address}': not 65 bytes long"))?;
+
+    let expected_short_id = address_to_short_id(address, chain_alias).map_err(|e| e.to_string())?;
+    match PublicKey::from_signature(digest, &signature).and_then(|key| key.to_short_id()) {
+        Ok(short_id) if short_id == expected_short_id => Ok(signature),
+        _ => Err(format!("signature does not match the address '{address}'")),
+    }
+}
+
+fn partial_tx_failure(content: &AvalanchePartialTxContent, msg: &str) -> AvalancheWalletError {
+    AvalancheWalletError::PartialTxFailure {
+        tx_type: content.tx_type(),
+        msg: msg.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::avalanche::wallets::LocalSigner;
+    use avalanche_types::{
+        key::secp256k1::{
+            private_key::Key as PrivateKey,
+            txs::{transfer, Input as SubnetAuth},
+        },
+        txs::{self, transferable, utxo},
+    };
+
+    const AVAX_EWOQ_PRIVATE_KEY: &str =
+        "PrivateKey-ewoqjP7PxY4yr3iLTpLisriqt94hdyDFNgchSxGGztUrTXtNN";
+
+    // Create a blockchain creation transaction with one input, authorized by the control keys at the given indices
+    fn create_chain_tx(sig_indices: Vec<u32>) -> AvalanchePartialTxContent {
+        AvalanchePartialTxContent::CreateChain(create_chain::Tx {
+            base_tx: txs::Tx {
+                network_id: 1,
+                transferable_inputs: Some(vec![transferable::Input {
+                    utxo_id: utxo::Id {
+                        tx_id: Id::from_slice(&[1; 32]),
+                        output_index: 0,
+                        ..Default::default()
+                    },
+                    asset_id: Id::from_slice(&[2; 32]),
+                    transfer_input: Some(transfer::Input {
+                        amount: 1_000_000_000,
+                        sig_indices: vec![0],
+                    }),
+                    ..Default::default()
+                }]),
+                ..Default::default()
+            },
+            subnet_id: Id::from_slice(&[3; 32]),
+            chain_name: "testPartialTx".to_string(),
+            vm_id: Id::from_slice(&[4; 32]),
+            genesis_data: vec![5; 32],
+            subnet_auth: SubnetAuth { sig_indices },
+            ..Default::default()
+        })
+    }
+
+    fn p_address(private_key: &PrivateKey) -> String {
+        private_key.to_public_key().to_hrp_address(1, "P").unwrap()
+    }
+
+    #[async_std::test]
+    async fn test_partial_tx_offline_sign() {
+        let ewoq_key = PrivateKey::from_cb58(AVAX_EWOQ_PRIVATE_KEY).unwrap();
+        let x_address = ewoq_key.to_public_key().to_hrp_address(1, "X").unwrap();
+
+        // X-Chain transfer with two inputs spent by the same key
+        let input = |tx_id: u8| transferable::Input {
+            utxo_id: utxo::Id {
+                tx_id: Id::from_slice(&[tx_id; 32]),
+                output_index: 0,
+                ..Default::default()
+            },
+            asset_id: Id::from_slice(&[2; 32]),
+            transfer_input: Some(transfer::Input {
+                amount: 1_000_000_000,
+                sig_indices: vec![0],
+            }),
+            ..Default::default()
+        };
+        let content = AvalanchePartialTxContent::Transfer(avm::txs::Tx::new(txs::Tx {
+            network_id: 1,
+            transferable_inputs: Some(vec![input(1), input(2)]),
+            ..Default::default()
+        }));
+        assert_eq!(content.chain(), AvalanchePrimaryNetworkChain::X);
+
+        let mut partial_tx = AvalanchePartialTx::new(
+            content.clone(),
+            vec![vec![x_address.clone()], vec![x_address.clone()]],
+        )
+        .await
+        .unwrap();
+        assert_eq!(partial_tx.missing_signers(), vec![x_address]);
+
+        // A single signature covers every input
+        assert_eq!(
+            partial_tx
+                .sign(&AvalancheSignerKey::new(LocalSigner::new(ewoq_key.clone())))
+                .await
+                .unwrap(),
+            2
+        );
+        let (tx_id, signed_tx_bytes) = partial_tx.signed_tx().await.unwrap();
+        let (expected_tx_id, _, expected_signed_tx_bytes) = content
+            .sign(vec![vec![ewoq_key.clone()], vec![ewoq_key]])
+            .await
+            .unwrap();
+        assert_eq!(tx_id, expected_tx_id);
+        assert_eq!(signed_tx_bytes, expected_signed_tx_bytes);
+    }
+
+    #[async_std::test]
+    async fn test_partial_tx_sign_merge() {
+        let ewoq_key = PrivateKey::from_cb58(AVAX_EWOQ_PRIVATE_KEY).unwrap();
+        let control_keys = (0..3)
+            .map(|_| PrivateKey::generate().unwrap())
+            .collect::<Vec<_>>();
+
+        // 2-of-3 Subnet authorized by the first and the last control keys
+        let content = create_chain_tx(vec![0, 2]);
+        let partial_tx = AvalanchePartialTx::new(
+            content.clone(),
+            vec![
+                vec![p_address(&ewoq_key)],
+                vec![p_address(&control_keys[0]), p_address(&control_keys[2])],
+            ],
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            partial_tx.missing_signers(),
+            vec![
+                p_address(&ewoq_key),
+                p_address(&control_keys[0]),
+                p_address(&control_keys[2])
+            ]
+        );
+
+        // The partially signed transaction can be stored and loaded
+        let partial_tx: AvalanchePartialTx =
+            serde_json::from_str(&serde_json::to_string(&partial_tx).unwrap()).unwrap();
+        assert_eq!(partial_tx.content, content);
+        assert!(partial_tx.verify().await.is_ok());
+
+        // Each key holder signs its own copy
+        let mut first_copy = partial_tx.clone();
+        let mut second_copy = partial_tx.clone();
+        let signer = |private_key: &PrivateKey| {
+            AvalancheSignerKey::new(LocalSigner::new(private_key.clone()))
+        };
+        assert_eq!(first_copy.sign(&signer(&ewoq_key)).await.unwrap(), 1);
+        assert_eq!(first_copy.sign(&signer(&control_keys[0])).await.unwrap(), 1);
+        assert_eq!(
+            second_copy.sign(&signer(&control_keys[2])).await.unwrap(),
+            1
+        );
+        assert!(second_copy.sign(&signer(&control_keys[1])).await.is_err());
+        assert!(!first_copy.is_fully_signed());
+        assert!(first_copy.signed_tx().await.is_err());
+
+        // Signatures that do not match the expected address are rejected
+        let mut tampered_copy = second_copy.clone();
+        tampered_copy.credentials[1][1].signature = first_copy.credentials[1][0].signature.clone();
+        assert!(first_copy.clone().merge(&tampered_copy).await.is_err());
+
+        assert_eq!(first_copy.merge(&second_copy).await.unwrap(), 1);
+        assert!(first_copy.is_fully_signed());
+
+        // The assembled transaction is the same as if it was signed by all the keys at once
+        let (tx_id, signed_tx_bytes) = first_copy.signed_tx().await.unwrap();
+        let (expected_tx_id, _, expected_signed_tx_bytes) = content
+            .sign(vec![
+                vec![ewoq_key.clone()],
+                vec![control_keys[0].clone(), control_keys[2].clone()],
+            ])
+            .await
+            .unwrap();
+        assert_eq!(tx_id, expected_tx_id);
+        assert_eq!(signed_tx_bytes, expected_signed_tx_bytes);
+
+        // Transactions with different content cannot be merged
+        let other_partial_tx = AvalanchePartialTx::new(
+            create_chain_tx(vec![0, 1]),
+            vec![
+                vec![p_address(&ewoq_key)],
+                vec![p_address(&control_keys[0]), p_address(&control_keys[1])],
+            ],
+        )
+        .await
+        .unwrap();
+        assert!(first_copy.merge(&other_partial_tx).await.is_err());
+    }
+
+    #[async_std::test]
+    async fn test_partial_tx_tampered_content() {
+        let ewoq_key = PrivateKey::from_cb58(AVAX_EWOQ_PRIVATE_KEY).unwrap();
+        let control_key = PrivateKey::generate().unwrap();
+        let partial_tx = AvalanchePartialTx::new(
+            create_chain_tx(vec![0]),
+            vec![vec![p_address(&ewoq_key)], vec![p_address(&control_key)]],
+        )
+        .await
+        .unwrap();
+
+        // The content shown to the signers does not match the bytes they would sign
+        let mut tampered_tx = partial_tx.clone();
+        if let AvalanchePartialTxContent::CreateChain(tx) = &mut tampered_tx.content {
+            tx.chain_name = "tamperedChain".to_string();
+        }
+        assert!(tampered_tx.verify().await.is_err());
+        assert!(tampered_tx.digest().await.is_err());
+        assert!(tampered_tx
+            .clone()
+            .sign(&AvalancheSignerKey::new(LocalSigner::new(ewoq_key.clone())))
+            .await
+            .is_err());
+
+        // A tampered copy cannot be merged, even if its unsigned bytes are the same
+        let mut signed_copy = partial_tx.clone();
+        signed_copy
+            .sign(&AvalancheSignerKey::new(LocalSigner::new(control_key)))
+            .await
+            .unwrap();
+        if let AvalanchePartialTxContent::CreateChain(tx) = &mut signed_copy.content {
+            tx.chain_name = "tamperedChain".to_string();
+        }
+        assert!(partial_tx.clone().merge(&signed_copy).await.is_err());
+        assert!(tampered_tx.merge(&partial_tx).await.is_err());
+
+        // Both encodings of the unsigned bytes have to match the content
+        let mut tampered_tx = partial_tx.clone();
+        tampered_tx.unsigned_tx_cb58 = AvalancheTxEncoding::Cb58.encode(&[0; 32]);
+        assert!(tampered_tx.verify().await.is_err());
+        assert_eq!(
+            AvalancheTxEncoding::decode(&partial_tx.unsigned_tx_cb58).unwrap(),
+            partial_tx.verify().await.unwrap()
+        );
+    }
+}
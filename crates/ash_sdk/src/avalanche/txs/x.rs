@@ -3,11 +3,49 @@
 
 // Module that contains code to issue transactions on the X-Chain
 
-use crate::{avalanche::wallets::AvalancheWallet, errors::*};
+use crate::{
+    avalanche::{
+        jsonrpc::{
+            avm,
+            info::{self, AVAX_INFO_API_ENDPOINT},
+        },
+        txs::{
+            filter_export_utxos, pack_output_owners, pack_transfer_output,
+            partial::{AvalanchePartialTx, AvalanchePartialTxContent},
+            sign_packed_tx, spend_avax_utxos, wait_tx_acceptance, wallet_avax_output,
+            SECP256K1FX_CREDENTIAL_TYPE_ID,
+        },
+        wallets::{AvalancheSignerKey, AvalancheWallet},
+    },
+    errors::*,
+};
 use avalanche_types::{
+    avm::txs::{self as avm_txs, import},
     ids::{short::Id as ShortId, Id},
-    wallet::x::transfer,
+    key::secp256k1::txs::{transfer as transfer_output, OutputOwners},
+    packer::Packer,
+    txs::{self, transferable, utxo},
+    wallet::x::{export, transfer},
 };
+use std::time::SystemTime;
+
+/// Version of the AVM codec
+const AVM_CODEC_VERSION: u16 = 0;
+// Type IDs registered in the AVM codec for the transactions and Fx types not supported by avalanche-types
+// See https://github.com/ava-labs/avalanchego/blob/master/vms/avm/txs/codec.go
+const CREATE_ASSET_TX_TYPE_ID: u32 = 1;
+const OPERATION_TX_TYPE_ID: u32 = 2;
+const SECP256K1FX_MINT_OUTPUT_TYPE_ID: u32 = 6;
+const SECP256K1FX_MINT_OPERATION_TYPE_ID: u32 = 8;
+const NFTFX_MINT_OUTPUT_TYPE_ID: u32 = 10;
+const NFTFX_MINT_OPERATION_TYPE_ID: u32 = 12;
+const NFTFX_CREDENTIAL_TYPE_ID: u32 = 14;
+// Indexes of the Fxs in the initial states of an asset
+const SECP256K1FX_INDEX: u32 = 0;
+const NFTFX_INDEX: u32 = 1;
+
+/// Maximum size of the payload of an NFT (in bytes)
+pub const NFT_MAX_PAYLOAD_SIZE: usize = 1024;
 
 /// Transfer AVAX from a wallet to the receiver
 pub async fn transfer_avax(
@@ -31,14 +69,829 @@ pub async fn transfer_avax(
     Ok(tx_id)
 }
 
+/// Build an unsigned transaction to transfer AVAX from a wallet to the receiver
+/// The transaction is signed later (e.g. on an air-gapped machine) with `AvalanchePartialTx::sign`
+pub async fn build_transfer_avax_partial_tx(
+    wallet: &AvalancheWallet,
+    receiver: ShortId,
+    amount: u64,
+) -> Result<AvalanchePartialTx, AshError> {
+    let xchain_wallet = &wallet.xchain_wallet;
+    let rpc_url = &format!("{}/ext/bc/X", xchain_wallet.pick_base_http_url().1);
+
+    let utxos = avm::get_utxos(
+        rpc_url,
+        std::slice::from_ref(&xchain_wallet.x_address),
+        None,
+    )?;
+    let amount_to_spend = amount + xchain_wallet.tx_fee;
+    let (inputs, signers, spent_amount) =
+        spend_avax_utxos(xchain_wallet, &utxos, Some(amount_to_spend));
+
+    if spent_amount < amount_to_spend {
+        return Err(AvalancheWalletError::IssueTx {
+            blockchain_name: "X-Chain".to_string(),
+            tx_type: "transfer".to_string(),
+            msg: format!(
+                "not enough AVAX to transfer {amount} nAVAX and pay the fee: {spent_amount} nAVAX available"
+            ),
+        }
+        .into());
+    }
+
+    let mut outputs = vec![transferable::Output {
+        asset_id: xchain_wallet.avax_asset_id,
+        transfer_output: Some(transfer_output::Output {
+            amount,
+            output_owners: OutputOwners {
+                locktime: 0,
+                threshold: 1,
+                addresses: vec![receiver],
+            },
+        }),
+        ..Default::default()
+    }];
+    if spent_amount > amount_to_spend {
+        outputs.push(wallet_avax_output(
+            xchain_wallet,
+            spent_amount - amount_to_spend,
+        ));
+    }
+    outputs.sort();
+
+    let tx = avm_txs::Tx::new(txs::Tx {
+        network_id: xchain_wallet.network_id,
+        blockchain_id: xchain_wallet.blockchain_id_x,
+        transferable_outputs: Some(outputs),
+        transferable_inputs: Some(inputs),
+        ..Default::default()
+    });
+    // The wallet key is the only one able to spend the wallet UTXOs
+    let inputs_signers = signers
+        .iter()
+        .map(|keys| vec![xchain_wallet.x_address.clone(); keys.len()])
+        .collect();
+
+    AvalanchePartialTx::new(AvalanchePartialTxContent::Transfer(tx), inputs_signers).await
+}
+
+/// Export AVAX from the X-Chain to another chain of the Primary Network
+/// The exported UTXOs are owned by the wallet and have to be imported on the destination chain
+pub async fn export_avax(
+    wallet: &AvalancheWallet,
+    destination_chain_id: Id,
+    amount: u64,
+    check_acceptance: bool,
+) -> Result<Id, AshError> {
+    let tx_id = export::Tx::new(&wallet.xchain_wallet.x())
+        .destination_blockchain_id(destination_chain_id)
+        .amount(amount)
+        .check_acceptance(check_acceptance)
+        .issue()
+        .await
+        .map_err(|e| AvalancheWalletError::IssueTx {
+            blockchain_name: "X-Chain".to_string(),
+            tx_type: "export".to_string(),
+            msg: format!("failed to export {amount} AVAX to '{destination_chain_id}': {e}"),
+        })?;
+
+    Ok(tx_id)
+}
+
+/// Import to the X-Chain the AVAX exported to the wallet from another chain of the Primary Network
+/// If an export transaction ID is provided, only the UTXOs created by this export are imported,
+/// otherwise all the atomic UTXOs of the wallet on the source chain are swept
+/// The transaction fee is deducted from the imported amount
+pub async fn import_avax(
+    wallet: &AvalancheWallet,
+    source_chain_id: Id,
+    export_tx_id: Option<Id>,
+    check_acceptance: bool,
+) -> Result<Id, AshError> {
+    let xchain_wallet = &wallet.xchain_wallet;
+    let rpc_url = &format!("{}/ext/bc/X", xchain_wallet.pick_base_http_url().1);
+
+    let utxos = avm::get_utxos(
+        rpc_url,
+        std::slice::from_ref(&xchain_wallet.x_address),
+        Some(source_chain_id),
+    )?;
+    let utxos = filter_export_utxos(utxos, export_tx_id);
+    let (inputs, signers, imported_amount) = spend_avax_utxos(xchain_wallet, &utxos, None);
+
+    if imported_amount <= xchain_wallet.tx_fee {
+        return Err(AvalancheWalletError::IssueTx {
+            blockchain_name: "X-Chain".to_string(),
+            tx_type: "import".to_string(),
+            msg: format!(
+                "not enough AVAX to import from '{source_chain_id}' to pay the fee: {imported_amount} nAVAX available"
+            ),
+        }
+        .into());
+    }
+
+    let mut tx = import::Tx {
+        base_tx: txs::Tx {
+            network_id: xchain_wallet.network_id,
+            blockchain_id: xchain_wallet.blockchain_id_x,
+            transferable_outputs: Some(vec![wallet_avax_output(
+                xchain_wallet,
+                imported_amount - xchain_wallet.tx_fee,
+            )]),
+            ..Default::default()
+        },
+        source_chain_id,
+        source_chain_transferable_inputs: Some(inputs),
+        ..Default::default()
+    };
+    tx.sign(signers)
+        .await
+        .map_err(|e| AvalancheWalletError::IssueTx {
+            blockchain_name: "X-Chain".to_string(),
+            tx_type: "import".to_string(),
+            msg: format!("failed to sign transaction: {e}"),
+        })?;
+
+    let tx_id = avm::issue_tx(
+        rpc_url,
+        &tx.base_tx.metadata.unwrap().tx_bytes_with_signatures,
+    )
+    .map_err(|e| AvalancheWalletError::IssueTx {
+        blockchain_name: "X-Chain".to_string(),
+        tx_type: "import".to_string(),
+        msg: format!("failed to import AVAX from '{source_chain_id}': {e}"),
+    })?;
+
+    if check_acceptance {
+        wait_tx_acceptance("X-Chain", tx_id, || avm::get_tx_status(rpc_url, tx_id)).await?;
+    }
+
+    Ok(tx_id)
+}
+
+/// Initial state of an asset created on the X-Chain
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AvalancheXChainAssetInitialState {
+    /// Fixed-cap asset: the whole supply is allocated to the initial holders
+    FixedCap(Vec<(ShortId, u64)>),
+    /// Variable-cap asset: new units are minted by the minters, `threshold` of them have to sign
+    VariableCap {
+        minters: Vec<ShortId>,
+        threshold: u32,
+    },
+    /// NFT family: one NFT of each group is minted by the minters, `threshold` of them have to sign
+    Nft {
+        minters: Vec<ShortId>,
+        threshold: u32,
+        groups: u32,
+    },
+}
+
+// Output allowing its owners to mint units of an asset (secp256k1fx) or the NFTs of a group (nftfx)
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum MintOutput {
+    Secp256k1(OutputOwners),
+    Nft { group_id: u32, owners: OutputOwners },
+}
+
+impl MintOutput {
+    fn owners(&self) -> &OutputOwners {
+        match self {
+            MintOutput::Secp256k1(owners) => owners,
+            MintOutput::Nft { owners, .. } => owners,
+        }
+    }
+
+    // Group of the NFTs minted by an nftfx mint output
+    fn group_id(&self) -> Option<u32> {
+        match self {
+            MintOutput::Secp256k1(_) => None,
+            MintOutput::Nft { group_id, .. } => Some(*group_id),
+        }
+    }
+
+    fn pack(&self, bytes: &mut Vec<u8>) {
+        match self {
+            MintOutput::Secp256k1(owners) => {
+                bytes.extend_from_slice(&SECP256K1FX_MINT_OUTPUT_TYPE_ID.to_be_bytes());
+                pack_output_owners(bytes, owners);
+            }
+            MintOutput::Nft { group_id, owners } => {
+                bytes.extend_from_slice(&NFTFX_MINT_OUTPUT_TYPE_ID.to_be_bytes());
+                bytes.extend_from_slice(&group_id.to_be_bytes());
+                pack_output_owners(bytes, owners);
+            }
+        }
+    }
+}
+
+// Unsigned X-Chain create asset transaction
+struct UnsignedCreateAssetTx {
+    base_tx: txs::Tx,
+    name: String,
+    symbol: String,
+    denomination: u8,
+    transfer_outputs: Vec<transfer_output::Output>,
+    mint_outputs: Vec<MintOutput>,
+}
+
+impl UnsignedCreateAssetTx {
+    // Pack the transaction with the AVM codec
+    fn pack(&self) -> Result<Vec<u8>, AshError> {
+        let mut bytes = pack_base_tx(&self.base_tx, CREATE_ASSET_TX_TYPE_ID, "create_asset")?;
+
+        for field in [&self.name, &self.symbol] {
+            bytes.extend_from_slice(&(field.len() as u16).to_be_bytes());
+            bytes.extend_from_slice(field.as_bytes());
+        }
+        bytes.push(self.denomination);
+
+        // The initial states are sorted by Fx index and their outputs by their bytes
+        let mut secp256k1fx_outputs = Vec::new();
+        let mut nftfx_outputs = Vec::new();
+        for output in self.transfer_outputs.iter() {
+            let mut output_bytes = Vec::new();
+            pack_transfer_output(&mut output_bytes, output);
+            secp256k1fx_outputs.push(output_bytes);
+        }
+        for output in self.mint_outputs.iter() {
+            let mut output_bytes = Vec::new();
+            output.pack(&mut output_bytes);
+            match output {
+                MintOutput::Secp256k1(_) => secp256k1fx_outputs.push(output_bytes),
+                MintOutput::Nft { .. } => nftfx_outputs.push(output_bytes),
+            }
+        }
+        let states: Vec<_> = [
+            (SECP256K1FX_INDEX, secp256k1fx_outputs),
+            (NFTFX_INDEX, nftfx_outputs),
+        ]
+        .into_iter()
+        .filter(|(_, outputs)| !outputs.is_empty())
+        .collect();
+
+        bytes.extend_from_slice(&(states.len() as u32).to_be_bytes());
+        for (fx_index, mut outputs) in states.into_iter() {
+            outputs.sort();
+            bytes.extend_from_slice(&fx_index.to_be_bytes());
+            bytes.extend_from_slice(&(outputs.len() as u32).to_be_bytes());
+            for output in outputs.iter() {
+                bytes.extend_from_slice(output);
+            }
+        }
+
+        Ok(bytes)
+    }
+}
+
+// Mint operation of an X-Chain operation transaction
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum MintOperation {
+    // Mint units of a variable-cap asset, the mint output is recreated for the next mints
+    Secp256k1 {
+        mint_owners: OutputOwners,
+        transfer_output: transfer_output::Output,
+    },
+    // Mint the NFT of a group, the mint output of the group is consumed
+    Nft {
+        group_id: u32,
+        payload: Vec<u8>,
+        owners: Vec<OutputOwners>,
+    },
+}
+
+// Unsigned X-Chain operation transaction with a single mint operation
+struct UnsignedMintTx {
+    base_tx: txs::Tx,
+    asset_id: Id,
+    utxo_id: utxo::Id,
+    sig_indices: Vec<u32>,
+    operation: MintOperation,
+}
+
+impl UnsignedMintTx {
+    // Pack the transaction with the AVM codec
+    fn pack(&self) -> Result<Vec<u8>, AshError> {
+        let mut bytes = pack_base_tx(&self.base_tx, OPERATION_TX_TYPE_ID, "operation")?;
+
+        bytes.extend_from_slice(&1u32.to_be_bytes());
+        bytes.extend_from_slice(self.asset_id.as_ref());
+        bytes.extend_from_slice(&1u32.to_be_bytes());
+        bytes.extend_from_slice(self.utxo_id.tx_id.as_ref());
+        bytes.extend_from_slice(&self.utxo_id.output_index.to_be_bytes());
+
+        match &self.operation {
+            MintOperation::Secp256k1 {
+                mint_owners,
+                transfer_output,
+            } => {
+                bytes.extend_from_slice(&SECP256K1FX_MINT_OPERATION_TYPE_ID.to_be_bytes());
+                pack_sig_indices(&mut bytes, &self.sig_indices);
+                pack_output_owners(&mut bytes, mint_owners);
+                bytes.extend_from_slice(&transfer_output.amount.to_be_bytes());
+                pack_output_owners(&mut bytes, &transfer_output.output_owners);
+            }
+            MintOperation::Nft {
+                group_id,
+                payload,
+                owners,
+            } => {
+                bytes.extend_from_slice(&NFTFX_MINT_OPERATION_TYPE_ID.to_be_bytes());
+                pack_sig_indices(&mut bytes, &self.sig_indices);
+                bytes.extend_from_slice(&group_id.to_be_bytes());
+                bytes.extend_from_slice(&(payload.len() as u32).to_be_bytes());
+                bytes.extend_from_slice(payload);
+                bytes.extend_from_slice(&(owners.len() as u32).to_be_bytes());
+                for owners in owners.iter() {
+                    pack_output_owners(&mut bytes, owners);
+                }
+            }
+        }
+
+        Ok(bytes)
+    }
+
+    // Type ID of the credential signing the operation, which depends on its Fx
+    fn operation_credential_type_id(&self) -> u32 {
+        match self.operation {
+            MintOperation::Secp256k1 { .. } => SECP256K1FX_CREDENTIAL_TYPE_ID,
+            MintOperation::Nft { .. } => NFTFX_CREDENTIAL_TYPE_ID,
+        }
+    }
+}
+
+// Pack the base transaction with the codec version and the type ID of the transaction
+fn pack_base_tx(base_tx: &txs::Tx, type_id: u32, tx_type: &str) -> Result<Vec<u8>, AshError> {
+    let packer =
+        base_tx
+            .pack(AVM_CODEC_VERSION, type_id)
+            .map_err(|e| AvalancheWalletError::IssueTx {
+                blockchain_name: "X-Chain".to_string(),
+                tx_type: tx_type.to_string(),
+                msg: format!("failed to pack transaction: {e}"),
+            })?;
+
+    Ok(packer.take_bytes().to_vec())
+}
+
+fn pack_sig_indices(bytes: &mut Vec<u8>, sig_indices: &[u32]) {
+    bytes.extend_from_slice(&(sig_indices.len() as u32).to_be_bytes());
+    for sig_index in sig_indices.iter() {
+        bytes.extend_from_slice(&sig_index.to_be_bytes());
+    }
+}
+
+// Decode an X-Chain UTXO holding a mint output
+// Return None if the UTXO holds another kind of output
+fn decode_mint_utxo(
+    utxo_bytes: &[u8],
+) -> Result<Option<(utxo::Id, Id, MintOutput)>, avalanche_types::errors::Error> {
+    let packer = Packer::load_bytes_for_unpack(utxo_bytes.len(), utxo_bytes);
+
+    let _codec_version = packer.unpack_u16()?;
+    let utxo_id = utxo::Id {
+        tx_id: Id::from_slice(&packer.unpack_bytes(32)?),
+        output_index: packer.unpack_u32()?,
+        ..Default::default()
+    };
+    let asset_id = Id::from_slice(&packer.unpack_bytes(32)?);
+
+    let output = match packer.unpack_u32()? {
+        SECP256K1FX_MINT_OUTPUT_TYPE_ID => MintOutput::Secp256k1(unpack_output_owners(&packer)?),
+        NFTFX_MINT_OUTPUT_TYPE_ID => MintOutput::Nft {
+            group_id: packer.unpack_u32()?,
+            owners: unpack_output_owners(&packer)?,
+        },
+        _ => return Ok(None),
+    };
+
+    Ok(Some((utxo_id, asset_id, output)))
+}
+
+fn unpack_output_owners(packer: &Packer) -> Result<OutputOwners, avalanche_types::errors::Error> {
+    let locktime = packer.unpack_u64()?;
+    let threshold = packer.unpack_u32()?;
+    let addresses_len = packer.unpack_u32()?;
+    let addresses = (0..addresses_len)
+        .map(|_| Ok(ShortId::from_slice(&packer.unpack_bytes(20)?)))
+        .collect::<Result<_, avalanche_types::errors::Error>>()?;
+
+    Ok(OutputOwners {
+        locktime,
+        threshold,
+        addresses,
+    })
+}
+
+// Build a base transaction spending the wallet AVAX UTXOs to pay a fee
+// Return the base transaction, with the change output if any, and the signers of its inputs
+fn build_fee_base_tx(
+    wallet: &AvalancheWallet,
+    rpc_url: &str,
+    fee: u64,
+    tx_type: &str,
+) -> Result<(txs::Tx, Vec<Vec<AvalancheSignerKey>>), AshError> {
+    let xchain_wallet = &wallet.xchain_wallet;
+
+    let utxos = avm::get_utxos(
+        rpc_url,
+        std::slice::from_ref(&xchain_wallet.x_address),
+        None,
+    )?;
+    let (inputs, signers, spent_amount) = spend_avax_utxos(xchain_wallet, &utxos, Some(fee));
+
+    if spent_amount < fee {
+        return Err(AvalancheWalletError::IssueTx {
+            blockchain_name: "X-Chain".to_string(),
+            tx_type: tx_type.to_string(),
+            msg: format!(
+                "not enough AVAX to pay the fee of {fee} nAVAX: {spent_amount} nAVAX available"
+            ),
+        }
+        .into());
+    }
+
+    let outputs = match spent_amount > fee {
+        true => vec![wallet_avax_output(xchain_wallet, spent_amount - fee)],
+        false => vec![],
+    };
+
+    let base_tx = txs::Tx {
+        network_id: xchain_wallet.network_id,
+        blockchain_id: xchain_wallet.blockchain_id_x,
+        transferable_outputs: Some(outputs),
+        transferable_inputs: Some(inputs),
+        ..Default::default()
+    };
+
+    Ok((base_tx, signers))
+}
+
+// Issue a signed X-Chain transaction and optionally wait for its acceptance
+// The action is described in the error if the transaction is refused
+async fn issue_xchain_tx(
+    rpc_url: &str,
+    signed_tx_bytes: &[u8],
+    tx_type: &str,
+    action: &str,
+    check_acceptance: bool,
+) -> Result<Id, AshError> {
+    let tx_id =
+        avm::issue_tx(rpc_url, signed_tx_bytes).map_err(|e| AvalancheWalletError::IssueTx {
+            blockchain_name: "X-Chain".to_string(),
+            tx_type: tx_type.to_string(),
+            msg: format!("failed to {action}: {e}"),
+        })?;
+
+    if check_acceptance {
+        wait_tx_acceptance("X-Chain", tx_id, || avm::get_tx_status(rpc_url, tx_id)).await?;
+    }
+
+    Ok(tx_id)
+}
+
+// Build the owners of a mint output, checking the threshold
+fn minters_output_owners(minters: &[ShortId], threshold: u32) -> Result<OutputOwners, AshError> {
+    let mut addresses = minters.to_vec();
+    addresses.sort();
+    addresses.dedup();
+
+    if threshold == 0 || threshold as usize > addresses.len() {
+        return Err(AvalancheWalletError::IssueTx {
+            blockchain_name: "X-Chain".to_string(),
+            tx_type: "create_asset".to_string(),
+            msg: format!(
+                "the minting threshold must be between 1 and the number of minters ({}), got {threshold}",
+                addresses.len()
+            ),
+        }
+        .into());
+    }
+
+    Ok(OutputOwners {
+        locktime: 0,
+        threshold,
+        addresses,
+    })
+}
+
+// Limits of the asset descriptions enforced by the AVM
+const ASSET_MAX_NAME_LEN: usize = 128;
+const ASSET_MAX_SYMBOL_LEN: usize = 4;
+const ASSET_MAX_DENOMINATION: u8 = 32;
+
+// Check the name, symbol and denomination of an asset against the limits of the AVM
+fn check_asset_description(name: &str, symbol: &str, denomination: u8) -> Result<(), AshError> {
+    let description_failure = |msg: String| AvalancheWalletError::IssueTx {
+        blockchain_name: "X-Chain".to_string(),
+        tx_type: "create_asset".to_string(),
+        msg,
+    };
+
+    if name.is_empty() || name.len() > ASSET_MAX_NAME_LEN || !name.is_ascii() {
+        return Err(description_failure(format!(
+            "the asset name must have between 1 and {ASSET_MAX_NAME_LEN} ASCII characters, got '{name}'"
+        ))
+        .into());
+    }
+    if symbol.len() > ASSET_MAX_SYMBOL_LEN || !symbol.is_ascii() {
+        return Err(description_failure(format!(
+            "the asset symbol must have up to {ASSET_MAX_SYMBOL_LEN} ASCII characters, got '{symbol}'"
+        ))
+        .into());
+    }
+    if denomination > ASSET_MAX_DENOMINATION {
+        return Err(description_failure(format!(
+            "the asset denomination must be at most {ASSET_MAX_DENOMINATION}, got {denomination}"
+        ))
+        .into());
+    }
+
+    Ok(())
+}
+
+/// Create an asset on the X-Chain
+/// The fee is paid by the wallet and the ID of the asset is the ID of the transaction
+/// The name (up to 128 characters), symbol (up to 4 characters) and denomination (up to 32) are checked before building the transaction
+pub async fn create_asset(
+    wallet: &AvalancheWallet,
+    name: &str,
+    symbol: &str,
+    denomination: u8,
+    initial_state: AvalancheXChainAssetInitialState,
+    check_acceptance: bool,
+) -> Result<Id, AshError> {
+    check_asset_description(name, symbol, denomination)?;
+
+    let xchain_wallet = &wallet.xchain_wallet;
+    let base_http_url = xchain_wallet.pick_base_http_url().1;
+    let rpc_url = &format!("{base_http_url}/ext/bc/X");
+
+    let (transfer_outputs, mint_outputs) = match initial_state {
+        AvalancheXChainAssetInitialState::FixedCap(holders) => {
+            if holders.is_empty() {
+                return Err(AvalancheWalletError::IssueTx {
+                    blockchain_name: "X-Chain".to_string(),
+                    tx_type: "create_asset".to_string(),
+                    msg: "a fixed-cap asset must have at least one initial holder".to_string(),
+                }
+                .into());
+            }
+
+            let transfer_outputs = holders
+                .into_iter()
+                .map(|(holder, amount)| transfer_output::Output {
+                    amount,
+                    output_owners: OutputOwners {
+                        locktime: 0,
+                        threshold: 1,
+                        addresses: vec![holder],
+                    },
+                })
+                .collect();
+            (transfer_outputs, vec![])
+        }
+        AvalancheXChainAssetInitialState::VariableCap { minters, threshold } => {
+            let owners = minters_output_owners(&minters, threshold)?;
+            (vec![], vec![MintOutput::Secp256k1(owners)])
+        }
+        AvalancheXChainAssetInitialState::Nft {
+            minters,
+            threshold,
+            groups,
+        } => {
+            let owners = minters_output_owners(&minters, threshold)?;
+            let mint_outputs = (0..groups)
+                .map(|group_id| MintOutput::Nft {
+                    group_id,
+                    owners: owners.clone(),
+                })
+                .collect();
+            (vec![], mint_outputs)
+        }
+    };
+
+    let fee =
+        info::get_tx_fee(&format!("{base_http_url}/{AVAX_INFO_API_ENDPOINT}"))?.create_asset_tx_fee;
+    let (base_tx, signers) = build_fee_base_tx(wallet, rpc_url, fee, "create_asset")?;
+
+    let tx = UnsignedCreateAssetTx {
+        base_tx,
+        name: name.to_string(),
+        symbol: symbol.to_string(),
+        denomination,
+        transfer_outputs,
+        mint_outputs,
+    };
+    let credentials: Vec<_> = signers
+        .into_iter()
+        .map(|keys| (SECP256K1FX_CREDENTIAL_TYPE_ID, keys))
+        .collect();
+    let signed_tx_bytes =
+        sign_packed_tx(tx.pack()?, &credentials, "X-Chain", "create_asset").await?;
+
+    issue_xchain_tx(
+        rpc_url,
+        &signed_tx_bytes,
+        "create_asset",
+        &format!("create asset '{name}' ({symbol})"),
+        check_acceptance,
+    )
+    .await
+}
+
+/// Mint units of a variable-cap asset on the X-Chain to the receiver
+/// The wallet must own a mint output of the asset and pays the fee
+pub async fn mint_asset(
+    wallet: &AvalancheWallet,
+    asset_id: Id,
+    amount: u64,
+    receiver: ShortId,
+    check_acceptance: bool,
+) -> Result<Id, AshError> {
+    mint(
+        wallet,
+        asset_id,
+        false,
+        |output| MintOperation::Secp256k1 {
+            mint_owners: output.owners().clone(),
+            transfer_output: transfer_output::Output {
+                amount,
+                output_owners: OutputOwners {
+                    locktime: 0,
+                    threshold: 1,
+                    addresses: vec![receiver],
+                },
+            },
+        },
+        &format!("mint {amount} units of asset '{asset_id}'"),
+        check_acceptance,
+    )
+    .await
+}
+
+/// Mint an NFT of an NFT family on the X-Chain to the receiver, with the payload attached
+/// The NFT of the first group the wallet can still mint is minted and the wallet pays the fee
+pub async fn mint_nft(
+    wallet: &AvalancheWallet,
+    asset_id: Id,
+    payload: Vec<u8>,
+    receiver: ShortId,
+    check_acceptance: bool,
+) -> Result<Id, AshError> {
+    if payload.len() > NFT_MAX_PAYLOAD_SIZE {
+        return Err(AvalancheWalletError::IssueTx {
+            blockchain_name: "X-Chain".to_string(),
+            tx_type: "operation".to_string(),
+            msg: format!(
+                "the NFT payload is {} bytes long, the maximum is {NFT_MAX_PAYLOAD_SIZE} bytes",
+                payload.len()
+            ),
+        }
+        .into());
+    }
+
+    mint(
+        wallet,
+        asset_id,
+        true,
+        |output| MintOperation::Nft {
+            group_id: output.group_id().unwrap_or_default(),
+            payload,
+            owners: vec![OutputOwners {
+                locktime: 0,
+                threshold: 1,
+                addresses: vec![receiver],
+            }],
+        },
+        &format!("mint NFT of asset '{asset_id}'"),
+        check_acceptance,
+    )
+    .await
+}
+
+// Issue an operation transaction consuming a mint output of the asset owned by the wallet
+// The operation is built from the consumed mint output (secp256k1fx or nftfx)
+async fn mint(
+    wallet: &AvalancheWallet,
+    asset_id: Id,
+    nft: bool,
+    build_operation: impl FnOnce(&MintOutput) -> MintOperation,
+    action: &str,
+    check_acceptance: bool,
+) -> Result<Id, AshError> {
+    let xchain_wallet = &wallet.xchain_wallet;
+    let rpc_url = &format!("{}/ext/bc/X", xchain_wallet.pick_base_http_url().1);
+    let now = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+
+    let mut mint_utxos = Vec::new();
+    for utxo_bytes in avm::get_raw_utxos(
+        rpc_url,
+        std::slice::from_ref(&xchain_wallet.x_address),
+        None,
+    )?
+    .iter()
+    {
+        let mint_utxo =
+            decode_mint_utxo(utxo_bytes).map_err(|e| AvalancheWalletError::IssueTx {
+                blockchain_name: "X-Chain".to_string(),
+                tx_type: "operation".to_string(),
+                msg: format!("failed to decode UTXO: {e}"),
+            })?;
+        let Some((utxo_id, utxo_asset_id, output)) = mint_utxo else {
+            continue;
+        };
+        if utxo_asset_id != asset_id || output.group_id().is_some() != nft {
+            continue;
+        }
+        let Some((sig_indices, keys)) =
+            xchain_wallet.keychain.match_threshold(output.owners(), now)
+        else {
+            continue;
+        };
+
+        mint_utxos.push((utxo_id, output, sig_indices, keys));
+    }
+
+    // The NFTs are minted in the order of their groups
+    let Some((utxo_id, output, sig_indices, keys)) = mint_utxos
+        .into_iter()
+        .min_by_key(|(_, output, _, _)| output.group_id())
+    else {
+        return Err(AvalancheWalletError::IssueTx {
+            blockchain_name: "X-Chain".to_string(),
+            tx_type: "operation".to_string(),
+            msg: format!(
+                "failed to {action}: the wallet cannot spend any {} mint output of the asset",
+                if nft { "NFT" } else { "secp256k1fx" }
+            ),
+        }
+        .into());
+    };
+
+    let (base_tx, signers) = build_fee_base_tx(wallet, rpc_url, xchain_wallet.tx_fee, "operation")?;
+
+    let tx = UnsignedMintTx {
+        base_tx,
+        asset_id,
+        utxo_id,
+        sig_indices,
+        operation: build_operation(&output),
+    };
+    // The credentials of the inputs come before the credential of the operation
+    let mut credentials: Vec<_> = signers
+        .into_iter()
+        .map(|keys| (SECP256K1FX_CREDENTIAL_TYPE_ID, keys))
+        .collect();
+    credentials.push((tx.operation_credential_type_id(), keys));
+    let signed_tx_bytes = sign_packed_tx(tx.pack()?, &credentials, "X-Chain", "operation").await?;
+
+    issue_xchain_tx(
+        rpc_url,
+        &signed_tx_bytes,
+        "operation",
+        action,
+        check_acceptance,
+    )
+    .await
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::avalanche::{address_to_short_id, jsonrpc::avm::get_balance, AvalancheNetwork};
+    use crate::avalanche::{
+        address_to_short_id, jsonrpc::avm::get_balance, txs::SECP256K1FX_TRANSFER_OUTPUT_TYPE_ID,
+        AvalancheNetwork,
+    };
+    use std::str::FromStr;
 
     const AVAX_EWOQ_PRIVATE_KEY: &str =
         "PrivateKey-ewoqjP7PxY4yr3iLTpLisriqt94hdyDFNgchSxGGztUrTXtNN";
     const AVAX_LOCAL_XCHAIN_ADDR: &str = "X-custom1w44zzlzf68gwaskce2s4r82t5u08pje5mhq2en";
+    const AVAX_EWOQ_XCHAIN_ADDR: &str = "X-custom18jma8ppw3nhx5r4ap8clazz0dps7rv5u9xde7p";
+    const AVAX_FUJI_XCHAIN_ID: &str = "2JVSBoinj9C2J33VntvzYtVJNZdN2NKiwwKjcumHUWEb5DbBrm";
+    const EWOQ_SHORT_ADDRESS: &str = "6Y3kysjF9jnHnYkdS9yGAuoHyae2eNmeV";
+
+    fn ewoq_owners() -> OutputOwners {
+        OutputOwners {
+            locktime: 0,
+            threshold: 1,
+            addresses: vec![ShortId::from_str(EWOQ_SHORT_ADDRESS).unwrap()],
+        }
+    }
+
+    fn empty_base_tx() -> txs::Tx {
+        txs::Tx {
+            network_id: 5,
+            blockchain_id: Id::from_str(AVAX_FUJI_XCHAIN_ID).unwrap(),
+            transferable_outputs: Some(vec![]),
+            transferable_inputs: Some(vec![]),
+            ..Default::default()
+        }
+    }
 
     // Load the test network using avalanche-network-runner
     fn load_test_network() -> AvalancheNetwork {
@@ -69,4 +922,251 @@ mod tests {
 
         assert_eq!(init_balance.balance + 100000000, final_balance.balance)
     }
+
+    #[async_std::test]
+    #[serial_test::serial]
+    #[ignore]
+    async fn test_transfer_partial_tx() {
+        let local_network = load_test_network();
+        let local_wallet = local_network
+            .create_wallet_from_cb58(AVAX_EWOQ_PRIVATE_KEY)
+            .unwrap();
+        let rpc_url = &local_network.get_xchain().unwrap().rpc_url;
+        let init_balance = get_balance(rpc_url, AVAX_LOCAL_XCHAIN_ADDR, "AVAX").unwrap();
+
+        let mut partial_tx = build_transfer_avax_partial_tx(
+            &local_wallet,
+            address_to_short_id(AVAX_LOCAL_XCHAIN_ADDR, "X").unwrap(),
+            100000000,
+        )
+        .await
+        .unwrap();
+        partial_tx.sign(&local_wallet.signer).await.unwrap();
+        partial_tx.issue(&local_network, true).await.unwrap();
+
+        let final_balance = get_balance(rpc_url, AVAX_LOCAL_XCHAIN_ADDR, "AVAX").unwrap();
+
+        assert_eq!(init_balance.balance + 100000000, final_balance.balance)
+    }
+
+    #[test]
+    fn test_unsigned_create_asset_tx_pack() {
+        let tx = UnsignedCreateAssetTx {
+            base_tx: empty_base_tx(),
+            name: "Test".to_string(),
+            symbol: "TST".to_string(),
+            denomination: 2,
+            transfer_outputs: vec![transfer_output::Output {
+                amount: 1_000,
+                output_owners: ewoq_owners(),
+            }],
+            mint_outputs: vec![MintOutput::Nft {
+                group_id: 3,
+                owners: ewoq_owners(),
+            }],
+        };
+        let bytes = tx.pack().unwrap();
+
+        // Base tx (54) + name (6) + symbol (5) + denomination (1)
+        // + 2 initial states with 1 output each (4 + 8 + 48 + 8 + 44)
+        assert_eq!(bytes.len(), 178);
+        assert_eq!(bytes[..6], [0, 0, 0, 0, 0, 1]);
+        assert_eq!(bytes[6..10], 5u32.to_be_bytes());
+        assert_eq!(bytes[54..60], [0, 4, b'T', b'e', b's', b't']);
+        assert_eq!(bytes[60..65], [0, 3, b'T', b'S', b'T']);
+        assert_eq!(bytes[65], 2);
+        assert_eq!(bytes[66..70], 2u32.to_be_bytes());
+        // secp256k1fx state with the transfer output
+        assert_eq!(bytes[70..74], SECP256K1FX_INDEX.to_be_bytes());
+        assert_eq!(bytes[74..78], 1u32.to_be_bytes());
+        assert_eq!(
+            bytes[78..82],
+            SECP256K1FX_TRANSFER_OUTPUT_TYPE_ID.to_be_bytes()
+        );
+        assert_eq!(bytes[82..90], 1_000u64.to_be_bytes());
+        // nftfx state with the mint output
+        assert_eq!(bytes[126..130], NFTFX_INDEX.to_be_bytes());
+        assert_eq!(bytes[134..138], NFTFX_MINT_OUTPUT_TYPE_ID.to_be_bytes());
+        assert_eq!(bytes[138..142], 3u32.to_be_bytes());
+        assert_eq!(
+            bytes[158..],
+            *ShortId::from_str(EWOQ_SHORT_ADDRESS).unwrap().as_ref()
+        );
+    }
+
+    #[test]
+    fn test_check_asset_description() {
+        assert!(check_asset_description("Test Asset", "TEST", 9).is_ok());
+        assert!(check_asset_description("Test Asset", "", 32).is_ok());
+
+        assert!(check_asset_description("", "TEST", 9).is_err());
+        assert!(check_asset_description(&"A".repeat(129), "TEST", 9).is_err());
+        assert!(check_asset_description("Test Asset", "TESTS", 9).is_err());
+        assert!(check_asset_description("Test Asset", "TÉST", 9).is_err());
+        assert!(check_asset_description("Test Asset", "TEST", 33).is_err());
+    }
+
+    #[test]
+    fn test_unsigned_mint_tx_pack() {
+        let tx = UnsignedMintTx {
+            base_tx: empty_base_tx(),
+            asset_id: Id::from_str(AVAX_FUJI_XCHAIN_ID).unwrap(),
+            utxo_id: utxo::Id {
+                tx_id: Id::from_str(AVAX_FUJI_XCHAIN_ID).unwrap(),
+                output_index: 1,
+                ..Default::default()
+            },
+            sig_indices: vec![0],
+            operation: MintOperation::Secp256k1 {
+                mint_owners: ewoq_owners(),
+                transfer_output: transfer_output::Output {
+                    amount: 5_000,
+                    output_owners: ewoq_owners(),
+                },
+            },
+        };
+        let bytes = tx.pack().unwrap();
+
+        // Base tx (54) + 1 operation (4 + 32 + 4 + 36 + 4 + 8 + 36 + 44)
+        assert_eq!(bytes.len(), 222);
+        assert_eq!(bytes[..6], [0, 0, 0, 0, 0, 2]);
+        assert_eq!(bytes[54..58], 1u32.to_be_bytes());
+        assert_eq!(bytes[90..94], 1u32.to_be_bytes());
+        assert_eq!(bytes[126..130], 1u32.to_be_bytes());
+        assert_eq!(
+            bytes[130..134],
+            SECP256K1FX_MINT_OPERATION_TYPE_ID.to_be_bytes()
+        );
+        assert_eq!(bytes[134..142], [0, 0, 0, 1, 0, 0, 0, 0]);
+        assert_eq!(bytes[178..186], 5_000u64.to_be_bytes());
+        assert_eq!(
+            tx.operation_credential_type_id(),
+            SECP256K1FX_CREDENTIAL_TYPE_ID
+        );
+    }
+
+    #[test]
+    fn test_decode_mint_utxo() {
+        let utxo_id = utxo::Id {
+            tx_id: Id::from_str(AVAX_FUJI_XCHAIN_ID).unwrap(),
+            output_index: 2,
+            ..Default::default()
+        };
+        let asset_id = Id::from_str(AVAX_FUJI_XCHAIN_ID).unwrap();
+        let mut utxo_bytes = AVM_CODEC_VERSION.to_be_bytes().to_vec();
+        utxo_bytes.extend_from_slice(utxo_id.tx_id.as_ref());
+        utxo_bytes.extend_from_slice(&utxo_id.output_index.to_be_bytes());
+        utxo_bytes.extend_from_slice(asset_id.as_ref());
+
+        for output in [
+            MintOutput::Secp256k1(ewoq_owners()),
+            MintOutput::Nft {
+                group_id: 7,
+                owners: ewoq_owners(),
+            },
+        ] {
+            let mut bytes = utxo_bytes.clone();
+            output.pack(&mut bytes);
+
+            assert_eq!(
+                decode_mint_utxo(&bytes).unwrap(),
+                Some((utxo_id.clone(), asset_id, output))
+            );
+        }
+
+        // UTXOs holding a transfer output are ignored
+        let mut bytes = utxo_bytes.clone();
+        pack_transfer_output(
+            &mut bytes,
+            &transfer_output::Output {
+                amount: 1,
+                output_owners: ewoq_owners(),
+            },
+        );
+        assert_eq!(
+            avm::utxo_output_type_id(&bytes),
+            Some(SECP256K1FX_TRANSFER_OUTPUT_TYPE_ID)
+        );
+        assert_eq!(decode_mint_utxo(&bytes).unwrap(), None);
+        assert!(decode_mint_utxo(&bytes[..60]).is_err());
+    }
+
+    #[async_std::test]
+    #[serial_test::serial]
+    #[ignore]
+    async fn test_create_and_mint_asset() {
+        let local_network = load_test_network();
+        let local_wallet = local_network
+            .create_wallet_from_cb58(AVAX_EWOQ_PRIVATE_KEY)
+            .unwrap();
+        let rpc_url = &local_network.get_xchain().unwrap().rpc_url;
+        let ewoq_short_id = address_to_short_id(AVAX_EWOQ_XCHAIN_ADDR, "X").unwrap();
+
+        let asset_id = create_asset(
+            &local_wallet,
+            "Ash Test Token",
+            "ATT",
+            2,
+            AvalancheXChainAssetInitialState::VariableCap {
+                minters: vec![ewoq_short_id.clone()],
+                threshold: 1,
+            },
+            true,
+        )
+        .await
+        .unwrap();
+
+        mint_asset(&local_wallet, asset_id, 1_000, ewoq_short_id.clone(), true)
+            .await
+            .unwrap();
+        // The mint output is recreated so the asset can be minted again
+        mint_asset(&local_wallet, asset_id, 500, ewoq_short_id, true)
+            .await
+            .unwrap();
+
+        let balance = get_balance(rpc_url, AVAX_EWOQ_XCHAIN_ADDR, &asset_id.to_string()).unwrap();
+        assert_eq!(balance.balance, 1_500);
+    }
+
+    #[async_std::test]
+    #[serial_test::serial]
+    #[ignore]
+    async fn test_create_and_mint_nft() {
+        let local_network = load_test_network();
+        let local_wallet = local_network
+            .create_wallet_from_cb58(AVAX_EWOQ_PRIVATE_KEY)
+            .unwrap();
+        let ewoq_short_id = address_to_short_id(AVAX_EWOQ_XCHAIN_ADDR, "X").unwrap();
+
+        let asset_id = create_asset(
+            &local_wallet,
+            "Ash Test NFT",
+            "ATN",
+            0,
+            AvalancheXChainAssetInitialState::Nft {
+                minters: vec![ewoq_short_id.clone()],
+                threshold: 1,
+                groups: 1,
+            },
+            true,
+        )
+        .await
+        .unwrap();
+
+        mint_nft(
+            &local_wallet,
+            asset_id,
+            b"ash".to_vec(),
+            ewoq_short_id.clone(),
+            true,
+        )
+        .await
+        .unwrap();
+        // The only group has already been minted
+        assert!(
+            mint_nft(&local_wallet, asset_id, vec![], ewoq_short_id, true)
+                .await
+                .is_err()
+        );
+    }
 }
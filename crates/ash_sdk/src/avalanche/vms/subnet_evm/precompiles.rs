@@ -5,12 +5,24 @@
 
 include!(concat!(env!("OUT_DIR"), "/warp_messenger.rs"));
 
-use crate::{avalanche::blockchains::AvalancheBlockchain, errors::*};
+use crate::{
+    avalanche::{
+        blockchains::AvalancheBlockchain,
+        vms::subnet_evm::warp::pack_predicate,
+        wallets::AvalancheEvmSigner,
+        warp::{WarpSignedMessage, WarpUnsignedMessage},
+    },
+    errors::*,
+};
 use avalanche_types::ids::Id;
 use ethers::{
-    core::types::{Address, BlockNumber, Log, H256},
+    core::types::{
+        transaction::eip2930::{AccessList, AccessListItem},
+        Address, BlockNumber, Bytes, Eip1559TransactionRequest, Log, H256,
+    },
     providers::{Http, Middleware, Provider},
 };
+use std::sync::Arc;
 
 /// WarpMessenger precompile address
 pub const WARP_MESSENGER_ADDRESS: &str = "0x0200000000000000000000000000000000000005";
@@ -102,4 +114,93 @@ impl WarpMessengerHttp {
 
         Ok(events)
     }
+
+    /// Deliver a signed Warp message to this chain
+    /// The message is set as the WarpMessenger predicate in the access list of a transaction
+    /// sent to the destination address with the given calldata
+    /// Return the transaction hash once the transaction is mined
+    pub async fn deliver_warp_message(
+        &self,
+        client: Arc<AvalancheEvmSigner>,
+        signed_message: &WarpSignedMessage,
+        destination_address: Address,
+        calldata: Bytes,
+    ) -> Result<H256, AshError> {
+        let tx = Eip1559TransactionRequest::new()
+            .to(destination_address)
+            .data(calldata)
+            .access_list(AccessList(vec![AccessListItem {
+                address: self.contract.address(),
+                storage_keys: pack_predicate(&signed_message.to_bytes()?),
+            }]));
+
+        let receipt = client
+            .send_transaction(tx, None)
+            .await
+            .map_err(|e| RpcError::EthTxFailure {
+                to_addr: format!("{destination_address:?}"),
+                msg: e.to_string(),
+            })?
+            .await
+            .map_err(|e| RpcError::EthTxFailure {
+                to_addr: format!("{destination_address:?}"),
+                msg: e.to_string(),
+            })?
+            .ok_or(RpcError::EthTxFailure {
+                to_addr: format!("{destination_address:?}"),
+                msg: "transaction was dropped from the mempool".to_string(),
+            })?;
+
+        Ok(receipt.transaction_hash)
+    }
+
+    /// Send a Warp message to a destination address on a destination chain
+    /// Return the transaction hash and the unsigned Warp message emitted once the transaction is mined
+    pub async fn send_warp_message(
+        &self,
+        client: Arc<AvalancheEvmSigner>,
+        destination_chain_id: H256,
+        destination_address: Address,
+        payload: Bytes,
+    ) -> Result<(H256, WarpUnsignedMessage), AshError> {
+        let warp_messenger = WarpMessenger::new(self.contract.address(), client);
+
+        let receipt = warp_messenger
+            .send_warp_message(destination_chain_id.into(), destination_address, payload)
+            .send()
+            .await
+            .map_err(|e| RpcError::EthCallFailure {
+                contract_addr: self.contract.address().to_string(),
+                function_name: "sendWarpMessage".to_string(),
+                msg: e.to_string(),
+            })?
+            .await
+            .map_err(|e| RpcError::EthCallFailure {
+                contract_addr: self.contract.address().to_string(),
+                function_name: "sendWarpMessage".to_string(),
+                msg: e.to_string(),
+            })?
+            .ok_or(RpcError::EthCallFailure {
+                contract_addr: self.contract.address().to_string(),
+                function_name: "sendWarpMessage".to_string(),
+                msg: "transaction was dropped from the mempool".to_string(),
+            })?;
+
+        // The unsigned Warp message is the data of the SendWarpMessage event log
+        let log = receipt
+            .logs
+            .iter()
+            .find(|log| log.address == self.contract.address())
+            .ok_or(AvalancheWarpMessagingError::ParseFailure {
+                property: "logs".to_string(),
+                msg: format!(
+                    "no SendWarpMessage event in the receipt of transaction '{:?}'",
+                    receipt.transaction_hash
+                ),
+            })?;
+        let unsigned_message =
+            WarpUnsignedMessage::try_from_subnet_evm_log_data(&log.data.to_vec()[..])?;
+
+        Ok((receipt.transaction_hash, unsigned_message))
+    }
 }
@@ -4,7 +4,10 @@
 // Module that contains code to interact with Subnet-EVM Warp messages
 
 use crate::{
-    avalanche::warp::{WarpMessagePayload, WarpUnsignedMessage},
+    avalanche::warp::{
+        codec::{pack_bytes, pack_codec_type_id, Unpacker},
+        WarpMessagePayload, WarpUnsignedMessage,
+    },
     errors::*,
 };
 use ethers::types::{Address, Bytes, Log, H256};
@@ -24,17 +27,29 @@ pub struct SubnetEVMWarpMessage {
     pub payload: Option<Bytes>,
 }
 
-impl From<Log> for SubnetEVMWarpMessage {
-    fn from(log: Log) -> Self {
+impl TryFrom<Log> for SubnetEVMWarpMessage {
+    type Error = AshError;
+
+    fn try_from(log: Log) -> Result<Self, AshError> {
+        // [0] -> event signature
+        // [1] -> destinationChainID
+        // [2] -> destinationAddress
+        // [3] -> sender
+        if log.topics.len() < 4 {
+            return Err(AvalancheWarpMessagingError::TruncatedInput {
+                property: "log topics".to_string(),
+                expected: 4,
+                found: log.topics.len(),
+            }
+            .into());
+        }
+
         // The log data is the WarpUnsignedMessage with the AddressedPayload as the payload
         let warp_unsigned_message =
             WarpUnsignedMessage::try_from_subnet_evm_log_data(&log.data.to_vec()[..])
-                .or_else::<Result<WarpUnsignedMessage, AshError>, _>(|_| {
-                    Ok(WarpUnsignedMessage::from(&log.data.to_vec()[..]))
-                })
-                .unwrap();
+                .or_else(|_| WarpUnsignedMessage::try_from(&log.data.to_vec()[..]))?;
 
-        Self {
+        Ok(Self {
             origin_chain_id: H256::from_slice(&warp_unsigned_message.source_chain_id.to_vec()),
             origin_sender_address: Address::from_slice(&log.topics[3].as_fixed_bytes()[12..]),
             destination_chain_id: H256::from_slice(log.topics[1].as_fixed_bytes()),
@@ -45,7 +60,7 @@ impl From<Log> for SubnetEVMWarpMessage {
                 }
                 _ => None,
             },
-        }
+        })
     }
 }
 
@@ -97,8 +112,8 @@ impl TryFrom<Vec<u8>> for AddressedPayload {
         }
 
         // Check that the payload length is correct
-        let payload_length = u32::from_be_bytes(payload[0..4].try_into().unwrap());
-        if (payload_length + 4) != payload.len() as u32 {
+        let payload_length = u32::from_be_bytes(payload[0..4].try_into().unwrap()) as usize;
+        if (payload_length + 4) != payload.len() {
             return Err(AshError::AvalancheWarpMessagingError(
                 AvalancheWarpMessagingError::ParseFailure {
                     property: "payload".to_string(),
@@ -116,6 +131,81 @@ impl TryFrom<Vec<u8>> for AddressedPayload {
     }
 }
 
+impl AddressedPayload {
+    /// Encode the payload to bytes (prefixed by its length)
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![];
+        pack_codec_type_id(&mut bytes, ADDRESSED_PAYLOAD_TYPE_ID);
+        bytes.extend_from_slice(self.source_address.as_bytes());
+        bytes.extend_from_slice(self.destination_chain_id.as_bytes());
+        bytes.extend_from_slice(self.destination_address.as_bytes());
+        bytes.extend_from_slice(&self.payload);
+
+        let mut packed = vec![];
+        pack_bytes(&mut packed, &bytes);
+
+        packed
+    }
+}
+
+/// Type ID of the Subnet-EVM AddressedPayload
+pub const ADDRESSED_PAYLOAD_TYPE_ID: u32 = 0;
+/// Type ID of the Subnet-EVM BlockHashPayload
+pub const BLOCK_HASH_PAYLOAD_TYPE_ID: u32 = 1;
+
+/// BlockHashPayload defines the format for delivering a block hash across VMs
+/// See https://github.com/ava-labs/subnet-evm/blob/309daad20ba17346ae3712c96c2db594e011b29c/warp/payload/payload.go
+#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockHashPayload {
+    pub block_hash: H256,
+}
+
+impl BlockHashPayload {
+    /// Encode the payload to bytes
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![];
+        pack_codec_type_id(&mut bytes, BLOCK_HASH_PAYLOAD_TYPE_ID);
+        bytes.extend_from_slice(self.block_hash.as_bytes());
+
+        bytes
+    }
+}
+
+impl TryFrom<&[u8]> for BlockHashPayload {
+    type Error = AshError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, AshError> {
+        let mut unpacker = Unpacker::new(bytes);
+        unpacker.read_codec_version()?;
+        let type_id = unpacker.read_u32("type ID")?;
+        if type_id != BLOCK_HASH_PAYLOAD_TYPE_ID {
+            return Err(AvalancheWarpMessagingError::ParseFailure {
+                property: "type ID".to_string(),
+                msg: format!("expected type ID {BLOCK_HASH_PAYLOAD_TYPE_ID}, found {type_id}"),
+            }
+            .into());
+        }
+        let block_hash = H256::from_slice(unpacker.read_fixed("block hash", 32)?);
+        unpacker.finish("BlockHashPayload")?;
+
+        Ok(Self { block_hash })
+    }
+}
+
+/// Byte appended to a predicate to delimit it from its padding
+const PREDICATE_END_BYTE: u8 = 0xff;
+
+/// Pack predicate bytes so they can be stored in the access list of a transaction
+/// The predicate is delimited by an end byte and right-padded with zeros to a multiple of 32 bytes
+pub fn pack_predicate(predicate: &[u8]) -> Vec<H256> {
+    let mut bytes = predicate.to_vec();
+    bytes.push(PREDICATE_END_BYTE);
+    bytes.resize(bytes.len().div_ceil(32) * 32, 0);
+
+    bytes.chunks(32).map(H256::from_slice).collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -138,9 +228,26 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_subnet_evm_warp_message_try_from_log_missing_topics() {
+        let mut log = warp_message_log();
+        log.topics.truncate(3);
+
+        assert_eq!(
+            SubnetEVMWarpMessage::try_from(log),
+            Err(AshError::AvalancheWarpMessagingError(
+                AvalancheWarpMessagingError::TruncatedInput {
+                    property: "log topics".to_string(),
+                    expected: 4,
+                    found: 3,
+                }
+            ))
+        );
+    }
+
     #[test]
     fn test_subnet_evm_warp_message_from_log() {
-        let warp_message = SubnetEVMWarpMessage::from(warp_message_log());
+        let warp_message = SubnetEVMWarpMessage::try_from(warp_message_log()).unwrap();
 
         assert_eq!(
             warp_message,
@@ -188,4 +295,37 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn test_pack_predicate() {
+        let packed = pack_predicate(&[1; 31]);
+        assert_eq!(packed.len(), 1);
+        assert_eq!(packed[0].as_bytes()[31], PREDICATE_END_BYTE);
+
+        let packed = pack_predicate(&[1; 32]);
+        assert_eq!(packed.len(), 2);
+        assert_eq!(packed[1].as_bytes()[0], PREDICATE_END_BYTE);
+        assert_eq!(packed[1].as_bytes()[1..], [0; 31]);
+    }
+
+    #[test]
+    fn test_addressed_payload_round_trip() {
+        let bytes = hex::decode(ADDRESSED_PAYLOAD_HEX).unwrap();
+        let addressed_payload = AddressedPayload::try_from(bytes.clone()).unwrap();
+
+        assert_eq!(addressed_payload.to_bytes(), bytes);
+    }
+
+    #[test]
+    fn test_block_hash_payload_round_trip() {
+        let payload = BlockHashPayload {
+            block_hash: H256::from_str(
+                "0x76dccb39c21a43aad4ffa98d4dd86a9ca29f5038a13b87658ef856bc161dbb47",
+            )
+            .unwrap(),
+        };
+
+        let bytes = payload.to_bytes();
+        assert_eq!(BlockHashPayload::try_from(&bytes[..]).unwrap(), payload);
+    }
 }
@@ -1,25 +1,283 @@
 // SPDX-License-Identifier: BSD-3-Clause
 // Copyright (c) 2023, E36 Knots
 
+pub mod keystore;
+#[cfg(unix)]
+pub mod remote;
+
 // Module that contains code to interact with Avalanche wallets
 
 use crate::{
-    avalanche::{address_to_short_id, txs::x},
+    avalanche::{
+        address_to_short_id,
+        txs::{
+            partial::AvalanchePartialTx,
+            x::{self, AvalancheXChainAssetInitialState},
+        },
+    },
     errors::*,
 };
+use async_trait::async_trait;
 use avalanche_types::{
-    ids::Id,
-    key::secp256k1::private_key::Key as PrivateKey,
+    errors::{Error as AvalancheTypesError, Result as AvalancheTypesResult},
+    ids::{short::Id as ShortId, Id},
+    key::secp256k1::{
+        private_key::Key as PrivateKey, public_key::Key as PublicKey, KeyType, ReadOnly, SignOnly,
+    },
     wallet::{Builder as WalletBuilder, Wallet},
 };
+use ethers::{
+    core::k256::ecdsa::SigningKey,
+    middleware::{NonceManagerMiddleware, SignerMiddleware},
+    providers::{Http, Middleware, Provider},
+    signers::{coins_bip39::English, LocalWallet, MnemonicBuilder, Signer},
+    types::{Address, H160},
+};
 use serde::{Deserialize, Serialize};
+use std::{fmt::Debug, ops::Range, sync::Arc};
+
+/// BIP-44 derivation path of the Avalanche (X-Chain and P-Chain) accounts
+pub const AVAX_ACCOUNT_DERIV_PATH: &str = "m/44'/9000'/0'";
+/// BIP-44 derivation path of the EVM (C-Chain) accounts
+pub const EVM_ACCOUNT_DERIV_PATH: &str = "m/44'/60'/0'";
+
+/// Ethers client that signs EVM transactions with the private key of an Avalanche wallet
+/// The nonces of the wallet EVM address are managed locally to send multiple transactions in a row
+pub type AvalancheEvmSigner = NonceManagerMiddleware<SignerMiddleware<Provider<Http>, LocalWallet>>;
+
+/// Backend able to sign Avalanche transactions
+/// Implementations only have to expose their public key and sign digests,
+/// so that the private key can be kept out of the process (e.g. in a remote signing process)
+#[async_trait]
+pub trait AvalancheSigner: Debug + Send + Sync {
+    /// Get the name of the signer backend, used to identify it in errors (e.g. `remote:<socket>`)
+    fn name(&self) -> String;
+
+    /// Get the public key of the signer
+    fn public_key(&self) -> PublicKey;
+
+    /// Sign a 32-byte SHA256 digest
+    /// Return the 65-byte recoverable signature `[r || s || v]`
+    async fn sign_digest(&self, digest: &[u8]) -> Result<[u8; 65], AshError>;
+
+    /// Get the private key of the signer if it is held in memory
+    /// It is required to sign EVM transactions and to export the key
+    fn private_key(&self) -> Option<PrivateKey> {
+        None
+    }
+}
+
+/// Signer that holds its private key in memory
+#[derive(Debug, Clone)]
+pub struct LocalSigner {
+    private_key: PrivateKey,
+}
+
+impl LocalSigner {
+    /// Create a new local signer from a private key
+    pub fn new(private_key: PrivateKey) -> Self {
+        Self { private_key }
+    }
+
+    /// Create a new local signer from a CB58-encoded private key
+    pub fn from_cb58(private_key: &str) -> Result<Self, AshError> {
+        let private_key = PrivateKey::from_cb58(private_key)
+            .map_err(|e| AvalancheWalletError::InvalidPrivateKey(e.to_string()))?;
+
+        Ok(Self::new(private_key))
+    }
+
+    /// Create a new local signer from an hex-encoded private key
+    pub fn from_hex(private_key: &str) -> Result<Self, AshError> {
+        let private_key = PrivateKey::from_hex(private_key)
+            .map_err(|e| AvalancheWalletError::InvalidPrivateKey(e.to_string()))?;
+
+        Ok(Self::new(private_key))
+    }
+}
+
+#[async_trait]
+impl AvalancheSigner for LocalSigner {
+    fn name(&self) -> String {
+        "local".to_string()
+    }
+
+    fn public_key(&self) -> PublicKey {
+        self.private_key.to_public_key()
+    }
+
+    async fn sign_digest(&self, digest: &[u8]) -> Result<[u8; 65], AshError> {
+        let signature = self
+            .private_key
+            .sign_digest(digest)
+            .map_err(|e| AvalancheWalletError::InvalidPrivateKey(e.to_string()))?;
+
+        Ok(signature.to_bytes())
+    }
+
+    fn private_key(&self) -> Option<PrivateKey> {
+        Some(self.private_key.clone())
+    }
+}
+
+/// Signer that only knows its public key and cannot sign
+/// It is used to build unsigned transactions on an online machine, to be signed on an air-gapped one
+#[derive(Debug, Clone)]
+pub struct WatchOnlySigner {
+    public_key: PublicKey,
+}
+
+impl WatchOnlySigner {
+    /// Create a new watch-only signer from a public key
+    pub fn new(public_key: PublicKey) -> Self {
+        Self { public_key }
+    }
+
+    /// Create a new watch-only signer from an hex-encoded public key (compressed or uncompressed)
+    pub fn from_hex(public_key: &str) -> Result<Self, AshError> {
+        let public_key_bytes = hex::decode(public_key.trim_start_matches("0x"))
+            .map_err(|e| AvalancheWalletError::InvalidPublicKey(e.to_string()))?;
+        let public_key = PublicKey::from_sec1_bytes(&public_key_bytes)
+            .map_err(|e| AvalancheWalletError::InvalidPublicKey(e.to_string()))?;
+
+        Ok(Self::new(public_key))
+    }
+}
+
+#[async_trait]
+impl AvalancheSigner for WatchOnlySigner {
+    fn name(&self) -> String {
+        "watch-only".to_string()
+    }
+
+    fn public_key(&self) -> PublicKey {
+        self.public_key
+    }
+
+    async fn sign_digest(&self, _digest: &[u8]) -> Result<[u8; 65], AshError> {
+        Err(AvalancheWalletError::SignerFailure {
+            signer: self.name(),
+            msg: "a watch-only signer cannot sign transactions".to_string(),
+        }
+        .into())
+    }
+}
+
+/// Key of an Avalanche wallet backed by a signer
+/// It implements the avalanche-types key traits so that the transaction builders sign through the signer
+#[derive(Debug, Clone)]
+pub struct AvalancheSignerKey(pub Arc<dyn AvalancheSigner>);
+
+impl<T: AvalancheSigner + 'static> From<T> for AvalancheSignerKey {
+    fn from(signer: T) -> Self {
+        Self::new(signer)
+    }
+}
+
+impl AvalancheSignerKey {
+    /// Create a new key from a signer
+    pub fn new(signer: impl AvalancheSigner + 'static) -> Self {
+        Self(Arc::new(signer))
+    }
+
+    /// Get the public key of the signer
+    pub fn public_key(&self) -> PublicKey {
+        self.0.public_key()
+    }
+
+    /// Get the private key of the signer
+    /// Fail if the signer does not hold its private key in memory
+    pub fn private_key(&self) -> Result<PrivateKey, AshError> {
+        self.0.private_key().ok_or(
+            AvalancheWalletError::SignerFailure {
+                signer: self.0.name(),
+                msg: "the private key is not available".to_string(),
+            }
+            .into(),
+        )
+    }
+
+    /// Create an ethers client that signs EVM transactions for a chain ID with the key
+    /// Fail if the signer does not hold its private key in memory
+    pub fn to_evm_signer(
+        &self,
+        provider: Provider<Http>,
+        chain_id: u64,
+    ) -> Result<AvalancheEvmSigner, AshError> {
+        let signer = LocalWallet::from_bytes(&self.private_key()?.to_bytes())
+            .map_err(|e| AvalancheWalletError::InvalidPrivateKey(e.to_string()))?
+            .with_chain_id(chain_id);
+        let address = signer.address();
+
+        Ok(NonceManagerMiddleware::new(
+            SignerMiddleware::new(provider, signer),
+            address,
+        ))
+    }
+}
+
+#[async_trait]
+impl SignOnly for AvalancheSignerKey {
+    fn signing_key(&self) -> AvalancheTypesResult<SigningKey> {
+        match self.0.private_key() {
+            Some(private_key) => Ok(private_key.signing_key()),
+            None => Err(AvalancheTypesError::Other {
+                message: "the signing key is not available".to_string(),
+                retryable: false,
+            }),
+        }
+    }
+
+    async fn sign_digest(&self, digest: &[u8]) -> AvalancheTypesResult<[u8; 65]> {
+        self.0
+            .sign_digest(digest)
+            .await
+            .map_err(|e| AvalancheTypesError::Other {
+                message: e.to_string(),
+                retryable: false,
+            })
+    }
+}
+
+impl ReadOnly for AvalancheSignerKey {
+    fn key_type(&self) -> KeyType {
+        match self.0.private_key() {
+            Some(_) => KeyType::Hot,
+            None => KeyType::Unknown("remote".to_string()),
+        }
+    }
+
+    fn hrp_address(&self, network_id: u32, chain_id_alias: &str) -> AvalancheTypesResult<String> {
+        self.public_key().to_hrp_address(network_id, chain_id_alias)
+    }
+
+    fn short_address(&self) -> AvalancheTypesResult<ShortId> {
+        self.public_key().to_short_id()
+    }
+
+    fn short_address_bytes(&self) -> AvalancheTypesResult<Vec<u8>> {
+        self.public_key().to_short_bytes()
+    }
+
+    fn eth_address(&self) -> String {
+        self.public_key().to_eth_address()
+    }
+
+    fn h160_address(&self) -> H160 {
+        self.public_key().to_h160()
+    }
+}
 
 /// Avalanche wallet
 #[derive(Debug, Clone)]
 pub struct AvalancheWallet {
-    pub private_key: PrivateKey,
-    pub xchain_wallet: Wallet<PrivateKey>,
-    pub pchain_wallet: Wallet<PrivateKey>,
+    /// Key used to sign the X-Chain and P-Chain transactions
+    pub signer: AvalancheSignerKey,
+    /// Key used to sign the EVM transactions
+    /// It is the same as `signer` unless the wallet is derived from a mnemonic phrase
+    pub evm_signer: AvalancheSignerKey,
+    pub xchain_wallet: Wallet<AvalancheSignerKey>,
+    pub pchain_wallet: Wallet<AvalancheSignerKey>,
 }
 
 impl AvalancheWallet {
@@ -28,21 +286,43 @@ impl AvalancheWallet {
         private_key: PrivateKey,
         xchain_url: &str,
         pchain_url: &str,
+    ) -> Result<Self, AshError> {
+        Self::new_from_signer(LocalSigner::new(private_key), xchain_url, pchain_url).await
+    }
+
+    /// Create a new Avalanche wallet that signs transactions with a signer
+    pub async fn new_from_signer(
+        signer: impl Into<AvalancheSignerKey>,
+        xchain_url: &str,
+        pchain_url: &str,
+    ) -> Result<Self, AshError> {
+        let signer = signer.into();
+
+        Self::new_with_evm_signer(signer.clone(), signer, xchain_url, pchain_url).await
+    }
+
+    // Create a new Avalanche wallet that uses a different key on EVM chains
+    async fn new_with_evm_signer(
+        signer: AvalancheSignerKey,
+        evm_signer: AvalancheSignerKey,
+        xchain_url: &str,
+        pchain_url: &str,
     ) -> Result<Self, AshError> {
         // Create one wallet for each chain because the RPC URLs can be different
-        let xchain_wallet = WalletBuilder::new(&private_key)
+        let xchain_wallet = WalletBuilder::new(&signer)
             .base_http_url(xchain_url.to_string())
             .build()
             .await
             .map_err(|e| AvalancheWalletError::CreationFailure(e.to_string()))?;
-        let pchain_wallet = WalletBuilder::new(&private_key)
+        let pchain_wallet = WalletBuilder::new(&signer)
             .base_http_url(pchain_url.to_string())
             .build()
             .await
             .map_err(|e| AvalancheWalletError::CreationFailure(e.to_string()))?;
 
         Ok(Self {
-            private_key,
+            signer,
+            evm_signer,
             xchain_wallet,
             pchain_wallet,
         })
@@ -72,32 +352,64 @@ impl AvalancheWallet {
         Self::new(private_key, xchain_url, pchain_url).await
     }
 
-    // Disabled because it has no concrete use case
-    /// Create a new Avalanche wallet from a mnemonic phrase
-    /// The phrase must be 24 words long
-    // pub async fn new_from_mnemonic_phrase(
-    //     phrase: &str,
-    //     account_index: u32,
-    //     xchain_url: &str,
-    //     pchain_url: &str,
-    // ) -> Result<Self, AshError> {
-    //     let private_key = PrivateKey::from_mnemonic_phrase(
-    //         phrase,
-    //         &format!("{}/0/{}", AVAX_ACCOUNT_DERIV_PATH, account_index),
-    //     )
-    //     .map_err(|e| AvalancheWalletError::InvalidPrivateKey(e.to_string()))?;
-
-    //     Self::new(private_key, xchain_url, pchain_url).await
-    // }
+    /// Create a new Avalanche wallet from a BIP-39 mnemonic phrase
+    /// The X-Chain/P-Chain key is derived at `m/44'/9000'/0'/0/{account_index}`
+    /// and the EVM key at `m/44'/60'/0'/0/{account_index}`
+    pub async fn new_from_mnemonic_phrase(
+        phrase: &str,
+        account_index: u32,
+        xchain_url: &str,
+        pchain_url: &str,
+    ) -> Result<Self, AshError> {
+        let private_key = derive_private_key(phrase, AVAX_ACCOUNT_DERIV_PATH, account_index)?;
+        let evm_private_key = derive_private_key(phrase, EVM_ACCOUNT_DERIV_PATH, account_index)?;
+
+        Self::new_with_evm_signer(
+            AvalancheSignerKey::new(LocalSigner::new(private_key)),
+            AvalancheSignerKey::new(LocalSigner::new(evm_private_key)),
+            xchain_url,
+            pchain_url,
+        )
+        .await
+    }
 
     /// Export the private key as a CB58-encoded string
-    pub fn export_private_key_cb58(&self) -> String {
-        self.private_key.to_cb58()
+    /// Fail if the signer does not hold its private key in memory
+    pub fn export_private_key_cb58(&self) -> Result<String, AshError> {
+        Ok(self.signer.private_key()?.to_cb58())
     }
 
     /// Export the private key as an hex-encoded string
-    pub fn export_private_key_hex(&self) -> String {
-        self.private_key.to_hex()
+    /// Fail if the signer does not hold its private key in memory
+    pub fn export_private_key_hex(&self) -> Result<String, AshError> {
+        Ok(self.signer.private_key()?.to_hex())
+    }
+
+    /// Get the EVM address of the wallet
+    pub fn get_evm_address(&self) -> Result<Address, AshError> {
+        Ok(self.evm_signer.public_key().to_h160())
+    }
+
+    /// Create an ethers client that signs EVM transactions with the wallet private key
+    /// The chain ID used to sign the transactions is queried from the provider
+    /// The client should be created once and shared by all the transactions sent in a row,
+    /// so that its nonce manager keeps track of the nonces already used
+    /// Fail if the EVM signer does not hold its private key in memory
+    pub async fn get_evm_signer(
+        &self,
+        provider: Provider<Http>,
+    ) -> Result<AvalancheEvmSigner, AshError> {
+        let chain_id = provider
+            .get_chainid()
+            .await
+            .map_err(|e| RpcError::GetFailure {
+                data_type: "chain ID".to_string(),
+                target_type: "provider".to_string(),
+                target_value: provider.url().to_string(),
+                msg: e.to_string(),
+            })?;
+
+        self.evm_signer.to_evm_signer(provider, chain_id.as_u64())
     }
 
     /// Transfer AVAX to a given address on the X-Chain
@@ -113,6 +425,67 @@ impl AvalancheWallet {
 
         Ok(tx_id)
     }
+
+    /// Build an unsigned transaction to transfer AVAX to a given address on the X-Chain
+    /// The transaction has to be signed with `AvalanchePartialTx::sign` before being issued
+    pub async fn build_transfer_avax_xchain_tx(
+        &self,
+        to: &str,
+        amount: u64,
+    ) -> Result<AvalanchePartialTx, AshError> {
+        let receiver = address_to_short_id(to, "X")?;
+
+        x::build_transfer_avax_partial_tx(self, receiver, amount).await
+    }
+
+    /// Create an asset on the X-Chain
+    /// Returns the asset ID
+    pub async fn create_xchain_asset(
+        &self,
+        name: &str,
+        symbol: &str,
+        denomination: u8,
+        initial_state: AvalancheXChainAssetInitialState,
+        check_acceptance: bool,
+    ) -> Result<Id, AshError> {
+        x::create_asset(
+            self,
+            name,
+            symbol,
+            denomination,
+            initial_state,
+            check_acceptance,
+        )
+        .await
+    }
+
+    /// Mint units of a variable-cap asset to a given address on the X-Chain
+    /// Returns the transaction ID
+    pub async fn mint_xchain_asset(
+        &self,
+        asset_id: Id,
+        amount: u64,
+        to: &str,
+        check_acceptance: bool,
+    ) -> Result<Id, AshError> {
+        let receiver = address_to_short_id(to, "X")?;
+
+        x::mint_asset(self, asset_id, amount, receiver, check_acceptance).await
+    }
+
+    /// Mint an NFT of an NFT family to a given address on the X-Chain
+    /// Returns the transaction ID
+    pub async fn mint_xchain_nft(
+        &self,
+        asset_id: Id,
+        payload: Vec<u8>,
+        to: &str,
+        check_acceptance: bool,
+    ) -> Result<Id, AshError> {
+        let receiver = address_to_short_id(to, "X")?;
+
+        x::mint_nft(self, asset_id, payload, receiver, check_acceptance).await
+    }
 }
 
 /// Avalanche wallet information
@@ -125,24 +498,103 @@ pub struct AvalancheWalletInfo {
     pub pchain_address: String,
     /// EVM address
     pub evm_address: String,
-    /// Hex-encoded private key
-    pub hex_private_key: String,
-    /// CB58-encoded private key
-    pub cb58_private_key: String,
+    /// Hex-encoded compressed public key
+    pub hex_public_key: String,
+    /// Hex-encoded private key (only if the signer holds it)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hex_private_key: Option<String>,
+    /// CB58-encoded private key (only if the signer holds it)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cb58_private_key: Option<String>,
+    /// Hex-encoded EVM private key (only if it differs from the private key)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hex_evm_private_key: Option<String>,
 }
 
 impl From<AvalancheWallet> for AvalancheWalletInfo {
     fn from(wallet: AvalancheWallet) -> Self {
         Self {
+            hex_public_key: format!(
+                "0x{}",
+                hex::encode(wallet.signer.public_key().to_compressed_bytes())
+            ),
+            hex_private_key: wallet.export_private_key_hex().ok(),
+            cb58_private_key: wallet.export_private_key_cb58().ok(),
             xchain_address: wallet.xchain_wallet.x_address,
             pchain_address: wallet.pchain_wallet.p_address,
-            evm_address: wallet.xchain_wallet.eth_address,
-            hex_private_key: wallet.private_key.to_hex(),
-            cb58_private_key: wallet.private_key.to_cb58(),
+            evm_address: wallet.evm_signer.eth_address(),
+            hex_evm_private_key: match wallet.evm_signer.public_key() == wallet.signer.public_key()
+            {
+                true => None,
+                false => wallet.evm_signer.private_key().ok().map(|key| key.to_hex()),
+            },
         }
     }
 }
 
+/// Addresses of an account derived from a mnemonic phrase
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AvalancheMnemonicAccount {
+    /// Account index in the derivation paths
+    pub index: u32,
+    /// X-Chain address
+    pub xchain_address: String,
+    /// P-Chain address
+    pub pchain_address: String,
+    /// EVM address
+    pub evm_address: String,
+}
+
+/// Derive a private key from a BIP-39 mnemonic phrase (without passphrase)
+/// The key is derived at `{account_deriv_path}/0/{account_index}`
+pub fn derive_private_key(
+    phrase: &str,
+    account_deriv_path: &str,
+    account_index: u32,
+) -> Result<PrivateKey, AshError> {
+    let signer = MnemonicBuilder::<English>::default()
+        .phrase(phrase)
+        .derivation_path(&format!("{account_deriv_path}/0/{account_index}"))
+        .and_then(|builder| builder.build())
+        .map_err(|e| AvalancheWalletError::InvalidPrivateKey(e.to_string()))?;
+
+    let private_key = PrivateKey::from_bytes(&signer.signer().to_bytes())
+        .map_err(|e| AvalancheWalletError::InvalidPrivateKey(e.to_string()))?;
+
+    Ok(private_key)
+}
+
+/// Derive the addresses of a range of accounts from a BIP-39 mnemonic phrase
+/// The X-Chain and P-Chain addresses are encoded with the HRP of the network ID
+pub fn derive_mnemonic_accounts(
+    phrase: &str,
+    network_id: u32,
+    account_indexes: Range<u32>,
+) -> Result<Vec<AvalancheMnemonicAccount>, AshError> {
+    account_indexes
+        .map(|index| {
+            let avax_public_key =
+                derive_private_key(phrase, AVAX_ACCOUNT_DERIV_PATH, index)?.to_public_key();
+            let evm_public_key =
+                derive_private_key(phrase, EVM_ACCOUNT_DERIV_PATH, index)?.to_public_key();
+
+            let hrp_address = |chain_alias| {
+                avax_public_key
+                    .to_hrp_address(network_id, chain_alias)
+                    .map_err(|e| AvalancheWalletError::InvalidPrivateKey(e.to_string()))
+            };
+
+            Ok(AvalancheMnemonicAccount {
+                index,
+                xchain_address: hrp_address("X")?,
+                pchain_address: hrp_address("P")?,
+                evm_address: evm_public_key.to_eth_address(),
+            })
+        })
+        .collect()
+}
+
 /// Generate a private key from random bytes
 pub fn generate_private_key() -> Result<PrivateKey, AshError> {
     let private_key = PrivateKey::generate()
@@ -161,8 +613,8 @@ mod tests {
     const AVAX_HEX_PRIVATE_KEY: &str =
         "0x56289e99c94b6912bfc12adc093c9b51124f0dc54ac7a766b2bc5ccf558d8027";
     // This mnemonic phrase is not linked to the ewoq account
-    // const AVAX_MNEMONIC_PHRASE: &str =
-    //     "vehicle arrive more spread busy regret onion fame argue nice grocery humble vocal slot quit toss learn artwork theory fault tip belt cloth disorder";
+    const AVAX_MNEMONIC_PHRASE: &str =
+        "vehicle arrive more spread busy regret onion fame argue nice grocery humble vocal slot quit toss learn artwork theory fault tip belt cloth disorder";
 
     // Load the test network using avalanche-network-runner
     fn load_test_network() -> AvalancheNetwork {
@@ -181,7 +633,10 @@ mod tests {
         .await
         .unwrap();
 
-        assert_eq!(wallet.private_key.to_cb58(), AVAX_CB58_PRIVATE_KEY);
+        assert_eq!(
+            wallet.export_private_key_cb58().unwrap(),
+            AVAX_CB58_PRIVATE_KEY
+        );
     }
 
     #[async_std::test]
@@ -196,25 +651,128 @@ mod tests {
         .await
         .unwrap();
 
-        assert_eq!(wallet.private_key.to_hex(), AVAX_HEX_PRIVATE_KEY);
-    }
-
-    // #[async_std::test]
-    // #[ignore]
-    // async fn test_create_new_from_mnemonic_phrase() {
-    //     let network = load_test_network();
-    //     let wallet = AvalancheWallet::new_from_mnemonic_phrase(
-    //         AVAX_MNEMONIC_PHRASE,
-    //         0,
-    //         &network.get_xchain().unwrap().rpc_url,
-    //         &network.get_pchain().unwrap().rpc_url,
-    //     )
-    //     .await
-    //     .unwrap();
-
-    //     assert_eq!(
-    //         wallet.private_key.to_hex(),
-    //         "0xf88975995ec2c83832dc7fb071b78d015ffc1bc4474810c1f05f60738f4ffd26"
-    //     );
-    // }
+        assert_eq!(
+            wallet.export_private_key_hex().unwrap(),
+            AVAX_HEX_PRIVATE_KEY
+        );
+    }
+
+    #[test]
+    fn test_avalanche_signer_key_to_evm_signer() {
+        let signer_key = AvalancheSignerKey::new(LocalSigner::new(
+            PrivateKey::from_cb58(AVAX_CB58_PRIVATE_KEY).unwrap(),
+        ));
+        // The provider is not queried to create the client
+        let provider = Provider::<Http>::try_from("http://127.0.0.1:1").unwrap();
+
+        let evm_signer = signer_key.to_evm_signer(provider, 43112).unwrap();
+
+        assert_eq!(
+            format!("{:?}", evm_signer.inner().address()),
+            "0x8db97c7cece249c2b98bdc0226cc4c2a57bf52fc"
+        );
+        assert_eq!(evm_signer.inner().signer().chain_id(), 43112);
+        // Transactions sent with the same client get consecutive nonces
+        let nonce = evm_signer.next();
+        assert_eq!(evm_signer.next(), nonce + 1);
+    }
+
+    #[async_std::test]
+    #[ignore]
+    async fn test_get_evm_address() {
+        let network = load_test_network();
+        let wallet = AvalancheWallet::new_from_cb58(
+            AVAX_CB58_PRIVATE_KEY,
+            &network.get_xchain().unwrap().rpc_url,
+            &network.get_pchain().unwrap().rpc_url,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            format!("{:?}", wallet.get_evm_address().unwrap()),
+            "0x8db97c7cece249c2b98bdc0226cc4c2a57bf52fc"
+        );
+    }
+
+    #[async_std::test]
+    #[ignore]
+    async fn test_create_new_from_mnemonic_phrase() {
+        let network = load_test_network();
+        let wallet = AvalancheWallet::new_from_mnemonic_phrase(
+            AVAX_MNEMONIC_PHRASE,
+            0,
+            &network.get_xchain().unwrap().rpc_url,
+            &network.get_pchain().unwrap().rpc_url,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            wallet.export_private_key_hex().unwrap(),
+            "0xc960efb06a74e53a3f7d2c4ab7aa3b3504457e37e54651f6591d615ff41315c1"
+        );
+        assert_eq!(
+            wallet.evm_signer.private_key().unwrap().to_hex(),
+            "0x963cf222d5831a37ea152b2f23b67051d5a2af6532f85dbb1f2b22baf16cb34c"
+        );
+    }
+
+    #[async_std::test]
+    async fn test_watch_only_signer() {
+        let local_signer = LocalSigner::from_cb58(AVAX_CB58_PRIVATE_KEY).unwrap();
+        let public_key = local_signer.public_key();
+
+        for public_key_hex in [
+            hex::encode(public_key.to_compressed_bytes()),
+            format!("0x{}", hex::encode(public_key.to_uncompressed_bytes())),
+        ] {
+            let watch_only_signer = WatchOnlySigner::from_hex(&public_key_hex).unwrap();
+            assert_eq!(watch_only_signer.public_key(), public_key);
+            assert!(watch_only_signer.private_key().is_none());
+            assert!(watch_only_signer.sign_digest(&[0; 32]).await.is_err());
+        }
+        assert!(WatchOnlySigner::from_hex("0x1234").is_err());
+    }
+
+    #[test]
+    fn test_derive_private_key() {
+        assert_eq!(
+            derive_private_key(AVAX_MNEMONIC_PHRASE, AVAX_ACCOUNT_DERIV_PATH, 1)
+                .unwrap()
+                .to_hex(),
+            "0x86967dc221fe6d88b7e6712fe6850f98f24a725a1df0e2480e5ef83c39f45396"
+        );
+        assert_eq!(
+            derive_private_key(AVAX_MNEMONIC_PHRASE, EVM_ACCOUNT_DERIV_PATH, 1)
+                .unwrap()
+                .to_hex(),
+            "0x5a0f6c34cf5570eb3d701312c19996206e048eb51994766ef854a669de51b4cb"
+        );
+        assert!(derive_private_key("vehicle arrive more", AVAX_ACCOUNT_DERIV_PATH, 0).is_err());
+    }
+
+    #[test]
+    fn test_derive_mnemonic_accounts() {
+        // Fuji network ID
+        let accounts = derive_mnemonic_accounts(AVAX_MNEMONIC_PHRASE, 5, 0..2).unwrap();
+
+        assert_eq!(
+            accounts,
+            vec![
+                AvalancheMnemonicAccount {
+                    index: 0,
+                    xchain_address: "X-fuji1t9wlp6cqhakj80g85q9etjaj2ljamtdh4clch3".to_string(),
+                    pchain_address: "P-fuji1t9wlp6cqhakj80g85q9etjaj2ljamtdh4clch3".to_string(),
+                    evm_address: "0xD8606355cE3cCf6817E2f9a99C4A329eF621Aae7".to_string(),
+                },
+                AvalancheMnemonicAccount {
+                    index: 1,
+                    xchain_address: "X-fuji16jsfky6y8qenaf3g9vnvfst02t04pk02tlwapw".to_string(),
+                    pchain_address: "P-fuji16jsfky6y8qenaf3g9vnvfst02t04pk02tlwapw".to_string(),
+                    evm_address: "0x89eFf4058a854e244C566f20E60C7F0D82d57a07".to_string(),
+                },
+            ]
+        );
+    }
 }
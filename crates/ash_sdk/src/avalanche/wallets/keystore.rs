@@ -0,0 +1,213 @@
+// SPDX-License-Identifier: BSD-3-Clause
+// Copyright (c) 2023, E36 Knots
+
+// Module that contains code to store private keys in encrypted keystore files
+// Keystore files follow the Ethereum keystore v3 format (scrypt + AES-128-CTR)
+// See https://ethereum.org/en/developers/docs/data-structures-and-encoding/web3-secret-storage/
+
+use crate::{avalanche::wallets::LocalSigner, errors::*};
+use avalanche_types::key::secp256k1::private_key::Key as PrivateKey;
+use eth_keystore::EthKeystore;
+use ethers::core::rand::thread_rng;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// Get the path of the keystore file named `name` in `dir`
+/// Names can only contain alphanumeric characters, '-', '_' and '.' (and cannot start with '.')
+pub fn get_keystore_path(dir: &Path, name: &str) -> Result<PathBuf, AshError> {
+    let keystore_path = dir.join(name);
+
+    if name.is_empty()
+        || name.starts_with('.')
+        || !name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "-_.".contains(c))
+    {
+        return Err(AvalancheWalletError::KeystoreFailure {
+            path: keystore_path.display().to_string(),
+            msg: format!("invalid keystore name '{name}'"),
+        }
+        .into());
+    }
+
+    Ok(keystore_path)
+}
+
+/// Encrypt a private key with a password and store it in a keystore file named `name` in `dir`
+/// The directory is created if it does not exist and existing keystores are never overwritten
+/// Return the path of the keystore file
+pub fn encrypt_keystore(
+    private_key: &PrivateKey,
+    dir: &Path,
+    name: &str,
+    password: &str,
+) -> Result<PathBuf, AshError> {
+    let keystore_path = get_keystore_path(dir, name)?;
+    let keystore_failure = |msg: String| AvalancheWalletError::KeystoreFailure {
+        path: keystore_path.display().to_string(),
+        msg,
+    };
+
+    if keystore_path.exists() {
+        return Err(
+            keystore_failure("a keystore with this name already exists".to_string()).into(),
+        );
+    }
+    fs::create_dir_all(dir).map_err(|e| keystore_failure(e.to_string()))?;
+
+    eth_keystore::encrypt_key(
+        dir,
+        &mut thread_rng(),
+        private_key.to_bytes(),
+        password,
+        Some(name),
+    )
+    .map_err(|e| keystore_failure(e.to_string()))?;
+
+    Ok(keystore_path)
+}
+
+/// List the names of the keystore files in `dir`, sorted alphabetically
+/// Files that are not v3 keystores are ignored
+/// Return an empty list if the directory does not exist
+pub fn list_keystores(dir: &Path) -> Result<Vec<String>, AshError> {
+    if !dir.exists() {
+        return Ok(vec![]);
+    }
+
+    let mut names = fs::read_dir(dir)
+        .map_err(|e| AvalancheWalletError::KeystoreFailure {
+            path: dir.display().to_string(),
+            msg: e.to_string(),
+        })?
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().is_file() && is_keystore_file(&entry.path()))
+        .filter_map(|entry| entry.file_name().into_string().ok())
+        .filter(|name| !name.starts_with('.'))
+        .collect::<Vec<_>>();
+    names.sort();
+
+    Ok(names)
+}
+
+// Check if a file parses as a v3 keystore
+fn is_keystore_file(path: &Path) -> bool {
+    fs::read_to_string(path)
+        .ok()
+        .and_then(|content| serde_json::from_str::<EthKeystore>(&content).ok())
+        .is_some_and(|keystore| keystore.version == 3)
+}
+
+/// Remove the keystore file named `name` from `dir`
+pub fn remove_keystore(dir: &Path, name: &str) -> Result<(), AshError> {
+    let keystore_path = get_keystore_path(dir, name)?;
+
+    fs::remove_file(&keystore_path).map_err(|e| AvalancheWalletError::KeystoreFailure {
+        path: keystore_path.display().to_string(),
+        msg: e.to_string(),
+    })?;
+
+    Ok(())
+}
+
+/// Decrypt the private key stored in a keystore file with a password
+pub fn decrypt_keystore(path: &Path, password: &str) -> Result<PrivateKey, AshError> {
+    let keystore_failure = |msg: String| AvalancheWalletError::KeystoreFailure {
+        path: path.display().to_string(),
+        msg,
+    };
+
+    let private_key_bytes =
+        eth_keystore::decrypt_key(path, password).map_err(|e| keystore_failure(e.to_string()))?;
+    let private_key =
+        PrivateKey::from_bytes(&private_key_bytes).map_err(|e| keystore_failure(e.to_string()))?;
+
+    Ok(private_key)
+}
+
+impl LocalSigner {
+    /// Create a new local signer from the private key stored in a keystore file
+    pub fn from_keystore(path: &Path, password: &str) -> Result<Self, AshError> {
+        Ok(Self::new(decrypt_keystore(path, password)?))
+    }
+
+    /// Store the private key of the signer in a keystore file named `name` in `dir`
+    /// Return the path of the keystore file
+    pub fn to_keystore(&self, dir: &Path, name: &str, password: &str) -> Result<PathBuf, AshError> {
+        encrypt_keystore(&self.private_key, dir, name, password)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const AVAX_EWOQ_PRIVATE_KEY: &str =
+        "PrivateKey-ewoqjP7PxY4yr3iLTpLisriqt94hdyDFNgchSxGGztUrTXtNN";
+
+    #[test]
+    fn test_encrypt_decrypt_keystore() {
+        let keystore_dir = tempfile::tempdir().unwrap();
+        let private_key = PrivateKey::from_cb58(AVAX_EWOQ_PRIVATE_KEY).unwrap();
+
+        let keystore_path =
+            encrypt_keystore(&private_key, keystore_dir.path(), "ewoq", "password").unwrap();
+        assert_eq!(keystore_path, keystore_dir.path().join("ewoq"));
+
+        let decrypted_key = decrypt_keystore(&keystore_path, "password").unwrap();
+        assert_eq!(decrypted_key.to_cb58(), AVAX_EWOQ_PRIVATE_KEY);
+
+        assert!(decrypt_keystore(&keystore_path, "wrong password").is_err());
+        assert!(decrypt_keystore(&keystore_dir.path().join("unknown"), "password").is_err());
+
+        // Existing keystores are never overwritten
+        assert!(encrypt_keystore(&private_key, keystore_dir.path(), "ewoq", "password").is_err());
+    }
+
+    #[test]
+    fn test_get_keystore_path() {
+        let keystore_dir = Path::new("/tmp/keystores");
+
+        assert_eq!(
+            get_keystore_path(keystore_dir, "ops-key_1.json").unwrap(),
+            keystore_dir.join("ops-key_1.json")
+        );
+        assert!(get_keystore_path(keystore_dir, "").is_err());
+        assert!(get_keystore_path(keystore_dir, ".hidden").is_err());
+        assert!(get_keystore_path(keystore_dir, "../ops-key").is_err());
+        assert!(get_keystore_path(keystore_dir, "ops/key").is_err());
+    }
+
+    #[test]
+    fn test_list_remove_keystores() {
+        let keystore_dir = tempfile::tempdir().unwrap();
+        let wallets_dir = keystore_dir.path().join("wallets");
+        let private_key = PrivateKey::from_cb58(AVAX_EWOQ_PRIVATE_KEY).unwrap();
+
+        assert!(list_keystores(&wallets_dir).unwrap().is_empty());
+
+        encrypt_keystore(&private_key, &wallets_dir, "ops-key", "password").unwrap();
+        encrypt_keystore(&private_key, &wallets_dir, "dev-key", "password").unwrap();
+        assert_eq!(
+            list_keystores(&wallets_dir).unwrap(),
+            vec!["dev-key".to_string(), "ops-key".to_string()]
+        );
+
+        // Files that are not keystores are not listed
+        fs::write(wallets_dir.join("notes.txt"), "not a keystore").unwrap();
+        fs::write(wallets_dir.join("other.json"), r#"{"version": 3}"#).unwrap();
+        assert_eq!(
+            list_keystores(&wallets_dir).unwrap(),
+            vec!["dev-key".to_string(), "ops-key".to_string()]
+        );
+
+        remove_keystore(&wallets_dir, "dev-key").unwrap();
+        assert_eq!(
+            list_keystores(&wallets_dir).unwrap(),
+            vec!["ops-key".to_string()]
+        );
+        assert!(remove_keystore(&wallets_dir, "dev-key").is_err());
+    }
+}
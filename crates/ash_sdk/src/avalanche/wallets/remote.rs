@@ -0,0 +1,274 @@
+// SPDX-License-Identifier: BSD-3-Clause
+// Copyright (c) 2023, E36 Knots
+
+// Module that contains code to sign transactions with a remote signing process
+// The signing process listens on a local Unix socket and answers one JSON request per line,
+// on a connection that is kept open between requests:
+// - `{"method": "getPublicKey"}` -> `{"publicKey": "0x<33-byte compressed public key>"}`
+// - `{"method": "signDigest", "digest": "0x<32-byte digest>"}` -> `{"signature": "0x<65-byte signature>"}`
+// Errors are returned as `{"error": "<message>"}`
+
+use crate::{avalanche::wallets::AvalancheSigner, errors::*};
+use async_std::{
+    io::{self, prelude::*, BufReader},
+    os::unix::net::UnixStream,
+    sync::Mutex,
+    task,
+};
+use async_trait::async_trait;
+use avalanche_types::key::secp256k1::public_key::Key as PublicKey;
+use serde::{Deserialize, Serialize};
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::Duration,
+};
+
+/// Maximum duration to wait for the remote signing process to answer
+pub const REMOTE_SIGNER_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Request sent to the remote signing process
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "method", rename_all = "camelCase")]
+pub enum RemoteSignerRequest {
+    GetPublicKey,
+    SignDigest { digest: String },
+}
+
+/// Response of the remote signing process
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct RemoteSignerResponse {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub public_key: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signature: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Signer that delegates signatures to a remote signing process listening on a Unix socket
+/// The private key never enters the process
+/// The connection to the signing process is reused by all the requests
+#[derive(Debug, Clone)]
+pub struct RemoteSigner {
+    socket_path: PathBuf,
+    public_key: PublicKey,
+    connection: RemoteSignerConnection,
+}
+
+impl RemoteSigner {
+    /// Connect to a remote signing process and get its public key
+    pub fn connect(socket_path: &Path) -> Result<Self, AshError> {
+        task::block_on(async {
+            let socket_path = socket_path.to_path_buf();
+            let connection = RemoteSignerConnection::default();
+
+            let public_key_hex = connection
+                .request(&socket_path, &RemoteSignerRequest::GetPublicKey)
+                .await?
+                .public_key
+                .ok_or_else(|| signer_failure(&socket_path, "no public key in response"))?;
+            let public_key = hex::decode(public_key_hex.trim_start_matches("0x"))
+                .map_err(|e| signer_failure(&socket_path, &e.to_string()))
+                .and_then(|bytes| {
+                    PublicKey::from_sec1_bytes(&bytes)
+                        .map_err(|e| signer_failure(&socket_path, &e.to_string()))
+                })?;
+
+            Ok(Self {
+                socket_path,
+                public_key,
+                connection,
+            })
+        })
+    }
+}
+
+#[async_trait]
+impl AvalancheSigner for RemoteSigner {
+    fn name(&self) -> String {
+        format!("remote:{}", self.socket_path.display())
+    }
+
+    fn public_key(&self) -> PublicKey {
+        self.public_key
+    }
+
+    async fn sign_digest(&self, digest: &[u8]) -> Result<[u8; 65], AshError> {
+        let signature_hex = self
+            .connection
+            .request(
+                &self.socket_path,
+                &RemoteSignerRequest::SignDigest {
+                    digest: format!("0x{}", hex::encode(digest)),
+                },
+            )
+            .await?
+            .signature
+            .ok_or_else(|| signer_failure(&self.socket_path, "no signature in response"))?;
+
+        let signature: [u8; 65] = hex::decode(signature_hex.trim_start_matches("0x"))
+            .map_err(|e| signer_failure(&self.socket_path, &e.to_string()))?
+            .try_into()
+            .map_err(|_| signer_failure(&self.socket_path, "signature is not 65 bytes long"))?;
+
+        // Make sure the remote process signed with the expected key
+        match PublicKey::from_signature(digest, &signature) {
+            Ok(public_key) if public_key == self.public_key => Ok(signature),
+            _ => Err(signer_failure(
+                &self.socket_path,
+                "signature does not match the signer public key",
+            )
+            .into()),
+        }
+    }
+}
+
+// Connection to the remote signing process, shared by the clones of the signer
+// It is opened by the first request and reopened by the request following an I/O failure
+#[derive(Debug, Clone, Default)]
+struct RemoteSignerConnection(Arc<Mutex<Option<BufReader<UnixStream>>>>);
+
+impl RemoteSignerConnection {
+    // Send a request to the remote signing process and read its response
+    async fn request(
+        &self,
+        socket_path: &Path,
+        request: &RemoteSignerRequest,
+    ) -> Result<RemoteSignerResponse, AvalancheWalletError> {
+        let io_failure = |e: io::Error| signer_failure(socket_path, &e.to_string());
+
+        // Requests are serialized so that each response is read by its request
+        let mut connection = self.0.lock().await;
+        let stream = match connection.as_mut() {
+            Some(stream) => stream,
+            None => connection.insert(BufReader::new(
+                UnixStream::connect(socket_path).await.map_err(io_failure)?,
+            )),
+        };
+
+        let response_line =
+            match io::timeout(REMOTE_SIGNER_TIMEOUT, send_request(stream, request)).await {
+                Ok(response_line) => response_line,
+                Err(e) => {
+                    // The connection is in an unknown state, it is reopened by the next request
+                    *connection = None;
+                    return Err(io_failure(e));
+                }
+            };
+
+        let response: RemoteSignerResponse = serde_json::from_str(&response_line)
+            .map_err(|e| signer_failure(socket_path, &format!("invalid response: {e}")))?;
+
+        match response.error {
+            Some(error) => Err(signer_failure(socket_path, &error)),
+            None => Ok(response),
+        }
+    }
+}
+
+// Write a request line to the remote signing process and read the response line
+async fn send_request(
+    stream: &mut BufReader<UnixStream>,
+    request: &RemoteSignerRequest,
+) -> io::Result<String> {
+    let mut request_line = serde_json::to_string(request).unwrap();
+    request_line.push('\n');
+    stream.get_mut().write_all(request_line.as_bytes()).await?;
+
+    let mut response_line = String::new();
+    if stream.read_line(&mut response_line).await? == 0 {
+        return Err(io::Error::new(
+            io::ErrorKind::UnexpectedEof,
+            "connection closed by the signing process",
+        ));
+    }
+
+    Ok(response_line)
+}
+
+fn signer_failure(socket_path: &Path, msg: &str) -> AvalancheWalletError {
+    AvalancheWalletError::SignerFailure {
+        signer: format!("remote:{}", socket_path.display()),
+        msg: msg.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use avalanche_types::key::secp256k1::private_key::Key as PrivateKey;
+    use std::{
+        io::{BufRead, BufReader as StdBufReader, Write},
+        os::unix::net::UnixListener,
+        thread,
+    };
+
+    const AVAX_EWOQ_PRIVATE_KEY: &str =
+        "PrivateKey-ewoqjP7PxY4yr3iLTpLisriqt94hdyDFNgchSxGGztUrTXtNN";
+
+    // Serve a given number of requests on a single connection with a local key,
+    // as a remote signing process would
+    fn serve_requests(listener: UnixListener, private_key: PrivateKey, count: usize) {
+        let (mut stream, _) = listener.accept().unwrap();
+        let mut reader = StdBufReader::new(stream.try_clone().unwrap());
+
+        for _ in 0..count {
+            let mut request_line = String::new();
+            reader.read_line(&mut request_line).unwrap();
+
+            let response = match serde_json::from_str(&request_line).unwrap() {
+                RemoteSignerRequest::GetPublicKey => RemoteSignerResponse {
+                    public_key: Some(format!(
+                        "0x{}",
+                        hex::encode(private_key.to_public_key().to_compressed_bytes())
+                    )),
+                    ..Default::default()
+                },
+                RemoteSignerRequest::SignDigest { digest } => {
+                    let digest = hex::decode(digest.trim_start_matches("0x")).unwrap();
+                    RemoteSignerResponse {
+                        signature: Some(format!(
+                            "0x{}",
+                            hex::encode(private_key.sign_digest(&digest).unwrap().to_bytes())
+                        )),
+                        ..Default::default()
+                    }
+                }
+            };
+
+            let mut response_line = serde_json::to_string(&response).unwrap();
+            response_line.push('\n');
+            stream.write_all(response_line.as_bytes()).unwrap();
+        }
+    }
+
+    #[async_std::test]
+    async fn test_remote_signer() {
+        let socket_dir = tempfile::tempdir().unwrap();
+        let socket_path = socket_dir.path().join("signer.sock");
+        let private_key = PrivateKey::from_cb58(AVAX_EWOQ_PRIVATE_KEY).unwrap();
+
+        let listener = UnixListener::bind(&socket_path).unwrap();
+        let server_key = private_key.clone();
+        let server = thread::spawn(move || serve_requests(listener, server_key, 3));
+
+        let signer = RemoteSigner::connect(&socket_path).unwrap();
+        assert_eq!(signer.public_key(), private_key.to_public_key());
+
+        // The signature requests reuse the connection opened to get the public key
+        for digest in [[7u8; 32], [8u8; 32]] {
+            let signature = signer.sign_digest(&digest).await.unwrap();
+            assert_eq!(
+                signature,
+                private_key.sign_digest(&digest).unwrap().to_bytes()
+            );
+        }
+
+        server.join().unwrap();
+
+        // The signing process is not listening anymore
+        assert!(signer.sign_digest(&[7u8; 32]).await.is_err());
+    }
+}
@@ -1,15 +1,30 @@
 // SPDX-License-Identifier: BSD-3-Clause
 // Copyright (c) 2023, E36 Knots
 
+pub mod codec;
+pub mod endpoints;
+pub mod payloads;
+
 // Module that contains code to interact with Avalanche Warp Messaging
 
 use crate::{
-    avalanche::vms::subnet_evm::warp::{AddressedPayload, SubnetEVMWarpMessage},
+    avalanche::{
+        subnets::AvalancheSubnetValidator,
+        vms::subnet_evm::warp::{AddressedPayload, BlockHashPayload, SubnetEVMWarpMessage},
+        warp::payloads::{AddressedCall, HashPayload, PlatformVMWarpMessage},
+    },
     errors::*,
 };
-use avalanche_types::ids::{node::Id as NodeId, Id};
+use avalanche_types::{
+    ids::{node::Id as NodeId, Id},
+    key::bls::{
+        public_key::Key as BlsPublicKey,
+        signature::{aggregate as aggregate_signatures, Sig},
+    },
+};
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
+use std::time::Duration;
 
 pub const WARP_ANYCAST_ID: &str = "2wkBET2rRgE8pahuaczxKbmv7ciehqsne57F9gtzf1PVcUJEQG";
 
@@ -31,41 +46,77 @@ impl WarpUnsignedMessage {
     /// Try to parse a Subnet-EVM Warp message event log data as an unsigned Warp message
     /// and parse the payload as a Subnet-EVM AddressedPayload
     pub fn try_from_subnet_evm_log_data(bytes: &[u8]) -> Result<Self, AshError> {
-        let mut warp_message = Self::from(bytes);
-        let warp_payload = match warp_message.payload {
-            WarpMessagePayload::Unknown(bytes) => bytes,
-            _ => panic!("Warp message payload is not Unknown"),
+        let mut warp_message = Self::try_from(bytes)?;
+        warp_message.payload = match warp_message.payload {
+            WarpMessagePayload::SubnetEVMAddressedPayload(addressed_payload) => {
+                WarpMessagePayload::SubnetEVMAddressedPayload(addressed_payload)
+            }
+            WarpMessagePayload::Unknown(bytes) => {
+                WarpMessagePayload::SubnetEVMAddressedPayload(AddressedPayload::try_from(bytes)?)
+            }
+            _ => {
+                return Err(AvalancheWarpMessagingError::ParseFailure {
+                    property: "payload".to_string(),
+                    msg: "payload is not a Subnet-EVM AddressedPayload".to_string(),
+                }
+                .into())
+            }
         };
 
-        warp_message.payload = WarpMessagePayload::SubnetEVMAddressedPayload(
-            AddressedPayload::try_from(warp_payload)?,
-        );
-
         Ok(warp_message)
     }
+
+    /// Encode the unsigned Warp message to bytes
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = codec::WARP_CODEC_VERSION.to_be_bytes().to_vec();
+        bytes.extend_from_slice(&self.network_id.to_be_bytes());
+        bytes.extend_from_slice(&self.source_chain_id.to_vec());
+        bytes.extend_from_slice(&self.payload.to_bytes());
+
+        bytes
+    }
+
+    /// Verify a BLS signature of the unsigned Warp message against a validator BLS public key
+    pub fn verify_signature(&self, signature: &[u8; 96], public_key: &[u8]) -> bool {
+        match (
+            BlsPublicKey::from_bytes(public_key),
+            Sig::from_bytes(signature),
+        ) {
+            (Ok(public_key), Ok(signature)) => signature.verify(&self.bytes, &public_key),
+            _ => false,
+        }
+    }
 }
 
-impl From<&[u8]> for WarpUnsignedMessage {
-    fn from(bytes: &[u8]) -> Self {
-        // [0..2] -> ?
+impl TryFrom<&[u8]> for WarpUnsignedMessage {
+    type Error = AshError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, AshError> {
+        // [0..2] -> codec version
         // [2..6] -> networkID
         // [6..38] -> sourceChainID
-        // [38..] -> payload
-        let network_id = u32::from_be_bytes(bytes[2..6].try_into().unwrap());
-        let source_chain_id = Id::from_slice(&bytes[6..38]);
-        let payload = WarpMessagePayload::Unknown(bytes[38..].to_vec());
+        // [38..] -> payload (prefixed by its length)
+        let mut unpacker = codec::Unpacker::new(bytes);
+        unpacker.read_codec_version()?;
+        let network_id = unpacker.read_u32("networkID")?;
+        let source_chain_id = Id::from_slice(unpacker.read_fixed("sourceChainID", 32)?);
+        unpacker.read_bytes("payload")?;
+        unpacker.finish("message")?;
+
+        // All the lengths have been checked, so the payload is the rest of the bytes
+        let payload = WarpMessagePayload::from(&bytes[38..]);
 
         // Compute the message ID = SHA256(bytes)
         let mut hasher = Sha256::new();
         hasher.update(bytes);
 
-        Self {
+        Ok(Self {
             id: Id::from_slice(&hasher.finalize()[..]),
             bytes: bytes.to_vec(),
             network_id,
             source_chain_id,
             payload,
-        }
+        })
     }
 }
 
@@ -77,6 +128,14 @@ pub enum WarpMessagePayload {
     Unknown(Vec<u8>),
     /// Subnet-EVM Warp Message payload
     SubnetEVMAddressedPayload(AddressedPayload),
+    /// Subnet-EVM block hash payload
+    SubnetEVMBlockHashPayload(BlockHashPayload),
+    /// Hash payload
+    Hash(HashPayload),
+    /// AddressedCall payload
+    AddressedCall(AddressedCall),
+    /// P-Chain message (AddressedCall payload with an empty source address)
+    PlatformVM(PlatformVMWarpMessage),
 }
 
 impl Default for WarpMessagePayload {
@@ -85,6 +144,78 @@ impl Default for WarpMessagePayload {
     }
 }
 
+impl WarpMessagePayload {
+    /// Encode the payload to bytes (prefixed by its length)
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let payload_bytes = match self {
+            WarpMessagePayload::Unknown(bytes) => return bytes.clone(),
+            WarpMessagePayload::SubnetEVMAddressedPayload(payload) => return payload.to_bytes(),
+            WarpMessagePayload::SubnetEVMBlockHashPayload(payload) => payload.to_bytes(),
+            WarpMessagePayload::Hash(payload) => payload.to_bytes(),
+            WarpMessagePayload::AddressedCall(payload) => payload.to_bytes(),
+            WarpMessagePayload::PlatformVM(message) => AddressedCall {
+                payload: message.to_bytes().into(),
+                ..Default::default()
+            }
+            .to_bytes(),
+        };
+
+        let mut bytes = vec![];
+        codec::pack_bytes(&mut bytes, &payload_bytes);
+
+        bytes
+    }
+}
+
+impl From<&[u8]> for WarpMessagePayload {
+    /// Parse the payload bytes (prefixed by their length) as one of the known payload types
+    /// Fall back to an Unknown payload if the bytes cannot be parsed
+    fn from(bytes: &[u8]) -> Self {
+        let mut unpacker = codec::Unpacker::new(bytes);
+        let payload_bytes = match unpacker
+            .read_bytes("payload")
+            .and_then(|payload_bytes| unpacker.finish("payload").map(|_| payload_bytes))
+        {
+            Ok(payload_bytes) => payload_bytes,
+            Err(_) => return WarpMessagePayload::Unknown(bytes.to_vec()),
+        };
+
+        // [0..2] -> codec version
+        // [2..6] -> type ID
+        let type_id = match payload_bytes.get(2..6) {
+            Some(type_id) => u32::from_be_bytes(type_id.try_into().unwrap()),
+            None => return WarpMessagePayload::Unknown(bytes.to_vec()),
+        };
+
+        // Type IDs of the Avalanche and Subnet-EVM payloads overlap, so try both
+        match type_id {
+            0 => HashPayload::try_from(payload_bytes)
+                .map(WarpMessagePayload::Hash)
+                .or_else(|_| {
+                    AddressedPayload::try_from(bytes.to_vec())
+                        .map(WarpMessagePayload::SubnetEVMAddressedPayload)
+                }),
+            1 => AddressedCall::try_from(payload_bytes)
+                .map(|addressed_call| {
+                    if addressed_call.source_address.is_empty() {
+                        if let Ok(message) =
+                            PlatformVMWarpMessage::try_from(&addressed_call.payload[..])
+                        {
+                            return WarpMessagePayload::PlatformVM(message);
+                        }
+                    }
+                    WarpMessagePayload::AddressedCall(addressed_call)
+                })
+                .or_else(|_| {
+                    BlockHashPayload::try_from(payload_bytes)
+                        .map(WarpMessagePayload::SubnetEVMBlockHashPayload)
+                }),
+            _ => Ok(WarpMessagePayload::Unknown(bytes.to_vec())),
+        }
+        .unwrap_or(WarpMessagePayload::Unknown(bytes.to_vec()))
+    }
+}
+
 /// Warp message status
 #[derive(Default, Clone, Debug, Serialize, Deserialize, PartialEq)]
 pub enum WarpMessageStatus {
@@ -134,6 +265,337 @@ impl WarpMessage {
             self.status = WarpMessageStatus::Sent;
         }
     }
+
+    /// Aggregate the node signatures of the Warp message into a signed Warp message
+    /// The signers bitset is computed against the canonical validator set built from the validators
+    /// Only the signatures of nodes that are part of the canonical validator set are aggregated
+    pub fn get_signed_message(
+        &self,
+        validators: &[AvalancheSubnetValidator],
+    ) -> Result<WarpSignedMessage, AshError> {
+        let canonical_validators = get_canonical_validator_set(validators);
+
+        let mut signers_indices = vec![];
+        let mut signatures = vec![];
+        for (index, canonical_validator) in canonical_validators.iter().enumerate() {
+            // Only use one signature per canonical validator
+            let node_signature = match self.node_signatures.iter().find(|node_signature| {
+                canonical_validator
+                    .node_ids
+                    .contains(&node_signature.node_id)
+            }) {
+                Some(node_signature) => node_signature,
+                None => continue,
+            };
+
+            let signature = Sig::from_bytes(&node_signature.signature).map_err(|e| {
+                AvalancheWarpMessagingError::InvalidSignature(format!(
+                    "signature of node '{}' is invalid: {e}",
+                    node_signature.node_id
+                ))
+            })?;
+
+            signers_indices.push(index);
+            signatures.push(signature);
+        }
+
+        if signatures.is_empty() {
+            return Err(AvalancheWarpMessagingError::SignatureAggregationFailure(
+                "no signature from the validator set to aggregate".to_string(),
+            )
+            .into());
+        }
+
+        let aggregated_signature = aggregate_signatures(&signatures)
+            .map_err(|e| AvalancheWarpMessagingError::SignatureAggregationFailure(e.to_string()))?;
+
+        Ok(WarpSignedMessage {
+            unsigned_message: self.unsigned_message.clone(),
+            signature: WarpBitSetSignature {
+                signers: signers_bitset_from_indices(&signers_indices),
+                signature: aggregated_signature.to_compressed_bytes(),
+            },
+        })
+    }
+
+    /// Verify the node signatures of the Warp message against the BLS public keys of the validators
+    /// Compute the stake weight of the validators with a valid signature versus the total weight of the validators
+    pub fn verify_node_signatures(
+        &self,
+        validators: &[AvalancheSubnetValidator],
+    ) -> WarpMessageSignaturesVerification {
+        let mut verification = WarpMessageSignaturesVerification {
+            total_weight: validators
+                .iter()
+                .map(|validator| validator.weight.unwrap_or_default())
+                .sum(),
+            ..Default::default()
+        };
+        let canonical_validators = get_canonical_validator_set(validators);
+        let mut signed_canonical_validators = vec![];
+
+        for node_signature in self.node_signatures.iter() {
+            let canonical_validator =
+                match canonical_validators.iter().find(|canonical_validator| {
+                    canonical_validator
+                        .node_ids
+                        .contains(&node_signature.node_id)
+                }) {
+                    Some(canonical_validator) => canonical_validator,
+                    None => {
+                        verification.unknown_signers.push(node_signature.node_id);
+                        continue;
+                    }
+                };
+
+            if !self
+                .unsigned_message
+                .verify_signature(&node_signature.signature, &canonical_validator.public_key)
+            {
+                verification.invalid_signers.push(node_signature.node_id);
+                continue;
+            }
+
+            verification.valid_signers.push(node_signature.node_id);
+            // The weight of a canonical validator is only counted once
+            if !signed_canonical_validators.contains(&canonical_validator.public_key) {
+                signed_canonical_validators.push(canonical_validator.public_key.clone());
+                verification.signed_weight += canonical_validator.weight;
+            }
+        }
+
+        verification
+    }
+}
+
+/// Result of the verification of the node signatures of a Warp message
+#[derive(Default, Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct WarpMessageSignaturesVerification {
+    /// Nodes with a valid signature
+    pub valid_signers: Vec<NodeId>,
+    /// Nodes with an invalid signature
+    pub invalid_signers: Vec<NodeId>,
+    /// Nodes that are not validators or have no BLS public key
+    pub unknown_signers: Vec<NodeId>,
+    /// Stake weight of the validators with a valid signature
+    pub signed_weight: u64,
+    /// Total stake weight of the validators
+    pub total_weight: u64,
+}
+
+impl WarpMessageSignaturesVerification {
+    /// Get the percentage of the total weight that signed the message
+    pub fn signed_weight_percentage(&self) -> f64 {
+        match self.total_weight {
+            0 => 0.0,
+            total_weight => self.signed_weight as f64 / total_weight as f64 * 100.0,
+        }
+    }
+
+    /// Check if the signed weight reaches the quorum (in percent of the total weight)
+    pub fn is_quorum_reached(&self, quorum_percentage: u8) -> bool {
+        self.total_weight > 0
+            && self.signed_weight as u128 * 100
+                >= self.total_weight as u128 * quorum_percentage as u128
+    }
+}
+
+/// Signed Warp message
+/// See https://github.com/ava-labs/avalanchego/blob/e70a17d9d988b5067f3ef5c4a057f15ae1271ac4/vms/platformvm/warp/message.go
+#[derive(Default, Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct WarpSignedMessage {
+    pub unsigned_message: WarpUnsignedMessage,
+    pub signature: WarpBitSetSignature,
+}
+
+impl WarpSignedMessage {
+    /// Encode the signed Warp message to bytes
+    pub fn to_bytes(&self) -> Result<Vec<u8>, AshError> {
+        // [0..2] -> codec version
+        // [2..unsigned_message_len] -> unsigned message (without its codec version)
+        // [..] -> signature
+        let unsigned_message_bytes = self.unsigned_message.bytes.get(2..).ok_or(
+            AvalancheWarpMessagingError::TruncatedInput {
+                property: "unsigned message".to_string(),
+                expected: 2,
+                found: self.unsigned_message.bytes.len(),
+            },
+        )?;
+
+        let mut bytes = vec![0; 2];
+        bytes.extend_from_slice(unsigned_message_bytes);
+        bytes.extend_from_slice(&self.signature.to_bytes());
+
+        Ok(bytes)
+    }
+}
+
+/// Warp message BLS multi-signature and the bitset of the validators that signed it
+/// See https://github.com/ava-labs/avalanchego/blob/e70a17d9d988b5067f3ef5c4a057f15ae1271ac4/vms/platformvm/warp/signature.go
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct WarpBitSetSignature {
+    /// Bitset of the validators (canonical validator set indices) that signed the message
+    #[serde(
+        serialize_with = "ethers::types::serialize_bytes",
+        deserialize_with = "hex::deserialize"
+    )]
+    pub signers: Vec<u8>,
+    /// Aggregated BLS signature
+    #[serde(
+        serialize_with = "ethers::types::serialize_bytes",
+        deserialize_with = "hex::deserialize"
+    )]
+    pub signature: [u8; 96],
+}
+
+impl Default for WarpBitSetSignature {
+    fn default() -> Self {
+        Self {
+            signers: vec![],
+            signature: [0; 96],
+        }
+    }
+}
+
+/// Policy used to request the Warp message signatures from the validator nodes
+#[derive(Clone, Debug, PartialEq)]
+pub struct WarpSignatureRequestPolicy {
+    /// Timeout of each signature request
+    pub timeout: Duration,
+    /// Number of retries after a failed signature request
+    pub retries: u32,
+    /// Delay between two attempts
+    pub retry_delay: Duration,
+    /// Maximum number of validator nodes queried at the same time
+    pub max_concurrent_requests: usize,
+}
+
+impl Default for WarpSignatureRequestPolicy {
+    fn default() -> Self {
+        Self {
+            timeout: Duration::from_secs(10),
+            retries: 2,
+            retry_delay: Duration::from_secs(1),
+            max_concurrent_requests: 16,
+        }
+    }
+}
+
+/// Failure to get the Warp message signature of a validator node
+#[derive(Default, Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct WarpNodeSignatureFailure {
+    #[serde(rename = "nodeID")]
+    pub node_id: NodeId,
+    /// Number of signature requests sent to the node
+    pub attempts: u32,
+    pub error: String,
+}
+
+/// Warp message signatures collected from the validator nodes
+/// Only signatures verified against the BLS public key of their validator are collected
+#[derive(Default, Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct WarpNodeSignaturesCollection {
+    pub signatures: Vec<WarpMessageNodeSignature>,
+    pub failures: Vec<WarpNodeSignatureFailure>,
+    /// Stake weight of the validators that returned a valid signature
+    pub collected_weight: u64,
+    /// Total stake weight of the validators
+    pub total_weight: u64,
+}
+
+impl WarpNodeSignaturesCollection {
+    /// Check if the collected weight reaches the threshold (in percent of the total weight)
+    pub fn is_weight_threshold_reached(&self, threshold_percentage: u8) -> bool {
+        self.total_weight > 0
+            && self.collected_weight as u128 * 100
+                >= self.total_weight as u128 * threshold_percentage as u128
+    }
+}
+
+impl WarpBitSetSignature {
+    /// Encode the signature to bytes
+    pub fn to_bytes(&self) -> Vec<u8> {
+        // [0..4] -> type ID (BitSetSignature = 0)
+        // [4..8] -> signers length
+        // [8..8+signers_len] -> signers
+        // [8+signers_len..] -> signature
+        let mut bytes = vec![0; 4];
+        bytes.extend_from_slice(&(self.signers.len() as u32).to_be_bytes());
+        bytes.extend_from_slice(&self.signers);
+        bytes.extend_from_slice(&self.signature);
+
+        bytes
+    }
+}
+
+/// Validator of a canonical Warp validator set
+/// Validators sharing the same BLS public key are merged into a single canonical validator
+#[derive(Default, Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct WarpCanonicalValidator {
+    #[serde(
+        serialize_with = "ethers::types::serialize_bytes",
+        deserialize_with = "hex::deserialize"
+    )]
+    pub public_key: Vec<u8>,
+    pub weight: u64,
+    #[serde(rename = "nodeIDs")]
+    pub node_ids: Vec<NodeId>,
+}
+
+/// Get the canonical Warp validator set from a list of validators
+/// Validators without BLS public key are ignored and the set is ordered by public key bytes
+/// See https://github.com/ava-labs/avalanchego/blob/e70a17d9d988b5067f3ef5c4a057f15ae1271ac4/vms/platformvm/warp/validator.go
+pub fn get_canonical_validator_set(
+    validators: &[AvalancheSubnetValidator],
+) -> Vec<WarpCanonicalValidator> {
+    let mut canonical_validators: Vec<WarpCanonicalValidator> = vec![];
+
+    for validator in validators {
+        let public_key = match &validator.signer {
+            Some(signer) if !signer.public_key.is_empty() => &signer.public_key,
+            _ => continue,
+        };
+
+        match canonical_validators
+            .iter_mut()
+            .find(|canonical_validator| &canonical_validator.public_key == public_key)
+        {
+            Some(canonical_validator) => {
+                canonical_validator.weight += validator.weight.unwrap_or_default();
+                canonical_validator.node_ids.push(validator.node_id);
+            }
+            None => canonical_validators.push(WarpCanonicalValidator {
+                public_key: public_key.clone(),
+                weight: validator.weight.unwrap_or_default(),
+                node_ids: vec![validator.node_id],
+            }),
+        }
+    }
+
+    canonical_validators.sort_by(|a, b| a.public_key.cmp(&b.public_key));
+
+    canonical_validators
+}
+
+/// Build a signers bitset from the indices of the signers in the canonical validator set
+/// The bitset is encoded as a big-endian big integer (without leading zero bytes)
+fn signers_bitset_from_indices(indices: &[usize]) -> Vec<u8> {
+    let bitset_len = match indices.iter().max() {
+        Some(max_index) => max_index / 8 + 1,
+        None => return vec![],
+    };
+
+    let mut bitset = vec![0_u8; bitset_len];
+    for index in indices {
+        bitset[bitset_len - 1 - index / 8] |= 1 << (index % 8);
+    }
+
+    bitset
 }
 
 /// Warp message signature from a validator node
@@ -160,7 +622,9 @@ impl Default for WarpMessageNodeSignature {
 mod tests {
     use super::*;
 
+    use avalanche_types::key::bls::{private_key::Key as BlsPrivateKey, public_key};
     use ethers::types::{Address, Bytes, H256};
+    use proptest::prelude::*;
     use std::str::FromStr;
 
     const WARP_MESSAGE_HEX: &str = "00000000303976dccb39c21a43aad4ffa98d4dd86a9ca29f5038a13b87658ef856bc161dbb470000005e0000000000008db97c7cece249c2b98bdc0226cc4c2a57bf52fcffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff8db97c7cece249c2b98bdc0226cc4c2a57bf52fc0000000c48656c6c6f20776f726c6421";
@@ -168,7 +632,8 @@ mod tests {
     #[test]
     fn test_warp_message_from_bytes() {
         let warp_message =
-            WarpUnsignedMessage::from(hex::decode(WARP_MESSAGE_HEX).unwrap().as_slice());
+            WarpUnsignedMessage::try_from(hex::decode(WARP_MESSAGE_HEX).unwrap().as_slice())
+                .unwrap();
 
         assert_eq!(warp_message.network_id, 12345);
         assert_eq!(
@@ -206,4 +671,410 @@ mod tests {
             })
         )
     }
+
+    #[test]
+    fn test_signers_bitset_from_indices() {
+        assert_eq!(signers_bitset_from_indices(&[]), Vec::<u8>::new());
+        assert_eq!(signers_bitset_from_indices(&[0, 2]), vec![0b0000_0101]);
+        assert_eq!(
+            signers_bitset_from_indices(&[1, 8]),
+            vec![0b0000_0001, 0b0000_0010]
+        );
+    }
+
+    #[test]
+    fn test_warp_message_get_signed_message() {
+        let unsigned_message =
+            WarpUnsignedMessage::try_from(hex::decode(WARP_MESSAGE_HEX).unwrap().as_slice())
+                .unwrap();
+
+        // Generate 3 validators, only 2 of them sign the message
+        let bls_keys = (0..3)
+            .map(|_| BlsPrivateKey::generate().unwrap())
+            .collect::<Vec<_>>();
+        let validators = bls_keys
+            .iter()
+            .enumerate()
+            .map(|(i, key)| AvalancheSubnetValidator {
+                node_id: NodeId::from_slice(&[i as u8 + 1; 20]),
+                weight: Some(100),
+                signer: Some(key.to_proof_of_possession()),
+                ..Default::default()
+            })
+            .collect::<Vec<_>>();
+
+        let mut warp_message = WarpMessage {
+            unsigned_message: unsigned_message.clone(),
+            ..Default::default()
+        };
+        for (validator, key) in validators.iter().zip(bls_keys.iter()).take(2) {
+            warp_message.add_node_signature(WarpMessageNodeSignature {
+                node_id: validator.node_id,
+                signature: key.sign(&unsigned_message.bytes).to_compressed_bytes(),
+            });
+        }
+
+        let signed_message = warp_message.get_signed_message(&validators).unwrap();
+
+        // The signers bitset must match the canonical validator set indices
+        let canonical_validators = get_canonical_validator_set(&validators);
+        let signers_indices = canonical_validators
+            .iter()
+            .enumerate()
+            .filter(|(_, canonical_validator)| {
+                canonical_validator.node_ids[0] != validators[2].node_id
+            })
+            .map(|(index, _)| index)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            signed_message.signature.signers,
+            signers_bitset_from_indices(&signers_indices)
+        );
+
+        // The aggregated signature must be valid for the aggregated public keys
+        let aggregated_public_key = public_key::aggregate(
+            &bls_keys
+                .iter()
+                .take(2)
+                .map(|key| key.to_public_key())
+                .collect::<Vec<_>>(),
+        )
+        .unwrap();
+        let aggregated_signature = Sig::from_bytes(&signed_message.signature.signature).unwrap();
+        assert!(aggregated_signature.verify(&unsigned_message.bytes, &aggregated_public_key));
+
+        // Check the signed message encoding
+        let signed_message_bytes = signed_message.to_bytes().unwrap();
+        let signers_len = signed_message.signature.signers.len();
+        assert_eq!(
+            signed_message_bytes.len(),
+            unsigned_message.bytes.len() + 8 + signers_len + 96
+        );
+        assert_eq!(
+            signed_message_bytes[..unsigned_message.bytes.len()],
+            unsigned_message.bytes[..]
+        );
+        assert_eq!(
+            signed_message_bytes[signed_message_bytes.len() - 96..],
+            signed_message.signature.signature[..]
+        );
+    }
+
+    #[test]
+    fn test_warp_message_get_signed_message_no_signature() {
+        let warp_message = WarpMessage {
+            unsigned_message: WarpUnsignedMessage::try_from(
+                hex::decode(WARP_MESSAGE_HEX).unwrap().as_slice(),
+            )
+            .unwrap(),
+            ..Default::default()
+        };
+
+        assert!(matches!(
+            warp_message.get_signed_message(&[]),
+            Err(AshError::AvalancheWarpMessagingError(
+                AvalancheWarpMessagingError::SignatureAggregationFailure(_)
+            ))
+        ));
+    }
+
+    #[test]
+    fn test_warp_signed_message_to_bytes_empty_message() {
+        let signed_message = WarpSignedMessage::default();
+
+        assert!(matches!(
+            signed_message.to_bytes(),
+            Err(AshError::AvalancheWarpMessagingError(
+                AvalancheWarpMessagingError::TruncatedInput { .. }
+            ))
+        ));
+    }
+
+    #[test]
+    fn test_get_canonical_validator_set() {
+        let bls_key = BlsPrivateKey::generate().unwrap();
+        let validators = vec![
+            AvalancheSubnetValidator {
+                node_id: NodeId::from_slice(&[1; 20]),
+                weight: Some(100),
+                signer: Some(bls_key.to_proof_of_possession()),
+                ..Default::default()
+            },
+            AvalancheSubnetValidator {
+                node_id: NodeId::from_slice(&[2; 20]),
+                weight: Some(50),
+                signer: Some(bls_key.to_proof_of_possession()),
+                ..Default::default()
+            },
+            AvalancheSubnetValidator {
+                node_id: NodeId::from_slice(&[3; 20]),
+                weight: Some(100),
+                ..Default::default()
+            },
+        ];
+
+        let canonical_validators = get_canonical_validator_set(&validators);
+
+        assert_eq!(canonical_validators.len(), 1);
+        assert_eq!(canonical_validators[0].weight, 150);
+        assert_eq!(
+            canonical_validators[0].node_ids,
+            vec![validators[0].node_id, validators[1].node_id]
+        );
+    }
+
+    #[test]
+    fn test_warp_message_verify_node_signatures() {
+        let unsigned_message =
+            WarpUnsignedMessage::try_from(hex::decode(WARP_MESSAGE_HEX).unwrap().as_slice())
+                .unwrap();
+
+        let bls_keys = (0..3)
+            .map(|_| BlsPrivateKey::generate().unwrap())
+            .collect::<Vec<_>>();
+        let mut validators = bls_keys
+            .iter()
+            .enumerate()
+            .map(|(i, key)| AvalancheSubnetValidator {
+                node_id: NodeId::from_slice(&[i as u8 + 1; 20]),
+                weight: Some(100),
+                signer: Some(key.to_proof_of_possession()),
+                ..Default::default()
+            })
+            .collect::<Vec<_>>();
+        // Validator without BLS public key
+        validators.push(AvalancheSubnetValidator {
+            node_id: NodeId::from_slice(&[4; 20]),
+            weight: Some(100),
+            ..Default::default()
+        });
+
+        let mut warp_message = WarpMessage {
+            unsigned_message: unsigned_message.clone(),
+            ..Default::default()
+        };
+        // Valid signatures
+        for (validator, key) in validators.iter().zip(bls_keys.iter()).take(2) {
+            warp_message.add_node_signature(WarpMessageNodeSignature {
+                node_id: validator.node_id,
+                signature: key.sign(&unsigned_message.bytes).to_compressed_bytes(),
+            });
+        }
+        // Signature of another message
+        warp_message.add_node_signature(WarpMessageNodeSignature {
+            node_id: validators[2].node_id,
+            signature: bls_keys[2].sign(b"not the message").to_compressed_bytes(),
+        });
+        // Signature from a validator without BLS public key
+        warp_message.add_node_signature(WarpMessageNodeSignature {
+            node_id: validators[3].node_id,
+            signature: bls_keys[0]
+                .sign(&unsigned_message.bytes)
+                .to_compressed_bytes(),
+        });
+
+        let verification = warp_message.verify_node_signatures(&validators);
+
+        assert_eq!(
+            verification.valid_signers,
+            vec![validators[0].node_id, validators[1].node_id]
+        );
+        assert_eq!(verification.invalid_signers, vec![validators[2].node_id]);
+        assert_eq!(verification.unknown_signers, vec![validators[3].node_id]);
+        assert_eq!(verification.signed_weight, 200);
+        assert_eq!(verification.total_weight, 400);
+        assert_eq!(verification.signed_weight_percentage(), 50.0);
+        assert!(verification.is_quorum_reached(50));
+        assert!(!verification.is_quorum_reached(67));
+    }
+
+    #[test]
+    fn test_warp_message_from_bytes_round_trip() {
+        let bytes = hex::decode(WARP_MESSAGE_HEX).unwrap();
+        let warp_message = WarpUnsignedMessage::try_from(bytes.as_slice()).unwrap();
+
+        assert!(matches!(
+            warp_message.payload,
+            WarpMessagePayload::SubnetEVMAddressedPayload(_)
+        ));
+        assert_eq!(warp_message.to_bytes(), bytes);
+    }
+
+    #[test]
+    fn test_warp_message_payload_from_bytes() {
+        let payloads = vec![
+            WarpMessagePayload::Hash(HashPayload {
+                hash: Id::from_slice(&[1; 32]),
+            }),
+            WarpMessagePayload::AddressedCall(AddressedCall {
+                source_address: Bytes::from_str("0x8db97c7cece249c2b98bdc0226cc4c2a57bf52fc")
+                    .unwrap(),
+                payload: Bytes::from_str("0x48656c6c6f20776f726c6421").unwrap(),
+            }),
+            WarpMessagePayload::PlatformVM(PlatformVMWarpMessage::L1ValidatorWeight(
+                payloads::L1ValidatorWeight {
+                    validation_id: Id::from_slice(&[2; 32]),
+                    nonce: 1,
+                    weight: 100,
+                },
+            )),
+            WarpMessagePayload::SubnetEVMBlockHashPayload(BlockHashPayload {
+                block_hash: H256::from_slice(&[3; 32]),
+            }),
+            WarpMessagePayload::Unknown(vec![0, 0, 0, 6, 0, 0, 0, 0, 0, 42]),
+        ];
+
+        for payload in payloads {
+            assert_eq!(WarpMessagePayload::from(&payload.to_bytes()[..]), payload);
+        }
+    }
+
+    #[test]
+    fn test_warp_message_try_from_truncated_input() {
+        let bytes = hex::decode(WARP_MESSAGE_HEX).unwrap();
+
+        for len in 0..bytes.len() {
+            assert!(
+                matches!(
+                    WarpUnsignedMessage::try_from(&bytes[..len]),
+                    Err(AshError::AvalancheWarpMessagingError(
+                        AvalancheWarpMessagingError::TruncatedInput { .. }
+                    ))
+                ),
+                "message truncated to {len} bytes was not rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn test_warp_message_try_from_unsupported_codec_version() {
+        let mut bytes = hex::decode(WARP_MESSAGE_HEX).unwrap();
+        bytes[1] = 1;
+
+        assert_eq!(
+            WarpUnsignedMessage::try_from(&bytes[..]),
+            Err(AshError::AvalancheWarpMessagingError(
+                AvalancheWarpMessagingError::UnsupportedCodecVersion(1)
+            ))
+        );
+    }
+
+    #[test]
+    fn test_warp_message_try_from_trailing_bytes() {
+        let mut bytes = hex::decode(WARP_MESSAGE_HEX).unwrap();
+        bytes.extend_from_slice(&[0; 3]);
+
+        assert_eq!(
+            WarpUnsignedMessage::try_from(&bytes[..]),
+            Err(AshError::AvalancheWarpMessagingError(
+                AvalancheWarpMessagingError::TrailingBytes {
+                    property: "message".to_string(),
+                    count: 3,
+                }
+            ))
+        );
+    }
+
+    #[test]
+    fn test_warp_message_try_from_malformed_corpus() {
+        // Malformed messages that used to crash the parser
+        let corpus = [
+            // Empty message
+            "",
+            // Codec version only
+            "0000",
+            // Missing sourceChainID
+            "0000000030390000",
+            // Missing payload length
+            "00000000303976dccb39c21a43aad4ffa98d4dd86a9ca29f5038a13b87658ef856bc161dbb47",
+            // Payload length larger than the message
+            "00000000303976dccb39c21a43aad4ffa98d4dd86a9ca29f5038a13b87658ef856bc161dbb47ffffffff00",
+            // Payload length shorter than the message
+            "00000000303976dccb39c21a43aad4ffa98d4dd86a9ca29f5038a13b87658ef856bc161dbb47000000000000",
+            // Unsupported codec version
+            "ffff0000303976dccb39c21a43aad4ffa98d4dd86a9ca29f5038a13b87658ef856bc161dbb4700000000",
+        ];
+
+        for hex_message in corpus {
+            let bytes = hex::decode(hex_message).unwrap();
+            assert!(WarpUnsignedMessage::try_from(&bytes[..]).is_err());
+            assert!(WarpUnsignedMessage::try_from_subnet_evm_log_data(&bytes[..]).is_err());
+        }
+    }
+
+    #[test]
+    fn test_warp_message_try_from_malformed_subnet_evm_payload() {
+        // AddressedPayload length prefix set to u32::MAX
+        let mut bytes = hex::decode(WARP_MESSAGE_HEX).unwrap();
+        bytes[38..42].copy_from_slice(&[0xff; 4]);
+
+        assert!(WarpUnsignedMessage::try_from_subnet_evm_log_data(&bytes[..]).is_err());
+        assert!(AddressedPayload::try_from(bytes[38..].to_vec()).is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn proptest_warp_message_parsing_never_panics(
+            bytes in prop::collection::vec(any::<u8>(), 0..256)
+        ) {
+            let _ = WarpUnsignedMessage::try_from(&bytes[..]);
+            let _ = WarpUnsignedMessage::try_from_subnet_evm_log_data(&bytes[..]);
+            let _ = WarpMessagePayload::from(&bytes[..]);
+            let _ = AddressedPayload::try_from(bytes.clone());
+            let _ = BlockHashPayload::try_from(&bytes[..]);
+            let _ = HashPayload::try_from(&bytes[..]);
+            let _ = AddressedCall::try_from(&bytes[..]);
+            let _ = PlatformVMWarpMessage::try_from(&bytes[..]);
+        }
+
+        #[test]
+        fn proptest_warp_message_mutation_never_panics(
+            index in 0usize..136,
+            value in any::<u8>()
+        ) {
+            let mut bytes = hex::decode(WARP_MESSAGE_HEX).unwrap();
+            bytes[index] = value;
+
+            let _ = WarpUnsignedMessage::try_from(&bytes[..]);
+            let _ = WarpUnsignedMessage::try_from_subnet_evm_log_data(&bytes[..]);
+        }
+
+        #[test]
+        fn proptest_warp_message_round_trip(
+            network_id in any::<u32>(),
+            source_chain_id in any::<[u8; 32]>(),
+            source_address in prop::collection::vec(any::<u8>(), 1..32),
+            payload in prop::collection::vec(any::<u8>(), 0..128)
+        ) {
+            let warp_message = WarpUnsignedMessage {
+                network_id,
+                source_chain_id: Id::from_slice(&source_chain_id),
+                payload: WarpMessagePayload::AddressedCall(AddressedCall {
+                    source_address: source_address.into(),
+                    payload: payload.into(),
+                }),
+                ..Default::default()
+            };
+            let bytes = warp_message.to_bytes();
+            let parsed_message = WarpUnsignedMessage::try_from(&bytes[..]).unwrap();
+
+            prop_assert_eq!(parsed_message.network_id, warp_message.network_id);
+            prop_assert_eq!(parsed_message.source_chain_id, warp_message.source_chain_id);
+            prop_assert_eq!(parsed_message.payload, warp_message.payload);
+            prop_assert_eq!(parsed_message.bytes, bytes);
+        }
+    }
+
+    #[test]
+    fn test_warp_node_signatures_collection_is_weight_threshold_reached() {
+        let collection = WarpNodeSignaturesCollection {
+            collected_weight: 67,
+            total_weight: 100,
+            ..Default::default()
+        };
+
+        assert!(collection.is_weight_threshold_reached(67));
+        assert!(!collection.is_weight_threshold_reached(68));
+        assert!(!WarpNodeSignaturesCollection::default().is_weight_threshold_reached(0));
+    }
 }
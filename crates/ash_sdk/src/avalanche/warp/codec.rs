@@ -0,0 +1,115 @@
+// SPDX-License-Identifier: BSD-3-Clause
+// Copyright (c) 2023, E36 Knots
+
+// Module that contains helpers to (un)pack Warp messages with the Avalanche codec
+// See https://github.com/ava-labs/avalanchego/tree/master/codec
+
+use crate::errors::*;
+
+/// Codec version used by all the Warp messages and payloads
+pub const WARP_CODEC_VERSION: u16 = 0;
+
+/// Reader of bytes packed with the Avalanche codec
+pub(crate) struct Unpacker<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> Unpacker<'a> {
+    pub(crate) fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, offset: 0 }
+    }
+
+    /// Number of bytes that have not been read yet
+    fn remaining(&self) -> usize {
+        self.bytes.len() - self.offset
+    }
+
+    /// Read a fixed number of bytes
+    pub(crate) fn read_fixed(&mut self, property: &str, len: usize) -> Result<&'a [u8], AshError> {
+        if self.remaining() < len {
+            return Err(AvalancheWarpMessagingError::TruncatedInput {
+                property: property.to_string(),
+                expected: len,
+                found: self.remaining(),
+            }
+            .into());
+        }
+
+        let fixed = &self.bytes[self.offset..self.offset + len];
+        self.offset += len;
+
+        Ok(fixed)
+    }
+
+    pub(crate) fn read_u16(&mut self, property: &str) -> Result<u16, AshError> {
+        Ok(u16::from_be_bytes(
+            self.read_fixed(property, 2)?.try_into().unwrap(),
+        ))
+    }
+
+    pub(crate) fn read_u32(&mut self, property: &str) -> Result<u32, AshError> {
+        Ok(u32::from_be_bytes(
+            self.read_fixed(property, 4)?.try_into().unwrap(),
+        ))
+    }
+
+    pub(crate) fn read_u64(&mut self, property: &str) -> Result<u64, AshError> {
+        Ok(u64::from_be_bytes(
+            self.read_fixed(property, 8)?.try_into().unwrap(),
+        ))
+    }
+
+    pub(crate) fn read_bool(&mut self, property: &str) -> Result<bool, AshError> {
+        match self.read_fixed(property, 1)?[0] {
+            0 => Ok(false),
+            1 => Ok(true),
+            value => Err(AvalancheWarpMessagingError::ParseFailure {
+                property: property.to_string(),
+                msg: format!("invalid boolean value {value}"),
+            }
+            .into()),
+        }
+    }
+
+    /// Read variable length bytes (prefixed by their length as u32)
+    pub(crate) fn read_bytes(&mut self, property: &str) -> Result<&'a [u8], AshError> {
+        let len = self.read_u32(property)? as usize;
+        self.read_fixed(property, len)
+    }
+
+    /// Read the codec version and check that it is supported
+    pub(crate) fn read_codec_version(&mut self) -> Result<u16, AshError> {
+        let codec_version = self.read_u16("codec version")?;
+        if codec_version != WARP_CODEC_VERSION {
+            return Err(AvalancheWarpMessagingError::UnsupportedCodecVersion(codec_version).into());
+        }
+
+        Ok(codec_version)
+    }
+
+    /// Check that all the bytes have been read
+    pub(crate) fn finish(&self, property: &str) -> Result<(), AshError> {
+        if self.remaining() != 0 {
+            return Err(AvalancheWarpMessagingError::TrailingBytes {
+                property: property.to_string(),
+                count: self.remaining(),
+            }
+            .into());
+        }
+
+        Ok(())
+    }
+}
+
+/// Pack variable length bytes (prefixed by their length as u32)
+pub(crate) fn pack_bytes(packed: &mut Vec<u8>, bytes: &[u8]) {
+    packed.extend_from_slice(&(bytes.len() as u32).to_be_bytes());
+    packed.extend_from_slice(bytes);
+}
+
+/// Pack the codec version and a type ID
+pub(crate) fn pack_codec_type_id(packed: &mut Vec<u8>, type_id: u32) {
+    packed.extend_from_slice(&WARP_CODEC_VERSION.to_be_bytes());
+    packed.extend_from_slice(&type_id.to_be_bytes());
+}
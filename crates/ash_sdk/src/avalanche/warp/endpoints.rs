@@ -0,0 +1,229 @@
+// SPDX-License-Identifier: BSD-3-Clause
+// Copyright (c) 2023, E36 Knots
+
+// Module that contains code to resolve the RPC endpoints of validator nodes
+// These endpoints are used to request the Warp message signatures
+
+use crate::{
+    avalanche::{
+        blockchains::AvalancheBlockchain,
+        jsonrpc::info,
+        nodes::{AvalancheNode, AvalancheNodeEndpoint},
+    },
+    errors::*,
+};
+use avalanche_types::{
+    ids::node::Id as NodeId, jsonrpc::info::Peer,
+    utils::urls::extract_scheme_host_port_path_chain_alias,
+};
+use std::collections::HashMap;
+
+/// Resolver of the RPC endpoints of validator nodes
+pub trait ValidatorEndpointResolver {
+    /// Resolve the RPC URL of a blockchain on a validator node
+    fn resolve_rpc_url(
+        &self,
+        node_id: NodeId,
+        blockchain: &AvalancheBlockchain,
+    ) -> Result<String, AshError>;
+}
+
+/// Resolver that derives the RPC endpoints from the peers of the node behind the blockchain RPC URL
+/// The HTTP port of a peer is assumed to be its staking port - 1 and the RPC path is the one of the blockchain RPC URL
+#[derive(Debug, Clone)]
+pub struct PeersEndpointResolver {
+    endpoint_node_id: NodeId,
+    endpoint_scheme: String,
+    endpoint_path: String,
+    peers: Vec<Peer>,
+}
+
+impl PeersEndpointResolver {
+    /// Create a new resolver by querying the peers of the node behind the blockchain RPC URL
+    /// Only the peers with the given node IDs are queried
+    pub fn new(blockchain: &AvalancheBlockchain, node_ids: Vec<NodeId>) -> Result<Self, AshError> {
+        // Parse the RPC URL to get the scheme, host, and port
+        let (scheme, endpoint_host, port, path, ..) =
+            extract_scheme_host_port_path_chain_alias(&blockchain.rpc_url).map_err(|e| {
+                RpcError::UrlParseFailure {
+                    rpc_url: blockchain.rpc_url.to_string(),
+                    msg: e.to_string(),
+                }
+            })?;
+        let endpoint_scheme = scheme.unwrap_or("http".to_string());
+        let endpoint_path = path.unwrap_or("/ext/bc/C/rpc".to_string());
+        let endpoint_port = port.unwrap_or(9650);
+
+        // Get the node information from the info endpoint
+        let mut endpoint_node = AvalancheNode {
+            http_host: endpoint_host,
+            http_port: endpoint_port,
+            https_enabled: matches!(endpoint_scheme.as_str(), "https"),
+            ..Default::default()
+        };
+        endpoint_node.update_info()?;
+
+        // Construct the RPC URL to query the info.peers endpoint
+        let info_rpc_url = format!(
+            "{}/{}",
+            endpoint_node.get_http_endpoint(),
+            info::AVAX_INFO_API_ENDPOINT
+        );
+
+        // Get the peers information from the info.peers endpoint (notably the nodes public IP addresses)
+        let peers = info::peers(&info_rpc_url, Some(node_ids))?;
+
+        Ok(Self {
+            endpoint_node_id: endpoint_node.id,
+            endpoint_scheme,
+            endpoint_path,
+            peers,
+        })
+    }
+}
+
+impl ValidatorEndpointResolver for PeersEndpointResolver {
+    fn resolve_rpc_url(
+        &self,
+        node_id: NodeId,
+        blockchain: &AvalancheBlockchain,
+    ) -> Result<String, AshError> {
+        // If the validator node is the node being used as endpoint, use the blockchain RPC URL
+        if node_id == self.endpoint_node_id {
+            return Ok(blockchain.rpc_url.clone());
+        }
+
+        let peer = self
+            .peers
+            .iter()
+            .find(|&peer| peer.node_id == node_id)
+            .ok_or(AvalancheWarpMessagingError::EndpointResolutionFailure {
+                node_id: node_id.to_string(),
+                msg: "node is not a peer of the blockchain RPC node".to_string(),
+            })?;
+
+        Ok(format!(
+            "{}://{}:{}{}",
+            self.endpoint_scheme,
+            peer.public_ip.ip(),
+            peer.public_ip.port() - 1,
+            self.endpoint_path
+        ))
+    }
+}
+
+/// Resolver that uses a map of the node HTTP endpoints
+/// The RPC URL of a blockchain is `<http_endpoint>/ext/bc/<blockchain_id>/rpc`
+/// Nodes that are not in the map are resolved by the fallback resolver, if any
+pub struct StaticEndpointResolver {
+    endpoints: HashMap<NodeId, String>,
+    fallback: Option<Box<dyn ValidatorEndpointResolver>>,
+}
+
+impl StaticEndpointResolver {
+    /// Create a new resolver from a list of node endpoints
+    pub fn new(
+        node_endpoints: &[AvalancheNodeEndpoint],
+        fallback: Option<Box<dyn ValidatorEndpointResolver>>,
+    ) -> Self {
+        Self {
+            endpoints: node_endpoints
+                .iter()
+                .map(|node_endpoint| {
+                    (
+                        node_endpoint.node_id,
+                        node_endpoint
+                            .http_endpoint
+                            .trim_end_matches('/')
+                            .to_string(),
+                    )
+                })
+                .collect(),
+            fallback,
+        }
+    }
+}
+
+impl ValidatorEndpointResolver for StaticEndpointResolver {
+    fn resolve_rpc_url(
+        &self,
+        node_id: NodeId,
+        blockchain: &AvalancheBlockchain,
+    ) -> Result<String, AshError> {
+        match (self.endpoints.get(&node_id), &self.fallback) {
+            (Some(http_endpoint), _) => {
+                Ok(format!("{}/ext/bc/{}/rpc", http_endpoint, blockchain.id))
+            }
+            (None, Some(fallback)) => fallback.resolve_rpc_url(node_id, blockchain),
+            (None, None) => Err(AvalancheWarpMessagingError::EndpointResolutionFailure {
+                node_id: node_id.to_string(),
+                msg: "no endpoint configured for the node".to_string(),
+            }
+            .into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use avalanche_types::ids::Id;
+    use std::str::FromStr;
+
+    const NODE_ID: &str = "NodeID-7Xhw2mDxuDS44j42TCB6U5579esbSt3Lg";
+    const OTHER_NODE_ID: &str = "NodeID-MFrZFVCXPv5iCn6M9K6XduxGTYp891xXZ";
+    const BLOCKCHAIN_ID: &str = "VctwH3nkmztWbkdNXbuo6eCYndsUuemtM9ZFmEUZ5QpA1Fu8G";
+
+    // Resolver that always returns the same URL
+    struct ConstantResolver(String);
+
+    impl ValidatorEndpointResolver for ConstantResolver {
+        fn resolve_rpc_url(
+            &self,
+            _node_id: NodeId,
+            _blockchain: &AvalancheBlockchain,
+        ) -> Result<String, AshError> {
+            Ok(self.0.clone())
+        }
+    }
+
+    fn blockchain() -> AvalancheBlockchain {
+        AvalancheBlockchain {
+            id: Id::from_str(BLOCKCHAIN_ID).unwrap(),
+            rpc_url: "https://api.example.com/ext/bc/C/rpc".to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_static_endpoint_resolver() {
+        let node_endpoints = vec![AvalancheNodeEndpoint {
+            node_id: NodeId::from_str(NODE_ID).unwrap(),
+            http_endpoint: "https://validator-1.example.com/".to_string(),
+        }];
+
+        let resolver = StaticEndpointResolver::new(&node_endpoints, None);
+        assert_eq!(
+            resolver
+                .resolve_rpc_url(NodeId::from_str(NODE_ID).unwrap(), &blockchain())
+                .unwrap(),
+            format!("https://validator-1.example.com/ext/bc/{BLOCKCHAIN_ID}/rpc")
+        );
+        assert!(resolver
+            .resolve_rpc_url(NodeId::from_str(OTHER_NODE_ID).unwrap(), &blockchain())
+            .is_err());
+
+        let resolver = StaticEndpointResolver::new(
+            &node_endpoints,
+            Some(Box::new(ConstantResolver(
+                "http://127.0.0.1:9650/ext/bc/C/rpc".to_string(),
+            ))),
+        );
+        assert_eq!(
+            resolver
+                .resolve_rpc_url(NodeId::from_str(OTHER_NODE_ID).unwrap(), &blockchain())
+                .unwrap(),
+            "http://127.0.0.1:9650/ext/bc/C/rpc"
+        );
+    }
+}
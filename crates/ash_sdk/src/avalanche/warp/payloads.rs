@@ -0,0 +1,365 @@
+// SPDX-License-Identifier: BSD-3-Clause
+// Copyright (c) 2023, E36 Knots
+
+// Module that contains the standard Avalanche Warp Messaging payloads
+
+use crate::{
+    avalanche::warp::codec::{pack_bytes, pack_codec_type_id, Unpacker},
+    errors::*,
+};
+use avalanche_types::ids::{node::Id as NodeId, short::Id as ShortId, Id};
+use ethers::types::Bytes;
+use serde::{Deserialize, Serialize};
+
+/// Type ID of the Hash payload
+pub const HASH_PAYLOAD_TYPE_ID: u32 = 0;
+/// Type ID of the AddressedCall payload
+pub const ADDRESSED_CALL_PAYLOAD_TYPE_ID: u32 = 1;
+
+/// Hash payload
+/// See https://github.com/ava-labs/avalanchego/blob/master/vms/platformvm/warp/payload/hash.go
+#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct HashPayload {
+    pub hash: Id,
+}
+
+impl HashPayload {
+    /// Encode the payload to bytes
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![];
+        pack_codec_type_id(&mut bytes, HASH_PAYLOAD_TYPE_ID);
+        bytes.extend_from_slice(&self.hash.to_vec());
+
+        bytes
+    }
+}
+
+impl TryFrom<&[u8]> for HashPayload {
+    type Error = AshError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, AshError> {
+        let mut unpacker = Unpacker::new(bytes);
+        unpacker.read_codec_version()?;
+        check_type_id(&mut unpacker, HASH_PAYLOAD_TYPE_ID)?;
+        let hash = Id::from_slice(unpacker.read_fixed("hash", 32)?);
+        unpacker.finish("Hash payload")?;
+
+        Ok(Self { hash })
+    }
+}
+
+/// AddressedCall payload
+/// See https://github.com/ava-labs/avalanchego/blob/master/vms/platformvm/warp/payload/addressed_call.go
+#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AddressedCall {
+    pub source_address: Bytes,
+    pub payload: Bytes,
+}
+
+impl AddressedCall {
+    /// Encode the payload to bytes
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![];
+        pack_codec_type_id(&mut bytes, ADDRESSED_CALL_PAYLOAD_TYPE_ID);
+        pack_bytes(&mut bytes, &self.source_address);
+        pack_bytes(&mut bytes, &self.payload);
+
+        bytes
+    }
+}
+
+impl TryFrom<&[u8]> for AddressedCall {
+    type Error = AshError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, AshError> {
+        let mut unpacker = Unpacker::new(bytes);
+        unpacker.read_codec_version()?;
+        check_type_id(&mut unpacker, ADDRESSED_CALL_PAYLOAD_TYPE_ID)?;
+        let source_address = Bytes::from(unpacker.read_bytes("source address")?.to_vec());
+        let payload = Bytes::from(unpacker.read_bytes("payload")?.to_vec());
+        unpacker.finish("AddressedCall payload")?;
+
+        Ok(Self {
+            source_address,
+            payload,
+        })
+    }
+}
+
+/// P-Chain Warp message
+/// These messages are sent by the P-Chain as AddressedCall payloads with an empty source address
+/// See https://github.com/ava-labs/avalanchego/tree/master/vms/platformvm/warp/message
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum PlatformVMWarpMessage {
+    SubnetToL1Conversion(SubnetToL1Conversion),
+    RegisterL1Validator(RegisterL1Validator),
+    L1ValidatorRegistration(L1ValidatorRegistration),
+    L1ValidatorWeight(L1ValidatorWeight),
+}
+
+impl PlatformVMWarpMessage {
+    /// Encode the message to bytes
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![];
+
+        match self {
+            PlatformVMWarpMessage::SubnetToL1Conversion(message) => {
+                pack_codec_type_id(&mut bytes, 0);
+                bytes.extend_from_slice(&message.conversion_id.to_vec());
+            }
+            PlatformVMWarpMessage::RegisterL1Validator(message) => {
+                pack_codec_type_id(&mut bytes, 1);
+                bytes.extend_from_slice(&message.subnet_id.to_vec());
+                pack_bytes(&mut bytes, message.node_id.as_ref());
+                bytes.extend_from_slice(&message.bls_public_key);
+                bytes.extend_from_slice(&message.expiry.to_be_bytes());
+                message.remaining_balance_owner.pack(&mut bytes);
+                message.disable_owner.pack(&mut bytes);
+                bytes.extend_from_slice(&message.weight.to_be_bytes());
+            }
+            PlatformVMWarpMessage::L1ValidatorRegistration(message) => {
+                pack_codec_type_id(&mut bytes, 2);
+                bytes.extend_from_slice(&message.validation_id.to_vec());
+                bytes.push(message.registered as u8);
+            }
+            PlatformVMWarpMessage::L1ValidatorWeight(message) => {
+                pack_codec_type_id(&mut bytes, 3);
+                bytes.extend_from_slice(&message.validation_id.to_vec());
+                bytes.extend_from_slice(&message.nonce.to_be_bytes());
+                bytes.extend_from_slice(&message.weight.to_be_bytes());
+            }
+        }
+
+        bytes
+    }
+}
+
+impl TryFrom<&[u8]> for PlatformVMWarpMessage {
+    type Error = AshError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, AshError> {
+        let mut unpacker = Unpacker::new(bytes);
+        unpacker.read_codec_version()?;
+
+        let message = match unpacker.read_u32("type ID")? {
+            0 => PlatformVMWarpMessage::SubnetToL1Conversion(SubnetToL1Conversion {
+                conversion_id: Id::from_slice(unpacker.read_fixed("conversion ID", 32)?),
+            }),
+            1 => PlatformVMWarpMessage::RegisterL1Validator(RegisterL1Validator {
+                subnet_id: Id::from_slice(unpacker.read_fixed("subnet ID", 32)?),
+                node_id: {
+                    let node_id = unpacker.read_bytes("node ID")?;
+                    if node_id.len() != 20 {
+                        return Err(AvalancheWarpMessagingError::ParseFailure {
+                            property: "node ID".to_string(),
+                            msg: format!("expected 20 bytes, found {}", node_id.len()),
+                        }
+                        .into());
+                    }
+                    NodeId::from_slice(node_id)
+                },
+                bls_public_key: unpacker.read_fixed("BLS public key", 48)?.to_vec(),
+                expiry: unpacker.read_u64("expiry")?,
+                remaining_balance_owner: PChainOwner::unpack(&mut unpacker)?,
+                disable_owner: PChainOwner::unpack(&mut unpacker)?,
+                weight: unpacker.read_u64("weight")?,
+            }),
+            2 => PlatformVMWarpMessage::L1ValidatorRegistration(L1ValidatorRegistration {
+                validation_id: Id::from_slice(unpacker.read_fixed("validation ID", 32)?),
+                registered: unpacker.read_bool("registered")?,
+            }),
+            3 => PlatformVMWarpMessage::L1ValidatorWeight(L1ValidatorWeight {
+                validation_id: Id::from_slice(unpacker.read_fixed("validation ID", 32)?),
+                nonce: unpacker.read_u64("nonce")?,
+                weight: unpacker.read_u64("weight")?,
+            }),
+            type_id => {
+                return Err(AvalancheWarpMessagingError::ParseFailure {
+                    property: "type ID".to_string(),
+                    msg: format!("unknown P-Chain message type ID {type_id}"),
+                }
+                .into())
+            }
+        };
+        unpacker.finish("P-Chain message")?;
+
+        Ok(message)
+    }
+}
+
+/// Conversion of a Subnet to an L1
+#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SubnetToL1Conversion {
+    /// ID of the conversion data (SHA256 of the conversion data)
+    #[serde(rename = "conversionID")]
+    pub conversion_id: Id,
+}
+
+/// Registration request of an L1 validator
+#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct RegisterL1Validator {
+    #[serde(rename = "subnetID")]
+    pub subnet_id: Id,
+    #[serde(rename = "nodeID")]
+    pub node_id: NodeId,
+    #[serde(
+        serialize_with = "ethers::types::serialize_bytes",
+        deserialize_with = "hex::deserialize"
+    )]
+    pub bls_public_key: Vec<u8>,
+    pub expiry: u64,
+    pub remaining_balance_owner: PChainOwner,
+    pub disable_owner: PChainOwner,
+    pub weight: u64,
+}
+
+/// Registration status of an L1 validator
+#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct L1ValidatorRegistration {
+    #[serde(rename = "validationID")]
+    pub validation_id: Id,
+    pub registered: bool,
+}
+
+/// Weight update of an L1 validator
+#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct L1ValidatorWeight {
+    #[serde(rename = "validationID")]
+    pub validation_id: Id,
+    pub nonce: u64,
+    pub weight: u64,
+}
+
+/// P-Chain owner of an L1 validator balance
+#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PChainOwner {
+    pub threshold: u32,
+    pub addresses: Vec<ShortId>,
+}
+
+impl PChainOwner {
+    fn pack(&self, bytes: &mut Vec<u8>) {
+        bytes.extend_from_slice(&self.threshold.to_be_bytes());
+        bytes.extend_from_slice(&(self.addresses.len() as u32).to_be_bytes());
+        for address in self.addresses.iter() {
+            bytes.extend_from_slice(address.as_ref());
+        }
+    }
+
+    fn unpack(unpacker: &mut Unpacker) -> Result<Self, AshError> {
+        let threshold = unpacker.read_u32("owner threshold")?;
+        let addresses_len = unpacker.read_u32("owner addresses")?;
+        let mut addresses = vec![];
+        for _ in 0..addresses_len {
+            addresses.push(ShortId::from_slice(
+                unpacker.read_fixed("owner address", 20)?,
+            ));
+        }
+
+        Ok(Self {
+            threshold,
+            addresses,
+        })
+    }
+}
+
+// Read the type ID and check that it is the expected one
+fn check_type_id(unpacker: &mut Unpacker, expected_type_id: u32) -> Result<(), AshError> {
+    let type_id = unpacker.read_u32("type ID")?;
+    if type_id != expected_type_id {
+        return Err(AvalancheWarpMessagingError::ParseFailure {
+            property: "type ID".to_string(),
+            msg: format!("expected type ID {expected_type_id}, found {type_id}"),
+        }
+        .into());
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_hash_payload_round_trip() {
+        let payload = HashPayload {
+            hash: Id::from_slice(&[7; 32]),
+        };
+
+        let bytes = payload.to_bytes();
+        assert_eq!(bytes.len(), 38);
+        assert_eq!(HashPayload::try_from(&bytes[..]).unwrap(), payload);
+    }
+
+    #[test]
+    fn test_addressed_call_round_trip() {
+        let payload = AddressedCall {
+            source_address: Bytes::from_str("0x8db97c7cece249c2b98bdc0226cc4c2a57bf52fc").unwrap(),
+            payload: Bytes::from_str("0x48656c6c6f20776f726c6421").unwrap(),
+        };
+
+        let bytes = payload.to_bytes();
+        assert_eq!(AddressedCall::try_from(&bytes[..]).unwrap(), payload);
+
+        // Trailing bytes are rejected
+        let mut bytes = bytes;
+        bytes.push(0);
+        assert!(AddressedCall::try_from(&bytes[..]).is_err());
+    }
+
+    #[test]
+    fn test_platformvm_warp_messages_round_trip() {
+        let messages = vec![
+            PlatformVMWarpMessage::SubnetToL1Conversion(SubnetToL1Conversion {
+                conversion_id: Id::from_slice(&[1; 32]),
+            }),
+            PlatformVMWarpMessage::RegisterL1Validator(RegisterL1Validator {
+                subnet_id: Id::from_slice(&[2; 32]),
+                node_id: NodeId::from_slice(&[3; 20]),
+                bls_public_key: vec![4; 48],
+                expiry: 1_700_000_000,
+                remaining_balance_owner: PChainOwner {
+                    threshold: 1,
+                    addresses: vec![ShortId::from_slice(&[5; 20])],
+                },
+                disable_owner: PChainOwner {
+                    threshold: 0,
+                    addresses: vec![],
+                },
+                weight: 100,
+            }),
+            PlatformVMWarpMessage::L1ValidatorRegistration(L1ValidatorRegistration {
+                validation_id: Id::from_slice(&[6; 32]),
+                registered: true,
+            }),
+            PlatformVMWarpMessage::L1ValidatorWeight(L1ValidatorWeight {
+                validation_id: Id::from_slice(&[7; 32]),
+                nonce: 2,
+                weight: 200,
+            }),
+        ];
+
+        for message in messages {
+            let bytes = message.to_bytes();
+            assert_eq!(
+                PlatformVMWarpMessage::try_from(&bytes[..]).unwrap(),
+                message
+            );
+        }
+    }
+
+    #[test]
+    fn test_platformvm_warp_message_unknown_type_id() {
+        let mut bytes = vec![];
+        pack_codec_type_id(&mut bytes, 42);
+
+        assert!(PlatformVMWarpMessage::try_from(&bytes[..]).is_err());
+    }
+}
@@ -166,6 +166,16 @@ mod tests {
         assert_eq!(name, "P-Chain");
         assert_eq!(vm_type, &AvalancheVmType::PlatformVM);
         assert_eq!(rpc_url, "https://api.ash.center/ext/bc/P");
+
+        assert_eq!(custom.node_endpoints.len(), 1);
+        assert_eq!(
+            custom.node_endpoints[0].node_id.to_string(),
+            "NodeID-7Xhw2mDxuDS44j42TCB6U5579esbSt3Lg"
+        );
+        assert_eq!(
+            custom.node_endpoints[0].http_endpoint,
+            "https://validator-1.ash.center"
+        );
     }
 
     #[test]
@@ -76,6 +76,8 @@ pub enum RpcError {
     },
     #[error("failed to query event logs on '{contract_addr}': {msg}")]
     EthLogsFailure { contract_addr: String, msg: String },
+    #[error("failed to send transaction to '{to_addr}': {msg}")]
+    EthTxFailure { to_addr: String, msg: String },
     #[error("unknown RPC error: {0}")]
     Unknown(String),
 }
@@ -108,6 +110,14 @@ pub enum AvalancheSubnetError {
         subnet_id: String,
         subnet_type: String,
     },
+    #[error("staking rules of Subnet '{subnet_id}' are not met: {msg}")]
+    StakingRulesViolation { subnet_id: String, msg: String },
+    #[error("failed to renew validator '{node_id}' of Subnet '{subnet_id}': {msg}")]
+    ValidatorRenewalFailure {
+        subnet_id: String,
+        node_id: String,
+        msg: String,
+    },
 }
 
 #[derive(Error, Debug, PartialEq)]
@@ -130,6 +140,8 @@ pub enum AvalancheWalletError {
     PrivateKeyGenerationFailure(String),
     #[error("failed to use provided private key: {0}")]
     InvalidPrivateKey(String),
+    #[error("failed to use provided public key: {0}")]
+    InvalidPublicKey(String),
     #[error("failed to create Avalanche wallet: {0}")]
     CreationFailure(String),
     #[error("failed to issue '{tx_type}' transaction on blockchain '{blockchain_name}': {msg}")]
@@ -138,6 +150,20 @@ pub enum AvalancheWalletError {
         tx_type: String,
         msg: String,
     },
+    #[error("transaction '{tx_id}' was not accepted on blockchain '{blockchain_name}': {msg}")]
+    TxAcceptanceFailure {
+        blockchain_name: String,
+        tx_id: String,
+        msg: String,
+    },
+    #[error("signer '{signer}' failed: {msg}")]
+    SignerFailure { signer: String, msg: String },
+    #[error("failed to use keystore '{path}': {msg}")]
+    KeystoreFailure { path: String, msg: String },
+    #[error("failed to handle partially signed '{tx_type}' transaction: {msg}")]
+    PartialTxFailure { tx_type: String, msg: String },
+    #[error("failed to decode transaction bytes: {0}")]
+    TxDecodingFailure(String),
 }
 
 #[derive(Error, Debug, PartialEq)]
@@ -160,8 +186,22 @@ pub enum AvalancheNodeError {
 pub enum AvalancheWarpMessagingError {
     #[error("failed to parse {property} of message: {msg}")]
     ParseFailure { property: String, msg: String },
+    #[error("failed to parse {property} of message: expected {expected} bytes, found {found}")]
+    TruncatedInput {
+        property: String,
+        expected: usize,
+        found: usize,
+    },
+    #[error("unsupported codec version {0}")]
+    UnsupportedCodecVersion(u16),
+    #[error("failed to parse {property} of message: {count} unexpected trailing bytes")]
+    TrailingBytes { property: String, count: usize },
     #[error("invalid message signature: {0}")]
     InvalidSignature(String),
+    #[error("failed to aggregate message signatures: {0}")]
+    SignatureAggregationFailure(String),
+    #[error("failed to resolve the RPC endpoint of node '{node_id}': {msg}")]
+    EndpointResolutionFailure { node_id: String, msg: String },
 }
 
 #[derive(Error, Debug, PartialEq)]
@@ -11,3 +11,4 @@ pub mod utils;
 extern crate enum_display_derive;
 
 pub use avalanche_types::ids;
+pub use ethers;